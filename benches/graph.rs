@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_algorithm::graph::dijkstra::{dijkstra, dijkstra_indexed, Graph};
+use std::collections::BTreeMap;
+
+/// 2000 个顶点，每个有序顶点对之间以 1/2 的概率有一条边，约 200 万条边。
+/// (2000 vertices with an edge between every ordered pair with probability 1/2, about 2 million
+/// edges.)
+const VERTICES: u32 = 2000;
+
+fn dense_graph() -> Graph<u32, u32> {
+  let mut rng = StdRng::seed_from_u64(2000);
+  let mut graph = BTreeMap::new();
+
+  for u in 0..VERTICES {
+    let mut edges = BTreeMap::new();
+    for v in (0..VERTICES).filter(|&v| v != u) {
+      if rng.gen_bool(0.5) {
+        edges.insert(v, rng.gen_range(1..1_000_000));
+      }
+    }
+    graph.insert(u, edges);
+  }
+
+  graph
+}
+
+/// `dijkstra` 的堆中最多有 O(E) 个条目，`dijkstra_indexed` 最多有 V 个。
+/// (The heap of `dijkstra` holds up to O(E) entries, the one of `dijkstra_indexed` at most V.)
+fn dijkstra_dense(c: &mut Criterion) {
+  let graph = dense_graph();
+
+  let mut group = c.benchmark_group("dijkstra_dense_2k");
+  group.sample_size(10);
+
+  group.bench_function("dijkstra", |b| {
+    b.iter(|| dijkstra(black_box(&graph), black_box(&0)))
+  });
+  group.bench_function("dijkstra_indexed", |b| {
+    b.iter(|| dijkstra_indexed(black_box(&graph), black_box(&0)))
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, dijkstra_dense);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_algorithm::search::linear_search::{find_byte, linear_search, linear_search_chunked};
+
+/// 10 MB 的干草堆，唯一的匹配位于末尾。 (A 10 MB haystack whose only match is at the end.)
+const LEN: usize = 10 * 1024 * 1024;
+
+fn linear_search_bytes(c: &mut Criterion) {
+  let mut haystack = vec![0u8; LEN];
+  haystack[LEN - 1] = 1;
+
+  let mut group = c.benchmark_group("linear_search_10mb");
+  group.sample_size(10);
+
+  group.bench_function("linear_search", |b| {
+    b.iter(|| linear_search(black_box(&1), black_box(&haystack)))
+  });
+  group.bench_function("linear_search_chunked", |b| {
+    b.iter(|| linear_search_chunked(black_box(&1), black_box(&haystack)))
+  });
+  group.bench_function("find_byte", |b| {
+    b.iter(|| find_byte(black_box(1), black_box(&haystack)))
+  });
+
+  group.finish();
+}
+
+fn linear_search_u32(c: &mut Criterion) {
+  // 与字节版本同样是 10 MB
+  // (10 MB, the same as the byte version)
+  let len = LEN / 4;
+  let mut haystack = vec![0u32; len];
+  haystack[len - 1] = 1;
+
+  let mut group = c.benchmark_group("linear_search_10mb_u32");
+  group.sample_size(10);
+
+  group.bench_function("linear_search", |b| {
+    b.iter(|| linear_search(black_box(&1), black_box(&haystack)))
+  });
+  group.bench_function("linear_search_chunked", |b| {
+    b.iter(|| linear_search_chunked(black_box(&1), black_box(&haystack)))
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, linear_search_bytes, linear_search_u32);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rust_algorithm::sorting::bench_support::{sorters, INPUTS, SIZES};
+
+// 每种输入一个分组，组内按算法和规模区分
+// (One group per input kind, with one benchmark per sorter and size)
+fn sorting(c: &mut Criterion) {
+  for (input_name, input) in INPUTS {
+    let mut group = c.benchmark_group(input_name);
+    group.sample_size(10);
+
+    for len in SIZES {
+      let data = input(len);
+
+      for sorter in sorters(len) {
+        group.bench_with_input(BenchmarkId::new(sorter.name(), len), &data, |b, data| {
+          b.iter_batched_ref(
+            || data.clone(),
+            |arr| sorter.sort(arr),
+            BatchSize::LargeInput,
+          )
+        });
+      }
+    }
+
+    group.finish();
+  }
+}
+
+criterion_group!(benches, sorting);
+criterion_main!(benches);
@@ -0,0 +1,356 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
+
+/// A* Search
+///
+/// A* finds a cheapest path from `start` to `goal` in a weighted graph with non-negative weights.
+/// Like `dijkstra`, it keeps a priority queue (binary heap) of vertices, but orders them by
+/// g + h, where g is the cost of the best known path from `start` and h is the caller's estimate
+/// of the remaining cost to `goal`. A good heuristic steers the search towards the goal and
+/// expands far fewer vertices; the zero heuristic turns A* back into Dijkstra's algorithm.
+///
+/// The returned path is a cheapest one as long as the heuristic is admissible: it never
+/// overestimates the real remaining cost. Vertices missing from `graph` are taken to have no
+/// outgoing edges.
+///
+/// A* 搜索
+///
+/// A* 在非负权重的带权图中查找从 `start` 到 `goal` 代价最小的路径。与 `dijkstra` 一样，它维护一个顶点的优先队列
+/// （二叉堆），但按 g + h 排序，其中 g 是从 `start` 出发已知的最佳路径代价，h 是调用者对到达 `goal` 剩余代价的估计。
+/// 好的启发函数会把搜索引向目标，展开的顶点少得多；启发函数恒为 0 时，A* 就退化为 Dijkstra 算法。
+///
+/// 只要启发函数是可采纳的（从不高估真实的剩余代价），返回的路径就是代价最小的。不在 `graph` 中的顶点被视为没有出边。
+///
+/// # Arguments
+///
+/// - `graph`: The graph represented as an adjacency list.（以邻接表表示的图。）
+/// - `start`: The first vertex of the path.（路径的起点。）
+/// - `goal`: The last vertex of the path.（路径的终点。）
+/// - `heuristic`: An estimate of the cost from a vertex to `goal`.（从某个顶点到 `goal` 的代价估计。）
+///
+/// # Returns
+///
+/// The vertices of a cheapest path from `start` to `goal`, both included, and its cost; or `None`
+/// if `goal` is unreachable. `E::default()` is taken as the zero cost.
+/// （从 `start` 到 `goal` 代价最小的路径上的顶点（包含两个端点）及其代价；`goal` 不可达时返回 `None`。
+/// `E::default()` 被视为代价 0。）
+///
+/// # Complexity
+///
+/// The time complexity is O((V + E) * log(V)) in the worst case, as for Dijkstra's algorithm; the
+/// better the heuristic, the fewer vertices are expanded. The space complexity is O(V).
+/// （最坏情况下时间复杂度与 Dijkstra 算法相同，为 O((V + E) * log(V))；启发函数越好，展开的顶点越少。
+/// 空间复杂度为 O(V)。）
+///
+/// # Example
+///
+/// ```
+/// let mut graph = BTreeMap::new();
+/// graph.insert(1, BTreeMap::from([(2, 5), (3, 2)]));
+/// graph.insert(3, BTreeMap::from([(2, 1)]));
+///
+/// assert_eq!(a_star(&graph, &1, &2, |_| 0), Some((vec![1, 3, 2], 3)));
+/// ```
+pub fn a_star<V, E, H>(
+  graph: &Graph<V, E>,
+  start: &V,
+  goal: &V,
+  heuristic: H,
+) -> Option<(Vec<V>, E)>
+where
+  V: Ord + Copy + Hash,
+  E: Ord + Copy + Default + Add<Output = E>,
+  H: Fn(&V) -> E,
+{
+  search(
+    *start,
+    *goal,
+    |v| {
+      graph
+        .get(&v)
+        .into_iter()
+        .flatten()
+        .map(|(&next, &weight)| (next, weight))
+    },
+    heuristic,
+  )
+}
+
+/// 在网格上用 A* 查找从 `start` 到 `goal` 的最短路径，网格中 `true` 表示可以通过，`false` 表示障碍。
+///
+/// Finds a shortest path on a grid from `start` to `goal` with A*, `true` cells being open and
+/// `false` ones blocked. Positions are `(row, column)`, each step moves to one of the four
+/// neighboring cells at a cost of 1, and the Manhattan distance to `goal` is the heuristic, which
+/// is admissible for such moves.
+///
+/// 位置为 `(行, 列)`，每一步移动到上下左右四个相邻格子之一，代价为 1；启发函数是到 `goal` 的曼哈顿距离，
+/// 对这种移动方式是可采纳的。
+///
+/// # Returns
+///
+/// The cells of a shortest path, both ends included, or `None` if there is none or `start` or
+/// `goal` is blocked or outside the grid.
+/// （最短路径经过的格子（包含两端）；没有路径，或 `start`、`goal` 是障碍或在网格外时返回 `None`。）
+///
+/// # Example
+///
+/// ```
+/// let grid = vec![vec![true, true], vec![false, true]];
+/// assert_eq!(a_star_grid(&grid, (1, 1), (0, 0)), Some(vec![(1, 1), (0, 1), (0, 0)]));
+/// ```
+pub fn a_star_grid(
+  grid: &[Vec<bool>],
+  start: (usize, usize),
+  goal: (usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+  let open = |(row, col): (usize, usize)| grid.get(row).and_then(|r| r.get(col)) == Some(&true);
+
+  if !open(start) || !open(goal) {
+    return None;
+  }
+
+  let neighbors = |(row, col): (usize, usize)| {
+    [
+      row.checked_sub(1).map(|r| (r, col)),
+      Some((row + 1, col)),
+      col.checked_sub(1).map(|c| (row, c)),
+      Some((row, col + 1)),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|&cell| open(cell))
+    .map(|cell| (cell, 1usize))
+  };
+  let manhattan = |&(row, col): &(usize, usize)| row.abs_diff(goal.0) + col.abs_diff(goal.1);
+
+  search(start, goal, neighbors, manhattan).map(|(path, _)| path)
+}
+
+/// A* 本身，`neighbors` 返回一个顶点的所有出边 `(终点, 权重)`。
+/// A* itself, `neighbors` returning every outgoing edge of a vertex as `(to, weight)`.
+fn search<V, E, N, I, H>(start: V, goal: V, neighbors: N, heuristic: H) -> Option<(Vec<V>, E)>
+where
+  V: Ord + Copy + Hash,
+  E: Ord + Copy + Default + Add<Output = E>,
+  N: Fn(V) -> I,
+  I: IntoIterator<Item = (V, E)>,
+  H: Fn(&V) -> E,
+{
+  // 每个顶点已知的最小代价 g 和它的前驱
+  // The smallest known cost g of every vertex and its predecessor
+  let mut best: HashMap<V, (E, Option<V>)> = HashMap::from([(start, (E::default(), None))]);
+  let mut prio = BinaryHeap::from([Reverse((heuristic(&start), E::default(), start))]);
+
+  while let Some(Reverse((_, cost, current))) = prio.pop() {
+    // 队列中过时的条目，已经找到了更便宜的路径
+    // A stale entry in the queue, a cheaper path having been found since
+    if cost > best[&current].0 {
+      continue;
+    }
+
+    if current == goal {
+      let mut path = vec![current];
+      while let Some(prev) = best[path.last().unwrap()].1 {
+        path.push(prev);
+      }
+      path.reverse();
+
+      return Some((path, cost));
+    }
+
+    for (next, weight) in neighbors(current) {
+      let next_cost = cost + weight;
+
+      if best.get(&next).is_none_or(|&(old, _)| next_cost < old) {
+        best.insert(next, (next_cost, Some(current)));
+        prio.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+      }
+    }
+  }
+
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+  use rust_algorithm::graph::bellman_ford::add_edge;
+  use rust_algorithm::graph::dijkstra::dijkstra;
+
+  fn cost_of(graph: &Graph<u32, u32>, path: &[u32]) -> u32 {
+    path.windows(2).map(|w| graph[&w[0]][&w[1]]).sum()
+  }
+
+  fn grid_from(rows: &[&str]) -> Vec<Vec<bool>> {
+    rows
+      .iter()
+      .map(|row| row.chars().map(|c| c == '.').collect())
+      .collect()
+  }
+
+  #[test]
+  fn small_graph() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'b', 7);
+    add_edge(&mut graph, 'a', 'c', 2);
+    add_edge(&mut graph, 'c', 'b', 3);
+    add_edge(&mut graph, 'b', 'd', 1);
+    add_edge(&mut graph, 'c', 'd', 9);
+
+    assert_eq!(
+      a_star(&graph, &'a', &'d', |_| 0),
+      Some((vec!['a', 'c', 'b', 'd'], 6))
+    );
+    assert_eq!(a_star(&graph, &'d', &'a', |_| 0), None);
+  }
+
+  #[test]
+  fn start_is_goal() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 1, 2, 4);
+
+    assert_eq!(a_star(&graph, &1, &1, |_| 0), Some((vec![1], 0)));
+    assert_eq!(a_star(&graph, &9, &9, |_| 0), Some((vec![9], 0)));
+    assert_eq!(
+      a_star_grid(&grid_from(&["."]), (0, 0), (0, 0)),
+      Some(vec![(0, 0)])
+    );
+  }
+
+  #[test]
+  fn zero_heuristic_matches_dijkstra() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let n = rng.gen_range(1..20u32);
+      let mut graph = BTreeMap::new();
+      for v in 0..n {
+        graph.insert(v, BTreeMap::new());
+      }
+      for _ in 0..rng.gen_range(0..4 * n) {
+        let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+
+        // `dijkstra` 不处理起点上的自环
+        // `dijkstra` does not handle a self-loop on the start vertex
+        if u != v {
+          add_edge(&mut graph, u, v, rng.gen_range(0..20));
+        }
+      }
+
+      let dists = dijkstra(&graph, &0);
+
+      for goal in 0..n {
+        let expected = match dists.get(&goal) {
+          Some(Some((_, d))) => Some(*d),
+          Some(None) => Some(0),
+          None => None,
+        };
+        let found = a_star(&graph, &0, &goal, |_| 0);
+
+        assert_eq!(found.as_ref().map(|(_, cost)| *cost), expected);
+
+        if let Some((path, cost)) = found {
+          assert_eq!((path[0], *path.last().unwrap()), (0, goal));
+          assert_eq!(cost_of(&graph, &path), cost);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn admissible_heuristic_keeps_optimal_cost() {
+    // 顶点是数轴上的点，边权不小于两点间的距离，所以 |v - goal| 是可采纳的
+    // Vertices are points on a line and weights are at least their distance, so |v - goal| is
+    // admissible
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let n = rng.gen_range(2..30u32);
+      let mut graph = BTreeMap::new();
+      for v in 0..n {
+        graph.insert(v, BTreeMap::new());
+      }
+      for _ in 0..rng.gen_range(0..4 * n) {
+        let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        add_edge(&mut graph, u, v, u.abs_diff(v) + rng.gen_range(0..5));
+      }
+
+      let goal = n - 1;
+      let informed = a_star(&graph, &0, &goal, |v| v.abs_diff(goal));
+      let uninformed = a_star(&graph, &0, &goal, |_| 0);
+
+      assert_eq!(
+        informed.map(|(_, cost)| cost),
+        uninformed.map(|(_, cost)| cost)
+      );
+    }
+  }
+
+  #[test]
+  fn grid_paths() {
+    let grid = grid_from(&[
+      ".....", //
+      ".###.", //
+      "...#.", //
+      "##.#.", //
+      "...#.", //
+    ]);
+    let path = a_star_grid(&grid, (4, 0), (4, 4)).unwrap();
+
+    assert_eq!(path.len() - 1, 16);
+    assert_eq!((path[0], *path.last().unwrap()), ((4, 0), (4, 4)));
+    assert!(path.windows(2).all(|w| {
+      let (a, b) = (w[0], w[1]);
+      a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1 && grid[b.0][b.1]
+    }));
+  }
+
+  #[test]
+  fn blocked_grids() {
+    let walled = grid_from(&[
+      "..#..", //
+      "..#..", //
+      "..#..", //
+    ]);
+
+    assert_eq!(a_star_grid(&walled, (0, 0), (2, 4)), None);
+    assert_eq!(a_star_grid(&walled, (0, 0), (0, 2)), None);
+    assert_eq!(a_star_grid(&walled, (0, 2), (0, 0)), None);
+    assert_eq!(a_star_grid(&walled, (0, 0), (9, 9)), None);
+    assert_eq!(a_star_grid(&[], (0, 0), (0, 0)), None);
+  }
+
+  #[test]
+  fn large_grid() {
+    let n = 500;
+    let mut grid = vec![vec![true; n]; n];
+
+    // 每隔一列竖一堵墙，交替在顶部和底部留出缺口，迫使路径蛇形前进
+    // A wall on every other column, with gaps alternately at the top and bottom, forces the
+    // path to snake
+    for col in (1..n - 1).step_by(2) {
+      let gap = if col % 4 == 1 { n - 1 } else { 0 };
+      for (row, line) in grid.iter_mut().enumerate() {
+        line[col] = row == gap;
+      }
+    }
+
+    let path = a_star_grid(&grid, (0, 0), (n - 1, n - 1)).unwrap();
+
+    assert_eq!((path[0], *path.last().unwrap()), ((0, 0), (n - 1, n - 1)));
+    // 249 个完整的偶数列、249 个缺口、第 498 列和第 499 列的最后一格
+    // 249 full even columns, 249 gaps, and the last cells of columns 498 and 499
+    assert_eq!(path.len(), 249 * n + 249 + 2);
+
+    let open = vec![vec![true; n]; n];
+    let straight = a_star_grid(&open, (0, 0), (n - 1, n - 1)).unwrap();
+    assert_eq!(straight.len(), 2 * n - 1);
+  }
+}
+
+fn main() {}
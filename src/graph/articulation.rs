@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+/// 查找无向图的所有割点（关节点）：删除后会使所在连通分量分裂的顶点。
+///
+/// Finds every articulation point (cut vertex) of an undirected graph: the vertices whose removal
+/// splits their connected component. A Depth-First Search gives every vertex its discovery time
+/// and its low-link, the earliest discovery time reachable from its subtree through at most one
+/// back edge. A vertex other than a DFS root is an articulation point if one of its children
+/// cannot reach above it, that is if the child's low-link is not below the vertex's discovery
+/// time; a root is one if it has more than one child in the DFS tree.
+///
+/// 深度优先搜索使用显式的栈而不是递归，所以很深的图（例如长路径）也不会导致栈溢出。每个连通分量都会被处理。
+/// 平行边和自环不影响结果。不在 `adj` 中的顶点被视为没有邻居。
+/// The search keeps an explicit stack instead of recursing, so deep graphs such as long paths
+/// cannot overflow the call stack. Every connected component is processed. Parallel edges and
+/// self-loops do not change the result. Vertices missing from `adj` are taken to have no
+/// neighbors.
+///
+/// # 参数 (Parameters)
+///
+/// - `adj`: 无向图的邻接表，每条边在两个端点的邻接表中各出现一次。
+///   (The adjacency lists of an undirected graph, every edge appearing in the lists of both of
+///   its endpoints.)
+///
+/// # 返回值 (Returns)
+///
+/// 按升序排列的所有割点。
+/// (Every articulation point, in ascending order.)
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let adj = HashMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![2])]);
+/// assert_eq!(articulation_points(&adj), vec![2]);
+/// ```
+///
+/// # 复杂度分析 (Complexity Analysis)
+///
+/// - 时间复杂度：O(V + E)，每个顶点和每条边都只被查看常数次。
+///   (Time Complexity: O(V + E), every vertex and edge being looked at a constant number of times.)
+/// - 空间复杂度：O(V)，用于发现时间、low 值和 DFS 栈。
+///   (Space Complexity: O(V), for the discovery times, the low-links and the DFS stack.)
+pub fn articulation_points(adj: &HashMap<u32, Vec<u32>>) -> Vec<u32> {
+  let mut disc: HashMap<u32, usize> = HashMap::new();
+  let mut low: HashMap<u32, usize> = HashMap::new();
+  let mut points = vec![];
+
+  // 按顺序选取根，使遍历顺序确定
+  // Roots are taken in order, keeping the traversal deterministic
+  let mut roots: Vec<u32> = adj.keys().copied().collect();
+  roots.sort_unstable();
+
+  for root in roots {
+    if disc.contains_key(&root) {
+      continue;
+    }
+
+    disc.insert(root, disc.len());
+    low.insert(root, disc[&root]);
+    let mut root_children = 0;
+
+    // 栈中每一项为 (顶点, 父节点, 下一个要查看的邻居的下标, 是否已经跳过了回到父节点的边)
+    // Every frame is (vertex, parent, index of the next neighbor to look at, whether the edge
+    // back to the parent has been skipped yet)
+    let mut stack = vec![(root, None, 0, false)];
+
+    while let Some(&(vertex, parent, next, skipped)) = stack.last() {
+      let neighbors = adj.get(&vertex).map_or(&[][..], Vec::as_slice);
+
+      let Some(&neighbor) = neighbors.get(next) else {
+        // 顶点的所有邻居都已查看完毕，把它的 low 值传给父节点
+        // Every neighbor of the vertex has been looked at, so pass its low-link to the parent
+        stack.pop();
+
+        if let Some(parent) = parent {
+          let low_vertex = low[&vertex];
+          low.insert(parent, low[&parent].min(low_vertex));
+
+          if parent == root {
+            root_children += 1;
+          } else if low_vertex >= disc[&parent] {
+            points.push(parent);
+          }
+        }
+
+        continue;
+      };
+
+      let top = stack.len() - 1;
+      stack[top].2 += 1;
+
+      // 只跳过一条回到父节点的边，其余的平行边是回边
+      // Only one edge back to the parent is skipped; any parallel one is a back edge
+      if Some(neighbor) == parent && !skipped {
+        stack[top].3 = true;
+        continue;
+      }
+
+      match disc.get(&neighbor) {
+        // 回边
+        // A back edge
+        Some(&time) => {
+          low.insert(vertex, low[&vertex].min(time));
+        }
+        // 树边，进入新的顶点
+        // A tree edge into a new vertex
+        None => {
+          disc.insert(neighbor, disc.len());
+          low.insert(neighbor, disc[&neighbor]);
+          stack.push((neighbor, Some(vertex), 0, false));
+        }
+      }
+    }
+
+    if root_children > 1 {
+      points.push(root);
+    }
+  }
+
+  // 一个顶点可能因为多个子节点而被加入多次
+  // A vertex may have been added once for each of several children
+  points.sort_unstable();
+  points.dedup();
+  points
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+  use std::collections::HashSet;
+
+  fn undirected(edges: &[(u32, u32)]) -> HashMap<u32, Vec<u32>> {
+    let mut adj: HashMap<u32, Vec<u32>> = HashMap::new();
+
+    for &(a, b) in edges {
+      adj.entry(a).or_default().push(b);
+      adj.entry(b).or_default().push(a);
+    }
+
+    adj
+  }
+
+  /// 连通分量的个数，跳过被删除的顶点。
+  /// (The number of connected components, leaving out the removed vertex.)
+  fn components(adj: &HashMap<u32, Vec<u32>>, removed: Option<u32>) -> usize {
+    let mut seen: HashSet<u32> = removed.into_iter().collect();
+    let mut count = 0;
+
+    for &start in adj.keys() {
+      if !seen.insert(start) {
+        continue;
+      }
+
+      count += 1;
+      let mut stack = vec![start];
+      while let Some(vertex) = stack.pop() {
+        for &neighbor in &adj[&vertex] {
+          if seen.insert(neighbor) {
+            stack.push(neighbor);
+          }
+        }
+      }
+    }
+
+    count
+  }
+
+  #[test]
+  fn path() {
+    let adj = undirected(&[(1, 2), (2, 3), (3, 4), (4, 5)]);
+
+    assert_eq!(articulation_points(&adj), vec![2, 3, 4]);
+  }
+
+  #[test]
+  fn cycle() {
+    let adj = undirected(&[(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)]);
+
+    assert_eq!(articulation_points(&adj), vec![]);
+  }
+
+  #[test]
+  fn two_cliques_joined_at_one_vertex() {
+    let mut edges = vec![];
+    for clique in [[1, 2, 3, 4], [4, 5, 6, 7]] {
+      for (i, &a) in clique.iter().enumerate() {
+        for &b in &clique[i + 1..] {
+          edges.push((a, b));
+        }
+      }
+    }
+
+    assert_eq!(articulation_points(&undirected(&edges)), vec![4]);
+  }
+
+  #[test]
+  fn disconnected() {
+    let mut adj = undirected(&[(1, 2), (2, 3), (10, 11), (11, 12), (12, 13), (20, 21)]);
+    adj.insert(30, vec![]);
+
+    assert_eq!(articulation_points(&adj), vec![2, 11, 12]);
+  }
+
+  #[test]
+  fn single_vertex() {
+    assert_eq!(articulation_points(&HashMap::from([(1, vec![])])), vec![]);
+    assert_eq!(articulation_points(&HashMap::from([(1, vec![1])])), vec![]);
+    assert_eq!(articulation_points(&HashMap::new()), vec![]);
+  }
+
+  #[test]
+  fn every_non_leaf_of_a_tree() {
+    // 1023 个顶点的完全二叉树，1..=511 是非叶子顶点
+    // A complete binary tree of 1023 vertices, 1..=511 being the non-leaves
+    let edges: Vec<(u32, u32)> = (2..=1023).map(|v| (v / 2, v)).collect();
+
+    assert_eq!(
+      articulation_points(&undirected(&edges)),
+      (1..=511).collect::<Vec<u32>>()
+    );
+  }
+
+  #[test]
+  fn parallel_edges() {
+    // 1 与 2 之间的两条平行边并不能让 2 不再是割点
+    // Two parallel edges between 1 and 2 do not keep 2 from being a cut vertex
+    let adj = undirected(&[(1, 2), (1, 2), (2, 3)]);
+    assert_eq!(articulation_points(&adj), vec![2]);
+
+    // 平行边本身不构成环，3 和 4 仍然是割点
+    // Parallel edges alone make no cycle, so 3 and 4 are still cut vertices
+    let adj = undirected(&[(3, 4), (4, 3), (2, 3), (4, 5)]);
+    assert_eq!(articulation_points(&adj), vec![3, 4]);
+  }
+
+  #[test]
+  fn deep_path_does_not_overflow() {
+    let n = 200_000;
+    let edges: Vec<(u32, u32)> = (1..n).map(|v| (v, v + 1)).collect();
+
+    assert_eq!(
+      articulation_points(&undirected(&edges)),
+      (2..n).collect::<Vec<u32>>()
+    );
+  }
+
+  #[test]
+  fn matches_removing_each_vertex() {
+    let mut rng = StdRng::seed_from_u64(418);
+
+    for _ in 0..200 {
+      let n = rng.gen_range(1..20);
+      let edges: Vec<(u32, u32)> = (0..rng.gen_range(0..2 * n))
+        .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+        .collect();
+      let mut adj = undirected(&edges);
+      for v in 0..n {
+        adj.entry(v).or_default();
+      }
+
+      let before = components(&adj, None);
+      let expected: Vec<u32> = (0..n)
+        .filter(|&v| components(&adj, Some(v)) > before)
+        .collect();
+
+      assert_eq!(articulation_points(&adj), expected, "{edges:?}");
+    }
+  }
+}
+
+fn main() {}
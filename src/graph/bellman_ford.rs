@@ -1,8 +1,12 @@
-use std::collections::BTreeMap;
-use std::ops::{Add, Neg};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::ops::Add;
 
 pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 
+/// 每个可达顶点的前驱和距离，起点映射到 `None`。
+/// (The predecessor and distance of every reachable vertex, the start being mapped to `None`.)
+pub type ShortestPaths<V, E> = BTreeMap<V, Option<(V, E)>>;
+
 /// Calculate the single-source shortest paths using the Bellman-Ford algorithm.
 ///
 /// This algorithm can handle graphs with negative-weight edges and can detect negative-weight cycles.
@@ -15,12 +19,15 @@ pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 ///            of neighboring nodes and edge weights. The types for nodes and edges must satisfy the
 ///            following requirements:
 ///   - `V`: The type of nodes, must implement `Ord` and `Copy` traits.
-///   - `E`: The type of edge weights, must implement `Ord`, `Copy`, `Add<Output = E>`, `Neg<Output = E>`,
-///          and `std::ops::Sub<Output = E>` traits.
+///   - `E`: The type of edge weights, must implement `Ord`, `Clone`, `Add<Output = E>` and `Default`
+///          traits, `E::default()` being the zero distance of the source. `u32` weights work too.
 ///
 /// - `start`: The source node from which to calculate the shortest paths. Its type should match the type
 ///            of nodes in the graph (`V`).
 ///
+/// Weights are cloned when they are added up or stored in the result, so they do not have to be
+/// `Copy`.
+///
 /// # 返回值 (Returns)
 ///
 /// If a negative-weight cycle is detected, `None` is returned. Otherwise, it returns a BTreeMap where each
@@ -76,77 +83,223 @@ pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 ///     }
 /// }
 /// ```
-pub fn bellman_ford<
-  V: Ord + Copy,
-  E: Ord + Copy + Add<Output = E> + Neg<Output = E> + std::ops::Sub<Output = E>,
->(
+pub fn bellman_ford<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
   graph: &Graph<V, E>,
   start: &V,
 ) -> Option<BTreeMap<V, Option<(V, E)>>> {
-  let mut ans: BTreeMap<V, Option<(V, E)>> = BTreeMap::new();
+  bellman_ford_with_cycle(graph, start).ok()
+}
+
+/// Calculate the single-source shortest paths using the Bellman-Ford algorithm, returning the
+/// negative-weight cycle that was found instead of just `None`.
+///
+/// 与 [`bellman_ford`] 相同，但检测到负权环时返回环本身，例如在套利检测中环就是要找的答案。
+/// 检测到负权环的松弛说明沿前驱回溯一定会进入一个环：先回溯 V 次落在环上，再沿前驱走一圈得到环上的顶点。
+/// Works like [`bellman_ford`], but when a negative-weight cycle is detected the cycle itself is
+/// returned, which is the answer in use cases such as arbitrage detection. A relaxation that still
+/// succeeds means that walking back along the predecessors runs into a cycle: the walk goes V
+/// steps back to land on the cycle, then once around it to collect its vertices.
+///
+/// 与 [`bellman_ford`] 一样，只有从 `start` 可达的负权环会被检测到。
+/// As with [`bellman_ford`], only negative-weight cycles reachable from `start` are detected.
+///
+/// # 返回值 (Returns)
+///
+/// `Ok` with the same map as [`bellman_ford`], or `Err` with the vertices of one negative-weight
+/// cycle in the order of its edges: every vertex has an edge to the next one, and the last one an
+/// edge back to the first.
+///
+/// # 使用示例 (Example)
+///
+/// ```rust
+/// let mut graph = BTreeMap::new();
+/// add_edge(&mut graph, 'a', 'b', 1);
+/// add_edge(&mut graph, 'b', 'c', -3);
+/// add_edge(&mut graph, 'c', 'b', 2);
+///
+/// assert_eq!(bellman_ford_with_cycle(&graph, &'a'), Err(vec!['c', 'b']));
+/// ```
+pub fn bellman_ford_with_cycle<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> Result<ShortestPaths<V, E>, Vec<V>> {
+  let mut ans: ShortestPaths<V, E> = BTreeMap::new();
 
   ans.insert(*start, None);
 
   for _ in 1..(graph.len()) {
     for (u, edges) in graph {
-      let dist_u = match ans.get(u) {
-        Some(Some((_, d))) => Some(*d),
-        Some(None) => None,
-        None => continue,
+      let Some(dist_u) = distance(&ans, u) else {
+        continue;
       };
 
       for (v, d) in edges {
+        let dist_v = dist_u.clone() + d.clone();
+
         match ans.get(v) {
-            Some(Some((_, dist)))
-            // if this is a longer path, do nothing
-            if match dist_u {
-              Some(dist_u) => dist_u + *d >= *dist,
-              None => d >= dist,
-            } => {}
-            Some(None) => {
-              match dist_u {
-                // if dist_u + d < 0 there is a negative loop going by start
-                // else it's just a longer path
-                Some(dist_u) if dist_u >= -*d => {}
-                // negative self edge or negative loop
-                _ => {
-                  if *d > *d + *d {
-                    return None;
-                  }
-                }
-              };
-            }
-            // it's a shorter path: either dist_v was infinite or it was longer than dist_u + d
-            _ => {
-              ans.insert(
-                *v,
-                Some((
-                  *u,
-                  match dist_u {
-                    Some(dist) => dist + *d,
-                    None => *d,
-                  },
-                )),
-              );
-            }
+          // a negative path back to the source means a negative loop going by it
+          Some(None) if dist_v < E::default() => return Err(negative_cycle(&ans, *u, *v)),
+          // the source stays at distance zero
+          Some(None) => {}
+          // if this is a longer path, do nothing
+          Some(Some((_, dist))) if dist_v >= *dist => {}
+          // it's a shorter path: either dist_v was infinite or it was longer than dist_u + d
+          _ => {
+            ans.insert(*v, Some((*u, dist_v)));
           }
+        }
       }
     }
   }
 
+  // an edge that can still be relaxed after V - 1 passes lies behind a negative loop
   for (u, edges) in graph {
+    let Some(dist_u) = distance(&ans, u) else {
+      continue;
+    };
+
     for (v, d) in edges {
-      match (ans.get(u), ans.get(v)) {
-        (Some(None), Some(None)) if *d > *d + *d => return None,
-        (Some(None), Some(Some((_, dv)))) if d < dv => return None,
-        (Some(Some((_, du))), Some(None)) if *du < -*d => return None,
-        (Some(Some((_, du))), Some(Some((_, dv)))) if *du + *d < *dv => return None,
-        (_, _) => {}
+      if distance(&ans, v).is_some_and(|dist_v| dist_u.clone() + d.clone() < dist_v) {
+        return Err(negative_cycle(&ans, *u, *v));
       }
     }
   }
 
-  Some(ans)
+  Ok(ans)
+}
+
+/// 已到达顶点的距离，起点的距离为 `E::default()`；未到达的顶点为 `None`。
+/// The distance of a vertex reached so far, `E::default()` for the start, or `None` for a vertex
+/// not reached.
+fn distance<V: Ord, E: Clone + Default>(ans: &ShortestPaths<V, E>, v: &V) -> Option<E> {
+  ans
+    .get(v)
+    .map(|entry| entry.as_ref().map_or_else(E::default, |(_, d)| d.clone()))
+}
+
+/// 边 `u -> v` 仍然可以松弛 `v` 时，把 `u` 当作 `v` 的前驱，沿前驱回溯找出负权环。
+/// Finds the negative-weight cycle behind the edge `u -> v` that can still relax `v`, taking `u`
+/// as the predecessor of `v` and walking back along the predecessors.
+fn negative_cycle<V: Ord + Copy, E>(ans: &ShortestPaths<V, E>, u: V, v: V) -> Vec<V> {
+  let pred = |x: V| {
+    if x == v {
+      u
+    } else {
+      // 前驱链一定会进入环，所以不会走到没有前驱的起点
+      // The chain of predecessors runs into a cycle, so it never reaches the start, which has none
+      ans[&x]
+        .as_ref()
+        .map(|(p, _)| *p)
+        .expect("the predecessors of a relaxed vertex run into a cycle")
+    }
+  };
+
+  // 前驱链在进入环之前最多经过每个顶点一次，回溯 V 次之后一定在环上
+  // The chain goes through every vertex at most once before the cycle, so V steps back land on it
+  let mut on_cycle = v;
+  for _ in 0..ans.len() {
+    on_cycle = pred(on_cycle);
+  }
+
+  let mut cycle = vec![on_cycle];
+  let mut x = pred(on_cycle);
+  while x != on_cycle {
+    cycle.push(x);
+    x = pred(x);
+  }
+
+  // 前驱链是反向的，翻转后得到沿边的顺序
+  // The predecessors run backwards, so reversing gives the order of the edges
+  cycle.reverse();
+  cycle
+}
+
+/// Calculate the single-source shortest paths using the Shortest Path Faster Algorithm (SPFA), a
+/// queue-based variant of Bellman-Ford.
+///
+/// 只有距离刚被降低的顶点的出边才值得再次松弛。SPFA 用 `VecDeque` 保存这些顶点，并用一个集合记录哪些顶点已经在
+/// 队列中，避免重复入队。它得到与 [`bellman_ford`] 相同的距离，但不必每次都做完固定的 V - 1 轮全图松弛，
+/// 在常见的稀疏图上快得多；最坏情况下仍为 O(V * E)。
+/// Only the edges leaving a vertex whose distance just went down are worth relaxing again. SPFA
+/// keeps those vertices in a `VecDeque`, with a set of the vertices already queued so none is
+/// queued twice. It finds the same distances as [`bellman_ford`] without always doing its fixed
+/// V - 1 passes over every edge, which is much faster on typical sparse graphs; the worst case is
+/// still O(V * E).
+///
+/// 没有负权环时，每个顶点最多入队 V 次；入队次数超过 V 说明存在从 `start` 可达的负权环。
+/// Without negative-weight cycles no vertex is queued more than V times, so a vertex queued more
+/// often reveals a negative-weight cycle reachable from `start`.
+///
+/// # 返回值 (Returns)
+///
+/// The same as [`bellman_ford`]: `None` if a negative-weight cycle is detected, otherwise the
+/// predecessor and distance of every reachable vertex. When several shortest paths reach a vertex,
+/// the predecessor kept may differ from the one of [`bellman_ford`].
+///
+/// # 使用示例 (Example)
+///
+/// ```rust
+/// let mut graph = BTreeMap::new();
+/// add_edge(&mut graph, 'a', 'b', 4);
+/// add_edge(&mut graph, 'a', 'c', 2);
+/// add_edge(&mut graph, 'c', 'b', -1);
+///
+/// assert_eq!(spfa(&graph, &'a'), bellman_ford(&graph, &'a'));
+/// ```
+pub fn spfa<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> Option<ShortestPaths<V, E>> {
+  search_spfa(graph, start).0
+}
+
+/// SPFA 本身，同时返回检查过的边数。 (SPFA itself, also returning how many edges it checked.)
+fn search_spfa<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> (Option<ShortestPaths<V, E>>, usize) {
+  let mut ans: ShortestPaths<V, E> = BTreeMap::new();
+  let mut queue = VecDeque::from([*start]);
+  let mut in_queue = BTreeSet::from([*start]);
+  let mut queued: BTreeMap<V, usize> = BTreeMap::new();
+  let mut checked = 0;
+
+  ans.insert(*start, None);
+
+  while let Some(u) = queue.pop_front() {
+    in_queue.remove(&u);
+
+    let dist_u = distance(&ans, &u).unwrap_or_default();
+
+    for (v, d) in graph.get(&u).into_iter().flatten() {
+      checked += 1;
+
+      let dist_v = dist_u.clone() + d.clone();
+
+      match ans.get(v) {
+        // 回到起点的路径为负，说明有经过起点的负权环
+        // A negative path back to the start means a negative-weight cycle through it
+        Some(None) if dist_v < E::default() => return (None, checked),
+        Some(None) => {}
+        Some(Some((_, dist))) if dist_v >= *dist => {}
+        _ => {
+          ans.insert(*v, Some((u, dist_v)));
+
+          if in_queue.insert(*v) {
+            queue.push_back(*v);
+
+            let count = queued.entry(*v).or_default();
+            *count += 1;
+            if *count > graph.len() {
+              return (None, checked);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  (Some(ans), checked)
 }
 
 pub fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c: E) {
@@ -157,6 +310,8 @@ pub fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c:
 #[cfg(test)]
 mod tests {
   use super::*;
+  use rust_algorithm::graph::dijkstra::dijkstra;
+  use rust_algorithm::graph::path::bellman_ford_path;
   use std::collections::BTreeMap;
 
   #[test]
@@ -200,6 +355,7 @@ mod tests {
         }
       }
     }
+    assert_eq!(spfa(&graph, &1), Some(dists.clone()));
     assert_eq!(bellman_ford(&graph, &1), Some(dists));
   }
 
@@ -240,6 +396,10 @@ mod tests {
     dists_e.insert('d', Some(('c', 51)));
     dists_e.insert('b', Some(('c', 39)));
     assert_eq!(bellman_ford(&graph, &'e'), Some(dists_e));
+
+    for start in ['a', 'b', 'c', 'd', 'e'] {
+      assert_eq!(spfa(&graph, &start), bellman_ford(&graph, &start));
+    }
   }
 
   #[test]
@@ -300,6 +460,10 @@ mod tests {
     dists_4.insert(4, None);
 
     assert_eq!(bellman_ford(&graph, &4), Some(dists_4));
+
+    for start in 0..5 {
+      assert_eq!(spfa(&graph, &start), bellman_ford(&graph, &start));
+    }
   }
 
   #[test]
@@ -321,6 +485,268 @@ mod tests {
     assert_eq!(bellman_ford(&graph, &2), None);
     assert_eq!(bellman_ford(&graph, &3), None);
     assert_eq!(bellman_ford(&graph, &4), None);
+
+    for start in 0..5 {
+      assert_eq!(spfa(&graph, &start), None);
+    }
+  }
+
+  #[test]
+  fn spfa_checks_fewer_edges_on_a_long_path() {
+    // 键的顺序与路径相反，每一轮 bellman_ford 只能向前推进一条边
+    // The keys run against the path, so every pass of bellman_ford only gets one edge further
+    let n = 1000;
+    let mut graph = BTreeMap::new();
+    for i in 0..n - 1 {
+      add_edge(&mut graph, n - i, n - i - 1, 1);
+    }
+
+    let (ans, checked) = search_spfa(&graph, &n);
+    let ans = ans.unwrap();
+
+    assert_eq!(ans[&1], Some((2, n - 1)));
+    assert_eq!(Some(ans), bellman_ford(&graph, &n));
+
+    // bellman_ford 总是做 V - 1 轮，每轮检查全部 E 条边
+    // bellman_ford always does V - 1 passes, each checking all E edges
+    let edges = graph.values().map(BTreeMap::len).sum::<usize>();
+    assert_eq!(checked, edges);
+    assert!(checked < (graph.len() - 1) * edges / 100);
+  }
+
+  #[test]
+  fn spfa_edge_cases() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 0, -1);
+    add_edge(&mut graph, 1, 2, 3);
+    add_edge(&mut graph, 2, 1, -3);
+
+    assert_eq!(spfa(&graph, &0), None);
+    assert_eq!(spfa(&graph, &1), bellman_ford(&graph, &1));
+    assert_eq!(spfa(&graph, &7), Some(BTreeMap::from([(7, None)])));
+
+    // 从起点可达、但不经过起点的负权环
+    // A negative-weight cycle reachable from the start without going through it
+    add_edge(&mut graph, 2, 3, 1);
+    add_edge(&mut graph, 3, 2, -2);
+
+    assert_eq!(spfa(&graph, &1), None);
+    assert_eq!(bellman_ford(&graph, &1), None);
+  }
+
+  #[test]
+  fn unsigned_weights() {
+    let mut graph: Graph<char, u32> = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'c', 12);
+    add_edge(&mut graph, 'a', 'd', 60);
+    add_edge(&mut graph, 'b', 'a', 10);
+    add_edge(&mut graph, 'c', 'b', 20);
+    add_edge(&mut graph, 'c', 'd', 32);
+    add_edge(&mut graph, 'e', 'a', 7);
+
+    for start in ['a', 'b', 'c', 'd', 'e'] {
+      let expected = dijkstra(&graph, &start);
+
+      assert_eq!(bellman_ford(&graph, &start), Some(expected.clone()));
+      assert_eq!(
+        bellman_ford_with_cycle(&graph, &start),
+        Ok(expected.clone())
+      );
+      assert_eq!(spfa(&graph, &start), Some(expected));
+    }
+
+    // 权重为 0 的环不是负权环
+    // A cycle of zero weight is not a negative one
+    add_edge(&mut graph, 'd', 'e', 0);
+    add_edge(&mut graph, 'e', 'd', 0);
+    add_edge(&mut graph, 'a', 'a', 0);
+
+    assert_eq!(bellman_ford(&graph, &'a').unwrap()[&'e'], Some(('d', 44)));
+    assert_eq!(spfa(&graph, &'a'), bellman_ford(&graph, &'a'));
+  }
+
+  /// 检查 `cycle` 是图中一个总权重为负的简单环。
+  /// (Checks that `cycle` is a simple cycle of the graph whose weights sum negative.)
+  fn assert_negative_cycle(graph: &Graph<u32, i32>, cycle: &[u32]) {
+    let mut distinct = cycle.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert!(!cycle.is_empty());
+    assert_eq!(distinct.len(), cycle.len(), "{cycle:?}");
+
+    let weight: i32 = (0..cycle.len())
+      .map(|i| graph[&cycle[i]][&cycle[(i + 1) % cycle.len()]])
+      .sum();
+    assert!(weight < 0, "{cycle:?} weighs {weight}");
+  }
+
+  #[test]
+  fn negative_loop_is_returned() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 1, 6);
+    add_edge(&mut graph, 0, 3, 7);
+    add_edge(&mut graph, 1, 2, 5);
+    add_edge(&mut graph, 1, 3, 8);
+    add_edge(&mut graph, 1, 4, -4);
+    add_edge(&mut graph, 2, 1, -4);
+    add_edge(&mut graph, 3, 2, -3);
+    add_edge(&mut graph, 3, 4, 9);
+    add_edge(&mut graph, 4, 0, 3);
+    add_edge(&mut graph, 4, 2, 7);
+
+    for start in 0..5 {
+      match bellman_ford_with_cycle(&graph, &start) {
+        Err(cycle) => assert_negative_cycle(&graph, &cycle),
+        Ok(ans) => panic!("no cycle found from {start}: {ans:?}"),
+      }
+    }
+  }
+
+  #[test]
+  fn negative_cycle_behind_a_path() {
+    // 0 -> 1 -> 2 -> 3 -> 4，环 4 -> 5 -> 6 -> 4 的总权重为 -2，起点不在环上
+    // 0 -> 1 -> 2 -> 3 -> 4 and the cycle 4 -> 5 -> 6 -> 4 weighing -2, the start being off it
+    let mut graph = BTreeMap::new();
+    for i in 0..4 {
+      add_edge(&mut graph, i, i + 1, 1);
+    }
+    add_edge(&mut graph, 4, 5, 2);
+    add_edge(&mut graph, 5, 6, -5);
+    add_edge(&mut graph, 6, 4, 1);
+    add_edge(&mut graph, 6, 7, 1);
+
+    let cycle = bellman_ford_with_cycle(&graph, &0).unwrap_err();
+    assert_negative_cycle(&graph, &cycle);
+
+    let mut vertices = cycle.clone();
+    vertices.sort_unstable();
+    assert_eq!(vertices, vec![4, 5, 6]);
+
+    assert_eq!(bellman_ford(&graph, &0), None);
+  }
+
+  #[test]
+  fn negative_cycle_through_start() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 1, 2);
+    add_edge(&mut graph, 1, 2, 1);
+    add_edge(&mut graph, 2, 0, -4);
+
+    let cycle = bellman_ford_with_cycle(&graph, &0).unwrap_err();
+    assert_negative_cycle(&graph, &cycle);
+    assert_eq!(cycle.len(), 3);
+
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 0, -1);
+    add_edge(&mut graph, 0, 1, 1);
+
+    assert_eq!(bellman_ford_with_cycle(&graph, &0), Err(vec![0]));
+  }
+
+  #[test]
+  fn negative_cycle_not_reachable_from_start() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 1, 1);
+    add_edge(&mut graph, 2, 3, -5);
+    add_edge(&mut graph, 3, 2, 1);
+    add_edge(&mut graph, 3, 0, 1);
+
+    // 环只影响从它可达的顶点，不影响从 0 出发的最短路径
+    // The cycle only matters to vertices reachable from it, not to the shortest paths from 0
+    assert_eq!(
+      bellman_ford_with_cycle(&graph, &0),
+      Ok(BTreeMap::from([(0, None), (1, Some((0, 1)))]))
+    );
+
+    let cycle = bellman_ford_with_cycle(&graph, &2).unwrap_err();
+    assert_negative_cycle(&graph, &cycle);
+    assert_eq!(cycle.len(), 2);
+  }
+
+  #[test]
+  fn with_cycle_agrees_with_bellman_ford() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'b', 1);
+    add_edge(&mut graph, 'b', 'c', -3);
+    add_edge(&mut graph, 'c', 'b', 2);
+
+    assert_eq!(bellman_ford_with_cycle(&graph, &'a'), Err(vec!['c', 'b']));
+    assert_eq!(bellman_ford(&graph, &'a'), None);
+
+    add_edge(&mut graph, 'c', 'b', 3);
+
+    assert_eq!(
+      bellman_ford_with_cycle(&graph, &'a').ok(),
+      bellman_ford(&graph, &'a')
+    );
+    assert!(bellman_ford(&graph, &'a').is_some());
+  }
+
+  /// 不是 `Copy` 的权重：数值加上路线标签，先按数值比较。
+  /// (A weight that is not `Copy`: a number with a route label, compared by the number first.)
+  #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+  struct Labeled(i64, String);
+
+  impl Add for Labeled {
+    type Output = Labeled;
+
+    fn add(self, rhs: Labeled) -> Labeled {
+      Labeled(self.0 + rhs.0, self.1 + &rhs.1)
+    }
+  }
+
+  #[test]
+  fn non_copy_weights() {
+    // graph_2，每条边的标签是它的终点
+    // graph_2, every edge labeled with its target
+    let edges = [
+      (0, 1, 6),
+      (0, 3, 7),
+      (1, 2, 5),
+      (1, 3, 8),
+      (1, 4, -4),
+      (2, 1, -2),
+      (3, 2, -3),
+      (3, 4, 9),
+      (4, 0, 3),
+      (4, 2, 7),
+    ];
+    let mut graph = BTreeMap::new();
+    let mut labeled = BTreeMap::new();
+    for (u, v, w) in edges {
+      add_edge(&mut graph, u, v, w);
+      add_edge(&mut labeled, u, v, Labeled(w, v.to_string()));
+    }
+
+    // 标签会影响距离相同的路径之间的选择，所以只比较距离
+    // Labels decide between paths of equal distance, so only the distances are compared
+    for start in 0..5 {
+      let distances: BTreeMap<u32, i64> = bellman_ford(&labeled, &start)
+        .unwrap()
+        .into_iter()
+        .map(|(v, entry)| (v, entry.map_or(0, |(_, Labeled(d, _))| d)))
+        .collect();
+      let expected: BTreeMap<u32, i64> = bellman_ford(&graph, &start)
+        .unwrap()
+        .into_iter()
+        .map(|(v, entry)| (v, entry.map_or(0, |(_, d)| d)))
+        .collect();
+
+      assert_eq!(distances, expected);
+    }
+
+    // 0 -> 3 -> 2 -> 1 -> 4
+    assert_eq!(
+      bellman_ford(&labeled, &0).unwrap()[&4],
+      Some((1, Labeled(-2, "3214".to_string())))
+    );
+    assert_eq!(
+      bellman_ford_path(&labeled, &0, &4),
+      Some((vec![0, 3, 2, 1, 4], Labeled(-2, "3214".to_string())))
+    );
+
+    add_edge(&mut labeled, 2, 1, Labeled(-4, "1".to_string()));
+    assert_eq!(bellman_ford(&labeled, &0), None);
   }
 }
 
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+/// 使用双向广度优先搜索查找无权图中从 `start` 到 `goal` 的最短路径。
+///
+/// Finds a shortest path from `start` to `goal` in an unweighted graph with bidirectional
+/// Breadth-First Search. One search grows from `start` and another from `goal`, each always
+/// expanding the smaller of the two frontiers by a full layer, until a newly discovered vertex has
+/// already been seen by the other side. With branching factor b and distance d this visits about
+/// 2 * b^(d/2) vertices instead of the b^d of a plain BFS.
+///
+/// 图必须是无向的：每条边都要在两个端点的邻接表中各出现一次，因为从 `goal` 出发的搜索也沿着 `adj` 前进。
+/// 不在 `adj` 中的顶点被视为没有邻居。
+/// The graph must be undirected: every edge has to appear in the adjacency lists of both of its
+/// endpoints, as the search from `goal` follows `adj` too. Vertices missing from `adj` are taken
+/// to have no neighbors.
+///
+/// # 参数 (Parameters)
+///
+/// - `adj`: 无向图的邻接表。
+///   (The adjacency lists of an undirected graph.)
+/// - `start`: 路径的起点。
+///   (The first vertex of the path.)
+/// - `goal`: 路径的终点。
+///   (The last vertex of the path.)
+///
+/// # 返回值 (Returns)
+///
+/// 返回从 `start` 到 `goal` 的一条最短路径（包含两个端点），`start == goal` 时为 `[start]`；不连通时返回 `None`。
+/// (A shortest path from `start` to `goal`, both included, which is `[start]` if they are equal,
+/// or `None` if they are not connected.)
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let adj = HashMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![2])]);
+/// assert_eq!(bidirectional_shortest_path(&adj, 1, 3), Some(vec![1, 2, 3]));
+/// ```
+///
+/// # 复杂度分析 (Complexity Analysis)
+///
+/// - 时间复杂度：最坏情况下为 O(V + E)，与普通 BFS 相同，但通常只访问图中很小的一部分。
+///   (Time Complexity: O(V + E) in the worst case, as for plain BFS, but usually only a small part
+///   of the graph is visited.)
+/// - 空间复杂度：O(V)，用于存储两侧已访问顶点的父节点。
+///   (Space Complexity: O(V), for the parents of the vertices both sides have visited.)
+pub fn bidirectional_shortest_path(
+  adj: &HashMap<u32, Vec<u32>>,
+  start: u32,
+  goal: u32,
+) -> Option<Vec<u32>> {
+  search(adj, start, goal).0
+}
+
+/// 双向搜索本身，同时返回展开（遍历过邻接表）的顶点个数。
+/// The bidirectional search itself, also returning how many vertices were expanded (had their
+/// adjacency lists scanned).
+fn search(adj: &HashMap<u32, Vec<u32>>, start: u32, goal: u32) -> (Option<Vec<u32>>, usize) {
+  if start == goal {
+    return (Some(vec![start]), 0);
+  }
+
+  // 每一侧已访问顶点的父节点，起点的父节点是它自己
+  // The parent of every vertex each side has visited, a side's origin being its own parent
+  let mut forward = HashMap::from([(start, start)]);
+  let mut backward = HashMap::from([(goal, goal)]);
+  let mut forward_frontier = vec![start];
+  let mut backward_frontier = vec![goal];
+  let mut expanded = 0;
+
+  while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+    // 总是展开较小的一侧
+    // Always expand the smaller side
+    let from_start = forward_frontier.len() <= backward_frontier.len();
+    let (frontier, parents, others) = if from_start {
+      (&mut forward_frontier, &mut forward, &backward)
+    } else {
+      (&mut backward_frontier, &mut backward, &forward)
+    };
+
+    let mut next = vec![];
+
+    for &vertex in frontier.iter() {
+      expanded += 1;
+
+      for &neighbor in adj.get(&vertex).into_iter().flatten() {
+        if parents.contains_key(&neighbor) {
+          continue;
+        }
+
+        parents.insert(neighbor, vertex);
+
+        // 两侧在 neighbor 处相遇；在无向图中第一次相遇时的路径就是最短的
+        // The sides meet at neighbor; in an undirected graph the first meeting gives a shortest
+        // path
+        if others.contains_key(&neighbor) {
+          return (Some(stitch(&forward, &backward, neighbor)), expanded);
+        }
+
+        next.push(neighbor);
+      }
+    }
+
+    *frontier = next;
+  }
+
+  (None, expanded)
+}
+
+/// 把两侧到相遇顶点的半条路径拼接起来，相遇顶点只出现一次。
+/// Joins the two half-paths to the meeting vertex, which appears only once.
+fn stitch(forward: &HashMap<u32, u32>, backward: &HashMap<u32, u32>, meet: u32) -> Vec<u32> {
+  // start -> meet，沿父节点回溯后反转
+  // start -> meet, walked back along the parents and reversed
+  let mut path = vec![meet];
+  let mut vertex = meet;
+  while forward[&vertex] != vertex {
+    vertex = forward[&vertex];
+    path.push(vertex);
+  }
+  path.reverse();
+
+  // meet -> goal，backward 的父节点已经指向 goal，不需要反转
+  // meet -> goal, where the backward parents already lead towards goal, needing no reversal
+  let mut vertex = meet;
+  while backward[&vertex] != vertex {
+    vertex = backward[&vertex];
+    path.push(vertex);
+  }
+
+  path
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+  use std::collections::VecDeque;
+
+  fn undirected(edges: &[(u32, u32)]) -> HashMap<u32, Vec<u32>> {
+    let mut adj: HashMap<u32, Vec<u32>> = HashMap::new();
+
+    for &(a, b) in edges {
+      adj.entry(a).or_default().push(b);
+      adj.entry(b).or_default().push(a);
+    }
+
+    adj
+  }
+
+  /// 普通 BFS，返回最短距离和展开的顶点个数。
+  /// (Plain BFS, returning the shortest distance and the number of vertices expanded.)
+  fn plain_bfs(adj: &HashMap<u32, Vec<u32>>, start: u32, goal: u32) -> (Option<usize>, usize) {
+    let mut dist = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    let mut expanded = 0;
+
+    while let Some(vertex) = queue.pop_front() {
+      if vertex == goal {
+        return (Some(dist[&vertex]), expanded);
+      }
+
+      expanded += 1;
+
+      for &neighbor in adj.get(&vertex).into_iter().flatten() {
+        if !dist.contains_key(&neighbor) {
+          dist.insert(neighbor, dist[&vertex] + 1);
+          queue.push_back(neighbor);
+        }
+      }
+    }
+
+    (None, expanded)
+  }
+
+  fn is_path(adj: &HashMap<u32, Vec<u32>>, path: &[u32]) -> bool {
+    path.windows(2).all(|w| adj[&w[0]].contains(&w[1]))
+  }
+
+  #[test]
+  fn start_is_goal() {
+    let adj = undirected(&[(1, 2)]);
+
+    assert_eq!(bidirectional_shortest_path(&adj, 1, 1), Some(vec![1]));
+    assert_eq!(bidirectional_shortest_path(&adj, 9, 9), Some(vec![9]));
+  }
+
+  #[test]
+  fn adjacent_vertices() {
+    let adj = undirected(&[(1, 2), (2, 3)]);
+
+    assert_eq!(bidirectional_shortest_path(&adj, 1, 2), Some(vec![1, 2]));
+    assert_eq!(bidirectional_shortest_path(&adj, 2, 1), Some(vec![2, 1]));
+  }
+
+  #[test]
+  fn meeting_vertex_appears_once() {
+    let adj = undirected(&[(1, 2), (2, 3), (3, 4), (4, 5)]);
+
+    for (start, goal) in [(1, 5), (5, 1), (1, 4), (2, 5)] {
+      let path = bidirectional_shortest_path(&adj, start, goal).unwrap();
+      let expected: Vec<u32> = if start < goal {
+        (start..=goal).collect()
+      } else {
+        (goal..=start).rev().collect()
+      };
+
+      assert_eq!(path, expected);
+    }
+  }
+
+  #[test]
+  fn disconnected() {
+    let adj = undirected(&[(1, 2), (3, 4)]);
+
+    assert_eq!(bidirectional_shortest_path(&adj, 1, 4), None);
+    assert_eq!(bidirectional_shortest_path(&adj, 1, 99), None);
+    assert_eq!(bidirectional_shortest_path(&adj, 99, 1), None);
+  }
+
+  #[test]
+  fn shortest_among_several_routes() {
+    // 1 - 2 - 3 - 4 - 6 与捷径 1 - 5 - 6
+    // 1 - 2 - 3 - 4 - 6 and the shortcut 1 - 5 - 6
+    let adj = undirected(&[(1, 2), (2, 3), (3, 4), (4, 6), (1, 5), (5, 6)]);
+
+    assert_eq!(bidirectional_shortest_path(&adj, 1, 6), Some(vec![1, 5, 6]));
+    assert_eq!(bidirectional_shortest_path(&adj, 6, 1), Some(vec![6, 5, 1]));
+  }
+
+  #[test]
+  fn long_path_expands_far_fewer_vertices() {
+    // 起点挂着 10000 个叶子，终点在一条长为 200 的路径的另一端
+    // The start has 10000 leaves hanging off it and the goal is at the far end of a path of 200
+    let mut edges: Vec<(u32, u32)> = (0..200).map(|i| (i, i + 1)).collect();
+    edges.extend((0..10_000).map(|i| (0, 100_000 + i)));
+    let adj = undirected(&edges);
+
+    let (path, expanded) = search(&adj, 0, 200);
+    let (distance, plain_expanded) = plain_bfs(&adj, 0, 200);
+
+    assert_eq!(path, Some((0..=200).collect()));
+    assert_eq!(distance, Some(200));
+    assert!(expanded <= 250, "{expanded}");
+    assert!(plain_expanded >= 10_000, "{plain_expanded}");
+  }
+
+  #[test]
+  fn matches_plain_bfs_on_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for _ in 0..200 {
+      let n = rng.gen_range(2..40);
+      let edges: Vec<(u32, u32)> = (0..rng.gen_range(0..2 * n))
+        .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+        .collect();
+      let adj = undirected(&edges);
+      let (start, goal) = (rng.gen_range(0..n), rng.gen_range(0..n));
+
+      let path = bidirectional_shortest_path(&adj, start, goal);
+      let (distance, _) = plain_bfs(&adj, start, goal);
+
+      assert_eq!(path.as_ref().map(|p| p.len() - 1), distance, "{edges:?}");
+
+      if let Some(path) = path {
+        assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+        assert!(is_path(&adj, &path), "{path:?}");
+      }
+    }
+  }
+}
+
+fn main() {}
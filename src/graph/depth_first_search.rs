@@ -1,12 +1,17 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
-/// 使用深度优先搜索算法在图中查找从根节点到目标节点的路径。
+/// 使用深度优先搜索算法在图中查找目标节点，返回找到目标之前访问过的所有顶点。
 ///
-/// This function implements the Depth-First Search (DFS) algorithm to find a path from the root vertex
-/// to the target vertex within the given graph. The algorithm starts at the root vertex and explores
-/// as far as possible along each branch before backtracking. It returns the history of visited vertices
-/// in the form of a `Vec<u32>` if a path to the target vertex is found, or `None` if no path exists.
+/// This function implements the Depth-First Search (DFS) algorithm to find the target vertex within
+/// the given graph. The algorithm starts at the root vertex and explores as far as possible along
+/// each branch before backtracking. It returns the history of visited vertices in the form of a
+/// `Vec<u32>` if the target vertex is reachable, or `None` if it is not.
+///
+/// 访问顺序通常不是图中的一条路径；需要从根节点到目标节点的路径时使用 [`dfs_path`]。
+/// (The visit order is usually not a path in the graph; use [`dfs_path`] for the route from the root
+/// to the target vertex.)
 ///
 /// # 参数 (Parameters)
 ///
@@ -19,9 +24,9 @@ use std::collections::VecDeque;
 ///
 /// # 返回值 (Returns)
 ///
-/// 如果找到了从根节点到目标节点的路径，将返回一个包含访问历史的 `Vec<u32>`，表示经过的顶点值的顺序。如果未找到路径，则返回 `None`。
-/// (If a path from the root vertex to the target vertex is found, it returns a `Some` containing a `Vec<u32>`
-/// representing the order of visited vertices. If no path exists, it returns `None`.)
+/// 如果找到了目标节点，将返回一个包含访问历史的 `Vec<u32>`，表示访问顶点值的顺序。如果目标不可达，则返回 `None`。
+/// (If the target vertex is found, it returns a `Some` containing a `Vec<u32>` representing the order
+/// of visited vertices. If it is unreachable, it returns `None`.)
 ///
 /// # 示例 (Examples)
 ///
@@ -48,7 +53,7 @@ use std::collections::VecDeque;
 /// let objective_vertex = /* 目标顶点 */;
 ///
 /// // 使用深度优先搜索算法查找路径
-/// let result = depth_first_search(&graph, root_vertex, objective_vertex);
+/// let result = dfs_visit_order(&graph, root_vertex, objective_vertex);
 ///
 /// match result {
 ///     Some(history) => {
@@ -69,7 +74,7 @@ use std::collections::VecDeque;
 ///   (Space Complexity: The space complexity is O(V), where V is the number of vertices, used to store the set
 ///   of visited vertices.)
 ///
-pub fn depth_first_search(graph: &Graph, root: Vertex, objective: Vertex) -> Option<Vec<u32>> {
+pub fn dfs_visit_order(graph: &Graph, root: Vertex, objective: Vertex) -> Option<Vec<u32>> {
   let mut visited: HashSet<Vertex> = HashSet::new();
   let mut history: Vec<u32> = Vec::new();
   let mut queue = VecDeque::new();
@@ -109,6 +114,75 @@ pub fn depth_first_search(graph: &Graph, root: Vertex, objective: Vertex) -> Opt
   None
 }
 
+/// 使用深度优先搜索算法在图中查找目标节点，返回访问顺序。
+///
+/// Deprecated: this returns the visit order rather than a path. Use [`dfs_visit_order`] for the
+/// same result or [`dfs_path`] for the path from the root to the target vertex.
+#[deprecated(note = "use `dfs_visit_order` for the visit order or `dfs_path` for a path instead")]
+pub fn depth_first_search(graph: &Graph, root: Vertex, objective: Vertex) -> Option<Vec<u32>> {
+  dfs_visit_order(graph, root, objective)
+}
+
+/// 使用深度优先搜索查找从根节点到目标节点的路径。
+///
+/// Finds a path from the root vertex to the target vertex with Depth-First Search. Every vertex
+/// remembers the vertex it was discovered from, and once the target is reached the path is rebuilt
+/// by following those parents back to the root, so consecutive vertices of the result are always
+/// joined by an edge. The path is the one DFS happens to find, not necessarily the shortest.
+///
+/// # 参数 (Parameters)
+///
+/// - `graph`: 要搜索的图。
+///   (The graph to search.)
+/// - `root`: 开始搜索的起始顶点。
+///   (The starting vertex for the search.)
+/// - `objective`: 要查找的目标顶点。
+///   (The target vertex to find.)
+///
+/// # 返回值 (Returns)
+///
+/// 返回从 `root` 到 `objective` 依次经过的顶点值，两者相同时只含 `root`；目标不可达时返回 `None`。
+/// (The values of the vertices from `root` to `objective` in order, just `root` if the two are the
+/// same, or `None` if the target is unreachable.)
+///
+/// # 复杂度分析 (Complexity Analysis)
+///
+/// - 时间复杂度：O(V + E)，其中 V 为顶点数，E 为边数。
+///   (Time Complexity: O(V + E), where V is the number of vertices and E is the number of edges.)
+/// - 空间复杂度：O(V)，用于存储已访问的顶点和它们的父节点。
+///   (Space Complexity: O(V), for the visited vertices and their parents.)
+pub fn dfs_path(graph: &Graph, root: Vertex, objective: Vertex) -> Option<Vec<u32>> {
+  let mut parents: HashMap<Vertex, Vertex> = HashMap::new();
+  let mut visited: HashSet<Vertex> = HashSet::from([root]);
+  let mut stack = vec![root];
+
+  while let Some(current_vertex) = stack.pop() {
+    if current_vertex == objective {
+      // 沿着父节点回到根节点，再反转得到路径
+      // Follow the parents back to the root, then reverse to get the path
+      let mut path = vec![current_vertex.value()];
+      let mut vertex = current_vertex;
+
+      while let Some(&parent) = parents.get(&vertex) {
+        path.push(parent.value());
+        vertex = parent;
+      }
+
+      path.reverse();
+      return Some(path);
+    }
+
+    for neighbor in current_vertex.neighbors(graph).into_iter().rev() {
+      if visited.insert(neighbor) {
+        parents.insert(neighbor, current_vertex);
+        stack.push(neighbor);
+      }
+    }
+  }
+
+  None
+}
+
 // Data Structures
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Vertex(u32);
@@ -175,10 +249,7 @@ mod tests {
     let root = 1;
     let objective = 99;
     let graph = gen_graph(edges, root, objective);
-    assert_eq!(
-      depth_first_search(&graph, root.into(), objective.into()),
-      None
-    );
+    assert_eq!(dfs_visit_order(&graph, root.into(), objective.into()), None);
   }
 
   #[test]
@@ -189,7 +260,7 @@ mod tests {
     let correct_path = vec![1, 2, 4, 5, 3, 6, 7];
     let graph = gen_graph(edges, root, objective);
     assert_eq!(
-      depth_first_search(&graph, root.into(), objective.into()),
+      dfs_visit_order(&graph, root.into(), objective.into()),
       Some(correct_path)
     );
   }
@@ -212,7 +283,7 @@ mod tests {
     let correct_path = vec![0, 1, 3, 2, 4, 5, 7, 6];
     let graph = gen_graph(edges, root, objective);
     assert_eq!(
-      depth_first_search(&graph, root.into(), objective.into()),
+      dfs_visit_order(&graph, root.into(), objective.into()),
       Some(correct_path)
     );
   }
@@ -235,10 +306,91 @@ mod tests {
     let correct_path = vec![0, 1, 3, 2, 4];
     let graph = gen_graph(edges, root, objective);
     assert_eq!(
-      depth_first_search(&graph, root.into(), objective.into()),
+      dfs_visit_order(&graph, root.into(), objective.into()),
       Some(correct_path)
     );
   }
+  #[test]
+  #[allow(deprecated)]
+  fn deprecated_shim() {
+    let edges = vec![(1, 2), (1, 3), (2, 4), (2, 5), (3, 6), (3, 7)];
+    let graph = gen_graph(edges, 1, 7);
+
+    assert_eq!(
+      depth_first_search(&graph, 1.into(), 7.into()),
+      dfs_visit_order(&graph, 1.into(), 7.into())
+    );
+  }
+
+  /// 路径中每一对相邻的顶点之间都必须有边。 (Every consecutive pair of the path must be an edge.)
+  fn is_path(edges: &[(u32, u32)], path: &[u32]) -> bool {
+    path.windows(2).all(|w| edges.contains(&(w[0], w[1])))
+  }
+
+  #[test]
+  fn path_to_objective() {
+    let edges = vec![(1, 2), (1, 3), (2, 4), (2, 5), (3, 6), (3, 7)];
+    let graph = gen_graph(edges.clone(), 1, 7);
+    let path = dfs_path(&graph, 1.into(), 7.into()).unwrap();
+
+    assert_eq!(path, vec![1, 3, 7]);
+    assert!(is_path(&edges, &path));
+  }
+
+  #[test]
+  fn path_through_cycles() {
+    let edges = vec![
+      (0, 1),
+      (1, 3),
+      (3, 2),
+      (2, 1),
+      (3, 4),
+      (4, 5),
+      (5, 7),
+      (7, 6),
+      (6, 4),
+    ];
+    let graph = gen_graph(edges.clone(), 0, 6);
+
+    for objective in [1, 2, 3, 4, 5, 6, 7] {
+      let path = dfs_path(&graph, 0.into(), objective.into()).unwrap();
+
+      assert_eq!((path[0], *path.last().unwrap()), (0, objective));
+      assert!(is_path(&edges, &path), "{path:?}");
+    }
+
+    assert_eq!(
+      dfs_path(&graph, 0.into(), 6.into()),
+      Some(vec![0, 1, 3, 4, 5, 7, 6])
+    );
+  }
+
+  #[test]
+  fn path_back_to_root_in_cycle() {
+    let edges = vec![(1, 2), (2, 3), (3, 1)];
+    let graph = gen_graph(edges, 2, 1);
+
+    assert_eq!(dfs_path(&graph, 2.into(), 1.into()), Some(vec![2, 3, 1]));
+  }
+
+  #[test]
+  fn path_unreachable() {
+    let edges = vec![(1, 2), (2, 1), (3, 4)];
+    let graph = gen_graph(edges, 1, 4);
+
+    assert_eq!(dfs_path(&graph, 1.into(), 4.into()), None);
+    assert_eq!(dfs_path(&graph, 1.into(), 99.into()), None);
+    assert_eq!(dfs_path(&graph, 4.into(), 3.into()), None);
+  }
+
+  #[test]
+  fn path_root_is_objective() {
+    let edges = vec![(1, 2), (2, 1)];
+    let graph = gen_graph(edges, 1, 1);
+
+    assert_eq!(dfs_path(&graph, 1.into(), 1.into()), Some(vec![1]));
+    assert_eq!(dfs_path(&graph, 5.into(), 5.into()), Some(vec![5]));
+  }
 }
 
 fn main() {}
@@ -1,3 +1,5 @@
+use rust_algorithm::graph::indexed_heap::IndexedHeap;
+use rust_algorithm::graph::path::reconstruct_path;
 use std::cmp::Reverse;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::ops::Add;
@@ -22,6 +24,15 @@ pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 /// 它维护一个优先队列（二叉堆），最初包含距离为0的起始顶点。该算法反复选择优先队列中距离最小的顶点，
 /// 并探索其相邻顶点以更新它们的距离。它使用数据结构（BTreeMap）来跟踪每个顶点的最短距离和前驱顶点。
 ///
+/// 只作为边的终点出现、自己不是图的键的顶点被视为没有出边；图中不存在的起始顶点得到只包含它自己的结果。
+/// A vertex that only appears as the target of an edge, without being a key of the graph, is
+/// taken to have no outgoing edges; a start vertex missing from the graph gives a result holding
+/// only itself.
+///
+/// 权重只需要实现 `Clone`，存入结果映射表或优先队列时才会被克隆。
+/// Weights only need to be `Clone`; they are cloned when stored in the result map or the
+/// priority queue.
+///
 /// # Arguments
 ///
 /// - `graph`: The graph represented as an adjacency list.（以邻接表表示的图。）
@@ -60,7 +71,7 @@ pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 ///
 /// println!("{:?}", result);
 /// ```
-pub fn dijkstra<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
+pub fn dijkstra<V: Ord + Copy, E: Ord + Clone + Add<Output = E>>(
   graph: &Graph<V, E>,
   start: &V,
 ) -> BTreeMap<V, Option<(V, E)>> {
@@ -77,18 +88,18 @@ pub fn dijkstra<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
 
   // 将起始节点相邻的节点加入结果和优先队列
   // Add neighboring nodes of the start node to the result and priority queue.
-  for (new, weight) in &graph[start] {
-    ans.insert(*new, Some((*start, *weight)));
-    prio.push(Reverse((*weight, new, start)));
+  for (new, weight) in graph.get(start).into_iter().flatten() {
+    ans.insert(*new, Some((*start, weight.clone())));
+    prio.push(Reverse((weight.clone(), new, start)));
   }
 
   // 主循环，直到优先队列为空
   // Main loop, continues until the priority queue is empty.
   while let Some(Reverse((dist_new, new, prev))) = prio.pop() {
-    match ans[new] {
+    match &ans[new] {
       // 如果优先队列中的节点已经在结果中，跳过计算
       // If the node from the priority queue is already in the result, skip the computation.
-      Some((p, d)) if p == *prev && d == dist_new => {}
+      Some((p, d)) if p == prev && *d == dist_new => {}
       // 否则，继续处理下一个节点
       // Otherwise, continue to the next node.
       _ => continue,
@@ -96,19 +107,23 @@ pub fn dijkstra<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
 
     // 遍历当前节点相邻的节点
     // Iterate through the neighbors of the current node.
-    for (next, weight) in &graph[new] {
+    // 不在图中的顶点被视为没有出边
+    // Vertices missing from the graph are taken to have no outgoing edges.
+    for (next, weight) in graph.get(new).into_iter().flatten() {
+      let dist = dist_new.clone() + weight.clone();
+
       match ans.get(next) {
         // 如果结果中已经包含了更短的路径，跳过
         // If a shorter path to the node is already in the result, skip it.
-        Some(Some((_, dist_next))) if dist_new + *weight >= *dist_next => {}
+        Some(Some((_, dist_next))) if dist >= *dist_next => {}
         // 如果结果中没有节点，表示新节点是起始节点，不会再次加入优先队列
         // If the node is not in the result (None), it means it's the start node, and it won't be added to the priority queue again.
         Some(None) => {}
         // 否则，更新最短路径和加入优先队列
         // Otherwise, update the shortest path and add it to the priority queue.
         _ => {
-          ans.insert(*next, Some((*new, *weight + dist_new)));
-          prio.push(Reverse((*weight + dist_new, next, new)));
+          ans.insert(*next, Some((*new, dist.clone())));
+          prio.push(Reverse((dist, next, new)));
         }
       }
     }
@@ -119,10 +134,183 @@ pub fn dijkstra<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
   ans
 }
 
+/// 使用戴克斯特拉算法查找从 `start` 到 `goal` 的最短路径，在 `goal` 出队时立即停止。
+///
+/// Finds a shortest path from `start` to `goal` with Dijkstra's algorithm, stopping as soon as
+/// `goal` is popped from the priority queue instead of settling every reachable vertex. The
+/// closer `goal` is to `start`, the less of the graph is explored. As in [`dijkstra`], vertices
+/// missing from `graph` are taken to have no outgoing edges.
+///
+/// # Returns
+///
+/// The vertices of a shortest path from `start` to `goal`, both included, and its distance; or
+/// `None` if `goal` is unreachable. `goal == start` gives `[start]` with `E::default()` as the zero
+/// distance.
+/// （从 `start` 到 `goal` 的最短路径上的顶点（包含两个端点）及其距离；`goal` 不可达时返回 `None`。
+/// `goal == start` 时返回 `[start]`，距离为视为 0 的 `E::default()`。）
+///
+/// # Example
+///
+/// ```
+/// let mut graph = BTreeMap::new();
+/// graph.insert(1, BTreeMap::from([(2, 5), (3, 2)]));
+/// graph.insert(3, BTreeMap::from([(2, 1), (4, 4)]));
+///
+/// assert_eq!(dijkstra_to(&graph, &1, &2), Some((vec![1, 3, 2], 3)));
+/// ```
+pub fn dijkstra_to<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+  goal: &V,
+) -> Option<(Vec<V>, E)> {
+  search_to(graph, start, goal).0
+}
+
+/// `dijkstra_to` 本身，同时返回从优先队列中弹出的条目数。
+/// `dijkstra_to` itself, also returning how many entries were popped from the priority queue.
+fn search_to<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+  goal: &V,
+) -> (Option<(Vec<V>, E)>, usize) {
+  if goal == start {
+    return (Some((vec![*start], E::default())), 0);
+  }
+
+  let mut ans = BTreeMap::new();
+  let mut prio = BinaryHeap::new();
+  let mut pops = 0;
+
+  ans.insert(*start, None);
+
+  // 起始节点的自环不会带来更短的路径，跳过它以保持起始节点没有前驱
+  // A self-loop of the start node never gives a shorter path, and is skipped so that the start
+  // node keeps having no predecessor.
+  for (new, weight) in graph.get(start).into_iter().flatten() {
+    if new != start {
+      ans.insert(*new, Some((*start, weight.clone())));
+      prio.push(Reverse((weight.clone(), new, start)));
+    }
+  }
+
+  while let Some(Reverse((dist_new, new, prev))) = prio.pop() {
+    pops += 1;
+
+    match &ans[new] {
+      Some((p, d)) if p == prev && *d == dist_new => {}
+      _ => continue,
+    }
+
+    // 目标出队时它的距离已经确定，不需要再继续
+    // The distance of the goal is final once it is popped, so there is no need to go on.
+    if new == goal {
+      break;
+    }
+
+    for (next, weight) in graph.get(new).into_iter().flatten() {
+      let dist = dist_new.clone() + weight.clone();
+
+      match ans.get(next) {
+        Some(Some((_, dist_next))) if dist >= *dist_next => {}
+        Some(None) => {}
+        _ => {
+          ans.insert(*next, Some((*new, dist.clone())));
+          prio.push(Reverse((dist, next, new)));
+        }
+      }
+    }
+  }
+
+  (reconstruct_path(&ans, start, goal), pops)
+}
+
+/// 使用索引堆的戴克斯特拉算法，返回与 [`dijkstra`] 相同形式的结果。
+///
+/// Dijkstra's algorithm on an [`IndexedHeap`], returning a result of the same form as
+/// [`dijkstra`]. Instead of pushing a new entry on every relaxation and skipping stale ones when
+/// popped, it lowers the priority of the vertex already in the heap, so the heap never holds more
+/// than one entry per vertex: O(V) entries rather than O(E), which matters on dense graphs.
+///
+/// The distances are the same as those of [`dijkstra`]. When several shortest paths reach a
+/// vertex, the predecessor kept may differ, as vertices at equal distance can be settled in a
+/// different order.
+/// （距离与 [`dijkstra`] 相同。有多条最短路径到达同一个顶点时，保留的前驱可能不同，因为距离相同的顶点的确定顺序可能不同。）
+///
+/// # Complexity
+///
+/// The heap holds at most V entries, so the extra space is O(V). Every relaxation costs an
+/// O(log²(V)) decrease-key, as the heap keeps its positions in a `BTreeMap`.
+/// （堆中最多有 V 个条目，额外空间为 O(V)。由于堆用 `BTreeMap` 记录位置，每次松弛需要 O(log²(V)) 的 decrease-key。）
+///
+/// `cargo bench --bench graph` compares it with [`dijkstra`] on a dense graph of 2000 vertices
+/// and about 2 million edges: both run in about the same time, the cheaper heap operations of
+/// [`dijkstra`] making up for its larger heap, so the gain is the memory of the heap.
+/// （`cargo bench --bench graph` 在 2000 个顶点、约 200 万条边的稠密图上与 [`dijkstra`] 进行比较：两者的运行时间
+/// 大致相同，[`dijkstra`] 更廉价的堆操作抵消了它更大的堆，所以收益在于堆占用的内存。）
+pub fn dijkstra_indexed<V: Ord + Copy, E: Ord + Clone + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> BTreeMap<V, Option<(V, E)>> {
+  search_indexed(graph, start).0
+}
+
+/// `dijkstra_indexed` 本身，同时返回堆中同时存在的条目数的最大值。
+/// `dijkstra_indexed` itself, also returning the largest number of entries the heap held at once.
+fn search_indexed<V: Ord + Copy, E: Ord + Clone + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> (BTreeMap<V, Option<(V, E)>>, usize) {
+  let mut ans = BTreeMap::new();
+  let mut prio = IndexedHeap::new();
+
+  ans.insert(*start, None);
+
+  // 起始节点的自环不会带来更短的路径
+  // A self-loop of the start node never gives a shorter path.
+  for (new, weight) in graph.get(start).into_iter().flatten() {
+    if new != start {
+      ans.insert(*new, Some((*start, weight.clone())));
+      prio.push(*new, weight.clone());
+    }
+  }
+
+  let mut peak = prio.len();
+
+  // 堆中的每个条目都是最新的，不需要跳过过时的条目
+  // Every entry in the heap is up to date, so there are no stale entries to skip.
+  while let Some((new, dist_new)) = prio.pop() {
+    for (next, weight) in graph.get(&new).into_iter().flatten() {
+      let dist_next = dist_new.clone() + weight.clone();
+
+      match ans.get(next) {
+        Some(Some((_, dist))) if dist_next >= *dist => {}
+        Some(None) => {}
+        _ => {
+          ans.insert(*next, Some((new, dist_next.clone())));
+
+          // 已在堆中的顶点降低优先级，其余的顶点第一次入堆
+          // A vertex already in the heap gets a lower priority, any other enters it for the
+          // first time.
+          if !prio.decrease_key(next, dist_next.clone()) {
+            prio.push(*next, dist_next);
+          }
+        }
+      }
+    }
+
+    peak = peak.max(prio.len());
+  }
+
+  (ans, peak)
+}
+
 #[cfg(test)]
 mod tests {
-  use super::{dijkstra, Graph};
-  use crate::graph::bellman_ford::add_edge;
+  use super::{dijkstra, dijkstra_indexed, dijkstra_to, search_indexed, search_to, Graph};
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+  use rust_algorithm::graph::bellman_ford::add_edge;
+  use rust_algorithm::graph::path::{dijkstra_path, reconstruct_path};
   use std::collections::BTreeMap;
 
   #[test]
@@ -227,6 +415,286 @@ mod tests {
 
     assert_eq!(dijkstra(&graph, &'e'), dists_e);
   }
+
+  #[test]
+  fn neighbor_without_adjacency_entry() {
+    // 直接插入边，不经过 add_edge，所以 2 和 3 都不是图的键
+    // Edges inserted directly, bypassing add_edge, so neither 2 nor 3 is a key of the graph
+    let mut graph = BTreeMap::new();
+    graph.insert(1, BTreeMap::from([(2, 3), (3, 10)]));
+
+    let mut dists = BTreeMap::new();
+    dists.insert(1, None);
+    dists.insert(2, Some((1, 3)));
+    dists.insert(3, Some((1, 10)));
+
+    assert_eq!(dijkstra(&graph, &1), dists);
+
+    // 2 的边可以到达 4，而 4 同样不是图的键
+    // The edge out of 2 reaches 4, which is not a key of the graph either
+    graph.insert(2, BTreeMap::from([(4, 1), (3, 5)]));
+    dists.insert(3, Some((2, 8)));
+    dists.insert(4, Some((2, 4)));
+
+    assert_eq!(dijkstra(&graph, &1), dists);
+  }
+
+  #[test]
+  fn start_not_in_graph() {
+    let mut graph = BTreeMap::new();
+    graph.insert(1, BTreeMap::from([(2, 3)]));
+
+    let mut dists = BTreeMap::new();
+    dists.insert(7, None);
+
+    assert_eq!(dijkstra(&graph, &7), dists);
+
+    let empty: Graph<u32, u32> = BTreeMap::new();
+    assert_eq!(dijkstra(&empty, &0), BTreeMap::from([(0, None)]));
+  }
+
+  #[test]
+  fn dijkstra_to_on_graph_1() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'c', 12);
+    add_edge(&mut graph, 'a', 'd', 60);
+    add_edge(&mut graph, 'b', 'a', 10);
+    add_edge(&mut graph, 'c', 'b', 20);
+    add_edge(&mut graph, 'c', 'd', 32);
+    add_edge(&mut graph, 'e', 'a', 7);
+
+    assert_eq!(
+      dijkstra_to(&graph, &'e', &'d'),
+      Some((vec!['e', 'a', 'c', 'd'], 51))
+    );
+    assert_eq!(
+      dijkstra_to(&graph, &'c', &'a'),
+      Some((vec!['c', 'b', 'a'], 30))
+    );
+    assert_eq!(dijkstra_to(&graph, &'d', &'a'), None);
+    assert_eq!(dijkstra_to(&graph, &'a', &'e'), None);
+    assert_eq!(dijkstra_to(&graph, &'a', &'z'), None);
+  }
+
+  #[test]
+  fn dijkstra_to_goal_is_start() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 1, 4);
+    add_edge(&mut graph, 0, 0, 1);
+
+    assert_eq!(dijkstra_to(&graph, &0, &0), Some((vec![0], 0)));
+    assert_eq!(dijkstra_to(&graph, &1, &1), Some((vec![1], 0)));
+    assert_eq!(dijkstra_to(&graph, &9, &9), Some((vec![9], 0)));
+    assert_eq!(dijkstra_to(&graph, &0, &1), Some((vec![0, 1], 4)));
+  }
+
+  #[test]
+  fn dijkstra_to_stops_early_on_a_long_chain() {
+    let n = 100_000;
+    let mut graph = BTreeMap::new();
+    for i in 0..n {
+      add_edge(&mut graph, i, i + 1, 1);
+    }
+
+    let (path, pops) = search_to(&graph, &0, &3);
+
+    assert_eq!(path, Some((vec![0, 1, 2, 3], 3)));
+    assert_eq!(pops, 3);
+
+    let (path, pops) = search_to(&graph, &0, &n);
+
+    assert_eq!(path.map(|(_, d)| d), Some(n));
+    assert_eq!(pops, n as usize);
+  }
+
+  #[test]
+  fn dijkstra_to_matches_dijkstra_on_random_graphs() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+      let n = rng.gen_range(1..30u32);
+      let mut graph = BTreeMap::new();
+      for v in 0..n {
+        graph.insert(v, BTreeMap::new());
+      }
+      for _ in 0..rng.gen_range(0..4 * n) {
+        add_edge(
+          &mut graph,
+          rng.gen_range(0..n),
+          rng.gen_range(0..n),
+          rng.gen_range(0..20u32),
+        );
+      }
+
+      let start = rng.gen_range(0..n);
+      let dists = dijkstra(&graph, &start);
+
+      for goal in (0..n).filter(|&goal| goal != start) {
+        let expected = dists.get(&goal).map(|entry| entry.unwrap().1);
+
+        match dijkstra_to(&graph, &start, &goal) {
+          Some((path, dist)) => {
+            assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+            assert_eq!(
+              path.windows(2).map(|w| graph[&w[0]][&w[1]]).sum::<u32>(),
+              dist
+            );
+            assert_eq!(Some(dist), expected, "{graph:?}");
+          }
+          None => assert_eq!(expected, None, "{graph:?}"),
+        }
+      }
+    }
+  }
+
+  /// 不是 `Copy` 的权重：数值加上路线标签，先按数值比较。
+  /// (A weight that is not `Copy`: a number with a route label, compared by the number first.)
+  #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+  struct Labeled(u64, String);
+
+  impl std::ops::Add for Labeled {
+    type Output = Labeled;
+
+    fn add(self, rhs: Labeled) -> Labeled {
+      Labeled(self.0 + rhs.0, self.1 + &rhs.1)
+    }
+  }
+
+  #[test]
+  fn non_copy_weights() {
+    // graph_1，每条边的标签是它的终点
+    // graph_1, every edge labeled with its target
+    let edges = [
+      ('a', 'c', 12),
+      ('a', 'd', 60),
+      ('b', 'a', 10),
+      ('c', 'b', 20),
+      ('c', 'd', 32),
+      ('e', 'a', 7),
+    ];
+    let mut graph = BTreeMap::new();
+    let mut labeled = BTreeMap::new();
+    for (u, v, w) in edges {
+      add_edge(&mut graph, u, v, w);
+      add_edge(&mut labeled, u, v, Labeled(w, v.to_string()));
+    }
+
+    for start in ['a', 'b', 'c', 'd', 'e'] {
+      let numbers: BTreeMap<char, Option<(char, u64)>> = dijkstra(&labeled, &start)
+        .into_iter()
+        .map(|(v, entry)| (v, entry.map(|(p, Labeled(d, _))| (p, d))))
+        .collect();
+
+      assert_eq!(numbers, dijkstra(&graph, &start));
+
+      let indexed: BTreeMap<char, Option<(char, u64)>> = dijkstra_indexed(&labeled, &start)
+        .into_iter()
+        .map(|(v, entry)| (v, entry.map(|(p, Labeled(d, _))| (p, d))))
+        .collect();
+
+      assert_eq!(indexed, numbers);
+    }
+
+    // e -> a -> c -> d
+    let acd = Labeled(51, "acd".to_string());
+    let path = Some((vec!['e', 'a', 'c', 'd'], acd.clone()));
+    assert_eq!(dijkstra(&labeled, &'e')[&'d'], Some(('c', acd)));
+    assert_eq!(dijkstra_to(&labeled, &'e', &'d'), path);
+    assert_eq!(dijkstra_path(&labeled, &'e', &'d'), path);
+    assert_eq!(
+      reconstruct_path(&dijkstra(&labeled, &'e'), &'e', &'d'),
+      path
+    );
+    assert_eq!(
+      dijkstra_to(&labeled, &'e', &'e'),
+      Some((vec!['e'], Labeled::default()))
+    );
+    assert_eq!(dijkstra_to(&labeled, &'d', &'e'), None);
+  }
+
+  /// 把结果映射表转换成每个顶点的距离，起始顶点的距离为 0。
+  /// (Turns a result map into the distance of every vertex, the start being at 0.)
+  fn distances(result: &BTreeMap<u32, Option<(u32, u32)>>) -> BTreeMap<u32, u32> {
+    result
+      .iter()
+      .map(|(&v, entry)| (v, entry.map_or(0, |(_, d)| d)))
+      .collect()
+  }
+
+  #[test]
+  fn dijkstra_indexed_on_graph_1() {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'c', 12);
+    add_edge(&mut graph, 'a', 'd', 60);
+    add_edge(&mut graph, 'b', 'a', 10);
+    add_edge(&mut graph, 'c', 'b', 20);
+    add_edge(&mut graph, 'c', 'd', 32);
+    add_edge(&mut graph, 'e', 'a', 7);
+
+    for start in ['a', 'b', 'c', 'd', 'e'] {
+      assert_eq!(dijkstra_indexed(&graph, &start), dijkstra(&graph, &start));
+    }
+    assert_eq!(
+      dijkstra_indexed(&graph, &'z'),
+      BTreeMap::from([('z', None)])
+    );
+  }
+
+  #[test]
+  fn dijkstra_indexed_matches_dijkstra_on_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(411);
+
+    for _ in 0..200 {
+      let n = rng.gen_range(1..30u32);
+      let mut graph = BTreeMap::new();
+      for v in 0..n {
+        graph.insert(v, BTreeMap::new());
+      }
+      for _ in 0..rng.gen_range(0..4 * n) {
+        let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        if u != v {
+          add_edge(&mut graph, u, v, rng.gen_range(0..20u32));
+        }
+      }
+
+      let start = rng.gen_range(0..n);
+      let result = dijkstra_indexed(&graph, &start);
+
+      assert_eq!(
+        distances(&result),
+        distances(&dijkstra(&graph, &start)),
+        "{graph:?}"
+      );
+
+      // 每个前驱都位于一条最短路径上
+      // Every predecessor lies on a shortest path
+      for entry in result.values() {
+        if let Some((prev, dist)) = *entry {
+          let dist_prev = result[&prev].map_or(0, |(_, d)| d);
+          assert!(graph[&prev].values().any(|&w| dist_prev + w == dist));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn dijkstra_indexed_keeps_one_entry_per_vertex() {
+    // 完全图：普通的 dijkstra 每次松弛都会压入一个条目
+    // A complete graph, where the plain dijkstra pushes an entry on every relaxation
+    let n = 200;
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut graph = BTreeMap::new();
+    for u in 0..n {
+      for v in (0..n).filter(|&v| v != u) {
+        add_edge(&mut graph, u, v, rng.gen_range(1..1000u32));
+      }
+    }
+
+    let (result, peak) = search_indexed(&graph, &0);
+
+    assert_eq!(distances(&result), distances(&dijkstra(&graph, &0)));
+    assert!(peak < n as usize, "{peak}");
+  }
 }
 
 fn main() {}
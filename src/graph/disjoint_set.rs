@@ -0,0 +1,220 @@
+/// 并查集（不相交集合），元素为 `0..n` 的整数，使用路径压缩和按秩合并。
+///
+/// A disjoint-set (union-find) structure over the integers `0..n`, with path compression and
+/// union by rank. Every element starts in a set of its own; [`union`](DisjointSet::union) merges
+/// two sets and [`find`](DisjointSet::find) returns the representative of an element's set.
+///
+/// 两种优化一起使用时，每次操作的均摊时间复杂度为 O(α(n))，其中 α 是增长极慢的反阿克曼函数，实际上可以视为常数。
+/// With both optimizations every operation takes O(α(n)) amortized time, α being the
+/// extremely slowly growing inverse Ackermann function, constant for all practical purposes.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut sets = DisjointSet::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.connected(0, 1));
+/// assert!(!sets.connected(0, 2));
+/// assert_eq!(sets.count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+  parent: Vec<usize>,
+  /// 以该元素为根的树的高度上界。 (An upper bound on the height of the tree rooted here.)
+  rank: Vec<u8>,
+  count: usize,
+}
+
+impl DisjointSet {
+  /// 创建 `n` 个单元素集合。 (Creates `n` singleton sets.)
+  pub fn new(n: usize) -> Self {
+    DisjointSet {
+      parent: (0..n).collect(),
+      rank: vec![0; n],
+      count: n,
+    }
+  }
+
+  /// 元素个数。 (The number of elements.)
+  pub fn len(&self) -> usize {
+    self.parent.len()
+  }
+
+  /// 是否没有元素。 (Whether there are no elements.)
+  pub fn is_empty(&self) -> bool {
+    self.parent.is_empty()
+  }
+
+  /// 当前不相交集合的个数。 (The number of disjoint sets right now.)
+  pub fn count(&self) -> usize {
+    self.count
+  }
+
+  /// 返回 `x` 所在集合的代表元素，并把查找路径上的元素直接挂到代表元素下。
+  /// Returns the representative of the set containing `x`, pointing every element on the way
+  /// straight at it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `x` is not below [`len`](DisjointSet::len).
+  /// `x` 不小于 [`len`](DisjointSet::len) 时 panic。
+  pub fn find(&mut self, x: usize) -> usize {
+    let mut root = x;
+    while self.parent[root] != root {
+      root = self.parent[root];
+    }
+
+    // 路径压缩
+    // Path compression
+    let mut current = x;
+    while self.parent[current] != root {
+      let next = self.parent[current];
+      self.parent[current] = root;
+      current = next;
+    }
+
+    root
+  }
+
+  /// 合并 `a` 和 `b` 所在的集合；两者原本就在同一个集合中时返回 false。
+  /// Merges the sets containing `a` and `b`, returning false if they already were the same set.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `a` or `b` is not below [`len`](DisjointSet::len).
+  /// `a` 或 `b` 不小于 [`len`](DisjointSet::len) 时 panic。
+  pub fn union(&mut self, a: usize, b: usize) -> bool {
+    let (a, b) = (self.find(a), self.find(b));
+
+    if a == b {
+      return false;
+    }
+
+    // 按秩合并：把较矮的树挂到较高的树下
+    // Union by rank: hang the shorter tree under the taller one
+    match self.rank[a].cmp(&self.rank[b]) {
+      std::cmp::Ordering::Less => self.parent[a] = b,
+      std::cmp::Ordering::Greater => self.parent[b] = a,
+      std::cmp::Ordering::Equal => {
+        self.parent[b] = a;
+        self.rank[a] += 1;
+      }
+    }
+
+    self.count -= 1;
+    true
+  }
+
+  /// 判断 `a` 和 `b` 是否在同一个集合中。 (Tells whether `a` and `b` are in the same set.)
+  ///
+  /// # Panics
+  ///
+  /// Panics if `a` or `b` is not below [`len`](DisjointSet::len).
+  /// `a` 或 `b` 不小于 [`len`](DisjointSet::len) 时 panic。
+  pub fn connected(&mut self, a: usize, b: usize) -> bool {
+    self.find(a) == self.find(b)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  #[test]
+  fn singletons() {
+    let mut sets = DisjointSet::new(3);
+
+    assert_eq!((sets.len(), sets.count()), (3, 3));
+    assert!((0..3).all(|x| sets.find(x) == x));
+    assert!(!sets.connected(0, 1));
+    assert!(sets.connected(2, 2));
+  }
+
+  #[test]
+  fn union_and_find() {
+    let mut sets = DisjointSet::new(6);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+    assert!(!sets.union(4, 4));
+
+    assert_eq!(sets.count(), 3);
+    assert!(sets.connected(0, 3));
+    assert_eq!(sets.find(0), sets.find(2));
+    assert!(!sets.connected(0, 4));
+    assert!(!sets.connected(4, 5));
+  }
+
+  #[test]
+  fn empty() {
+    let sets = DisjointSet::new(0);
+
+    assert!(sets.is_empty());
+    assert_eq!(sets.count(), 0);
+  }
+
+  #[test]
+  #[should_panic]
+  fn out_of_range() {
+    DisjointSet::new(2).find(2);
+  }
+
+  #[test]
+  fn long_chain_is_compressed() {
+    let n = 100_000;
+    let mut sets = DisjointSet::new(n);
+
+    for i in 1..n {
+      sets.union(i - 1, i);
+    }
+
+    let root = sets.find(n - 1);
+    assert!((0..n).all(|x| sets.find(x) == root));
+
+    // 每个元素都找过一次之后，所有元素都直接挂在根下
+    // Once every element has been found, all of them hang right under the root
+    assert!(sets.parent.iter().all(|&p| p == root));
+    assert!(sets.rank.iter().all(|&r| r <= 17));
+    assert_eq!(sets.count(), 1);
+  }
+
+  #[test]
+  fn matches_naive_labels() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let n = rng.gen_range(1..30);
+      let mut sets = DisjointSet::new(n);
+      let mut labels: Vec<usize> = (0..n).collect();
+
+      for _ in 0..n {
+        let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        let merged = labels[a] != labels[b];
+
+        assert_eq!(sets.union(a, b), merged);
+
+        let (from, to) = (labels[b], labels[a]);
+        labels
+          .iter_mut()
+          .filter(|l| **l == from)
+          .for_each(|l| *l = to);
+      }
+
+      for a in 0..n {
+        for b in 0..n {
+          assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+        }
+      }
+
+      let mut distinct = labels.clone();
+      distinct.sort_unstable();
+      distinct.dedup();
+      assert_eq!(sets.count(), distinct.len());
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+
+/// 索引二叉最小堆：每个键最多出现一次，并且可以降低已在堆中的键的优先级 (decrease-key)。
+///
+/// An indexed binary min-heap: every key appears at most once, and the priority of a key already
+/// in the heap can be lowered in place (decrease-key). [`pop`](IndexedHeap::pop) returns the key
+/// with the smallest priority; keys of equal priority come out in no particular order.
+///
+/// 普通的 `BinaryHeap` 无法修改其中的条目，所以 Dijkstra 算法每次松弛都要压入一个新条目，并在弹出时丢弃过时的条目，
+/// 堆中最多会有 O(E) 个条目。索引堆记录每个键在堆中的位置，可以直接调整已有的条目，堆中的条目数不超过键的个数。
+/// A plain `BinaryHeap` cannot change its entries, so Dijkstra's algorithm pushes a new entry on
+/// every relaxation and drops the stale ones when popped, leaving up to O(E) entries in the heap.
+/// The indexed heap remembers where every key is and adjusts the existing entry, never holding
+/// more entries than there are keys.
+///
+/// # 复杂度 (Complexity)
+///
+/// [`push`](IndexedHeap::push)、[`decrease_key`](IndexedHeap::decrease_key) 和
+/// [`pop`](IndexedHeap::pop) 需要 O(log(n)) 次交换，每次交换更新位置映射需要 O(log(n))，总计 O(log²(n))。
+/// [`push`](IndexedHeap::push), [`decrease_key`](IndexedHeap::decrease_key) and
+/// [`pop`](IndexedHeap::pop) take O(log(n)) swaps, each updating the position map in O(log(n)),
+/// for O(log²(n)) in total.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut heap = IndexedHeap::new();
+/// assert!(heap.push('a', 5));
+/// assert!(heap.push('b', 3));
+/// assert!(!heap.push('a', 1));
+/// assert!(heap.decrease_key(&'a', 1));
+/// assert_eq!(heap.pop(), Some(('a', 1)));
+/// assert_eq!(heap.pop(), Some(('b', 3)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<K, P> {
+  /// 按堆序排列的 (键, 优先级)。 (The (key, priority) entries in heap order.)
+  heap: Vec<(K, P)>,
+  /// 每个键在 `heap` 中的下标。 (The index of every key in `heap`.)
+  positions: BTreeMap<K, usize>,
+}
+
+impl<K: Ord + Copy, P: Ord + Clone> Default for IndexedHeap<K, P> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<K: Ord + Copy, P: Ord + Clone> IndexedHeap<K, P> {
+  /// 创建空堆。 (Creates an empty heap.)
+  pub fn new() -> Self {
+    IndexedHeap {
+      heap: Vec::new(),
+      positions: BTreeMap::new(),
+    }
+  }
+
+  /// 堆中的键的个数。 (The number of keys in the heap.)
+  pub fn len(&self) -> usize {
+    self.heap.len()
+  }
+
+  /// 堆是否为空。 (Whether the heap is empty.)
+  pub fn is_empty(&self) -> bool {
+    self.heap.is_empty()
+  }
+
+  /// 判断 `key` 是否在堆中。 (Tells whether `key` is in the heap.)
+  pub fn contains(&self, key: &K) -> bool {
+    self.positions.contains_key(key)
+  }
+
+  /// `key` 当前的优先级，不在堆中时为 `None`。 (The current priority of `key`, or `None` if it is
+  /// not in the heap.)
+  pub fn priority(&self, key: &K) -> Option<P> {
+    self.positions.get(key).map(|&i| self.heap[i].1.clone())
+  }
+
+  /// 优先级最小的键及其优先级，不移除。 (The key with the smallest priority and its priority,
+  /// without removing it.)
+  pub fn peek(&self) -> Option<(K, P)> {
+    self.heap.first().cloned()
+  }
+
+  /// 以 `priority` 加入 `key`；`key` 已经在堆中时拒绝加入，返回 false，堆保持不变。
+  /// Adds `key` with `priority`; if `key` is already in the heap it is rejected, returning false
+  /// and leaving the heap unchanged.
+  pub fn push(&mut self, key: K, priority: P) -> bool {
+    if self.contains(&key) {
+      return false;
+    }
+
+    self.heap.push((key, priority));
+    self.positions.insert(key, self.heap.len() - 1);
+    self.sift_up(self.heap.len() - 1);
+
+    true
+  }
+
+  /// 把 `key` 的优先级降低为 `priority`；`key` 不在堆中或 `priority` 不比当前的更小时返回 false，堆保持不变。
+  /// Lowers the priority of `key` to `priority`; returns false, leaving the heap unchanged, if
+  /// `key` is not in the heap or `priority` is not smaller than its current one.
+  pub fn decrease_key(&mut self, key: &K, priority: P) -> bool {
+    let Some(&i) = self.positions.get(key) else {
+      return false;
+    };
+
+    if priority >= self.heap[i].1 {
+      return false;
+    }
+
+    self.heap[i].1 = priority;
+    self.sift_up(i);
+
+    true
+  }
+
+  /// 移除并返回优先级最小的键及其优先级。 (Removes and returns the key with the smallest
+  /// priority and its priority.)
+  pub fn pop(&mut self) -> Option<(K, P)> {
+    if self.heap.is_empty() {
+      return None;
+    }
+
+    let last = self.heap.len() - 1;
+    self.swap(0, last);
+    let (key, priority) = self.heap.pop()?;
+    self.positions.remove(&key);
+    self.sift_down(0);
+
+    Some((key, priority))
+  }
+
+  /// 交换两个条目并更新它们的位置。 (Swaps two entries and updates their positions.)
+  fn swap(&mut self, i: usize, j: usize) {
+    self.heap.swap(i, j);
+    self.positions.insert(self.heap[i].0, i);
+    self.positions.insert(self.heap[j].0, j);
+  }
+
+  fn sift_up(&mut self, mut i: usize) {
+    while i > 0 {
+      let parent = (i - 1) / 2;
+
+      if self.heap[i].1 >= self.heap[parent].1 {
+        break;
+      }
+
+      self.swap(i, parent);
+      i = parent;
+    }
+  }
+
+  fn sift_down(&mut self, mut i: usize) {
+    loop {
+      let (left, right) = (2 * i + 1, 2 * i + 2);
+      let mut smallest = i;
+
+      if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+        smallest = left;
+      }
+      if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+        smallest = right;
+      }
+      if smallest == i {
+        break;
+      }
+
+      self.swap(i, smallest);
+      i = smallest;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  /// 检查堆序以及位置映射与堆的一致性。
+  /// (Checks the heap order and that the position map agrees with the heap.)
+  fn assert_valid<K: Ord + Copy + std::fmt::Debug, P: Ord + Copy>(heap: &IndexedHeap<K, P>) {
+    for i in 1..heap.heap.len() {
+      assert!(heap.heap[(i - 1) / 2].1 <= heap.heap[i].1);
+    }
+
+    assert_eq!(heap.positions.len(), heap.heap.len());
+    for (i, (key, _)) in heap.heap.iter().enumerate() {
+      assert_eq!(heap.positions[key], i, "{key:?}");
+    }
+  }
+
+  #[test]
+  fn empty() {
+    let mut heap: IndexedHeap<u32, u32> = IndexedHeap::new();
+
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+    assert_eq!(heap.pop(), None);
+    assert!(!heap.decrease_key(&0, 0));
+  }
+
+  #[test]
+  fn push_and_pop_in_priority_order() {
+    let mut heap = IndexedHeap::new();
+    for (key, priority) in [('d', 4), ('a', 9), ('c', 1), ('e', 7), ('b', 3)] {
+      assert!(heap.push(key, priority));
+      assert_valid(&heap);
+    }
+
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.peek(), Some(('c', 1)));
+    assert_eq!(heap.priority(&'e'), Some(7));
+
+    let mut popped = vec![];
+    while let Some(entry) = heap.pop() {
+      assert_valid(&heap);
+      popped.push(entry);
+    }
+
+    assert_eq!(
+      popped,
+      vec![('c', 1), ('b', 3), ('d', 4), ('e', 7), ('a', 9)]
+    );
+    assert!(!heap.contains(&'c'));
+  }
+
+  #[test]
+  fn duplicate_keys_are_rejected() {
+    let mut heap = IndexedHeap::new();
+
+    assert!(heap.push(1, 10));
+    assert!(!heap.push(1, 5));
+    assert!(!heap.push(1, 20));
+
+    assert_eq!(heap.len(), 1);
+    assert_eq!(heap.priority(&1), Some(10));
+
+    // 弹出之后同一个键可以再次加入
+    // Once popped, the same key can be pushed again
+    assert_eq!(heap.pop(), Some((1, 10)));
+    assert!(heap.push(1, 5));
+  }
+
+  #[test]
+  fn decrease_key() {
+    let mut heap = IndexedHeap::new();
+    for key in 0..10 {
+      heap.push(key, 100 + key);
+    }
+
+    assert!(heap.decrease_key(&7, 50));
+    assert_valid(&heap);
+    assert_eq!(heap.peek(), Some((7, 50)));
+
+    // 优先级不降低时拒绝，键不在堆中时也拒绝
+    // Rejected when the priority does not go down, and when the key is not in the heap
+    assert!(!heap.decrease_key(&7, 50));
+    assert!(!heap.decrease_key(&3, 200));
+    assert!(!heap.decrease_key(&42, 0));
+    assert_eq!(heap.priority(&3), Some(103));
+
+    assert!(heap.decrease_key(&9, 10));
+    assert_eq!(heap.pop(), Some((9, 10)));
+    assert_eq!(heap.pop(), Some((7, 50)));
+    assert_eq!(heap.pop(), Some((0, 100)));
+  }
+
+  #[test]
+  fn matches_sorting_after_random_operations() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let mut heap = IndexedHeap::new();
+      let mut expected = BTreeMap::new();
+
+      for _ in 0..rng.gen_range(0..200) {
+        let key = rng.gen_range(0..50u32);
+        let priority = rng.gen_range(0..1000u32);
+
+        if rng.gen_bool(0.5) {
+          assert_eq!(heap.push(key, priority), !expected.contains_key(&key));
+          expected.entry(key).or_insert(priority);
+        } else {
+          let lower = expected.get(&key).is_some_and(|&old| priority < old);
+          assert_eq!(heap.decrease_key(&key, priority), lower);
+          if lower {
+            expected.insert(key, priority);
+          }
+        }
+      }
+
+      assert_valid(&heap);
+
+      let mut sorted: Vec<(u32, u32)> = expected.into_iter().map(|(k, p)| (p, k)).collect();
+      sorted.sort_unstable();
+
+      let mut popped = vec![];
+      while let Some((key, priority)) = heap.pop() {
+        popped.push((priority, key));
+      }
+
+      // 相同优先级的键没有固定顺序，所以只比较优先级序列和键的集合
+      // Keys of equal priority come out in no fixed order, so compare the priorities in order
+      // and the keys as a set
+      assert!(popped.windows(2).all(|w| w[0].0 <= w[1].0));
+      popped.sort_unstable();
+      assert_eq!(popped, sorted);
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,209 @@
+use rust_algorithm::graph::disjoint_set::DisjointSet;
+use rust_algorithm::sorting::heap_sort::heap_sort;
+use std::collections::BTreeMap;
+use std::iter::Sum;
+
+/// Kruskal's Algorithm
+///
+/// Kruskal's algorithm computes a minimum spanning forest of an undirected, weighted graph given
+/// as a list of edges. The edges are sorted by weight, and each one is kept if it joins two
+/// different components, which a [`DisjointSet`] tracks. A connected graph yields a minimum
+/// spanning tree; a disconnected one yields a minimum spanning tree of every component.
+///
+/// Self-loops never join two components and are skipped; of several parallel edges only the
+/// lightest can be kept.
+///
+/// Kruskal 算法
+///
+/// Kruskal 算法计算以边列表给出的无向带权图的最小生成森林。边按权重排序后依次检查，如果一条边连接两个不同的
+/// 连通分量（由 [`DisjointSet`] 维护）就保留它。连通图得到最小生成树，不连通图得到每个连通分量的最小生成树。
+///
+/// 自环不会连接两个分量而被跳过；多条平行边中最多只保留最轻的一条。
+///
+/// # Arguments
+///
+/// - `edges`: The undirected edges as `(u, v, weight)`.（无向边 `(u, v, weight)` 的列表。）
+///
+/// # Returns
+///
+/// The edges of the minimum spanning forest in ascending order of weight, and their total weight.
+/// Equal weights are taken in input order.
+/// （按权重升序排列的最小生成森林的边，以及它们的总权重。权重相同的边按输入顺序处理。）
+///
+/// # Complexity
+///
+/// The time complexity is O(E * log(E)) for sorting the edges; the union-find operations take
+/// O(E * α(V)) in total. The space complexity is O(V + E).
+/// （时间复杂度为排序边所需的 O(E * log(E))，并查集操作总共为 O(E * α(V))。空间复杂度为 O(V + E)。）
+///
+/// # Example
+///
+/// ```
+/// let edges = [('a', 'b', 3), ('b', 'c', 1), ('a', 'c', 2)];
+/// assert_eq!(kruskal(&edges), (vec![('b', 'c', 1), ('a', 'c', 2)], 3));
+/// ```
+pub fn kruskal<V: Ord + Copy, E: Ord + Copy + Sum>(edges: &[(V, V, E)]) -> (Vec<(V, V, E)>, E) {
+  // 给每个顶点编号，供并查集使用
+  // Number every vertex for the disjoint set
+  let mut ids = BTreeMap::new();
+  for &(u, v, _) in edges {
+    let next = ids.len();
+    ids.entry(u).or_insert(next);
+    let next = ids.len();
+    ids.entry(v).or_insert(next);
+  }
+
+  // 按 (权重, 下标) 排序，权重相同时保持输入顺序
+  // Sort by (weight, index), keeping the input order for equal weights
+  let mut order: Vec<(E, usize)> = edges.iter().enumerate().map(|(i, e)| (e.2, i)).collect();
+  heap_sort(&mut order);
+
+  let mut components = DisjointSet::new(ids.len());
+  let forest: Vec<(V, V, E)> = order
+    .into_iter()
+    .map(|(_, i)| edges[i])
+    .filter(|(u, v, _)| components.union(ids[u], ids[v]))
+    .collect();
+  let total = forest.iter().map(|&(_, _, w)| w).sum();
+
+  (forest, total)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::seq::SliceRandom;
+  use rand::Rng;
+  use rust_algorithm::graph::prim::{add_undirected_edge, prim};
+
+  /// 《算法导论》第 23 章的示例图，最小生成树的总权重为 37。
+  /// (The example graph of CLRS chapter 23, whose minimum spanning tree weighs 37.)
+  const CLRS: [(char, char, u32); 14] = [
+    ('a', 'b', 4),
+    ('a', 'h', 8),
+    ('b', 'c', 8),
+    ('b', 'h', 11),
+    ('c', 'd', 7),
+    ('c', 'f', 4),
+    ('c', 'i', 2),
+    ('d', 'e', 9),
+    ('d', 'f', 14),
+    ('e', 'f', 10),
+    ('f', 'g', 2),
+    ('g', 'h', 1),
+    ('g', 'i', 6),
+    ('h', 'i', 7),
+  ];
+
+  #[test]
+  fn clrs_example() {
+    let (tree, total) = kruskal(&CLRS);
+
+    assert_eq!(total, 37);
+    assert_eq!(
+      tree,
+      vec![
+        ('g', 'h', 1),
+        ('c', 'i', 2),
+        ('f', 'g', 2),
+        ('a', 'b', 4),
+        ('c', 'f', 4),
+        ('c', 'd', 7),
+        ('a', 'h', 8),
+        ('d', 'e', 9),
+      ]
+    );
+  }
+
+  #[test]
+  fn self_loops_and_parallel_edges() {
+    let edges = [
+      (1, 1, 0),
+      (1, 2, 5),
+      (2, 1, 3),
+      (2, 2, 1),
+      (2, 3, 4),
+      (3, 2, 4),
+    ];
+    let (tree, total) = kruskal(&edges);
+
+    assert_eq!(tree, vec![(2, 1, 3), (2, 3, 4)]);
+    assert_eq!(total, 7);
+  }
+
+  #[test]
+  fn disconnected_gives_a_forest() {
+    let edges = [(1, 2, 1), (2, 3, 2), (1, 3, 3), (10, 11, 5)];
+    let (forest, total) = kruskal(&edges);
+
+    assert_eq!(forest, vec![(1, 2, 1), (2, 3, 2), (10, 11, 5)]);
+    assert_eq!(total, 8);
+  }
+
+  #[test]
+  fn no_edges() {
+    let edges: [(u32, u32, i64); 0] = [];
+
+    assert_eq!(kruskal(&edges), (vec![], 0));
+  }
+
+  #[test]
+  fn shuffled_input_gives_the_same_total() {
+    let mut rng = rand::thread_rng();
+    let mut edges = CLRS.to_vec();
+    edges.sort_by_key(|e| e.2);
+    let (_, sorted_total) = kruskal(&edges);
+
+    for _ in 0..20 {
+      edges.shuffle(&mut rng);
+      let (tree, total) = kruskal(&edges);
+
+      assert_eq!(total, sorted_total);
+      assert_eq!(tree.len(), 8);
+    }
+  }
+
+  #[test]
+  fn agrees_with_prim() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let n = rng.gen_range(1..20u32);
+
+      // 先连成一条路径保证连通，再加入随机边
+      // A path first keeps the graph connected, then random edges are added
+      let mut edges: Vec<(u32, u32, u32)> =
+        (1..n).map(|v| (v - 1, v, rng.gen_range(1..50))).collect();
+      for _ in 0..rng.gen_range(0..3 * n) {
+        edges.push((
+          rng.gen_range(0..n),
+          rng.gen_range(0..n),
+          rng.gen_range(1..50),
+        ));
+      }
+
+      // Prim 的图中平行边只保留最轻的一条
+      // The graph for Prim keeps only the lightest of parallel edges
+      let mut graph = BTreeMap::new();
+      graph.insert(0, BTreeMap::new());
+      for &(u, v, w) in &edges {
+        if u != v
+          && graph
+            .get(&u)
+            .and_then(|adj| adj.get(&v))
+            .is_none_or(|&old| w < old)
+        {
+          add_undirected_edge(&mut graph, u, v, w);
+        }
+      }
+
+      let (tree, total) = kruskal(&edges);
+      let prim_tree = prim(&graph, &0).unwrap();
+
+      assert_eq!(tree.len() as u32, n - 1);
+      assert_eq!(total, prim_tree.iter().map(|e| e.2).sum::<u32>());
+    }
+  }
+}
+
+fn main() {}
@@ -2,4 +2,20 @@ pub mod bellman_ford;
 
 pub mod depth_first_search;
 
+pub mod bidirectional_bfs;
+
+pub mod prim;
+
+pub mod disjoint_set;
+
+pub mod kruskal;
+
+pub mod a_star;
+
+pub mod path;
+
+pub mod indexed_heap;
+
+pub mod articulation;
+
 pub mod dijkstra;
@@ -0,0 +1,242 @@
+use rust_algorithm::graph::bellman_ford::bellman_ford;
+use rust_algorithm::graph::dijkstra::{dijkstra, Graph};
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+/// Path Reconstruction
+///
+/// `dijkstra` and `bellman_ford` return a predecessor map: every reachable vertex is mapped to
+/// its predecessor on a shortest path and its distance from `start`, while `start` itself is
+/// mapped to `None`. This walks the predecessors back from `goal` to `start` and returns the path
+/// in the right order.
+///
+/// The walk takes at most as many steps as the map has entries, so a corrupted map, whose chain
+/// is broken or runs in a loop, gives `None` instead of hanging.
+///
+/// 路径重建
+///
+/// `dijkstra` 和 `bellman_ford` 返回前驱映射：每个可达顶点映射到它在最短路径上的前驱以及它到 `start` 的距离，
+/// `start` 本身映射到 `None`。此函数从 `goal` 沿前驱回溯到 `start`，并按正确的顺序返回路径。
+///
+/// 回溯的步数不超过映射的条目数，所以前驱链断开或成环的损坏映射会得到 `None`，而不会陷入死循环。
+///
+/// # Arguments
+///
+/// - `result`: The predecessor map returned by a shortest-path algorithm.（最短路径算法返回的前驱映射。）
+/// - `start`: The vertex the shortest-path algorithm started from.（最短路径算法的起始顶点。）
+/// - `goal`: The last vertex of the path.（路径的终点。）
+///
+/// # Returns
+///
+/// The vertices of the path from `start` to `goal`, both included, and the distance of `goal`;
+/// or `None` if `goal` is not in the map or its chain of predecessors does not lead to `start`.
+/// `goal == start` gives `[start]` with `E::default()` as the zero cost.
+/// （从 `start` 到 `goal` 的路径上的顶点（包含两个端点）以及 `goal` 的距离；`goal` 不在映射中或它的前驱链
+/// 到达不了 `start` 时返回 `None`。`goal == start` 时返回 `[start]`，代价为视为 0 的 `E::default()`。）
+///
+/// # Complexity
+///
+/// The time complexity is O(P * log(V)), P being the number of vertices on the path, and the
+/// space complexity is O(P).
+/// （时间复杂度为 O(P * log(V))，其中 P 是路径上的顶点数；空间复杂度为 O(P)。）
+///
+/// # Example
+///
+/// ```
+/// let result = BTreeMap::from([('a', None), ('b', Some(('a', 2))), ('c', Some(('b', 5)))]);
+/// assert_eq!(reconstruct_path(&result, &'a', &'c'), Some((vec!['a', 'b', 'c'], 5)));
+/// ```
+pub fn reconstruct_path<V: Ord + Copy, E: Clone + Default>(
+  result: &BTreeMap<V, Option<(V, E)>>,
+  start: &V,
+  goal: &V,
+) -> Option<(Vec<V>, E)> {
+  if goal == start {
+    return result
+      .contains_key(start)
+      .then(|| (vec![*start], E::default()));
+  }
+
+  let (_, cost) = result.get(goal)?.as_ref()?;
+  let mut path = vec![*goal];
+  let mut current = *goal;
+
+  // 一条合法的路径最多经过映射中的每个顶点一次
+  // A valid path goes through every vertex of the map at most once
+  while current != *start {
+    if path.len() > result.len() {
+      return None;
+    }
+
+    // 前驱不在映射中，或者在到达 start 之前遇到了另一个起点
+    // The predecessor is missing from the map, or another start is met before reaching start
+    let &(prev, _) = result.get(&current)?.as_ref()?;
+    path.push(prev);
+    current = prev;
+  }
+
+  path.reverse();
+  Some((path, cost.clone()))
+}
+
+/// 用 `dijkstra` 查找从 `start` 到 `goal` 的最短路径，并返回路径和距离。
+///
+/// Finds a shortest path from `start` to `goal` with `dijkstra`, returning the path and its
+/// distance as [`reconstruct_path`] does, or `None` if `goal` is unreachable.
+///
+/// # Example
+///
+/// ```
+/// let mut graph = BTreeMap::new();
+/// graph.insert(1, BTreeMap::from([(2, 5), (3, 2)]));
+/// graph.insert(3, BTreeMap::from([(2, 1)]));
+///
+/// assert_eq!(dijkstra_path(&graph, &1, &2), Some((vec![1, 3, 2], 3)));
+/// ```
+pub fn dijkstra_path<V: Ord + Copy, E: Ord + Clone + Default + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+  goal: &V,
+) -> Option<(Vec<V>, E)> {
+  reconstruct_path(&dijkstra(graph, start), start, goal)
+}
+
+/// 用 `bellman_ford` 查找从 `start` 到 `goal` 的最短路径，并返回路径和距离。
+///
+/// Finds a shortest path from `start` to `goal` with `bellman_ford`, returning the path and its
+/// distance as [`reconstruct_path`] does, or `None` if `goal` is unreachable or the graph has a
+/// negative-weight cycle reachable from `start`.
+///
+/// # Example
+///
+/// ```
+/// let mut graph = BTreeMap::new();
+/// graph.insert(1, BTreeMap::from([(2, 5), (3, 7)]));
+/// graph.insert(3, BTreeMap::from([(2, -4)]));
+/// graph.insert(2, BTreeMap::new());
+///
+/// assert_eq!(bellman_ford_path(&graph, &1, &2), Some((vec![1, 3, 2], 3)));
+/// ```
+pub fn bellman_ford_path<V, E>(graph: &Graph<V, E>, start: &V, goal: &V) -> Option<(Vec<V>, E)>
+where
+  V: Ord + Copy,
+  E: Ord + Clone + Default + Add<Output = E>,
+{
+  reconstruct_path(&bellman_ford(graph, start)?, start, goal)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_algorithm::graph::bellman_ford::add_edge;
+
+  /// `dijkstra` 测试中的 graph_1。 (The graph_1 of the `dijkstra` tests.)
+  fn graph_1() -> Graph<char, u32> {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 'a', 'c', 12);
+    add_edge(&mut graph, 'a', 'd', 60);
+    add_edge(&mut graph, 'b', 'a', 10);
+    add_edge(&mut graph, 'c', 'b', 20);
+    add_edge(&mut graph, 'c', 'd', 32);
+    add_edge(&mut graph, 'e', 'a', 7);
+    graph
+  }
+
+  /// `bellman_ford` 测试中带负权边的 graph_2。 (The graph_2 of the `bellman_ford` tests, with
+  /// negative weights.)
+  fn graph_2() -> Graph<u32, i32> {
+    let mut graph = BTreeMap::new();
+    add_edge(&mut graph, 0, 1, 6);
+    add_edge(&mut graph, 0, 3, 7);
+    add_edge(&mut graph, 1, 2, 5);
+    add_edge(&mut graph, 1, 3, 8);
+    add_edge(&mut graph, 1, 4, -4);
+    add_edge(&mut graph, 2, 1, -2);
+    add_edge(&mut graph, 3, 2, -3);
+    add_edge(&mut graph, 3, 4, 9);
+    add_edge(&mut graph, 4, 0, 3);
+    add_edge(&mut graph, 4, 2, 7);
+    graph
+  }
+
+  #[test]
+  fn dijkstra_paths_on_graph_1() {
+    let graph = graph_1();
+
+    assert_eq!(
+      dijkstra_path(&graph, &'e', &'d'),
+      Some((vec!['e', 'a', 'c', 'd'], 51))
+    );
+    assert_eq!(
+      dijkstra_path(&graph, &'b', &'d'),
+      Some((vec!['b', 'a', 'c', 'd'], 54))
+    );
+    assert_eq!(
+      dijkstra_path(&graph, &'c', &'a'),
+      Some((vec!['c', 'b', 'a'], 30))
+    );
+    assert_eq!(
+      dijkstra_path(&graph, &'a', &'c'),
+      Some((vec!['a', 'c'], 12))
+    );
+  }
+
+  #[test]
+  fn bellman_ford_paths_on_graph_2() {
+    let graph = graph_2();
+
+    assert_eq!(
+      bellman_ford_path(&graph, &0, &4),
+      Some((vec![0, 3, 2, 1, 4], -2))
+    );
+    assert_eq!(
+      bellman_ford_path(&graph, &3, &0),
+      Some((vec![3, 2, 1, 4, 0], -6))
+    );
+    assert_eq!(bellman_ford_path(&graph, &4, &3), Some((vec![4, 0, 3], 10)));
+  }
+
+  #[test]
+  fn negative_cycle() {
+    let mut graph = graph_2();
+    add_edge(&mut graph, 2, 1, -4);
+
+    assert_eq!(bellman_ford_path(&graph, &0, &4), None);
+  }
+
+  #[test]
+  fn unreachable_goal() {
+    let graph = graph_1();
+
+    assert_eq!(dijkstra_path(&graph, &'d', &'a'), None);
+    assert_eq!(dijkstra_path(&graph, &'a', &'e'), None);
+    assert_eq!(dijkstra_path(&graph, &'a', &'z'), None);
+  }
+
+  #[test]
+  fn goal_is_start() {
+    assert_eq!(dijkstra_path(&graph_1(), &'d', &'d'), Some((vec!['d'], 0)));
+    assert_eq!(bellman_ford_path(&graph_2(), &2, &2), Some((vec![2], 0)));
+  }
+
+  #[test]
+  fn corrupted_maps() {
+    // 前驱链在 2 处断开
+    // The chain of predecessors breaks at 2
+    let broken = BTreeMap::from([(1, None), (3, Some((2, 4)))]);
+    assert_eq!(reconstruct_path(&broken, &1, &3), None);
+
+    // 2 和 3 互为前驱，永远到达不了 1
+    // 2 and 3 are each other's predecessors, never leading to 1
+    let looping = BTreeMap::from([(1, None), (2, Some((3, 5))), (3, Some((2, 4)))]);
+    assert_eq!(reconstruct_path(&looping, &1, &2), None);
+
+    // 前驱链通向另一个起点
+    // The chain of predecessors leads to another start
+    let other_start = BTreeMap::from([(1, None), (2, Some((1, 5)))]);
+    assert_eq!(reconstruct_path(&other_start, &3, &2), None);
+    assert_eq!(reconstruct_path(&other_start, &3, &3), None);
+  }
+}
+
+fn main() {}
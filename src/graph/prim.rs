@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::ops::Add;
+
+pub type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
+
+/// Prim's Algorithm
+///
+/// Prim's algorithm grows a minimum spanning tree (MST) of a connected, undirected, weighted
+/// graph from a start vertex. Like `dijkstra`, it keeps a priority queue (binary heap) of edges
+/// leaving the tree, and repeatedly adds the lightest one that reaches a vertex not yet in the
+/// tree.
+///
+/// The graph is taken as undirected, so every edge must be stored in both directions, as
+/// [`add_undirected_edge`] does. Every vertex must be a key of the map, even without edges. If
+/// the graph is disconnected there is no spanning tree and `None` is returned; no spanning forest
+/// is built.
+///
+/// Prim 算法
+///
+/// Prim 算法从起始顶点出发，逐步生成连通无向带权图的最小生成树。与 `dijkstra` 一样，它用优先队列（二叉堆）
+/// 保存离开当前树的边，并反复加入能到达树外顶点的最轻的边。
+///
+/// 图被视为无向图，所以每条边都必须在两个方向上存储，例如使用 [`add_undirected_edge`]。每个顶点都必须是映射的键，
+/// 即使它没有边。图不连通时没有生成树，返回 `None`，而不是构造生成森林。
+///
+/// # Arguments
+///
+/// - `graph`: The undirected graph represented as an adjacency list.（以邻接表表示的无向图。）
+/// - `start`: The vertex to grow the tree from.（生成树的起始顶点。）
+///
+/// # Returns
+///
+/// The edges of a minimum spanning tree as `(from, to, weight)`, in the order they were added,
+/// `from` being the endpoint already in the tree; or `None` if the graph is disconnected or does
+/// not contain `start`.
+/// （按加入顺序排列的最小生成树的边 `(from, to, weight)`，其中 `from` 是已经在树中的端点；
+/// 图不连通或不包含 `start` 时返回 `None`。）
+///
+/// # Complexity
+///
+/// The time complexity is O(E * log(E)), as every edge is pushed to the heap at most twice, and
+/// the space complexity is O(E).
+/// （每条边最多入堆两次，所以时间复杂度为 O(E * log(E))，空间复杂度为 O(E)。）
+///
+/// # Example
+///
+/// ```
+/// let mut graph = BTreeMap::new();
+/// add_undirected_edge(&mut graph, 'a', 'b', 3);
+/// add_undirected_edge(&mut graph, 'b', 'c', 1);
+/// add_undirected_edge(&mut graph, 'a', 'c', 2);
+///
+/// assert_eq!(prim(&graph, &'a'), Some(vec![('a', 'c', 2), ('c', 'b', 1)]));
+/// ```
+pub fn prim<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
+  graph: &Graph<V, E>,
+  start: &V,
+) -> Option<Vec<(V, V, E)>> {
+  let neighbors = graph.get(start)?;
+
+  let mut in_tree = BTreeSet::from([*start]);
+  let mut tree = Vec::with_capacity(graph.len() - 1);
+  let mut prio = BinaryHeap::new();
+
+  // 将起始顶点的所有边加入优先队列
+  // Add every edge of the start vertex to the priority queue.
+  for (to, weight) in neighbors {
+    prio.push(Reverse((*weight, *start, *to)));
+  }
+
+  while let Some(Reverse((weight, from, to))) = prio.pop() {
+    // 边的两端都已经在树中时跳过
+    // Skip edges whose both ends are already in the tree.
+    if !in_tree.insert(to) {
+      continue;
+    }
+
+    tree.push((from, to, weight));
+
+    // 加入新顶点离开树的边
+    // Add the edges leaving the tree from the new vertex.
+    for (next, weight) in &graph[&to] {
+      if !in_tree.contains(next) {
+        prio.push(Reverse((*weight, to, *next)));
+      }
+    }
+  }
+
+  // 有顶点没有被加入树中，说明图不连通
+  // A vertex left out of the tree means the graph is disconnected.
+  (in_tree.len() == graph.len()).then_some(tree)
+}
+
+/// 在两个方向上各添加一条权重为 `c` 的边，使图成为无向图。
+/// Adds an edge of weight `c` in both directions, keeping the graph undirected.
+pub fn add_undirected_edge<V: Ord + Copy, E: Ord + Copy>(
+  graph: &mut Graph<V, E>,
+  v1: V,
+  v2: V,
+  c: E,
+) {
+  graph.entry(v1).or_default().insert(v2, c);
+  graph.entry(v2).or_default().insert(v1, c);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn total<V, E: Copy + std::iter::Sum>(tree: &[(V, V, E)]) -> E {
+    tree.iter().map(|&(_, _, w)| w).sum()
+  }
+
+  #[test]
+  fn unique_mst() {
+    let mut graph = BTreeMap::new();
+    add_undirected_edge(&mut graph, 'a', 'b', 4);
+    add_undirected_edge(&mut graph, 'a', 'c', 1);
+    add_undirected_edge(&mut graph, 'b', 'c', 2);
+    add_undirected_edge(&mut graph, 'b', 'd', 5);
+    add_undirected_edge(&mut graph, 'c', 'd', 8);
+    add_undirected_edge(&mut graph, 'd', 'e', 3);
+    add_undirected_edge(&mut graph, 'c', 'e', 9);
+
+    assert_eq!(
+      prim(&graph, &'a'),
+      Some(vec![
+        ('a', 'c', 1),
+        ('c', 'b', 2),
+        ('b', 'd', 5),
+        ('d', 'e', 3)
+      ])
+    );
+
+    // 从其他顶点出发得到同一棵树
+    // Starting elsewhere yields the same tree
+    let mut edges: Vec<(char, char)> = prim(&graph, &'e')
+      .unwrap()
+      .into_iter()
+      .map(|(u, v, _)| (u.min(v), u.max(v)))
+      .collect();
+    edges.sort_unstable();
+
+    assert_eq!(edges, vec![('a', 'c'), ('b', 'c'), ('b', 'd'), ('d', 'e')]);
+  }
+
+  #[test]
+  fn ties_give_the_same_total_weight() {
+    // 四条边权重为 1 的环和两条权重为 1 的对角线
+    // A cycle of four weight-1 edges and two weight-1 diagonals
+    let mut graph = BTreeMap::new();
+    for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3)] {
+      add_undirected_edge(&mut graph, u, v, 1);
+    }
+
+    for start in 0..4 {
+      let tree = prim(&graph, &start).unwrap();
+
+      assert_eq!(tree.len(), 3);
+      assert_eq!(total(&tree), 3);
+    }
+  }
+
+  #[test]
+  fn disconnected_graph() {
+    let mut graph = BTreeMap::new();
+    add_undirected_edge(&mut graph, 0, 1, 1);
+    add_undirected_edge(&mut graph, 2, 3, 1);
+
+    assert_eq!(prim(&graph, &0), None);
+
+    graph.insert(4, BTreeMap::new());
+    add_undirected_edge(&mut graph, 1, 2, 1);
+
+    assert_eq!(prim(&graph, &0), None);
+  }
+
+  #[test]
+  fn single_vertex() {
+    let mut graph: Graph<u32, u32> = BTreeMap::new();
+    graph.insert(0, BTreeMap::new());
+
+    assert_eq!(prim(&graph, &0), Some(vec![]));
+    assert_eq!(prim(&graph, &1), None);
+  }
+
+  #[test]
+  fn adding_an_edge_again_replaces_its_weight() {
+    let mut graph = BTreeMap::new();
+    add_undirected_edge(&mut graph, 'a', 'b', 2);
+    add_undirected_edge(&mut graph, 'b', 'a', 10);
+
+    assert_eq!(graph[&'a'][&'b'], 10);
+    assert_eq!(prim(&graph, &'b'), Some(vec![('b', 'a', 10)]));
+  }
+}
+
+fn main() {}
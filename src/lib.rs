@@ -1,3 +1,8 @@
+// 让同时作为 [[bin]] 编译的模块可以通过 `rust_algorithm::` 路径引用库中的其他模块
+// Lets modules that are also compiled as a [[bin]] refer to the rest of the library through
+// `rust_algorithm::` paths, which resolve in both crates.
+extern crate self as rust_algorithm;
+
 pub mod sorting;
 
 pub mod search;
@@ -5,3 +10,9 @@ pub mod search;
 pub mod string;
 
 pub mod graph;
+
+// 测试辅助代码，不属于公开 API；同时作为 [[bin]] 编译的模块的测试也要能引用，所以不能放在 cfg(test) 之后
+// Test helpers, not part of the public API; tests of modules that are also compiled as a [[bin]]
+// must reach them too, so they cannot sit behind cfg(test).
+#[doc(hidden)]
+pub mod test_support;
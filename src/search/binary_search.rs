@@ -74,9 +74,186 @@ pub fn binary_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
   None
 }
 
+/// 使用比较函数进行二分查找，`f` 返回元素相对于目标的顺序。
+/// Binary search with a comparator; `f` returns the ordering of an element relative to the target.
+///
+/// 数组必须按照 `f` 的顺序排列：返回 `Less` 的元素都在返回 `Equal` 的元素之前，返回 `Greater` 的元素都在之后。
+/// The array must be ordered consistently with `f`: elements for which it returns `Less` come
+/// before those returning `Equal`, which come before those returning `Greater`.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 3, 4, 5];
+/// assert_eq!(binary_search_by(&items, |x| x.cmp(&3)), Some(2));
+/// assert_eq!(binary_search_by(&items, |x| x.cmp(&6)), None);
+/// ```
+pub fn binary_search_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], f: F) -> Option<usize> {
+  search_by(arr, f).ok()
+}
+
+/// 二分查找的核心循环：找到时返回 `Ok(index)`，未找到时返回 `Err(index)`，其中 `index` 是保持数组有序的插入位置。
+/// The core binary search loop: returns `Ok(index)` when found, or `Err(index)` with the index
+/// at which the target could be inserted to keep the array sorted.
+fn search_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], mut f: F) -> Result<usize, usize> {
+  let mut left = 0;
+  let mut right = arr.len();
+
+  while left < right {
+    let mid = left + (right - left) / 2;
+
+    // f 比较的是元素与目标，方向与 binary_search 中的 item.cmp(&arr[mid]) 相反
+    // f compares the element with the target, the reverse of item.cmp(&arr[mid]) in binary_search
+    match f(&arr[mid]) {
+      Ordering::Greater => right = mid,
+      Ordering::Equal => return Ok(mid),
+      Ordering::Less => left = mid + 1,
+    }
+  }
+
+  // 循环结束时 [0, left) 中的元素都小于目标，[left, len) 中的元素都大于目标
+  // when the loop ends, elements in [0, left) are less than the target and those in [left, len) greater
+  Err(left)
+}
+
+/// 按照 `f` 从元素中提取的键进行二分查找，数组必须按该键有序。
+/// Binary search by the key that `f` extracts from each element; the array must be sorted by
+/// that key.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![(1, "a"), (3, "b"), (5, "c")];
+/// assert_eq!(binary_search_by_key(&items, &3, |&(id, _)| id), Some(1));
+/// assert_eq!(binary_search_by_key(&items, &4, |&(id, _)| id), None);
+/// ```
+pub fn binary_search_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+  arr: &[T],
+  key: &K,
+  mut f: F,
+) -> Option<usize> {
+  binary_search_by(arr, |x| f(x).cmp(key))
+}
+
+/// 与 [`slice::binary_search`] 语义相同的二分查找：找到时返回 `Ok(index)`，未找到时返回 `Err(index)`，
+/// 其中 `index` 是插入 `item` 后数组仍然有序的位置。存在多个相等元素时，可能返回其中任意一个的索引。
+/// Binary search with the same semantics as [`slice::binary_search`]: returns `Ok(index)` when
+/// found, or `Err(index)` with the position at which `item` could be inserted to keep the array
+/// sorted. If several elements are equal, the index of any one of them may be returned.
+///
+/// # Examples
+///
+/// ```
+/// let mut items = vec![1, 3, 5];
+/// assert_eq!(binary_search_insertion(&items, &3), Ok(1));
+///
+/// if let Err(index) = binary_search_insertion(&items, &4) {
+///   items.insert(index, 4);
+/// }
+/// assert_eq!(items, vec![1, 3, 4, 5]);
+/// ```
+pub fn binary_search_insertion<T: Ord>(arr: &[T], item: &T) -> Result<usize, usize> {
+  search_by(arr, |x| x.cmp(item))
+}
+
+/// 返回有序数组中第一个不小于 `target` 的元素的索引，所有元素都小于 `target` 时返回数组长度。
+/// Returns the index of the first element in the sorted array that is not less than `target`, or
+/// the length of the array if every element is less than `target`.
+///
+/// 与 [`binary_search`]`(item, arr)` 不同，这组函数按 `(arr, target)` 的顺序接收参数，
+/// 和标准库的查找函数保持一致；`binary_search` 保留旧的参数顺序以免破坏已有调用。
+/// Unlike [`binary_search`]`(item, arr)`, this family takes `(arr, target)`, slice first, like
+/// the standard library's searches; `binary_search` keeps its legacy order so existing callers
+/// do not break.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(lower_bound(&items, &2), 1);
+/// assert_eq!(lower_bound(&items, &4), 5);
+/// ```
+pub fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+  let mut left = 0;
+  let mut right = arr.len();
+
+  // 循环不变式：[0, left) 中的元素都小于 target，[right, len) 中的元素都不小于 target
+  // loop invariant: elements in [0, left) are < target, elements in [right, len) are >= target
+  while left < right {
+    let mid = left + (right - left) / 2;
+
+    if arr[mid] < *target {
+      left = mid + 1;
+    } else {
+      right = mid;
+    }
+  }
+
+  left
+}
+
+/// 返回有序数组中第一个大于 `target` 的元素的索引，所有元素都不大于 `target` 时返回数组长度。
+/// Returns the index of the first element in the sorted array that is greater than `target`, or
+/// the length of the array if no element is greater than `target`.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(upper_bound(&items, &2), 4);
+/// assert_eq!(upper_bound(&items, &0), 0);
+/// ```
+pub fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+  let mut left = 0;
+  let mut right = arr.len();
+
+  // 循环不变式：[0, left) 中的元素都不大于 target，[right, len) 中的元素都大于 target
+  // loop invariant: elements in [0, left) are <= target, elements in [right, len) are > target
+  while left < right {
+    let mid = left + (right - left) / 2;
+
+    if arr[mid] <= *target {
+      left = mid + 1;
+    } else {
+      right = mid;
+    }
+  }
+
+  left
+}
+
+/// 返回有序数组中等于 `target` 的元素的第一个和最后一个索引（都包含在内），未找到时返回 None。
+/// Returns the first and last indices (both inclusive) of the elements equal to `target` in the
+/// sorted array, or None if there are none.
+///
+/// 使用 [`lower_bound`] 和 [`upper_bound`] 两次二分查找，即使一半的元素都等于 `target` 也只需 O(log n)。
+/// Uses two binary searches, [`lower_bound`] and [`upper_bound`], so it stays O(log n) even when
+/// half of the elements equal `target`.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(equal_range(&items, &2), Some((1, 3)));
+/// assert_eq!(equal_range(&items, &4), None);
+/// ```
+pub fn equal_range<T: Ord>(arr: &[T], target: &T) -> Option<(usize, usize)> {
+  let first = lower_bound(arr, target);
+
+  // lower_bound 处的元素不等于 target，说明 target 不存在
+  // if the element at the lower bound is not target, target is absent
+  if first == arr.len() || arr[first] != *target {
+    return None;
+  }
+
+  Some((first, upper_bound(arr, target) - 1))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
 
   #[test]
   fn empty() {
@@ -124,6 +301,175 @@ mod tests {
 
     assert_eq!(index, None);
   }
+
+  #[derive(Debug)]
+  struct User {
+    id: u32,
+    name: String,
+  }
+
+  #[test]
+  fn search_by() {
+    let items = [1, 3, 5, 7];
+
+    for (index, item) in items.iter().enumerate() {
+      assert_eq!(binary_search_by(&items, |x| x.cmp(item)), Some(index));
+    }
+
+    assert_eq!(binary_search_by(&items, |x| x.cmp(&4)), None);
+    assert_eq!(binary_search_by(&[] as &[i32], |x| x.cmp(&4)), None);
+  }
+
+  #[test]
+  fn search_by_key_struct_id() {
+    let users: Vec<User> = [2, 4, 8, 16]
+      .iter()
+      .map(|&id| User {
+        id,
+        name: format!("user{}", id),
+      })
+      .collect();
+
+    let index = binary_search_by_key(&users, &8, |user| user.id).unwrap();
+    assert_eq!(users[index].name, "user8");
+
+    assert_eq!(binary_search_by_key(&users, &2, |user| user.id), Some(0));
+    assert_eq!(binary_search_by_key(&users, &16, |user| user.id), Some(3));
+  }
+
+  #[test]
+  fn search_by_key_of_different_type() {
+    // 用 &str 键查找 String 记录
+    // search String records with &str keys
+    let records: Vec<String> = ["apple", "banana", "cherry"]
+      .iter()
+      .map(|s| s.to_string())
+      .collect();
+
+    // 键不能借用 f 的参数，因此在引用上查找，使 &str 借用的是 records
+    // the key cannot borrow from f's argument, so search over references and borrow from records
+    let refs: Vec<&String> = records.iter().collect();
+
+    assert_eq!(
+      binary_search_by_key(&refs, &"banana", |&s| s.as_str()),
+      Some(1)
+    );
+    assert_eq!(
+      binary_search_by_key(&refs, &"durian", |&s| s.as_str()),
+      None
+    );
+    assert_eq!(binary_search_by_key(&records, &5, |s| s.len()), Some(0));
+  }
+
+  #[test]
+  fn search_by_key_misses() {
+    let users: Vec<User> = [2, 4, 8]
+      .iter()
+      .map(|&id| User {
+        id,
+        name: String::new(),
+      })
+      .collect();
+
+    for id in [0, 3, 5, 9] {
+      assert_eq!(binary_search_by_key(&users, &id, |user| user.id), None);
+    }
+
+    assert_eq!(binary_search_by_key(&users[..0], &2, |user| user.id), None);
+  }
+
+  #[test]
+  fn insertion_edges() {
+    assert_eq!(binary_search_insertion(&[], &1), Err(0));
+
+    let items = [1, 3, 5];
+    assert_eq!(binary_search_insertion(&items, &0), Err(0));
+    assert_eq!(binary_search_insertion(&items, &2), Err(1));
+    assert_eq!(binary_search_insertion(&items, &4), Err(2));
+    assert_eq!(binary_search_insertion(&items, &6), Err(3));
+    assert_eq!(binary_search_insertion(&items, &5), Ok(2));
+
+    let items = [1, 2, 2, 2, 3];
+    let index = binary_search_insertion(&items, &2).unwrap();
+    assert_eq!(items[index], 2);
+  }
+
+  #[test]
+  fn insertion_builds_sorted_vec() {
+    use rand::seq::SliceRandom;
+
+    let mut input: Vec<i32> = (0..200).map(|x| x / 2).collect();
+    input.shuffle(&mut rand::thread_rng());
+
+    // 每次都插入到 Err 给出的位置（找到时插在相等元素处），Vec 始终保持有序
+    // insert each element at the Err index (or next to an equal one), keeping the Vec sorted
+    let mut sorted = Vec::new();
+    for item in &input {
+      let index = binary_search_insertion(&sorted, item).unwrap_or_else(|index| index);
+      sorted.insert(index, *item);
+      assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    input.sort();
+    assert_eq!(sorted, input);
+  }
+
+  #[test]
+  fn equal_range_all_same() {
+    let items = vec![7; 1000];
+
+    assert_eq!(equal_range(&items, &7), Some((0, 999)));
+    assert_eq!(equal_range(&items, &6), None);
+    assert_eq!(equal_range(&items, &8), None);
+  }
+
+  #[test]
+  fn equal_range_single_occurrence() {
+    let items = vec![1, 2, 3, 4];
+
+    for (index, item) in items.iter().enumerate() {
+      assert_eq!(equal_range(&items, item), Some((index, index)));
+    }
+  }
+
+  #[test]
+  fn equal_range_absent() {
+    let items = vec![1, 1, 3, 3, 5, 5];
+
+    for item in [0, 2, 4, 6] {
+      assert_eq!(equal_range(&items, &item), None);
+    }
+
+    assert_eq!(equal_range(&[], &1), None);
+  }
+
+  #[test]
+  fn bounds() {
+    let items = vec![1, 2, 2, 2, 3];
+
+    assert_eq!(lower_bound(&items, &0), 0);
+    assert_eq!(lower_bound(&items, &2), 1);
+    assert_eq!(lower_bound(&items, &4), 5);
+    assert_eq!(upper_bound(&items, &0), 0);
+    assert_eq!(upper_bound(&items, &2), 4);
+    assert_eq!(upper_bound(&items, &4), 5);
+  }
+
+  proptest! {
+    #[test]
+    fn bounds_and_equal_range_agree(mut items in vec(-20..20i32, 0..100), item in -25..25i32) {
+      items.sort();
+
+      let lower = lower_bound(&items, &item);
+      let upper = upper_bound(&items, &item);
+
+      prop_assert_eq!(lower, items.iter().filter(|&&x| x < item).count());
+      prop_assert_eq!(upper, items.iter().filter(|&&x| x <= item).count());
+
+      let expected = if lower < upper { Some((lower, upper - 1)) } else { None };
+      prop_assert_eq!(equal_range(&items, &item), expected);
+    }
+  }
 }
 
 fn main() {}
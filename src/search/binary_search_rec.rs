@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::ops::Range;
 
 /// 使用递归实现的二分查找算法。在有序数组中搜索目标元素，并返回其索引。
 /// 如果找到目标元素，则返回 Some(index)，否则返回 None。
@@ -20,6 +21,8 @@ use std::cmp::Ordering;
 /// 该算法的时间复杂度为 O(log n)，其中 n 是数组的长度。
 /// 空间复杂度为 O(log n)。
 ///
+/// 已弃用：请使用按值接收 `Range` 的 [`binary_search_rec_range`]，或搜索整个数组的 [`binary_search_recursive`]。
+///
 /// # 示例
 ///
 /// ```rust
@@ -51,6 +54,9 @@ use std::cmp::Ordering;
 /// The time complexity of this algorithm is O(log n), where n is the length of the array.
 /// The space complexity is O(log n).
 ///
+/// Deprecated: use [`binary_search_rec_range`], which takes a `Range` by value, or
+/// [`binary_search_recursive`] to search the whole array.
+///
 /// # Example
 ///
 /// ```rust
@@ -59,11 +65,66 @@ use std::cmp::Ordering;
 /// let result = binary_search_rec(&arr, &target, &0, &arr.len());
 /// assert_eq!(result, Some(2));
 /// ```
+#[deprecated(note = "use `binary_search_rec_range` or `binary_search_recursive` instead")]
 pub fn binary_search_rec<T: Ord>(
   list_of_items: &[T],
   target: &T,
   left: &usize,
   right: &usize,
+) -> Option<usize> {
+  binary_search_rec_range(list_of_items, target, *left..*right)
+}
+
+/// 使用递归实现的二分查找算法，在 `range` 指定的范围内搜索目标元素。
+/// 范围为空或超出数组边界时返回 None，而不会 panic。
+///
+/// Recursive binary search within the half-open `range` of the sorted array. An empty range, or
+/// one that reaches past the end of the array, returns None instead of panicking.
+///
+/// # 示例 (Example)
+///
+/// ```rust
+/// let arr = vec![1, 2, 3, 4, 5];
+/// assert_eq!(binary_search_rec_range(&arr, &4, 2..5), Some(3));
+/// assert_eq!(binary_search_rec_range(&arr, &1, 2..5), None);
+/// assert_eq!(binary_search_rec_range(&arr, &1, 0..10), None);
+/// ```
+pub fn binary_search_rec_range<T: Ord>(
+  arr: &[T],
+  target: &T,
+  range: Range<usize>,
+) -> Option<usize> {
+  // If the range reaches past the end of the array, return None rather than indexing out of bounds.
+  // 如果范围超出数组边界，返回 None，而不是越界访问
+  if range.end > arr.len() {
+    return None;
+  }
+
+  search(arr, target, range)
+}
+
+/// 在整个数组中递归地进行二分查找，等价于 `binary_search_rec_range(arr, target, 0..arr.len())`。
+///
+/// Recursive binary search over the whole array, equivalent to
+/// `binary_search_rec_range(arr, target, 0..arr.len())`.
+///
+/// # 示例 (Example)
+///
+/// ```rust
+/// let arr = vec![1, 2, 3, 4, 5];
+/// assert_eq!(binary_search_recursive(&arr, &3), Some(2));
+/// ```
+pub fn binary_search_recursive<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+  search(arr, target, 0..arr.len())
+}
+
+fn search<T: Ord>(
+  list_of_items: &[T],
+  target: &T,
+  Range {
+    start: left,
+    end: right,
+  }: Range<usize>,
 ) -> Option<usize> {
   // If the left boundary is greater than or equal to the right boundary, the search range is empty, so return None.
   // 如果左边界大于等于右边界，说明搜索范围为空，返回 None
@@ -80,11 +141,11 @@ pub fn binary_search_rec<T: Ord>(
   match target.cmp(&list_of_items[middle]) {
     // If the target element is less than the middle element, continue searching in the left half.
     // 如果目标元素小于中间元素，则在左半边搜索
-    Ordering::Less => binary_search_rec(list_of_items, target, left, &middle),
+    Ordering::Less => search(list_of_items, target, left..middle),
 
     // If the target element is greater than the middle element, continue searching in the right half.
     // 如果目标元素大于中间元素，则在右半边搜索
-    Ordering::Greater => binary_search_rec(list_of_items, target, &(middle + 1), right),
+    Ordering::Greater => search(list_of_items, target, middle + 1..right),
 
     // If the target element is equal to the middle element, the target is found, so return Some(middle).
     // 如果目标元素等于中间元素，则找到目标元素，返回 Some(middle)
@@ -96,42 +157,152 @@ pub fn binary_search_rec<T: Ord>(
 mod tests {
   use super::*;
 
-  const LEFT: usize = 0;
+  // 原有的测试，继续覆盖已弃用的 `binary_search_rec`
+  // The original tests, still covering the deprecated `binary_search_rec`
+  #[allow(deprecated)]
+  mod shim {
+    use super::*;
+
+    const LEFT: usize = 0;
+
+    #[test]
+    fn fail_empty_list() {
+      let list_of_items = vec![];
+
+      assert_eq!(
+        binary_search_rec(&list_of_items, &1, &LEFT, &list_of_items.len()),
+        None
+      );
+    }
+
+    #[test]
+    fn success_one_item() {
+      let list_of_items = vec![30];
+
+      assert_eq!(
+        binary_search_rec(&list_of_items, &30, &LEFT, &list_of_items.len()),
+        Some(0)
+      );
+    }
+
+    #[test]
+    fn success_search_strings() {
+      let say_hello_list = vec!["hi", "olá", "salut"];
+      let right = say_hello_list.len();
+
+      assert_eq!(
+        binary_search_rec(&say_hello_list, &"hi", &LEFT, &right),
+        Some(0)
+      );
+
+      assert_eq!(
+        binary_search_rec(&say_hello_list, &"salut", &LEFT, &right),
+        Some(2)
+      );
+    }
+
+    #[test]
+    fn fail_search_strings() {
+      let say_hello_list = vec!["hi", "olá", "salut"];
+
+      for target in &["adiós", "你好"] {
+        assert_eq!(
+          binary_search_rec(&say_hello_list, target, &LEFT, &say_hello_list.len()),
+          None
+        );
+      }
+    }
+
+    #[test]
+    fn success_search_integers() {
+      let integers = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
+
+      for (index, target) in integers.iter().enumerate() {
+        assert_eq!(
+          binary_search_rec(&integers, target, &LEFT, &integers.len()),
+          Some(index)
+        )
+      }
+    }
+
+    #[test]
+    fn fail_search_integers() {
+      let integers = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
+
+      for target in &[100, 444, 336] {
+        assert_eq!(
+          binary_search_rec(&integers, target, &LEFT, &integers.len()),
+          None
+        );
+      }
+    }
+
+    #[test]
+    fn fail_search_unsorted_strings_list() {
+      let unsorted_strings = vec!["salut", "olá", "hi"];
+
+      for target in &["hi", "salut"] {
+        assert_eq!(
+          binary_search_rec(&unsorted_strings, target, &LEFT, &unsorted_strings.len()),
+          None
+        );
+      }
+    }
+
+    #[test]
+    fn fail_search_unsorted_integers_list() {
+      let unsorted_integers = vec![90, 80, 70, 60, 50, 40, 30, 20, 10, 0];
+
+      for target in &[0, 80, 90] {
+        assert_eq!(
+          binary_search_rec(&unsorted_integers, target, &LEFT, &unsorted_integers.len()),
+          None
+        );
+      }
+    }
+
+    #[test]
+    fn success_search_string_in_middle_of_unsorted_list() {
+      let unsorted_strings = vec!["salut", "olá", "hi"];
+
+      assert_eq!(
+        binary_search_rec(&unsorted_strings, &"olá", &LEFT, &unsorted_strings.len()),
+        Some(1)
+      );
+    }
+
+    #[test]
+    fn success_search_integer_in_middle_of_unsorted_list() {
+      let unsorted_integers = vec![90, 80, 70];
+
+      assert_eq!(
+        binary_search_rec(&unsorted_integers, &80, &LEFT, &unsorted_integers.len()),
+        Some(1)
+      );
+    }
+  }
 
   #[test]
   fn fail_empty_list() {
     let list_of_items = vec![];
 
-    assert_eq!(
-      binary_search_rec(&list_of_items, &1, &LEFT, &list_of_items.len()),
-      None
-    );
+    assert_eq!(binary_search_recursive(&list_of_items, &1), None);
   }
 
   #[test]
   fn success_one_item() {
     let list_of_items = vec![30];
 
-    assert_eq!(
-      binary_search_rec(&list_of_items, &30, &LEFT, &list_of_items.len()),
-      Some(0)
-    );
+    assert_eq!(binary_search_recursive(&list_of_items, &30), Some(0));
   }
 
   #[test]
   fn success_search_strings() {
     let say_hello_list = vec!["hi", "olá", "salut"];
-    let RIGHT = say_hello_list.len();
 
-    assert_eq!(
-      binary_search_rec(&say_hello_list, &"hi", &LEFT, &RIGHT),
-      Some(0)
-    );
+    assert_eq!(binary_search_recursive(&say_hello_list, &"hi"), Some(0));
 
-    assert_eq!(
-      binary_search_rec(&say_hello_list, &"salut", &LEFT, &RIGHT),
-      Some(2)
-    );
+    assert_eq!(binary_search_recursive(&say_hello_list, &"salut"), Some(2));
   }
 
   #[test]
@@ -139,10 +310,7 @@ mod tests {
     let say_hello_list = vec!["hi", "olá", "salut"];
 
     for target in &["adiós", "你好"] {
-      assert_eq!(
-        binary_search_rec(&say_hello_list, target, &LEFT, &say_hello_list.len()),
-        None
-      );
+      assert_eq!(binary_search_recursive(&say_hello_list, target), None);
     }
   }
 
@@ -151,10 +319,7 @@ mod tests {
     let integers = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
 
     for (index, target) in integers.iter().enumerate() {
-      assert_eq!(
-        binary_search_rec(&integers, target, &LEFT, &integers.len()),
-        Some(index)
-      )
+      assert_eq!(binary_search_recursive(&integers, target), Some(index))
     }
   }
 
@@ -163,10 +328,7 @@ mod tests {
     let integers = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
 
     for target in &[100, 444, 336] {
-      assert_eq!(
-        binary_search_rec(&integers, target, &LEFT, &integers.len()),
-        None
-      );
+      assert_eq!(binary_search_recursive(&integers, target), None);
     }
   }
 
@@ -175,10 +337,7 @@ mod tests {
     let unsorted_strings = vec!["salut", "olá", "hi"];
 
     for target in &["hi", "salut"] {
-      assert_eq!(
-        binary_search_rec(&unsorted_strings, target, &LEFT, &unsorted_strings.len()),
-        None
-      );
+      assert_eq!(binary_search_recursive(&unsorted_strings, target), None);
     }
   }
 
@@ -187,10 +346,7 @@ mod tests {
     let unsorted_integers = vec![90, 80, 70, 60, 50, 40, 30, 20, 10, 0];
 
     for target in &[0, 80, 90] {
-      assert_eq!(
-        binary_search_rec(&unsorted_integers, target, &LEFT, &unsorted_integers.len()),
-        None
-      );
+      assert_eq!(binary_search_recursive(&unsorted_integers, target), None);
     }
   }
 
@@ -198,20 +354,61 @@ mod tests {
   fn success_search_string_in_middle_of_unsorted_list() {
     let unsorted_strings = vec!["salut", "olá", "hi"];
 
-    assert_eq!(
-      binary_search_rec(&unsorted_strings, &"olá", &LEFT, &unsorted_strings.len()),
-      Some(1)
-    );
+    assert_eq!(binary_search_recursive(&unsorted_strings, &"olá"), Some(1));
   }
 
   #[test]
   fn success_search_integer_in_middle_of_unsorted_list() {
     let unsorted_integers = vec![90, 80, 70];
 
+    assert_eq!(binary_search_recursive(&unsorted_integers, &80), Some(1));
+  }
+
+  #[test]
+  fn empty_ranges() {
+    let integers = vec![0, 10, 20, 30];
+
+    assert_eq!(binary_search_rec_range(&integers, &10, 1..1), None);
+    assert_eq!(binary_search_rec_range(&integers, &10, 4..4), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 3..1;
+    assert_eq!(binary_search_rec_range(&integers, &10, reversed), None);
+  }
+
+  #[test]
+  fn sub_window() {
+    let integers = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
+
+    for (index, target) in integers.iter().enumerate() {
+      let expected = if (3..7).contains(&index) {
+        Some(index)
+      } else {
+        None
+      };
+
+      assert_eq!(binary_search_rec_range(&integers, target, 3..7), expected);
+    }
+  }
+
+  #[test]
+  fn out_of_bounds_range() {
+    let integers = vec![0, 10, 20, 30];
+
+    assert_eq!(binary_search_rec_range(&integers, &30, 0..5), None);
+    assert_eq!(binary_search_rec_range(&integers, &30, 10..20), None);
+    assert_eq!(binary_search_rec_range(&integers, &30, 0..usize::MAX), None);
+  }
+
+  #[test]
+  #[allow(deprecated)]
+  fn deprecated_shim() {
+    let integers = vec![0, 10, 20, 30];
+
     assert_eq!(
-      binary_search_rec(&unsorted_integers, &80, &LEFT, &unsorted_integers.len()),
-      Some(1)
+      binary_search_rec(&integers, &20, &0, &integers.len()),
+      Some(2)
     );
+    assert_eq!(binary_search_rec(&integers, &20, &3, &integers.len()), None);
   }
 }
 
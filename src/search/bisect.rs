@@ -0,0 +1,203 @@
+/// 浮点二分的最大迭代次数。每次迭代区间减半，200 次足以从任意 f64 区间收敛到 f64 的精度，
+/// 同时避免 eps 小于 f64 分辨率时 `hi - lo > eps` 永远成立而死循环。
+/// The maximum number of iterations of the floating-point bisection. Each iteration halves the
+/// interval, so 200 is enough to reach f64 resolution from any f64 interval, and it stops the
+/// loop from running forever when eps is below that resolution and `hi - lo > eps` never fails.
+pub const MAX_ITERATIONS: usize = 200;
+
+/// 在连续区间上二分查找单调谓词的边界，即满足 `pred(x)` 的最小的 x。
+/// This function bisects a continuous interval to find the boundary of a monotone predicate,
+/// i.e. the smallest x for which `pred(x)` holds.
+///
+/// `pred` 必须是单调的：在 [lo, hi] 上先全为 false，后全为 true。
+/// `pred` must be monotone: false...false, true...true over [lo, hi].
+///
+/// # Arguments
+///
+/// * `lo` - 区间左端点
+///   The left end of the interval.
+/// * `hi` - 区间右端点，假定 `pred(hi)` 为 true
+///   The right end of the interval, assumed to satisfy `pred`.
+/// * `pred` - 单调谓词
+///   The monotone predicate.
+/// * `eps` - 允许的误差
+///   The tolerance.
+///
+/// # Returns
+///
+/// 返回满足 `pred` 的点，它与真正边界的距离不超过 eps（或已达到 f64 的精度）。`pred(lo)` 为 true 时返回 lo。
+/// Returns a point satisfying `pred` that is within eps of the true boundary (or as close as f64
+/// resolution allows). Returns lo if `pred(lo)` already holds.
+///
+/// # Examples
+///
+/// ```
+/// let sqrt2 = bisect_f64(0.0, 2.0, |x| x * x >= 2.0, 1e-9);
+/// assert!((sqrt2 - 2f64.sqrt()).abs() <= 1e-9);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log((hi - lo) / eps)) 次调用 `pred`，最多 [`MAX_ITERATIONS`] 次。
+/// O(log((hi - lo) / eps)) calls to `pred`, at most [`MAX_ITERATIONS`].
+pub fn bisect_f64<F: FnMut(f64) -> bool>(mut lo: f64, mut hi: f64, mut pred: F, eps: f64) -> f64 {
+  if pred(lo) {
+    return lo;
+  }
+
+  // 循环不变式：pred(lo) 为 false，pred(hi) 为 true
+  // loop invariant: pred(lo) is false and pred(hi) is true
+  for _ in 0..MAX_ITERATIONS {
+    if hi - lo <= eps {
+      break;
+    }
+
+    let mid = lo + (hi - lo) / 2.0;
+
+    if pred(mid) {
+      hi = mid;
+    } else {
+      lo = mid;
+    }
+  }
+
+  hi
+}
+
+/// 在整数区间 [lo, hi) 上二分查找单调谓词的边界，返回第一个满足 `pred` 的整数。
+/// This function binary searches the integer range [lo, hi) for the boundary of a monotone
+/// predicate and returns the first integer that satisfies `pred`.
+///
+/// 与 [`slice::partition_point`] 相同，但作用于整数区间而不是切片，
+/// 适用于“满足条件的最小答案”一类的问题。
+/// It mirrors [`slice::partition_point`] over an integer range instead of a slice, which suits
+/// "smallest answer that satisfies a check" problems.
+///
+/// # Arguments
+///
+/// * `lo` - 区间左端点（包含）
+///   The start of the range (inclusive).
+/// * `hi` - 区间右端点（不包含）
+///   The end of the range (exclusive).
+/// * `pred` - 单调谓词：先全为 false，后全为 true
+///   The monotone predicate: false...false, true...true.
+///
+/// # Returns
+///
+/// 返回第一个满足 `pred` 的整数，区间内都不满足时返回 hi。
+/// Returns the first integer satisfying `pred`, or hi if none in the range does.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(partition_point_u64(0, 100, |x| x * x >= 50), 8);
+/// assert_eq!(partition_point_u64(0, 5, |_| false), 5);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log(hi - lo)) 次调用 `pred`。
+/// O(log(hi - lo)) calls to `pred`.
+pub fn partition_point_u64<F: FnMut(u64) -> bool>(mut lo: u64, mut hi: u64, mut pred: F) -> u64 {
+  // 循环不变式：[初始 lo, lo) 都不满足 pred，[hi, 初始 hi) 都满足 pred
+  // loop invariant: [initial lo, lo) fails pred and [hi, initial hi) satisfies it
+  while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+
+    if pred(mid) {
+      hi = mid;
+    } else {
+      lo = mid + 1;
+    }
+  }
+
+  lo
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sqrt() {
+    for n in [0.25f64, 2.0, 10.0, 12345.678] {
+      let root = bisect_f64(0.0, n.max(1.0), |x| x * x >= n, 1e-9);
+
+      assert!(
+        (root - f64::sqrt(n)).abs() <= 1e-9,
+        "sqrt({}) = {}",
+        n,
+        root
+      );
+    }
+  }
+
+  #[test]
+  fn boundary_at_lo_and_hi() {
+    assert_eq!(bisect_f64(1.0, 5.0, |_| true, 1e-9), 1.0);
+
+    let x = bisect_f64(1.0, 5.0, |x| x >= 5.0, 1e-9);
+    assert_eq!(x, 5.0);
+  }
+
+  #[test]
+  fn tiny_eps_terminates() {
+    let mut calls = 0;
+    let x = bisect_f64(
+      0.0,
+      1e6,
+      |x| {
+        calls += 1;
+        x >= 0.1
+      },
+      0.0,
+    );
+
+    assert!(calls <= MAX_ITERATIONS + 1);
+    assert!((x - 0.1).abs() < 1e-9);
+  }
+
+  #[test]
+  fn integer_boundaries() {
+    assert_eq!(partition_point_u64(0, 100, |x| x * x >= 50), 8);
+    assert_eq!(partition_point_u64(3, 10, |_| true), 3);
+    assert_eq!(partition_point_u64(3, 10, |_| false), 10);
+    assert_eq!(partition_point_u64(7, 7, |_| true), 7);
+    assert_eq!(
+      partition_point_u64(0, u64::MAX, |x| x >= u64::MAX - 1),
+      u64::MAX - 1
+    );
+  }
+
+  #[test]
+  fn min_capacity_to_ship() {
+    // 在 days 天内按顺序运完所有包裹所需的最小载重
+    // the smallest capacity that ships every package, in order, within `days` days
+    let weights = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let fits = |capacity: u64, days: u64| {
+      let (mut used, mut load) = (1, 0);
+
+      for &weight in &weights {
+        if weight > capacity {
+          return false;
+        }
+
+        if load + weight > capacity {
+          used += 1;
+          load = 0;
+        }
+
+        load += weight;
+      }
+
+      used <= days
+    };
+    let total: u64 = weights.iter().sum();
+
+    assert_eq!(partition_point_u64(1, total + 1, |c| fits(c, 5)), 15);
+    assert_eq!(partition_point_u64(1, total + 1, |c| fits(c, 1)), total);
+    assert_eq!(partition_point_u64(1, total + 1, |c| fits(c, 10)), 10);
+  }
+}
+
+fn main() {}
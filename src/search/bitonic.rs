@@ -0,0 +1,115 @@
+use rust_algorithm::search::binary_search::{binary_search, binary_search_by};
+use rust_algorithm::search::peak::find_peak;
+
+/// 在双调数组（先严格递增，后严格递减）中查找目标元素。
+/// This function searches a bitonic array, one that strictly increases and then strictly
+/// decreases, for the target element.
+///
+/// 先用 [`find_peak`] 找到峰值，再在左边的递增部分做升序二分查找，在右边的递减部分做降序二分查找。
+/// 任何一部分都可以为空，因此单调递增或单调递减的数组也可以查找。
+/// The peak is found with [`find_peak`], then the increasing part on its left is binary searched
+/// in ascending order and the decreasing part on its right in descending order. Either part may
+/// be empty, so purely increasing or decreasing arrays work too.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的双调数组
+///   The given bitonic array.
+/// * `target` - 待查找的元素
+///   The element to be searched for.
+///
+/// # Returns
+///
+/// 返回找到的元素在数组中的索引值。如果未找到，则返回 None。
+/// Returns the index of the found element in the array. If not found, returns None.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 4, 8, 6, 2];
+/// assert_eq!(bitonic_search(&items, &6), Some(3));
+/// assert_eq!(bitonic_search(&items, &5), None);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log n)：三次二分查找。
+/// O(log n): three binary searches.
+pub fn bitonic_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+  let peak = find_peak(arr)?;
+
+  // 递增部分 [0, peak]，包含峰值
+  // the increasing part [0, peak], peak included
+  if let Some(index) = binary_search(target, &arr[..=peak]) {
+    return Some(index);
+  }
+
+  // 递减部分 (peak, len)：比目标大的元素在前面
+  // the decreasing part (peak, len): elements greater than the target come first
+  binary_search_by(&arr[peak + 1..], |item| target.cmp(item)).map(|index| peak + 1 + index)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ascending_half() {
+    let items = [1, 3, 5, 9, 7, 4, 2];
+
+    assert_eq!(bitonic_search(&items, &1), Some(0));
+    assert_eq!(bitonic_search(&items, &5), Some(2));
+  }
+
+  #[test]
+  fn descending_half() {
+    let items = [1, 3, 5, 9, 7, 4, 2];
+
+    assert_eq!(bitonic_search(&items, &7), Some(4));
+    assert_eq!(bitonic_search(&items, &2), Some(6));
+  }
+
+  #[test]
+  fn peak() {
+    assert_eq!(bitonic_search(&[1, 3, 5, 9, 7, 4, 2], &9), Some(3));
+  }
+
+  #[test]
+  fn value_in_both_halves() {
+    let items = [1, 4, 8, 4, 0];
+    let index = bitonic_search(&items, &4).unwrap();
+
+    assert_eq!(items[index], 4);
+  }
+
+  #[test]
+  fn absent() {
+    let items = [1, 3, 5, 9, 7, 4, 2];
+
+    for target in [0, 6, 8, 10] {
+      assert_eq!(bitonic_search(&items, &target), None);
+    }
+
+    assert_eq!(bitonic_search(&[], &1), None);
+  }
+
+  #[test]
+  fn monotonic() {
+    let ascending: Vec<i32> = (0..50).collect();
+    let descending: Vec<i32> = (0..50).rev().collect();
+
+    for target in 0..50 {
+      assert_eq!(bitonic_search(&ascending, &target), Some(target as usize));
+      assert_eq!(
+        bitonic_search(&descending, &target),
+        Some(49 - target as usize)
+      );
+    }
+
+    assert_eq!(bitonic_search(&ascending, &50), None);
+    assert_eq!(bitonic_search(&descending, &-1), None);
+    assert_eq!(bitonic_search(&[5], &5), Some(0));
+  }
+}
+
+fn main() {}
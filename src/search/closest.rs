@@ -0,0 +1,194 @@
+use std::time::{Duration, Instant};
+
+/// 可以用 [`k_closest`] 查找的类型：两个值之间的距离可以比较。
+///
+/// A type [`k_closest`] can search: the distance between two values can be compared.
+pub trait Distance: Ord + Copy {
+  /// 距离的类型。 (The type of a distance.)
+  type Output: Ord;
+
+  /// 返回 `self` 与 `other` 之间的距离，与参数的顺序无关。
+  /// (Returns the distance between `self` and `other`, regardless of argument order.)
+  fn distance(self, other: Self) -> Self::Output;
+}
+
+macro_rules! impl_distance {
+  ($($t:ty => $d:ty),*) => {
+    $(
+      impl Distance for $t {
+        type Output = $d;
+
+        fn distance(self, other: Self) -> $d {
+          self.abs_diff(other)
+        }
+      }
+    )*
+  };
+}
+
+impl_distance!(
+  i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+  u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+impl Distance for Duration {
+  type Output = Duration;
+
+  fn distance(self, other: Self) -> Duration {
+    self.max(other) - self.min(other)
+  }
+}
+
+impl Distance for Instant {
+  type Output = Duration;
+
+  fn distance(self, other: Self) -> Duration {
+    self.max(other) - self.min(other)
+  }
+}
+
+/// 返回有序数组中离 `target` 最近的 k 个元素组成的连续窗口。
+/// This function returns the contiguous window of the k elements closest to `target` in a
+/// sorted array.
+///
+/// 二分查找窗口的起点 i：比较窗口左端 `arr[i]` 与窗口右边第一个元素 `arr[i + k]` 谁离 `target` 更近，
+/// 右边的更近时窗口右移。距离相同时选择较小的元素，即窗口尽量靠左。
+/// The window start i is binary searched by comparing the window's left end `arr[i]` with the
+/// first element past it, `arr[i + k]`: the window moves right when the latter is closer. Ties
+/// prefer the smaller element, keeping the window as far left as possible.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的有序数组
+///   The given sorted array.
+/// * `target` - 目标值，可以不在数组中，也可以超出数组的范围
+///   The target, which need not be in the array or even within its range.
+/// * `k` - 窗口的大小，不小于数组长度时返回整个数组
+///   The window size; the whole array is returned if it is at least the length.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 3, 4, 5];
+/// assert_eq!(k_closest(&items, &3, 4), &[1, 2, 3, 4]);
+/// assert_eq!(k_closest(&items, &-1, 2), &[1, 2]);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log n) 找到窗口，返回的是切片，不需要拷贝 k 个元素。
+/// O(log n) to find the window; the result borrows from `arr`, so no k elements are copied.
+pub fn k_closest<'a, T: Distance>(arr: &'a [T], target: &T, k: usize) -> &'a [T] {
+  if k >= arr.len() {
+    return arr;
+  }
+
+  let mut lo = 0;
+  let mut hi = arr.len() - k;
+
+  // 循环不变式：最优窗口的起点位于 [lo, hi] 中
+  // loop invariant: the best window starts within [lo, hi]
+  while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+
+    if moves_right(arr[mid], arr[mid + k], *target) {
+      lo = mid + 1;
+    } else {
+      hi = mid;
+    }
+  }
+
+  &arr[lo..lo + k]
+}
+
+/// 窗口 `[left, .., right)` 是否应该右移，即 `right` 是否比 `left` 严格更近于 `target`，其中 `left <= right`。
+/// Whether the window `[left, .., right)` should move right, i.e. whether `right` is strictly
+/// closer to `target` than `left`, where `left <= right`.
+fn moves_right<T: Distance>(left: T, right: T, target: T) -> bool {
+  // 先按顺序判断，left 与 right 相等（重复元素）时也能正确地决定方向
+  // decide by order first, which also gets the direction right when left equals right (duplicates)
+  if target <= left {
+    false
+  } else if right <= target {
+    true
+  } else {
+    left.distance(target) > right.distance(target)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+
+  /// 按距离排序后取前 k 个，距离相同时较小的元素优先
+  /// sort by distance and take the first k, preferring smaller elements on ties
+  fn brute_force(arr: &[i32], target: i32, k: usize) -> Vec<i32> {
+    let mut sorted = arr.to_vec();
+    sorted.sort_by_key(|&x| (x.abs_diff(target), x));
+    sorted.truncate(k);
+    sorted.sort();
+    sorted
+  }
+
+  #[test]
+  fn k_is_zero_or_at_least_len() {
+    let items = [1, 2, 3];
+
+    assert!(k_closest(&items, &2, 0).is_empty());
+    assert_eq!(k_closest(&items, &2, 3), &items);
+    assert_eq!(k_closest(&items, &2, 10), &items);
+    assert!(k_closest::<i32>(&[], &2, 1).is_empty());
+  }
+
+  #[test]
+  fn target_outside_range() {
+    let items = [10, 20, 30, 40];
+
+    assert_eq!(k_closest(&items, &-100, 2), &[10, 20]);
+    assert_eq!(k_closest(&items, &100, 2), &[30, 40]);
+  }
+
+  #[test]
+  fn ties_prefer_smaller() {
+    // 2 和 4 离 3 一样近，选择 2
+    // 2 and 4 are equally close to 3, so 2 is chosen
+    assert_eq!(k_closest(&[1, 2, 4, 5], &3, 1), &[2]);
+    assert_eq!(k_closest(&[1, 2, 4, 5], &3, 3), &[1, 2, 4]);
+  }
+
+  #[test]
+  fn duplicates_around_target() {
+    assert_eq!(k_closest(&[1, 1, 2, 2, 2, 2, 2, 3, 3], &3, 3), &[2, 3, 3]);
+    assert_eq!(k_closest(&[1, 1, 1, 10, 10, 10], &9, 2), &[10, 10]);
+    assert_eq!(k_closest(&[5, 5, 5, 5], &5, 2), &[5, 5]);
+  }
+
+  #[test]
+  fn timestamps() {
+    let start = Instant::now();
+    let stamps: Vec<Instant> = [0, 10, 20, 35, 50]
+      .iter()
+      .map(|&ms| start + Duration::from_millis(ms))
+      .collect();
+
+    let window = k_closest(&stamps, &(start + Duration::from_millis(30)), 2);
+    assert_eq!(window, &stamps[2..4]);
+  }
+
+  proptest! {
+    #[test]
+    fn matches_brute_force(
+      mut items in vec(-30..30i32, 0..60),
+      target in -40..40i32,
+      k in 0..70usize,
+    ) {
+      items.sort();
+
+      prop_assert_eq!(k_closest(&items, &target, k).to_vec(), brute_force(&items, target, k));
+    }
+  }
+}
+
+fn main() {}
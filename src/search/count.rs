@@ -0,0 +1,83 @@
+use rust_algorithm::search::binary_search::{lower_bound, upper_bound};
+
+/// 统计有序数组中等于 `target` 的元素个数。
+/// This function counts the elements equal to `target` in a sorted array.
+///
+/// 结果是 [`upper_bound`] 与 [`lower_bound`] 之差，只需两次二分查找而不是扫描，
+/// 即使目标值占据了一百万个元素中的一半也只需 O(log n)。
+/// The count is [`upper_bound`] minus [`lower_bound`], two binary searches rather than a scan,
+/// so it stays O(log n) even when the target fills half of a million-element array.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的有序数组
+///   The given sorted array.
+/// * `target` - 要统计的元素
+///   The element to count.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(count_occurrences(&items, &2), 3);
+/// assert_eq!(count_occurrences(&items, &4), 0);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log n)。
+/// O(log n).
+pub fn count_occurrences<T: Ord>(arr: &[T], target: &T) -> usize {
+  upper_bound(arr, target) - lower_bound(arr, target)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+
+  #[test]
+  fn absent() {
+    assert_eq!(count_occurrences(&[1, 3, 5], &0), 0);
+    assert_eq!(count_occurrences(&[1, 3, 5], &2), 0);
+    assert_eq!(count_occurrences(&[1, 3, 5], &6), 0);
+    assert_eq!(count_occurrences(&[], &1), 0);
+  }
+
+  #[test]
+  fn entire_slice() {
+    assert_eq!(count_occurrences(&["a"; 100], &"a"), 100);
+  }
+
+  #[test]
+  fn runs_at_either_end() {
+    let items = [1, 1, 1, 2, 3, 4, 4];
+
+    assert_eq!(count_occurrences(&items, &1), 3);
+    assert_eq!(count_occurrences(&items, &4), 2);
+    assert_eq!(count_occurrences(&items, &2), 1);
+  }
+
+  #[test]
+  fn half_of_a_million() {
+    let items: Vec<u32> = (0..1_000_000)
+      .map(|i| if i < 500_000 { 7 } else { i })
+      .collect();
+
+    assert_eq!(count_occurrences(&items, &7), 500_000);
+    assert_eq!(count_occurrences(&items, &500_000), 1);
+  }
+
+  proptest! {
+    #[test]
+    fn matches_filter_count(mut items in vec(0..10i32, 0..200), target in -1..11i32) {
+      items.sort();
+
+      let expected = items.iter().filter(|&&x| x == target).count();
+      prop_assert_eq!(count_occurrences(&items, &target), expected);
+    }
+  }
+}
+
+fn main() {}
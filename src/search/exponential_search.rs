@@ -0,0 +1,124 @@
+use rust_algorithm::search::binary_search_rec::binary_search_rec_range;
+
+/// 指数查找：从下标 1 开始不断把上界翻倍（1, 2, 4, ...），直到 `arr[bound] >= target` 或越过数组末尾，
+/// 再在 `[bound / 2, min(bound, len - 1)]` 区间内用 [`binary_search_rec_range`] 进行二分查找。
+/// This function implements exponential search: the upper bound starts at index 1 and doubles
+/// (1, 2, 4, ...) until `arr[bound] >= target` or the end is passed, then
+/// [`binary_search_rec_range`] searches the window `[bound / 2, min(bound, len - 1)]`.
+///
+/// 当目标很可能位于一个很长的有序数组的前部时，这比直接二分查找整个数组更快。
+/// This beats a plain binary search over the whole slice when the target is likely near the
+/// front of a very long sorted slice.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的有序数组
+///   The given sorted array.
+/// * `target` - 待查找的元素
+///   The element to be searched for.
+///
+/// # Returns
+///
+/// 返回找到的元素在数组中的索引值。如果未找到，则返回 None。
+/// Returns the index of the found element in the array. If not found, returns None.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 3, 4, 5];
+/// assert_eq!(exponential_search(&items, &4), Some(3));
+/// assert_eq!(exponential_search(&items, &6), None);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log i)，其中 i 是目标元素（或其插入位置）的索引。
+/// O(log i), where i is the index of the target (or of its insertion point).
+pub fn exponential_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+  if arr.is_empty() {
+    return None;
+  }
+
+  // 不断翻倍上界，直到上界处的元素不小于目标值或越过数组末尾
+  // keep doubling the bound until the element there is no less than the target or the end is passed
+  let mut bound = 1;
+  while bound < arr.len() && arr[bound] < *target {
+    bound *= 2;
+  }
+
+  // 目标只可能位于 [bound / 2, bound] 之间，右边界不包含在内，所以加一
+  // the target can only lie in [bound / 2, bound]; the right boundary is exclusive, hence the + 1
+  let left = bound / 2;
+  let right = arr.len().min(bound + 1);
+
+  binary_search_rec_range(arr, target, left..right)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_algorithm::test_support::{count_comparisons, Counted};
+
+  #[test]
+  fn empty() {
+    let items: [i32; 0] = [];
+
+    assert_eq!(exponential_search(&items, &1), None);
+  }
+
+  #[test]
+  fn first_and_last() {
+    let items: Vec<i32> = (0..100).collect();
+
+    assert_eq!(exponential_search(&items, &0), Some(0));
+    assert_eq!(exponential_search(&items, &99), Some(99));
+    assert_eq!(exponential_search(&[7], &7), Some(0));
+  }
+
+  #[test]
+  fn between_powers_of_two() {
+    let items: Vec<i32> = (0..1000).map(|x| x * 2).collect();
+
+    for index in [3, 5, 6, 7, 9, 100, 511, 513, 999] {
+      assert_eq!(exponential_search(&items, &items[index]), Some(index));
+    }
+  }
+
+  #[test]
+  fn not_found() {
+    let items: Vec<i32> = (0..1000).map(|x| x * 2).collect();
+
+    for target in [-1, 1, 3, 255, 1023, 1999, 2000, i32::MAX] {
+      assert_eq!(exponential_search(&items, &target), None);
+    }
+  }
+
+  #[test]
+  fn search_strings() {
+    let items = vec!["a", "b", "c", "d", "google", "zoo"];
+
+    assert_eq!(exponential_search(&items, &"google"), Some(4));
+    assert_eq!(exponential_search(&items, &"x"), None);
+  }
+
+  #[test]
+  fn comparisons_grow_with_log_position() {
+    let items: Vec<Counted<usize>> = (0..1 << 20).map(Counted).collect();
+
+    for position in [0, 1, 2, 10, 100, 1_000, 100_000, (1 << 20) - 1] {
+      let count = count_comparisons(|| {
+        assert_eq!(
+          exponential_search(&items, &Counted(position)),
+          Some(position)
+        );
+      });
+
+      // 翻倍和二分各约 log2(position) 次比较
+      // about log2(position) comparisons for doubling and as many again for the binary search
+      let log = (position + 1).next_power_of_two().trailing_zeros() as usize;
+      assert!(count <= 2 * log + 3, "{}: {}", position, count);
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,199 @@
+/// 从提示位置 `hint` 开始指数探测，返回 `target` 在有序数组中最左边的插入位置，
+/// 即第一个不小于 `target` 的元素的索引（所有元素都小于 `target` 时返回数组长度）。
+/// This function probes exponentially outwards from `hint` and returns the leftmost insertion
+/// point of `target` in the sorted array: the index of the first element not less than
+/// `target`, or the length if every element is less.
+///
+/// 这是 TimSort 合并时使用的 galloping 查找：答案离 `hint` 的距离为 d 时只需 O(log d) 次比较，
+/// 适合在上一次找到的位置附近继续查找。`hint` 可以是任意值，包括过期的或超出数组范围的值，
+/// 结果总是正确的，只是越接近答案越快。
+/// This is the galloping search TimSort uses while merging: an answer at distance d from `hint`
+/// costs O(log d) comparisons, which suits searching near a previously found position. `hint`
+/// may be anything, including stale or out-of-range values; the result is always correct, only
+/// faster the closer the hint is.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的有序数组
+///   The given sorted array.
+/// * `target` - 要查找插入位置的元素
+///   The element whose insertion point is wanted.
+/// * `hint` - 开始探测的位置
+///   The index to start probing from.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(gallop_left(&items, &2, 4), 1);
+/// assert_eq!(gallop_left(&items, &5, 0), 5);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log d)，其中 d 是答案与 `hint` 之间的距离。
+/// O(log d), where d is the distance between the answer and `hint`.
+pub fn gallop_left<T: Ord>(arr: &[T], target: &T, hint: usize) -> usize {
+  gallop(arr, hint, |item| item < target)
+}
+
+/// 从提示位置 `hint` 开始指数探测，返回 `target` 在有序数组中最右边的插入位置，
+/// 即第一个大于 `target` 的元素的索引（没有元素大于 `target` 时返回数组长度）。
+/// This function probes exponentially outwards from `hint` and returns the rightmost insertion
+/// point of `target` in the sorted array: the index of the first element greater than `target`,
+/// or the length if no element is greater.
+///
+/// 与 [`gallop_left`] 的区别只在于等于 `target` 的元素：`gallop_left` 返回它们之前的位置，
+/// `gallop_right` 返回它们之后的位置。
+/// It differs from [`gallop_left`] only on elements equal to `target`: `gallop_left` returns the
+/// position before them and `gallop_right` the position after them.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 3];
+/// assert_eq!(gallop_right(&items, &2, 0), 4);
+/// assert_eq!(gallop_right(&items, &0, 4), 0);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log d)，其中 d 是答案与 `hint` 之间的距离。
+/// O(log d), where d is the distance between the answer and `hint`.
+pub fn gallop_right<T: Ord>(arr: &[T], target: &T, hint: usize) -> usize {
+  gallop(arr, hint, |item| item <= target)
+}
+
+/// 返回第一个不满足 `pred` 的元素的索引，`pred` 在数组的前缀上成立、其余部分不成立。
+/// Returns the index of the first element failing `pred`, which holds on a prefix of the array
+/// and fails on the rest.
+fn gallop<T, F: FnMut(&T) -> bool>(arr: &[T], hint: usize, mut pred: F) -> usize {
+  let len = arr.len();
+
+  if len == 0 {
+    return 0;
+  }
+
+  let hint = hint.min(len - 1);
+
+  if pred(&arr[hint]) {
+    // 答案在 hint 右边：以 1, 2, 4, ... 的步长向右探测，last 始终满足 pred
+    // the answer is right of hint: probe rightwards at offsets 1, 2, 4, ...; last always satisfies pred
+    let mut last = hint;
+    let mut offset = 1;
+
+    while hint + offset < len && pred(&arr[hint + offset]) {
+      last = hint + offset;
+      offset *= 2;
+    }
+
+    // 答案位于 (last, min(hint + offset, len)] 之间
+    // the answer lies in (last, min(hint + offset, len)]
+    let hi = (hint + offset).min(len);
+    last + 1 + arr[last + 1..hi].partition_point(pred)
+  } else {
+    // 答案不超过 hint：以 1, 2, 4, ... 的步长向左探测，first 始终不满足 pred
+    // the answer is at most hint: probe leftwards at offsets 1, 2, 4, ...; first always fails pred
+    let mut first = hint;
+    let mut offset = 1;
+
+    while offset <= hint && !pred(&arr[hint - offset]) {
+      first = hint - offset;
+      offset *= 2;
+    }
+
+    // 答案位于 [max(hint - offset + 1, 0), first] 之间
+    // the answer lies in [max(hint - offset + 1, 0), first]
+    let lo = if offset > hint { 0 } else { hint - offset + 1 };
+    lo + arr[lo..first].partition_point(pred)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use std::cell::Cell;
+
+  #[test]
+  fn empty() {
+    assert_eq!(gallop_left(&[], &1, 0), 0);
+    assert_eq!(gallop_right(&[], &1, 5), 0);
+  }
+
+  #[test]
+  fn hint_at_answer() {
+    let items = [10, 20, 30, 40, 50];
+
+    assert_eq!(gallop_left(&items, &30, 2), 2);
+    assert_eq!(gallop_right(&items, &30, 3), 3);
+    assert_eq!(gallop_left(&items, &25, 2), 2);
+  }
+
+  #[test]
+  fn hint_far_from_answer() {
+    let items: Vec<i32> = (0..1_000).map(|i| i * 2).collect();
+
+    assert_eq!(gallop_left(&items, &1_900, 0), 950);
+    assert_eq!(gallop_left(&items, &100, 999), 50);
+    assert_eq!(gallop_right(&items, &1_901, 0), 951);
+    assert_eq!(gallop_right(&items, &-5, 999), 0);
+    assert_eq!(gallop_left(&items, &5_000, 500), 1_000);
+  }
+
+  #[test]
+  fn stale_or_out_of_range_hint() {
+    let items = [1, 2, 3, 4, 5];
+
+    for hint in [0, 4, 5, 100, usize::MAX] {
+      assert_eq!(gallop_left(&items, &3, hint), 2);
+      assert_eq!(gallop_right(&items, &3, hint), 3);
+    }
+  }
+
+  #[test]
+  fn runs_of_duplicates() {
+    let items = [1, 2, 2, 2, 2, 2, 3];
+
+    for hint in 0..items.len() {
+      assert_eq!(gallop_left(&items, &2, hint), 1);
+      assert_eq!(gallop_right(&items, &2, hint), 6);
+    }
+
+    assert_eq!(gallop_left(&[7; 10], &7, 5), 0);
+    assert_eq!(gallop_right(&[7; 10], &7, 5), 10);
+  }
+
+  #[test]
+  fn comparisons_grow_with_distance() {
+    let items: Vec<u32> = (0..1 << 20).collect();
+
+    for distance in [1u32, 10, 1_000, 100_000] {
+      let count = Cell::new(0u32);
+      let index = gallop(&items, 0, |&x| {
+        count.set(count.get() + 1);
+        x < distance
+      });
+
+      assert_eq!(index, distance as usize);
+      assert!(count.get() <= 2 * (32 - distance.leading_zeros()) + 2);
+    }
+  }
+
+  proptest! {
+    #[test]
+    fn matches_partition_point(
+      mut items in vec(0..20i32, 0..100),
+      target in -1..21i32,
+      hint in 0..120usize,
+    ) {
+      items.sort();
+
+      prop_assert_eq!(gallop_left(&items, &target, hint), items.partition_point(|x| *x < target));
+      prop_assert_eq!(gallop_right(&items, &target, hint), items.partition_point(|x| *x <= target));
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,134 @@
+/// 跳跃查找：以 sqrt(n) 为步长按块向前跳跃，找到第一个末尾元素不小于目标值的块后，在块内线性查找。
+/// This function implements jump search: it jumps ahead in blocks of sqrt(n) elements until it
+/// reaches the first block whose last element is no less than the target, then scans that
+/// block linearly.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的有序数组
+///   The given sorted array.
+/// * `target` - 待查找的元素
+///   The element to be searched for.
+///
+/// # Returns
+///
+/// 返回第一个等于目标值的元素的索引。如果未找到，则返回 None。
+/// Returns the index of the first element equal to the target. If not found, returns None.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 2, 2, 5];
+/// assert_eq!(jump_search(&items, &2), Some(1));
+/// assert_eq!(jump_search(&items, &3), None);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(sqrt(n))：最多跳跃 sqrt(n) 次，块内最多比较 sqrt(n) 次。
+/// O(sqrt(n)): at most sqrt(n) jumps followed by at most sqrt(n) comparisons within a block.
+pub fn jump_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+  let len = arr.len();
+
+  if len == 0 {
+    return None;
+  }
+
+  let step = ((len as f64).sqrt() as usize).max(1);
+
+  // 当前块为 [start, end)，end 不会超过数组长度
+  // the current block is [start, end); end never exceeds the length of the array
+  let mut start = 0;
+  let mut end = step.min(len);
+
+  // 跳过所有末尾元素小于目标值的块
+  // skip every block whose last element is less than the target
+  while arr[end - 1] < *target {
+    if end == len {
+      return None;
+    }
+
+    start = end;
+    end = (end + step).min(len);
+  }
+
+  // 之前的块都小于目标值，所以块内的第一个匹配就是整个数组的第一个匹配
+  // every earlier block is less than the target, so the first match in this block is the first overall
+  for (i, item) in arr.iter().enumerate().take(end).skip(start) {
+    if item == target {
+      return Some(i);
+    }
+
+    if item > target {
+      break;
+    }
+  }
+
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::btree_set;
+  use proptest::prelude::*;
+  use rust_algorithm::search::binary_search::binary_search;
+
+  #[test]
+  fn empty() {
+    let index = jump_search(&vec![], &"a");
+
+    assert_eq!(index, None);
+  }
+
+  #[test]
+  fn one_item() {
+    assert_eq!(jump_search(&vec!["a"], &"a"), Some(0));
+    assert_eq!(jump_search(&vec!["a"], &"b"), None);
+    assert_eq!(jump_search(&vec!["b"], &"a"), None);
+  }
+
+  #[test]
+  fn search_strings() {
+    let index = jump_search(&vec!["a", "b", "c", "d", "google", "zoo"], &"a");
+
+    assert_eq!(index, Some(0));
+  }
+
+  #[test]
+  fn search_ints() {
+    let items = vec![1, 2, 3, 4];
+
+    assert_eq!(jump_search(&items, &4), Some(3));
+    assert_eq!(jump_search(&items, &3), Some(2));
+    assert_eq!(jump_search(&items, &2), Some(1));
+    assert_eq!(jump_search(&items, &1), Some(0));
+  }
+
+  #[test]
+  fn not_found() {
+    let items = vec![1, 2, 3, 4];
+
+    assert_eq!(jump_search(&items, &5), None);
+    assert_eq!(jump_search(&items, &0), None);
+  }
+
+  #[test]
+  fn first_of_duplicates() {
+    let items = vec![0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2];
+
+    assert_eq!(jump_search(&items, &1), Some(1));
+    assert_eq!(jump_search(&items, &2), Some(11));
+  }
+
+  proptest! {
+    #[test]
+    fn agrees_with_binary_search(set in btree_set(-500..500i32, 0..200), target in -600..600i32) {
+      let items: Vec<i32> = set.into_iter().collect();
+
+      prop_assert_eq!(jump_search(&items, &target), binary_search(&target, &items));
+    }
+  }
+}
+
+fn main() {}
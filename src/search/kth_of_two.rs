@@ -0,0 +1,207 @@
+/// 计算两个有序切片合并后（保留重复元素）的第 k 小元素，不需要修改或合并切片。
+///
+/// Finds the kth smallest element of the multiset union of two individually sorted slices,
+/// without modifying or merging them. Unlike [`kth_smallest`], it needs neither mutable access
+/// nor a single slice.
+/// If k is out of range (`k == 0` or `k > a.len() + b.len()`), it returns None.
+///
+/// 每一步比较两个切片中各自第 k / 2 个元素，较小的那一侧的前 k / 2 个元素不可能是答案，直接丢弃。
+///
+/// Each step compares the (k / 2)th element of both slices; the first k / 2 elements on the
+/// smaller side cannot be the answer and are discarded.
+///
+/// [`kth_smallest`]: rust_algorithm::search::kth_smallest::kth_smallest
+///
+/// # 参数 (Parameters)
+/// - `a`, `b`: 两个升序切片。 (Two slices sorted in ascending order.)
+/// - `k`: 要查找的第 k 小元素的位置，从 1 开始。 (The 1-based position of the element to find.)
+///
+/// # 使用示例 (Example)
+/// ```
+/// let a = [1, 3, 5];
+/// let b = [2, 4, 6, 8];
+/// assert_eq!(kth_of_two_sorted(&a, &b, 4), Some(4));
+/// assert_eq!(kth_of_two_sorted(&a, &b, 8), None);
+/// ```
+///
+/// # 算法复杂度 (Algorithm Complexity)
+/// - 时间复杂度为 O(log k)，空间复杂度为 O(1)。 (O(log k) time and O(1) space.)
+pub fn kth_of_two_sorted<T: Ord + Copy>(a: &[T], b: &[T], k: usize) -> Option<T> {
+  if k == 0 || k > a.len() + b.len() {
+    return None;
+  }
+
+  let (mut a, mut b, mut k) = (a, b, k);
+
+  loop {
+    // 一个切片已经为空，答案就在另一个切片中
+    // One slice is exhausted, so the answer lies in the other.
+    if a.is_empty() {
+      return Some(b[k - 1]);
+    }
+    if b.is_empty() {
+      return Some(a[k - 1]);
+    }
+
+    if k == 1 {
+      return Some(a[0].min(b[0]));
+    }
+
+    // 较短的切片可能不足 k / 2 个元素
+    // The shorter slice may hold fewer than k / 2 elements.
+    let i = (k / 2).min(a.len());
+    let j = (k / 2).min(b.len());
+
+    if a[i - 1] <= b[j - 1] {
+      a = &a[i..];
+      k -= i;
+    } else {
+      b = &b[j..];
+      k -= j;
+    }
+  }
+}
+
+/// 计算两个有序切片合并后的下中位数和上中位数：总长度为奇数时两者是同一个元素，
+/// 为偶数时是中间的两个元素。两个切片都为空时返回 None。
+///
+/// Finds the lower and upper medians of the union of two sorted slices with
+/// [`kth_of_two_sorted`]. For an odd total length both are the middle element; for an even one
+/// they are the two middle elements. Returns None if both slices are empty.
+///
+/// 返回两个元素而不是平均值，这样任何 `Ord` 类型都能使用（包括 `i64`、`u64`、`usize` 等无法
+/// 无损转换为 `f64` 的类型），由调用者决定如何取平均。
+///
+/// It returns the two elements rather than their mean, so it works for any `Ord` type, including
+/// `i64`, `u64` and `usize`, which do not convert to `f64` losslessly; the caller decides how
+/// to average them.
+///
+/// # 使用示例 (Example)
+/// ```
+/// assert_eq!(median_of_two_sorted(&[1, 3], &[2]), Some((2, 2)));
+/// assert_eq!(median_of_two_sorted(&[1, 2], &[3, 4]), Some((2, 3)));
+///
+/// let (lower, upper) = median_of_two_sorted(&[1, 2], &[3, 4]).unwrap();
+/// assert_eq!((lower + upper) as f64 / 2.0, 2.5);
+/// ```
+pub fn median_of_two_sorted<T: Ord + Copy>(a: &[T], b: &[T]) -> Option<(T, T)> {
+  let len = a.len() + b.len();
+
+  let lower = kth_of_two_sorted(a, b, len.div_ceil(2))?;
+  let upper = kth_of_two_sorted(a, b, len / 2 + 1)?;
+
+  Some((lower, upper))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{kth_of_two_sorted, median_of_two_sorted};
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+
+  fn merged(a: &[i32], b: &[i32]) -> Vec<i32> {
+    let mut all = [a, b].concat();
+    all.sort();
+    all
+  }
+
+  #[test]
+  fn one_empty() {
+    let a = [1, 2, 3];
+
+    for k in 1..=3 {
+      assert_eq!(Some(k as i32), kth_of_two_sorted(&a, &[], k));
+      assert_eq!(Some(k as i32), kth_of_two_sorted(&[], &a, k));
+    }
+
+    assert_eq!(None, kth_of_two_sorted::<i32>(&[], &[], 1));
+  }
+
+  #[test]
+  fn wildly_different_lengths() {
+    let a = [50];
+    let b: Vec<i32> = (0..1_000).collect();
+    let all = merged(&a, &b);
+
+    for k in 1..=all.len() {
+      assert_eq!(Some(all[k - 1]), kth_of_two_sorted(&a, &b, k));
+      assert_eq!(Some(all[k - 1]), kth_of_two_sorted(&b, &a, k));
+    }
+  }
+
+  #[test]
+  fn one_array_entirely_smaller() {
+    let a = [1, 2, 3];
+    let b = [10, 20, 30, 40];
+
+    assert_eq!(Some(3), kth_of_two_sorted(&a, &b, 3));
+    assert_eq!(Some(10), kth_of_two_sorted(&a, &b, 4));
+    assert_eq!(Some(40), kth_of_two_sorted(&b, &a, 7));
+  }
+
+  #[test]
+  fn duplicates_across_arrays() {
+    let a = [1, 2, 2, 2, 5];
+    let b = [2, 2, 3];
+    let all = merged(&a, &b);
+
+    for k in 1..=all.len() {
+      assert_eq!(Some(all[k - 1]), kth_of_two_sorted(&a, &b, k));
+    }
+  }
+
+  #[test]
+  fn k_out_of_range() {
+    let a = [1, 2];
+    let b = [3];
+
+    assert_eq!(None, kth_of_two_sorted(&a, &b, 0));
+    assert_eq!(Some(3), kth_of_two_sorted(&a, &b, 3));
+    assert_eq!(None, kth_of_two_sorted(&a, &b, 4));
+    assert_eq!(None, kth_of_two_sorted(&a, &b, usize::MAX));
+  }
+
+  #[test]
+  fn medians() {
+    assert_eq!(None, median_of_two_sorted::<i32>(&[], &[]));
+    assert_eq!(Some((7, 7)), median_of_two_sorted(&[7], &[]));
+    assert_eq!(Some((2, 2)), median_of_two_sorted(&[1, 3], &[2]));
+    assert_eq!(Some((2, 3)), median_of_two_sorted(&[1, 2], &[3, 4]));
+    assert_eq!(Some((-2, -1)), median_of_two_sorted(&[-5, -2], &[-1, 9]));
+  }
+
+  #[test]
+  fn medians_of_wide_integers() {
+    // 这些值转换为 f64 会丢失精度
+    // These values lose precision as f64.
+    let a = [u64::MAX - 3, u64::MAX - 1];
+    let b = [u64::MAX - 2, u64::MAX];
+    assert_eq!(
+      Some((u64::MAX - 2, u64::MAX - 1)),
+      median_of_two_sorted(&a, &b)
+    );
+
+    let a = [i64::MIN, 0];
+    let b = [i64::MAX];
+    assert_eq!(Some((0, 0)), median_of_two_sorted(&a, &b));
+
+    let a = [1usize, 5];
+    let b = [3usize];
+    assert_eq!(Some((3, 3)), median_of_two_sorted(&a, &b));
+  }
+
+  proptest! {
+    #[test]
+    fn agrees_with_merging(mut a in vec(-20..20i32, 0..50), mut b in vec(-20..20i32, 0..50)) {
+      a.sort();
+      b.sort();
+      let all = merged(&a, &b);
+
+      for k in 1..=all.len() {
+        prop_assert_eq!(Some(all[k - 1]), kth_of_two_sorted(&a, &b, k));
+      }
+    }
+  }
+}
+
+fn main() {}
@@ -1,95 +1,334 @@
-pub mod kth_smallest {
-  use std::cmp::Ordering;
-
-  use rust_algorithm::sorting::quick_sort::partition;
-
-  /// 计算给定可变切片中的第 k 小元素。
-  ///
-  /// Uses the QuickSelect algorithm to find the kth smallest element. This function
-  /// modifies the input slice for sorting purposes, but does not perform element copying.
-  /// If the input slice is empty or k is out of range, it returns None.
-  ///
-  /// 使用快速选择算法来查找第 k 小元素。该函数会修改输入切片以进行排序，但不会
-  /// 对切片中的元素进行拷贝。如果输入切片为空或 k 超出了切片范围，将返回 None。
-  ///
-  /// # 参数 (Parameters)
-  /// - `input`: 可变切片，包含待查找第 k 小元素的元素。 (Mutable slice containing elements to find the kth smallest element from.)
-  /// - `k`: 要查找的第 k 小元素的位置。 (The position of the kth smallest element to find.)
-  ///
-  /// # 返回值 (Returns)
-  /// - 如果找到第 k 小元素，则返回 Some(T)，其中 T 是元素的类型。
-  /// - If the kth smallest element is found, it returns Some(T), where T is the type of the element.
-  /// - 如果输入切片为空或 k 超出了切片范围，则返回 None。
-  /// - If the input slice is empty or k is out of range, it returns None.
-  ///
-  /// # 使用示例 (Example)
-  /// ```
-  /// let mut nums = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
-  /// let k = 5;
-  /// let result = kth_smallest(&mut nums, k);
-  /// assert_eq!(result, Some(3));
-  /// ```
-  ///
-  /// # 算法复杂度 (Algorithm Complexity)
-  /// - 平均情况下，时间复杂度为 O(n)，其中 n 是切片的大小。 (Average-case time complexity is O(n), where n is the size of the slice.)
-  /// - 最坏情况下，时间复杂度为 O(n^2)。但通过选择合适的主元素和随机化，最坏情况
-  ///   可以避免。 (Worst-case time complexity is O(n^2). However, this can be mitigated by selecting
-  ///   a suitable pivot element and applying randomization.)
-  ///
-  /// # 注意事项 (Note)
-  /// - 这个函数会修改输入切片以进行排序。 (This function modifies the input slice for sorting purposes.)
-  pub fn kth_smallest<T>(input: &mut [T], k: usize) -> Option<T>
-  where
-    T: PartialOrd + Copy,
-  {
-    // 如果输入数组为空，直接返回None
-    // If the input array is empty, return None.
-    if input.is_empty() {
-      return None;
-    }
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use rust_algorithm::sorting::quick_sort::partition_random_with;
+
+/// 计算给定可变切片中的第 k 小元素。
+///
+/// Uses the QuickSelect algorithm to find the kth smallest element. This function
+/// modifies the input slice for sorting purposes, but does not perform element copying.
+/// If the input slice is empty or k is out of range, it returns None.
+///
+/// 使用快速选择算法来查找第 k 小元素。该函数会修改输入切片以进行排序，但不会
+/// 对切片中的元素进行拷贝。如果输入切片为空或 k 超出了切片范围，将返回 None。
+///
+/// # 参数 (Parameters)
+/// - `input`: 可变切片，包含待查找第 k 小元素的元素。 (Mutable slice containing elements to find the kth smallest element from.)
+/// - `k`: 要查找的第 k 小元素的位置。 (The position of the kth smallest element to find.)
+///
+/// # 返回值 (Returns)
+/// - 如果找到第 k 小元素，则返回 Some(T)，其中 T 是元素的类型。
+/// - If the kth smallest element is found, it returns Some(T), where T is the type of the element.
+/// - 如果输入切片为空或 k 超出了切片范围，则返回 None。
+/// - If the input slice is empty or k is out of range, it returns None.
+///
+/// # 使用示例 (Example)
+/// ```
+/// let mut nums = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// let k = 5;
+/// let result = kth_smallest(&mut nums, k);
+/// assert_eq!(result, Some(3));
+/// ```
+///
+/// # 算法复杂度 (Algorithm Complexity)
+/// - 平均情况下，时间复杂度为 O(n)，其中 n 是切片的大小。 (Average-case time complexity is O(n), where n is the size of the slice.)
+/// - 最坏情况下，时间复杂度为 O(n^2)。主元是随机选择的，因此最坏情况与输入的顺序无关，
+///   有序的输入也是期望 O(n)。 (Worst-case time complexity is O(n^2). The pivot is chosen at
+///   random, so the worst case does not depend on the input order and sorted input is expected
+///   O(n) as well.)
+/// - 需要保证线性最坏情况时，使用 [`kth_smallest_deterministic`]。 (Use
+///   [`kth_smallest_deterministic`] when a linear worst case is required.)
+///
+/// # 注意事项 (Note)
+/// - 这个函数会修改输入切片以进行排序。 (This function modifies the input slice for sorting purposes.)
+pub fn kth_smallest<T>(input: &mut [T], k: usize) -> Option<T>
+where
+  T: PartialOrd + Copy,
+{
+  // 如果k不在[1, len]范围内（包括输入数组为空的情况），直接返回None
+  // If k is not within [1, len] (which includes an empty input array), return None.
+  if k == 0 || k > input.len() {
+    return None;
+  }
+
+  kth_smallest_with_rng(input, k, &mut rand::thread_rng())
+}
 
-    // 调用私有函数_kth_smallest来找到第k小的元素
-    // Call the private function _kth_smallest to find the kth smallest element.
-    let kth = _kth_smallest(input, k, 0, input.len() - 1);
-    Some(kth)
+/// 与 [`kth_smallest`] 相同，但使用给定的随机数生成器选择主元，便于得到可复现的结果。
+///
+/// The same as [`kth_smallest`], but picks pivots with the given random number generator, which
+/// makes the work done reproducible.
+///
+/// # 使用示例 (Example)
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut nums = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// let mut rng = StdRng::seed_from_u64(7);
+/// assert_eq!(kth_smallest_with_rng(&mut nums, 5, &mut rng), Some(3));
+/// ```
+pub fn kth_smallest_with_rng<T, R>(input: &mut [T], k: usize, rng: &mut R) -> Option<T>
+where
+  T: PartialOrd + Copy,
+  R: Rng + ?Sized,
+{
+  if k == 0 || k > input.len() {
+    return None;
   }
 
-  fn _kth_smallest<T>(input: &mut [T], k: usize, lo: usize, hi: usize) -> T
-  where
-    T: PartialOrd + Copy,
-  {
+  // 调用私有函数_kth_smallest来找到第k小的元素
+  // Call the private function _kth_smallest to find the kth smallest element.
+  let kth = _kth_smallest(input, k, 0, input.len() - 1, rng);
+  Some(kth)
+}
+
+/// 计算给定可变切片中的第 k 大元素。
+///
+/// Uses the QuickSelect algorithm to find the kth largest element, translating `k` into the
+/// matching position from the small end, so callers do not have to compute `len - k + 1`.
+/// If the input slice is empty or k is out of range (`k == 0` or `k > len`), it returns None.
+///
+/// 使用快速选择算法来查找第 k 大元素，在内部把 `k` 换算成从小端数起的位置，
+/// 调用者不需要自己计算 `len - k + 1`。如果输入切片为空或 k 超出了切片范围（`k == 0` 或 `k > len`），将返回 None。
+///
+/// # 参数 (Parameters)
+/// - `input`: 可变切片，包含待查找第 k 大元素的元素。 (Mutable slice containing elements to find the kth largest element from.)
+/// - `k`: 要查找的第 k 大元素的位置，从 1 开始。 (The 1-based position of the kth largest element to find.)
+///
+/// # 使用示例 (Example)
+/// ```
+/// let mut nums = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// assert_eq!(kth_largest(&mut nums, 1), Some(9));
+/// assert_eq!(kth_largest(&mut nums, 3), Some(5));
+/// ```
+///
+/// # 注意事项 (Note)
+/// - 这个函数会修改输入切片。 (This function modifies the input slice.)
+pub fn kth_largest<T>(input: &mut [T], k: usize) -> Option<T>
+where
+  T: PartialOrd + Copy,
+{
+  let len = input.len();
+
+  if k == 0 || k > len {
+    return None;
+  }
+
+  // 第 k 大就是第 len - k + 1 小
+  // The kth largest is the (len - k + 1)th smallest.
+  Some(_kth_smallest(
+    input,
+    len - k + 1,
+    0,
+    len - 1,
+    &mut rand::thread_rng(),
+  ))
+}
+
+/// 计算给定可变切片的中位数，长度为偶数时返回较小的那个（下中位数）。
+///
+/// Finds the median of the slice with QuickSelect, returning the lower median for even lengths.
+/// If the input slice is empty, it returns None.
+///
+/// # 使用示例 (Example)
+/// ```
+/// let mut nums = [4, 1, 3, 2];
+/// assert_eq!(median(&mut nums), Some(2));
+/// ```
+///
+/// # 注意事项 (Note)
+/// - 这个函数会修改输入切片。 (This function modifies the input slice.)
+pub fn median<T>(input: &mut [T]) -> Option<T>
+where
+  T: PartialOrd + Copy,
+{
+  if input.is_empty() {
+    return None;
+  }
+
+  let len = input.len();
+  Some(_kth_smallest(
+    input,
+    len.div_ceil(2),
+    0,
+    len - 1,
+    &mut rand::thread_rng(),
+  ))
+}
+
+/// 在 `input[lo..=hi]` 中查找第 `k` 小的元素，调用者需保证 `1 <= k <= hi - lo + 1`。
+/// 使用循环缩小 lo/hi 而不是递归，主元选得很差时也不会栈溢出。
+/// Finds the `k`th smallest element of `input[lo..=hi]`; callers must ensure
+/// `1 <= k <= hi - lo + 1`. It narrows lo/hi in a loop instead of recursing, so poor pivots
+/// cannot overflow the stack.
+fn _kth_smallest<T, R>(
+  input: &mut [T],
+  mut k: usize,
+  mut lo: usize,
+  mut hi: usize,
+  rng: &mut R,
+) -> T
+where
+  T: PartialOrd + Copy,
+  R: Rng + ?Sized,
+{
+  loop {
     // 如果子数组只有一个元素，直接返回该元素
     // If the subarray contains only one element, return that element.
     if lo == hi {
       return input[lo];
     }
 
-    // 使用partition函数找到pivot元素的索引
-    // Use the partition function to find the index of the pivot element.
-    let pivot = partition(input, lo, hi);
+    // 使用随机选择的主元进行划分，找到pivot元素的索引
+    // Partition around a randomly chosen pivot to find the index of the pivot element.
+    let pivot = partition_random_with(input, lo, hi, rng);
     // 计算pivot元素是第几小的元素（i）
     // Calculate the position (i) of the pivot element.
     let i = pivot - lo + 1;
 
-    // 根据k和i的比较来决定递归查找左边或右边的子数组
-    // Determine whether to recursively search in the left or right subarray based on the comparison of k and i.
+    // 根据k和i的比较来决定在左边或右边的子数组中继续查找
+    // Determine whether to continue in the left or right subarray based on the comparison of k and i.
     match k.cmp(&i) {
       // 如果k等于i，直接返回pivot元素
       // If k is equal to i, directly return the pivot element.
-      Ordering::Equal => input[pivot],
-      // 如果k小于i，递归在左边子数组中查找
-      // If k is less than i, recursively search in the left subarray.
-      Ordering::Less => _kth_smallest(input, k, lo, pivot - 1),
-      // 如果k大于i，递归在右边子数组中查找
-      // If k is greater than i, recursively search in the right subarray.
-      Ordering::Greater => _kth_smallest(input, k - i, pivot + 1, hi),
+      Ordering::Equal => return input[pivot],
+      // 如果k小于i，在左边子数组中继续查找；k >= 1 保证 pivot > lo，不会下溢
+      // If k is less than i, continue in the left subarray; k >= 1 ensures pivot > lo, so no underflow.
+      Ordering::Less => hi = pivot - 1,
+      // 如果k大于i，在右边子数组中继续查找
+      // If k is greater than i, continue in the right subarray.
+      Ordering::Greater => {
+        k -= i;
+        lo = pivot + 1;
+      }
+    }
+  }
+}
+
+/// 使用中位数的中位数（Median of Medians）算法计算第 k 小元素，最坏情况下也是 O(n)；
+/// 而 [`kth_smallest`] 的随机主元只保证期望 O(n)。
+///
+/// Finds the kth smallest element with the median-of-medians algorithm (groups of five), which
+/// guarantees linear time in the worst case, whereas the random pivots of [`kth_smallest`] are
+/// only linear in expectation. It is slower than [`kth_smallest`] on typical inputs, so callers
+/// opt into it.
+/// If the input slice is empty or k is out of range (`k == 0` or `k > len`), it returns None.
+///
+/// 每 5 个元素分为一组，取各组中位数的中位数作为主元，保证每次划分至少丢弃约 3/10 的元素。
+/// 划分采用三路划分，大量相等元素也不会退化。
+///
+/// Every group of five elements contributes its median, and the median of those medians is
+/// used as the pivot, so each partition discards at least about 3/10 of the elements. The
+/// partition is three-way, so many equal elements do not degrade it either.
+///
+/// # 使用示例 (Example)
+/// ```
+/// let mut nums = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// assert_eq!(kth_smallest_deterministic(&mut nums, 5), Some(3));
+/// ```
+///
+/// # 注意事项 (Note)
+/// - 这个函数会修改输入切片。 (This function modifies the input slice.)
+pub fn kth_smallest_deterministic<T>(input: &mut [T], k: usize) -> Option<T>
+where
+  T: Ord + Copy,
+{
+  if k == 0 || k > input.len() {
+    return None;
+  }
+
+  Some(select(input, k - 1))
+}
+
+/// 返回切片中从 0 开始数第 `k` 小的元素，`k` 必须小于切片长度。
+/// Returns the 0-based `k`th smallest element of the slice; `k` must be less than its length.
+fn select<T: Ord + Copy>(input: &mut [T], mut k: usize) -> T {
+  let mut slice = input;
+
+  loop {
+    if slice.len() <= 5 {
+      insertion_sort(slice);
+      return slice[k];
+    }
+
+    let pivot = median_of_medians(slice);
+    let (lt, gt) = partition_three_way(slice, pivot);
+
+    // [0, lt) 小于主元，[lt, gt) 等于主元，[gt, len) 大于主元
+    // [0, lt) is less than the pivot, [lt, gt) equal to it and [gt, len) greater.
+    if k < lt {
+      slice = &mut slice[..lt];
+    } else if k < gt {
+      return pivot;
+    } else {
+      k -= gt;
+      slice = &mut slice[gt..];
+    }
+  }
+}
+
+/// 把每组 5 个元素的中位数移到切片开头，再递归选出这些中位数的中位数。
+/// Moves the median of every group of five to the front of the slice, then recursively selects
+/// the median of those medians.
+fn median_of_medians<T: Ord + Copy>(slice: &mut [T]) -> T {
+  let groups = slice.len().div_ceil(5);
+
+  for group in 0..groups {
+    let start = group * 5;
+    let end = (start + 5).min(slice.len());
+
+    insertion_sort(&mut slice[start..end]);
+    slice.swap(group, start + (end - start - 1) / 2);
+  }
+
+  select(&mut slice[..groups], (groups - 1) / 2)
+}
+
+/// 三路划分，返回 `(lt, gt)`：`[0, lt)` 小于 `pivot`，`[lt, gt)` 等于 `pivot`，`[gt, len)` 大于 `pivot`。
+/// Three-way partition returning `(lt, gt)`: `[0, lt)` is less than `pivot`, `[lt, gt)` equal
+/// to it and `[gt, len)` greater.
+fn partition_three_way<T: Ord>(slice: &mut [T], pivot: T) -> (usize, usize) {
+  let (mut lt, mut mid, mut gt) = (0, 0, slice.len());
+
+  while mid < gt {
+    match slice[mid].cmp(&pivot) {
+      Ordering::Less => {
+        slice.swap(lt, mid);
+        lt += 1;
+        mid += 1;
+      }
+      Ordering::Equal => mid += 1,
+      Ordering::Greater => {
+        gt -= 1;
+        slice.swap(mid, gt);
+      }
+    }
+  }
+
+  (lt, gt)
+}
+
+fn insertion_sort<T: Ord>(slice: &mut [T]) {
+  for i in 1..slice.len() {
+    let mut j = i;
+
+    while j > 0 && slice[j - 1] > slice[j] {
+      slice.swap(j - 1, j);
+      j -= 1;
     }
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::kth_smallest::kth_smallest;
+  use super::{
+    kth_largest, kth_smallest, kth_smallest_deterministic, kth_smallest_with_rng, median,
+  };
+  use proptest::prelude::*;
+  use rand::rngs::StdRng;
+  use rand::{RngCore, SeedableRng};
 
   #[test]
   fn empty() {
@@ -118,6 +357,166 @@ mod tests {
     assert_eq!(7, sixth.unwrap());
     assert_eq!(17, fourteenth.unwrap());
   }
+
+  #[test]
+  fn k_out_of_range() {
+    let mut many = [3, 1, 2];
+    let len = many.len();
+    assert_eq!(None, kth_smallest(&mut many, 0));
+    assert_eq!(Some(3), kth_smallest(&mut many, len));
+    assert_eq!(None, kth_smallest(&mut many, len + 1));
+    assert_eq!(None, kth_smallest(&mut many, usize::MAX));
+
+    assert_eq!(None, kth_largest(&mut many, usize::MAX));
+    assert_eq!(None, kth_smallest(&mut [0u8; 0], 0));
+  }
+
+  #[test]
+  fn sorted_input_does_not_overflow_the_stack() {
+    // 划分在循环中进行而不是递归，即使主元选得很差（每次只去掉主元）也不会加深调用栈
+    // Partitions run in a loop rather than recursing, so even poor pivots that only remove
+    // themselves cannot deepen the call stack.
+    let mut sorted: Vec<u32> = (0..10_000).collect();
+    assert_eq!(Some(9_999), kth_smallest(&mut sorted, 10_000));
+  }
+
+  /// 统计随机数的生成次数，每次划分只生成一个主元下标
+  /// Counts the random numbers drawn; each partition draws a single pivot index.
+  struct CountingRng {
+    inner: StdRng,
+    draws: usize,
+  }
+
+  impl RngCore for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+      self.draws += 1;
+      self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      self.draws += 1;
+      self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      self.draws += 1;
+      self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+      self.draws += 1;
+      self.inner.try_fill_bytes(dest)
+    }
+  }
+
+  #[test]
+  fn sorted_input_takes_logarithmic_partitions() {
+    // 以 lo 为主元时，有序输入上选择中位数需要约 n / 2 次划分；随机主元期望只需 O(log n) 次
+    // With lo as the pivot, selecting the median of sorted input took about n / 2 partitions;
+    // random pivots need O(log n) in expectation.
+    let n = 200_000;
+    let mut sorted: Vec<u32> = (0..n).collect();
+    let mut rng = CountingRng {
+      inner: StdRng::seed_from_u64(0x5eed),
+      draws: 0,
+    };
+
+    let median = kth_smallest_with_rng(&mut sorted, n as usize / 2, &mut rng);
+
+    assert_eq!(Some(n / 2 - 1), median);
+    assert!(rng.draws < 200, "{} partitions", rng.draws);
+  }
+
+  #[test]
+  fn many_elements_from_the_large_end() {
+    // 17 16 13 12 10 9 9 8 7 5 4 3 1 0
+    let mut many = [9, 17, 3, 16, 13, 10, 1, 5, 7, 12, 4, 8, 9, 0];
+    let first = kth_largest(&mut many, 1);
+    let third = kth_largest(&mut many, 3);
+    let sixth = kth_largest(&mut many, 6);
+    let seventh = kth_largest(&mut many, 7);
+    let fourteenth = kth_largest(&mut many, 14);
+    assert_eq!(17, first.unwrap());
+    assert_eq!(13, third.unwrap());
+    assert_eq!(9, sixth.unwrap());
+    assert_eq!(9, seventh.unwrap());
+    assert_eq!(0, fourteenth.unwrap());
+  }
+
+  #[test]
+  fn kth_largest_out_of_range() {
+    let mut many = [3, 1, 2];
+    assert_eq!(None, kth_largest(&mut many, 0));
+    assert_eq!(None, kth_largest(&mut many, 4));
+    assert_eq!(None, kth_largest(&mut [0u8; 0], 1));
+    assert_eq!(Some(1), kth_largest(&mut many, 3));
+  }
+
+  #[test]
+  fn median_odd_and_even() {
+    assert_eq!(None, median(&mut [0u8; 0]));
+    assert_eq!(Some(5), median(&mut [5]));
+    assert_eq!(Some(3), median(&mut [5, 1, 3]));
+    assert_eq!(Some(2), median(&mut [4, 1, 3, 2]));
+    assert_eq!(Some(1), median(&mut [2, 1]));
+  }
+
+  #[test]
+  fn deterministic_sorted_input() {
+    // 中位数的中位数在有序输入上也保证线性时间
+    // Median of medians guarantees linear time on sorted input too.
+    let n = 100_000;
+    let mut sorted: Vec<u32> = (0..n).collect();
+
+    for k in [1, 2, n / 2, n - 1, n] {
+      assert_eq!(
+        Some(k - 1),
+        kth_smallest_deterministic(&mut sorted, k as usize)
+      );
+    }
+
+    let mut reversed: Vec<u32> = (0..n).rev().collect();
+    assert_eq!(
+      Some(n / 2),
+      kth_smallest_deterministic(&mut reversed, n as usize / 2 + 1)
+    );
+  }
+
+  #[test]
+  fn deterministic_equal_elements() {
+    let mut equal = vec![7; 10_000];
+    assert_eq!(Some(7), kth_smallest_deterministic(&mut equal, 1));
+    assert_eq!(Some(7), kth_smallest_deterministic(&mut equal, 5_000));
+    assert_eq!(Some(7), kth_smallest_deterministic(&mut equal, 10_000));
+
+    let mut two_values: Vec<u8> = (0..1_000).map(|i| (i % 2) as u8).collect();
+    assert_eq!(Some(0), kth_smallest_deterministic(&mut two_values, 500));
+    assert_eq!(Some(1), kth_smallest_deterministic(&mut two_values, 501));
+  }
+
+  #[test]
+  fn deterministic_out_of_range() {
+    let mut many = [3, 1, 2];
+    assert_eq!(None, kth_smallest_deterministic(&mut many, 0));
+    assert_eq!(None, kth_smallest_deterministic(&mut many, 4));
+    assert_eq!(None, kth_smallest_deterministic(&mut [0u8; 0], 1));
+  }
+
+  proptest! {
+    #[test]
+    fn selectors_agree_with_sorting(input in proptest::collection::vec(-50..50i32, 1..100)) {
+      let mut sorted = input.clone();
+      sorted.sort();
+
+      for k in 1..=input.len() {
+        let mut quick = input.clone();
+        let mut deterministic = input.clone();
+
+        prop_assert_eq!(kth_smallest(&mut quick, k), Some(sorted[k - 1]));
+        prop_assert_eq!(kth_smallest_deterministic(&mut deterministic, k), Some(sorted[k - 1]));
+      }
+    }
+  }
 }
 
 fn main() {}
@@ -1,47 +1,172 @@
 fn main() {}
 
-pub mod linear_search {
-  use std::cmp::PartialEq;
-
-  /// 这个函数用于在给定数组中进行线性搜索，查找特定元素的位置。
-  ///
-  /// This function performs a linear search in the given array to find the position of a specific element.
-  ///
-  /// # 参数 (Arguments)
-  /// * `item` - 要查找的元素的引用 (A reference to the element to search for)。
-  /// * `arr` - 输入的数组 (The input array)。
-  ///
-  /// # 返回值 (Returns)
-  /// 返回找到的元素的索引，如果未找到则返回None。
-  ///
-  /// Returns the index of the found element, or None if not found
-  ///
-  /// # 示例 (Examples)
-  /// ```
-  /// let array = vec![1, 2, 3, 4, 5];
-  /// let target = 3;
-  /// let result = linear_search(&target, &array);
-  /// assert_eq!(result, Some(2));
-  /// ```
-  ///
-  /// # 注意事项 (Note)
-  /// 该函数要求元素类型T实现了PartialEq trait以进行比较
-  ///
-  /// This function requires that the element type T implements the PartialEq trait for comparison
-  pub fn linear_search<T: PartialEq>(item: &T, arr: &[T]) -> Option<usize> {
-    for (i, data) in arr.iter().enumerate() {
-      if item == data {
-        return Some(i);
-      }
+/// 这个函数用于在给定数组中进行线性搜索，查找特定元素的位置。
+///
+/// This function performs a linear search in the given array to find the position of a specific element.
+///
+/// # 参数 (Arguments)
+/// * `item` - 要查找的元素的引用 (A reference to the element to search for)。
+/// * `arr` - 输入的数组 (The input array)。
+///
+/// # 返回值 (Returns)
+/// 返回找到的元素的索引，如果未找到则返回None。
+///
+/// Returns the index of the found element, or None if not found
+///
+/// # 示例 (Examples)
+/// ```
+/// let array = vec![1, 2, 3, 4, 5];
+/// let target = 3;
+/// let result = linear_search(&target, &array);
+/// assert_eq!(result, Some(2));
+/// ```
+///
+/// # 注意事项 (Note)
+/// 该函数要求元素类型T实现了PartialEq trait以进行比较
+///
+/// This function requires that the element type T implements the PartialEq trait for comparison
+pub fn linear_search<T: PartialEq>(item: &T, arr: &[T]) -> Option<usize> {
+  for (i, data) in arr.iter().enumerate() {
+    if item == data {
+      return Some(i);
     }
+  }
+
+  None
+}
+
+/// [`linear_search_chunked`] 每次检查的元素个数。 (The number of elements [`linear_search_chunked`] checks at a time.)
+const CHUNK: usize = 32;
 
-    None
+/// 按固定大小的块进行线性搜索：先判断整个块中是否存在匹配，存在时才确定具体的索引。
+///
+/// Linear search in fixed-size chunks: each chunk is first tested for any match, and only a
+/// chunk that contains one is scanned for the exact index.
+///
+/// 块内的判断不提前退出，没有分支，对整数等基本类型可以被自动向量化，在大切片上比逐个比较更快。
+/// 结果与 [`linear_search`] 完全相同。
+///
+/// The chunk-level test does not exit early and has no branches, so it autovectorizes well for
+/// primitive types such as integers and beats comparing one element at a time on large slices.
+/// The result is identical to [`linear_search`].
+///
+/// # 示例 (Examples)
+/// ```
+/// let array: Vec<u32> = (0..100).collect();
+/// assert_eq!(linear_search_chunked(&70, &array), Some(70));
+/// assert_eq!(linear_search_chunked(&100, &array), None);
+/// ```
+pub fn linear_search_chunked<T: PartialEq>(item: &T, arr: &[T]) -> Option<usize> {
+  let chunks = arr.chunks_exact(CHUNK);
+  let remainder = chunks.remainder();
+
+  for (i, chunk) in chunks.enumerate() {
+    // 使用不短路的 `|`，让编译器可以把整个块的比较向量化
+    // The non-short-circuiting `|` lets the compiler vectorize the comparisons of the whole chunk
+    if chunk
+      .iter()
+      .fold(false, |found, data| found | (data == item))
+    {
+      return linear_search(item, chunk).map(|j| i * CHUNK + j);
+    }
   }
+
+  linear_search(item, remainder).map(|j| arr.len() - remainder.len() + j)
+}
+
+/// 在字节切片中查找给定字节第一次出现的位置，是 [`linear_search`] 针对 `u8` 的快速版本。
+///
+/// Finds the first occurrence of a byte in a byte slice, a fast path of [`linear_search`] for
+/// `u8`.
+///
+/// 每次读取 8 个字节作为一个 `u64`，与重复 8 次的 `needle` 异或后，
+/// 用 `(x - 0x01..01) & !x & 0x80..80` 判断其中是否存在为零的字节，即是否存在匹配。
+///
+/// Eight bytes at a time are read as a `u64` and XORed with `needle` repeated eight times;
+/// `(x - 0x01..01) & !x & 0x80..80` then tells whether any byte is zero, i.e. whether any byte
+/// matched.
+///
+/// # 示例 (Examples)
+/// ```
+/// assert_eq!(find_byte(b'o', b"hello world"), Some(4));
+/// assert_eq!(find_byte(b'z', b"hello world"), None);
+/// ```
+pub fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+  const LO: u64 = u64::from_ne_bytes([0x01; 8]);
+  const HI: u64 = u64::from_ne_bytes([0x80; 8]);
+
+  let repeated = LO * needle as u64;
+  let words = haystack.chunks_exact(8);
+  let remainder = words.remainder();
+
+  for (i, word) in words.enumerate() {
+    let x = u64::from_ne_bytes(word.try_into().unwrap()) ^ repeated;
+
+    if x.wrapping_sub(LO) & !x & HI != 0 {
+      return linear_search(&needle, word).map(|j| i * 8 + j);
+    }
+  }
+
+  linear_search(&needle, remainder).map(|j| haystack.len() - remainder.len() + j)
+}
+
+/// 返回给定数组中所有等于 `item` 的元素的索引，按升序排列。
+///
+/// Returns the indices of every element equal to `item` in the given array, in ascending order.
+///
+/// # 示例 (Examples)
+/// ```
+/// let array = vec![1, 2, 1, 3, 1];
+/// assert_eq!(linear_search_all(&1, &array), vec![0, 2, 4]);
+/// assert!(linear_search_all(&5, &array).is_empty());
+/// ```
+pub fn linear_search_all<T: PartialEq>(item: &T, arr: &[T]) -> Vec<usize> {
+  find_all_by(arr, |data| item == data)
+}
+
+/// 返回给定数组中第一个满足 `pred` 的元素的索引，如果没有则返回None。
+/// 可以按字段查找，不需要为元素类型实现与探测值比较的 PartialEq。
+///
+/// Returns the index of the first element satisfying `pred`, or None if there is none. This
+/// allows searching by a field without implementing PartialEq against a probe value.
+///
+/// # 示例 (Examples)
+/// ```
+/// let array = vec![("a", 1), ("b", 2), ("c", 3)];
+/// assert_eq!(find_by(&array, |&(_, n)| n > 1), Some(1));
+/// ```
+pub fn find_by<T, F: FnMut(&T) -> bool>(arr: &[T], mut pred: F) -> Option<usize> {
+  for (i, data) in arr.iter().enumerate() {
+    if pred(data) {
+      return Some(i);
+    }
+  }
+
+  None
+}
+
+/// 返回给定数组中所有满足 `pred` 的元素的索引，按升序排列。
+///
+/// Returns the indices of every element satisfying `pred`, in ascending order.
+///
+/// # 示例 (Examples)
+/// ```
+/// let array = vec![1, 2, 3, 4, 5, 6];
+/// assert_eq!(find_all_by(&array, |n| n % 2 == 0), vec![1, 3, 5]);
+/// ```
+pub fn find_all_by<T, F: FnMut(&T) -> bool>(arr: &[T], mut pred: F) -> Vec<usize> {
+  arr
+    .iter()
+    .enumerate()
+    .filter(|(_, data)| pred(data))
+    .map(|(i, _)| i)
+    .collect()
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::linear_search::linear_search;
+  use super::*;
+  use proptest::prelude::*;
 
   #[test]
   fn search_strings() {
@@ -72,4 +197,100 @@ mod tests {
     let index = linear_search(&1, &vec![]);
     assert_eq!(index, None);
   }
+
+  #[test]
+  fn search_all_repeated() {
+    let items = vec!["a", "b", "a", "c", "a"];
+    assert_eq!(linear_search_all(&"a", &items), vec![0, 2, 4]);
+    assert_eq!(linear_search_all(&"c", &items), vec![3]);
+    assert!(linear_search_all(&"z", &items).is_empty());
+    assert!(linear_search_all(&1, &[]).is_empty());
+  }
+
+  #[test]
+  fn predicate_matching_nothing() {
+    let items = [1, 2, 3, 4];
+    assert_eq!(find_by(&items, |&n| n > 10), None);
+    assert!(find_all_by(&items, |&n| n > 10).is_empty());
+  }
+
+  #[test]
+  fn predicate_matching_everything() {
+    let items = [1, 2, 3, 4];
+    assert_eq!(find_by(&items, |_| true), Some(0));
+    assert_eq!(find_all_by(&items, |_| true), vec![0, 1, 2, 3]);
+  }
+
+  #[derive(Debug)]
+  struct City {
+    name: &'static str,
+    country: &'static str,
+  }
+
+  #[test]
+  fn search_struct_by_field() {
+    let cities = [
+      City {
+        name: "Lyon",
+        country: "FR",
+      },
+      City {
+        name: "Osaka",
+        country: "JP",
+      },
+      City {
+        name: "Paris",
+        country: "FR",
+      },
+    ];
+
+    let index = find_by(&cities, |city| city.name == "Osaka").unwrap();
+    assert_eq!(cities[index].country, "JP");
+
+    assert_eq!(
+      find_all_by(&cities, |city| city.country == "FR"),
+      vec![0, 2]
+    );
+    assert_eq!(find_by(&cities, |city| city.name == "Rome"), None);
+  }
+
+  #[test]
+  fn chunked_boundaries() {
+    let array: Vec<u32> = (0..100).collect();
+
+    for item in [0, 31, 32, 63, 64, 95, 96, 99] {
+      assert_eq!(linear_search_chunked(&item, &array), Some(item as usize));
+    }
+
+    assert_eq!(linear_search_chunked(&100, &array), None);
+    assert_eq!(linear_search_chunked(&1, &[]), None);
+    assert_eq!(linear_search_chunked(&"b", &["a", "b", "b"]), Some(1));
+  }
+
+  #[test]
+  fn find_byte_boundaries() {
+    let mut haystack = vec![0u8; 100];
+
+    for index in [0, 7, 8, 63, 95, 96, 99] {
+      haystack[index] = 0xff;
+      assert_eq!(find_byte(0xff, &haystack), Some(index));
+      haystack[index] = 0;
+    }
+
+    assert_eq!(find_byte(0xff, &haystack), None);
+    assert_eq!(find_byte(0, &[]), None);
+    assert_eq!(find_byte(0x80, &[0x7f, 0x81, 0x00, 0x80]), Some(3));
+  }
+
+  proptest! {
+    #[test]
+    fn chunked_matches_naive(arr in proptest::collection::vec(0..8u16, 0..300), item in 0..9u16) {
+      prop_assert_eq!(linear_search_chunked(&item, &arr), linear_search(&item, &arr));
+    }
+
+    #[test]
+    fn find_byte_matches_naive(haystack in proptest::collection::vec(any::<u8>(), 0..300), needle: u8) {
+      prop_assert_eq!(find_byte(needle, &haystack), linear_search(&needle, &haystack));
+    }
+  }
 }
@@ -1,3 +1,35 @@
 pub mod binary_search;
 
 pub mod binary_search_rec;
+
+pub mod bisect;
+
+pub mod bitonic;
+
+pub mod closest;
+
+pub mod count;
+
+pub mod exponential_search;
+
+pub mod gallop;
+
+pub mod jump_search;
+
+pub mod kth_of_two;
+
+pub mod kth_smallest;
+
+pub mod linear_search;
+
+pub mod peak;
+
+pub mod select;
+
+pub mod subslice;
+
+pub mod top_k;
+
+pub mod two_pointer;
+
+pub mod unbounded;
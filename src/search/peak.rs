@@ -0,0 +1,124 @@
+/// 峰值查找：返回某个峰值元素的索引，峰值元素不小于它的两个相邻元素。
+/// This function finds a peak: it returns the index of some element that is greater than or
+/// equal to both of its neighbors.
+///
+/// 越界的邻居视为负无穷，因此索引 0 和 len - 1 也可以是峰值。相邻元素互不相等时，返回的峰值严格大于两个邻居；
+/// 存在相等的相邻元素（平台）时只保证不小于邻居，例如所有元素都相等时任何索引都是峰值。
+/// Out-of-bounds neighbors count as negative infinity, so indices 0 and len - 1 can be peaks.
+/// When no two adjacent elements are equal the returned peak is strictly greater than both
+/// neighbors; with plateaus it is only guaranteed to be no less than them, e.g. every index is a
+/// peak when all elements are equal.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的数组，不需要有序
+///   The given array, which does not need to be sorted.
+///
+/// # Returns
+///
+/// 返回某个峰值的索引，数组为空时返回 None。
+/// Returns the index of some peak, or None if the array is empty.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(find_peak(&[1, 3, 2]), Some(1));
+/// assert_eq!(find_peak(&[1, 2, 3]), Some(2));
+/// assert_eq!(find_peak::<i32>(&[]), None);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log n)：每次比较中点和它右边的元素，向上升的一侧缩小一半范围。
+/// O(log n): each step compares the midpoint with its right neighbor and halves the range
+/// toward the rising side.
+pub fn find_peak<T: Ord>(arr: &[T]) -> Option<usize> {
+  if arr.is_empty() {
+    return None;
+  }
+
+  let mut left = 0;
+  let mut right = arr.len() - 1;
+
+  // 循环不变式：left == 0 或 arr[left - 1] < arr[left]，right == len - 1 或 arr[right] >= arr[right + 1]，
+  // 因此 [left, right] 中一定存在峰值
+  // loop invariant: left == 0 or arr[left - 1] < arr[left], and right == len - 1 or
+  // arr[right] >= arr[right + 1], so [left, right] always contains a peak
+  while left < right {
+    let mid = left + (right - left) / 2;
+
+    if arr[mid] < arr[mid + 1] {
+      // 右侧在上升，峰值在 mid 右边
+      // the right side is rising, so a peak lies to the right of mid
+      left = mid + 1;
+    } else {
+      right = mid;
+    }
+  }
+
+  Some(left)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  fn is_peak<T: Ord>(arr: &[T], index: usize) -> bool {
+    (index == 0 || arr[index - 1] <= arr[index])
+      && (index + 1 == arr.len() || arr[index + 1] <= arr[index])
+  }
+
+  #[test]
+  fn empty() {
+    assert_eq!(find_peak::<i32>(&[]), None);
+  }
+
+  #[test]
+  fn one_and_two_items() {
+    assert_eq!(find_peak(&[1]), Some(0));
+    assert_eq!(find_peak(&[1, 2]), Some(1));
+    assert_eq!(find_peak(&[2, 1]), Some(0));
+    assert!(is_peak(&[1, 1], find_peak(&[1, 1]).unwrap()));
+  }
+
+  #[test]
+  fn strictly_increasing() {
+    let items: Vec<i32> = (0..100).collect();
+
+    assert_eq!(find_peak(&items), Some(99));
+  }
+
+  #[test]
+  fn strictly_decreasing() {
+    let items: Vec<i32> = (0..100).rev().collect();
+
+    assert_eq!(find_peak(&items), Some(0));
+  }
+
+  #[test]
+  fn multiple_peaks() {
+    let items = [1, 5, 2, 6, 3, 7, 4, 8, 0];
+    let index = find_peak(&items).unwrap();
+
+    assert!(is_peak(&items, index), "{} is not a peak", index);
+  }
+
+  #[test]
+  fn all_equal() {
+    let items = ["a"; 10];
+
+    assert!(is_peak(&items, find_peak(&items).unwrap()));
+  }
+
+  proptest! {
+    #[test]
+    fn returns_a_peak(items in proptest::collection::vec(0..10i32, 1..100)) {
+      let index = find_peak(&items).unwrap();
+
+      prop_assert!(is_peak(&items, index));
+    }
+  }
+}
+
+fn main() {}
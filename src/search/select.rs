@@ -0,0 +1,148 @@
+use rust_algorithm::sorting::quick_sort::partition_random;
+
+/// 重排切片，使第 `n` 小的元素（从 0 开始）位于排序后的位置 `n`，并返回三路划分的结果。
+/// This function reorders the slice so that the `n`th smallest element (0-based) lands at its
+/// sorted position `n`, and returns the resulting three-way split.
+///
+/// 与 [`slice::select_nth_unstable`] 相同：调用之后 `arr[..n]` 中的元素都不大于 `arr[n]`，
+/// `arr[n + 1..]` 中的元素都不小于 `arr[n]`，但两侧内部的顺序不做保证。
+/// 使用随机主元的迭代快速选择，而不是完整排序，适合廉价地计算百分位数。
+/// Like [`slice::select_nth_unstable`]: afterwards every element of `arr[..n]` is no greater than
+/// `arr[n]` and every element of `arr[n + 1..]` no less, with no order guaranteed inside either
+/// side. It runs an iterative quickselect with random pivots instead of a full sort, which makes
+/// percentiles cheap.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的数组，不需要有序
+///   The given array, which does not need to be sorted.
+/// * `n` - 目标位置，从 0 开始
+///   The 0-based target position.
+///
+/// # Returns
+///
+/// 返回 `(左侧, 第 n 个元素, 右侧)`，`n >= arr.len()` 时返回 None。
+/// Returns `(left, nth, right)`, or None if `n >= arr.len()`.
+///
+/// # Examples
+///
+/// ```
+/// let mut items = [5, 1, 4, 2, 3];
+/// let (left, median, right) = select_nth(&mut items, 2).unwrap();
+/// assert_eq!(*median, 3);
+/// assert!(left.iter().all(|x| *x <= 3) && right.iter().all(|x| *x >= 3));
+/// ```
+///
+/// # Time Complexity
+///
+/// 期望 O(n)，最坏情况 O(n^2)，但随机主元使最坏情况与输入的顺序无关。
+/// O(n) expected and O(n^2) worst case, though random pivots make the worst case independent
+/// of the input order.
+pub fn select_nth<T: Ord>(arr: &mut [T], n: usize) -> Option<(&[T], &T, &[T])> {
+  if n >= arr.len() {
+    return None;
+  }
+
+  let mut lo = 0;
+  let mut hi = arr.len() - 1;
+
+  // 循环不变式：第 n 小的元素位于 [lo, hi] 中，左边的都不大于它，右边的都不小于它
+  // loop invariant: the nth smallest element lies in [lo, hi], with nothing greater on the left
+  // and nothing smaller on the right
+  while lo < hi {
+    let pivot = partition_random(arr, lo, hi);
+
+    if n < pivot {
+      hi = pivot - 1;
+    } else if n > pivot {
+      lo = pivot + 1;
+    } else {
+      break;
+    }
+  }
+
+  let arr: &[T] = arr;
+  let (left, rest) = arr.split_at(n);
+  let (nth, right) = rest.split_first()?;
+
+  Some((left, nth, right))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use rand::Rng;
+
+  fn assert_partitioned<T: Ord + std::fmt::Debug>(left: &[T], nth: &T, right: &[T]) {
+    assert!(left.iter().all(|x| x <= nth), "{:?} > {:?}", left, nth);
+    assert!(right.iter().all(|x| x >= nth), "{:?} < {:?}", right, nth);
+  }
+
+  #[test]
+  fn first_and_last() {
+    let mut items = [4, 2, 5, 1, 3];
+
+    let (left, nth, right) = select_nth(&mut items, 0).unwrap();
+    assert_eq!((left.len(), *nth, right.len()), (0, 1, 4));
+    assert_partitioned(left, nth, right);
+
+    let (left, nth, right) = select_nth(&mut items, 4).unwrap();
+    assert_eq!((left.len(), *nth, right.len()), (4, 5, 0));
+    assert_partitioned(left, nth, right);
+  }
+
+  #[test]
+  fn out_of_range() {
+    assert!(select_nth(&mut [1, 2, 3], 3).is_none());
+    assert!(select_nth::<i32>(&mut [], 0).is_none());
+    assert_eq!(select_nth(&mut [7], 0), Some((&[][..], &7, &[][..])));
+  }
+
+  #[test]
+  fn median_of_large_random_vector() {
+    let mut rng = rand::thread_rng();
+    let mut items: Vec<u32> = (0..100_001).map(|_| rng.gen()).collect();
+    let mut sorted = items.clone();
+    sorted.sort();
+
+    let (left, median, right) = select_nth(&mut items, 50_000).unwrap();
+
+    assert_eq!(*median, sorted[50_000]);
+    assert_partitioned(left, median, right);
+  }
+
+  #[test]
+  fn sorted_input() {
+    let mut items: Vec<u32> = (0..100_000).collect();
+
+    assert_eq!(
+      select_nth(&mut items, 99_999).map(|(_, nth, _)| *nth),
+      Some(99_999)
+    );
+  }
+
+  proptest! {
+    #[test]
+    fn partition_invariants(items in vec(-20..20i32, 1..100), n in 0..100usize) {
+      let n = n % items.len();
+      let mut sorted = items.clone();
+      sorted.sort();
+
+      let mut items = items;
+      let (left, nth, right) = select_nth(&mut items, n).unwrap();
+
+      prop_assert_eq!(*nth, sorted[n]);
+      prop_assert_eq!(left.len(), n);
+      prop_assert!(left.iter().all(|x| x <= nth));
+      prop_assert!(right.iter().all(|x| x >= nth));
+
+      let mut all = [left, &[*nth], right].concat();
+      all.sort();
+      prop_assert_eq!(all, sorted);
+    }
+  }
+}
+
+fn main() {}
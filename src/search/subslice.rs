@@ -0,0 +1,210 @@
+/// 在 `haystack` 中查找子切片 `needle` 第一次出现的位置。
+/// This function finds the first position at which the sub-slice `needle` occurs in `haystack`.
+///
+/// 使用推广到任意 `T: PartialEq` 的 KMP 算法：先为 `needle` 构建失败函数，扫描时失配只回退 `needle`
+/// 中的位置，`haystack` 中的每个元素只看一次。
+/// It uses the Knuth-Morris-Pratt algorithm generalized over any `T: PartialEq`: a failure
+/// function is built for `needle`, and on a mismatch only the position in `needle` falls back,
+/// so every element of `haystack` is looked at once.
+///
+/// # Arguments
+///
+/// * `haystack` - 被查找的切片
+///   The slice to search in.
+/// * `needle` - 要查找的子切片
+///   The sub-slice to search for.
+///
+/// # Returns
+///
+/// 返回第一次出现的起始索引，未找到时返回 None。与 [`str::find`] 一致，空的 `needle` 在位置 0 处匹配。
+/// Returns the start index of the first occurrence, or None. As with [`str::find`], an empty
+/// `needle` matches at position 0.
+///
+/// # Examples
+///
+/// ```
+/// let haystack = [0xde, 0xad, 0xbe, 0xef, 0xbe, 0xef];
+/// assert_eq!(find_subslice(&haystack, &[0xbe, 0xef]), Some(2));
+/// assert_eq!(find_subslice(&haystack, &[0xef, 0xde]), None);
+/// ```
+///
+/// # Complexity
+///
+/// 时间复杂度 O(n + m)，空间复杂度 O(m)，其中 n 和 m 分别是 `haystack` 和 `needle` 的长度。
+/// O(n + m) time and O(m) space, where n and m are the lengths of `haystack` and `needle`.
+pub fn find_subslice<T: PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize> {
+  let mut first = None;
+
+  kmp(haystack, needle, |start| {
+    first = Some(start);
+    false
+  });
+
+  first
+}
+
+/// 查找 `needle` 在 `haystack` 中所有出现的位置，包括相互重叠的匹配，按升序返回。
+/// This function finds every position at which `needle` occurs in `haystack`, overlapping
+/// matches included, in ascending order.
+///
+/// 空的 `needle` 在每个位置都匹配，包括末尾，因此返回 `0..=haystack.len()`。
+/// An empty `needle` matches at every position including the end, so `0..=haystack.len()` is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(find_all_subslices(&['a', 'a', 'a'], &['a', 'a']), vec![0, 1]);
+/// assert_eq!(find_all_subslices(&[1, 2], &[]), vec![0, 1, 2]);
+/// ```
+///
+/// # Complexity
+///
+/// 时间复杂度 O(n + m)，除结果外的空间复杂度 O(m)。
+/// O(n + m) time and O(m) space besides the result.
+pub fn find_all_subslices<T: PartialEq>(haystack: &[T], needle: &[T]) -> Vec<usize> {
+  let mut starts = vec![];
+
+  kmp(haystack, needle, |start| {
+    starts.push(start);
+    true
+  });
+
+  starts
+}
+
+/// 对每个匹配的起始位置调用 `on_match`，`on_match` 返回 false 时停止查找。
+/// Calls `on_match` with the start of each match, stopping once it returns false.
+fn kmp<T: PartialEq, F: FnMut(usize) -> bool>(haystack: &[T], needle: &[T], mut on_match: F) {
+  if needle.is_empty() {
+    for start in 0..=haystack.len() {
+      if !on_match(start) {
+        return;
+      }
+    }
+
+    return;
+  }
+
+  if needle.len() > haystack.len() {
+    return;
+  }
+
+  let failure = failure_function(needle);
+  // needle 中已经匹配的长度
+  // the length of needle matched so far
+  let mut j = 0;
+
+  for (i, item) in haystack.iter().enumerate() {
+    while j > 0 && *item != needle[j] {
+      j = failure[j - 1];
+    }
+
+    if *item == needle[j] {
+      j += 1;
+    }
+
+    if j == needle.len() {
+      if !on_match(i + 1 - j) {
+        return;
+      }
+
+      // 回退到最长的真前缀，使重叠的匹配也能被找到
+      // fall back to the longest proper border so overlapping matches are found too
+      j = failure[j - 1];
+    }
+  }
+}
+
+/// 失败函数：`failure[i]` 是 `pattern[..=i]` 的既是真前缀又是真后缀的最长长度。
+/// The failure function: `failure[i]` is the length of the longest proper prefix of
+/// `pattern[..=i]` that is also a suffix of it.
+fn failure_function<T: PartialEq>(pattern: &[T]) -> Vec<usize> {
+  let mut failure = vec![0; pattern.len()];
+  let mut j = 0;
+
+  for i in 1..pattern.len() {
+    while j > 0 && pattern[i] != pattern[j] {
+      j = failure[j - 1];
+    }
+
+    if pattern[i] == pattern[j] {
+      j += 1;
+    }
+
+    failure[i] = j;
+  }
+
+  failure
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+
+  #[test]
+  fn bytes() {
+    let haystack = b"GET /index.html HTTP/1.1\r\nHost: x\r\n\r\n";
+
+    assert_eq!(find_subslice(haystack, b"\r\n"), Some(24));
+    assert_eq!(find_all_subslices(haystack, b"\r\n"), vec![24, 33, 35]);
+    assert_eq!(find_subslice(haystack, b"\r\n\r\n"), Some(33));
+    assert_eq!(find_subslice(haystack, b"POST"), None);
+  }
+
+  #[test]
+  fn integers() {
+    let readings = [3, 5, 7, 5, 7, 9, 5, 7];
+
+    assert_eq!(find_subslice(&readings, &[5, 7, 9]), Some(3));
+    assert_eq!(find_all_subslices(&readings, &[5, 7]), vec![1, 3, 6]);
+    assert!(find_all_subslices(&readings, &[7, 3]).is_empty());
+  }
+
+  #[test]
+  fn chars() {
+    let text: Vec<char> = "ababababa".chars().collect();
+    let pattern: Vec<char> = "aba".chars().collect();
+
+    assert_eq!(find_subslice(&text, &pattern), Some(0));
+    assert_eq!(find_all_subslices(&text, &pattern), vec![0, 2, 4, 6]);
+  }
+
+  #[test]
+  fn overlapping() {
+    assert_eq!(find_all_subslices(b"aaa", b"aa"), vec![0, 1]);
+    assert_eq!(find_all_subslices(b"aaaa", b"a"), vec![0, 1, 2, 3]);
+    assert_eq!(find_all_subslices(b"aabaabaa", b"aabaa"), vec![0, 3]);
+  }
+
+  #[test]
+  fn empty_needle() {
+    assert_eq!(find_subslice(b"abc", b""), Some(0));
+    assert_eq!(find_all_subslices(b"abc", b""), vec![0, 1, 2, 3]);
+    assert_eq!(find_subslice::<u8>(&[], &[]), Some(0));
+    assert_eq!(find_all_subslices::<u8>(&[], &[]), vec![0]);
+  }
+
+  #[test]
+  fn needle_longer_than_haystack() {
+    assert_eq!(find_subslice(b"ab", b"abc"), None);
+    assert!(find_all_subslices(b"ab", b"abc").is_empty());
+    assert_eq!(find_subslice(&[], &[1]), None);
+  }
+
+  proptest! {
+    #[test]
+    fn matches_naive_scan(haystack in vec(0..3u8, 0..60), needle in vec(0..3u8, 1..5)) {
+      let expected: Vec<usize> = (0..haystack.len())
+        .filter(|&start| haystack[start..].starts_with(&needle))
+        .collect();
+
+      prop_assert_eq!(find_all_subslices(&haystack, &needle), expected.clone());
+      prop_assert_eq!(find_subslice(&haystack, &needle), expected.first().copied());
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,153 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// 返回输入中最小的 k 个元素，按升序排列，不修改输入。
+/// This function returns the k smallest elements of the input in ascending order, without
+/// modifying the input.
+///
+/// 维护一个大小不超过 k 的最大堆：堆未满时直接放入，否则只有比堆顶小的元素才会替换堆顶。
+/// It keeps a max-heap of at most k elements: while the heap is not full every element is
+/// pushed, afterwards an element only replaces the top if it is smaller.
+///
+/// # Arguments
+///
+/// * `input` - 给定的数组，不需要有序
+///   The given array, which does not need to be sorted.
+/// * `k` - 需要的元素个数，大于数组长度时返回整个数组排序后的结果
+///   The number of elements wanted; if larger than the array, the whole array is returned sorted.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(k_smallest(&[5, 1, 4, 2, 3], 2), vec![1, 2]);
+/// assert_eq!(k_smallest(&[5, 1, 4], 0), Vec::<i32>::new());
+/// ```
+///
+/// # Complexity
+///
+/// 时间复杂度 O(n log k)，额外空间 O(k)，k 远小于 n 时比排序整个数组（O(n log n) 时间，O(n) 空间）更好。
+/// O(n log k) time and O(k) extra memory, which beats sorting a copy of the whole input
+/// (O(n log n) time, O(n) memory) when k is much smaller than n.
+pub fn k_smallest<T: Ord + Clone>(input: &[T], k: usize) -> Vec<T> {
+  if k == 0 {
+    return vec![];
+  }
+
+  let mut heap = BinaryHeap::with_capacity(k.min(input.len()));
+
+  for item in input {
+    if heap.len() < k {
+      heap.push(item.clone());
+    } else if let Some(mut top) = heap.peek_mut() {
+      // 比堆顶（当前第 k 小）更小时替换堆顶，PeekMut 在释放时重新调整堆
+      // replace the top (the current kth smallest) with a smaller item; PeekMut restores the heap on drop
+      if *item < *top {
+        *top = item.clone();
+      }
+    }
+  }
+
+  heap.into_sorted_vec()
+}
+
+/// 返回输入中最大的 k 个元素，按降序排列，不修改输入。
+/// This function returns the k largest elements of the input in descending order, without
+/// modifying the input.
+///
+/// 与 [`k_smallest`] 对称，使用大小不超过 k 的最小堆。
+/// It mirrors [`k_smallest`] with a min-heap of at most k elements.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(k_largest(&[5, 1, 4, 2, 3], 2), vec![5, 4]);
+/// ```
+///
+/// # Complexity
+///
+/// 时间复杂度 O(n log k)，额外空间 O(k)。
+/// O(n log k) time and O(k) extra memory.
+pub fn k_largest<T: Ord + Clone>(input: &[T], k: usize) -> Vec<T> {
+  if k == 0 {
+    return vec![];
+  }
+
+  let mut heap = BinaryHeap::with_capacity(k.min(input.len()));
+
+  for item in input {
+    if heap.len() < k {
+      heap.push(Reverse(item.clone()));
+    } else if let Some(mut top) = heap.peek_mut() {
+      if *item > top.0 {
+        *top = Reverse(item.clone());
+      }
+    }
+  }
+
+  // Reverse 的升序就是元素的降序
+  // ascending order of Reverse is descending order of the items
+  heap
+    .into_sorted_vec()
+    .into_iter()
+    .map(|Reverse(item)| item)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+
+  #[test]
+  fn k_is_zero() {
+    assert!(k_smallest(&[3, 1, 2], 0).is_empty());
+    assert!(k_largest(&[3, 1, 2], 0).is_empty());
+    assert!(k_smallest::<i32>(&[], 0).is_empty());
+  }
+
+  #[test]
+  fn k_at_least_len() {
+    let input = [3, 1, 2];
+
+    assert_eq!(k_smallest(&input, 3), vec![1, 2, 3]);
+    assert_eq!(k_smallest(&input, 10), vec![1, 2, 3]);
+    assert_eq!(k_largest(&input, 10), vec![3, 2, 1]);
+    assert!(k_largest::<i32>(&[], 5).is_empty());
+  }
+
+  #[test]
+  fn duplicates() {
+    let input = [2, 1, 2, 1, 3, 1, 3, 2];
+
+    assert_eq!(k_smallest(&input, 4), vec![1, 1, 1, 2]);
+    assert_eq!(k_largest(&input, 3), vec![3, 3, 2]);
+    assert_eq!(k_smallest(&["a"; 100], 5), vec!["a"; 5]);
+  }
+
+  #[test]
+  fn input_is_not_modified() {
+    let input = vec![String::from("b"), String::from("c"), String::from("a")];
+    let copy = input.clone();
+
+    assert_eq!(k_smallest(&input, 2), vec!["a", "b"]);
+    assert_eq!(input, copy);
+  }
+
+  #[test]
+  fn million_elements() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let input: Vec<u32> = (0..1_000_000).map(|_| rng.gen()).collect();
+
+    let mut sorted = input.clone();
+    sorted.sort_unstable();
+
+    assert_eq!(k_smallest(&input, 10), sorted[..10]);
+    assert_eq!(
+      k_largest(&input, 10),
+      sorted.iter().rev().take(10).copied().collect::<Vec<_>>()
+    );
+  }
+}
+
+fn main() {}
@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+use std::ops::Add;
+
+/// 可以用 [`pair_with_sum`] 查找的数值类型：两数之和与目标值比较时不会溢出。
+///
+/// A numeric type that [`pair_with_sum`] can search: comparing the sum of two values with a
+/// target never overflows.
+pub trait PairSum: Copy + Ord + Add<Output = Self> {
+  /// 比较 `self + other` 与 `target`。和超出类型范围时，按真实的数学结果比较。
+  /// (Compares `self + other` with `target`. If the sum overflows the type it is compared by its
+  /// true mathematical value.)
+  fn cmp_sum(self, other: Self, target: Self) -> Ordering;
+}
+
+macro_rules! impl_pair_sum {
+  ($($t:ty),*) => {
+    $(
+      impl PairSum for $t {
+        fn cmp_sum(self, other: Self, target: Self) -> Ordering {
+          match self.checked_add(other) {
+            Some(sum) => sum.cmp(&target),
+            // 只有同号相加才会溢出：都为正时和大于任何目标值，都为负时小于任何目标值
+            // (Only same-sign operands overflow: a positive overflow exceeds every target and a
+            // negative one is below every target)
+            None if other > 0 as $t => Ordering::Greater,
+            None => Ordering::Less,
+          }
+        }
+      }
+    )*
+  };
+}
+
+impl_pair_sum!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// 在有序数组中查找两个索引 i < j，使 `arr[i] + arr[j] == target`。
+/// This function finds indices i < j in a sorted array such that `arr[i] + arr[j] == target`.
+///
+/// 双指针分别从两端向中间移动：和太小时左指针右移，和太大时右指针左移。
+/// Two pointers walk inwards from both ends: the left one moves right when the sum is too small,
+/// the right one moves left when it is too large.
+///
+/// # Arguments
+///
+/// * `arr` - 给定的升序数组
+///   The given array, sorted in ascending order.
+/// * `target` - 目标和
+///   The target sum.
+///
+/// # Returns
+///
+/// 返回第一个找到的索引对，未找到时返回 None。存在多个答案时结果是确定的：i 是所有答案中最小的，
+/// 对于这个 i，j 是最大的。
+/// Returns the first pair found, or None. When several pairs exist the result is deterministic:
+/// i is the smallest over all answers, and j the largest for that i.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![1, 2, 4, 7, 11];
+/// assert_eq!(pair_with_sum(&items, 9), Some((1, 3)));
+/// assert_eq!(pair_with_sum(&items, 100), None);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(n)：每一步至少移动一个指针。
+/// O(n): every step moves at least one pointer.
+pub fn pair_with_sum<T: PairSum>(arr: &[T], target: T) -> Option<(usize, usize)> {
+  if arr.len() < 2 {
+    return None;
+  }
+
+  let mut i = 0;
+  let mut j = arr.len() - 1;
+
+  while i < j {
+    match arr[i].cmp_sum(arr[j], target) {
+      Ordering::Less => i += 1,
+      Ordering::Equal => return Some((i, j)),
+      Ordering::Greater => j -= 1,
+    }
+  }
+
+  None
+}
+
+/// 找出所有和为 0 的三元组，数组不需要有序。
+/// This function finds every triplet summing to zero; the array does not need to be sorted.
+///
+/// 先按值对索引排序，再对每个不同的第一个值，在它右边的窗口中反复调用 [`pair_with_sum`]，
+/// 每找到一对就把窗口收缩到这对的内部并跳过相同的值。内部使用 i128 计算，不会溢出。
+/// Indices are sorted by value, then for each distinct first value [`pair_with_sum`] is called
+/// repeatedly on the window to its right, shrinking the window inside each pair found and
+/// skipping equal values. Values are widened to i128 internally, so nothing overflows.
+///
+/// # Returns
+///
+/// 每组不同的值 `a <= b <= c` 只返回一次，返回的是原数组中的索引 `(i, j, k)`，
+/// 满足 `arr[i] <= arr[j] <= arr[k]`。结果按值的字典序排列。
+/// Each distinct value triplet `a <= b <= c` is returned once, as indices `(i, j, k)` into the
+/// original array with `arr[i] <= arr[j] <= arr[k]`. Results are in lexicographic order of the
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// let items = vec![-1, 0, 1, 2, -1, -4];
+/// assert_eq!(three_sum_zero(&items), vec![(0, 4, 3), (0, 1, 2)]);
+/// ```
+///
+/// # Time Complexity
+///
+/// O(n^2)。
+/// O(n^2).
+pub fn three_sum_zero(arr: &[i64]) -> Vec<(usize, usize, usize)> {
+  let mut order: Vec<usize> = (0..arr.len()).collect();
+  order.sort_by_key(|&index| arr[index]);
+
+  let values: Vec<i128> = order.iter().map(|&index| arr[index] as i128).collect();
+  let mut triplets = vec![];
+
+  for first in 0..values.len() {
+    if first > 0 && values[first] == values[first - 1] {
+      continue;
+    }
+
+    let target = -values[first];
+    let (mut lo, mut hi) = (first + 1, values.len());
+
+    while let Some((i, j)) = pair_with_sum(&values[lo..hi], target) {
+      let (i, j) = (lo + i, lo + j);
+      triplets.push((order[first], order[i], order[j]));
+
+      // 在这一对的内部继续查找，并跳过与它相同的值
+      // keep searching inside this pair, skipping values equal to it
+      lo = i + 1;
+      while lo < j && values[lo] == values[i] {
+        lo += 1;
+      }
+      hi = j;
+    }
+  }
+
+  triplets
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_valid_pair() {
+    assert_eq!(pair_with_sum(&[1, 2, 3], 10), None);
+    assert_eq!(pair_with_sum(&[1, 2, 3], 2), None);
+    assert_eq!(pair_with_sum(&[5], 10), None);
+    assert_eq!(pair_with_sum::<i32>(&[], 0), None);
+  }
+
+  #[test]
+  fn first_and_last() {
+    assert_eq!(pair_with_sum(&[1, 3, 5, 7, 20], 21), Some((0, 4)));
+  }
+
+  #[test]
+  fn duplicates_are_deterministic() {
+    // 1 + 4 和 2 + 3 都等于 5，返回 i 最小的那一对，以及该 i 对应的最大的 j
+    // both 1 + 4 and 2 + 3 equal 5; the pair with the smallest i wins, with the largest j for it
+    assert_eq!(pair_with_sum(&[1, 2, 2, 3, 3, 4, 4], 5), Some((0, 6)));
+    assert_eq!(pair_with_sum(&[2, 2, 3, 3], 5), Some((0, 3)));
+    assert_eq!(pair_with_sum(&[3, 3, 3], 6), Some((0, 2)));
+  }
+
+  #[test]
+  fn negative_numbers() {
+    assert_eq!(pair_with_sum(&[-10, -3, 0, 4, 8], -13), Some((0, 1)));
+    assert_eq!(pair_with_sum(&[-10, -3, 0, 4, 8], 1), Some((1, 3)));
+    assert_eq!(pair_with_sum(&[-10, -3, 0, 4, 8], -2), Some((0, 4)));
+  }
+
+  #[test]
+  fn extremes_do_not_overflow() {
+    assert_eq!(
+      pair_with_sum(&[i32::MIN, -1, 0, i32::MAX], -1),
+      Some((0, 3))
+    );
+    assert_eq!(pair_with_sum(&[i32::MIN, i32::MIN, 5], 5), None);
+    assert_eq!(
+      pair_with_sum(&[1, u8::MAX - 1, u8::MAX], u8::MAX),
+      Some((0, 1))
+    );
+    assert_eq!(pair_with_sum(&[i64::MAX - 1, i64::MAX], 0), None);
+  }
+
+  #[test]
+  fn three_sum() {
+    let items = [-1, 0, 1, 2, -1, -4];
+    let values: Vec<(i64, i64, i64)> = three_sum_zero(&items)
+      .into_iter()
+      .map(|(i, j, k)| (items[i], items[j], items[k]))
+      .collect();
+
+    assert_eq!(values, vec![(-1, -1, 2), (-1, 0, 1)]);
+  }
+
+  #[test]
+  fn three_sum_duplicates_and_extremes() {
+    assert_eq!(three_sum_zero(&[0, 0, 0, 0]), vec![(0, 1, 3)]);
+    assert!(three_sum_zero(&[1, 2, 3]).is_empty());
+    assert!(three_sum_zero(&[]).is_empty());
+
+    let items = [i64::MIN, 1, i64::MAX];
+    assert_eq!(three_sum_zero(&items), vec![(0, 1, 2)]);
+  }
+
+  #[test]
+  fn three_sum_matches_brute_force() {
+    let items = [-4, -2, -2, -1, 0, 1, 2, 2, 3, 4, 4, -3];
+    let mut expected = vec![];
+
+    for i in 0..items.len() {
+      for j in i + 1..items.len() {
+        for k in j + 1..items.len() {
+          if items[i] + items[j] + items[k] == 0 {
+            let mut triplet = [items[i], items[j], items[k]];
+            triplet.sort();
+            expected.push(triplet);
+          }
+        }
+      }
+    }
+    expected.sort();
+    expected.dedup();
+
+    let found: Vec<[i64; 3]> = three_sum_zero(&items)
+      .into_iter()
+      .map(|(i, j, k)| [items[i], items[j], items[k]])
+      .collect();
+
+    assert_eq!(found, expected);
+  }
+}
+
+fn main() {}
@@ -0,0 +1,205 @@
+use rust_algorithm::search::bisect::partition_point_u64;
+
+/// [`unbounded_search`] 和 [`first_true`] 默认查找的最大索引，保证查找一定会结束。
+/// The largest index [`unbounded_search`] and [`first_true`] look at by default, which
+/// guarantees that the search terminates.
+pub const MAX_INDEX: u64 = 1 << 32;
+
+/// 把单调递增的函数 `f` 看作一个无限长的有序数组，查找第一个满足 `f(i) >= target` 的索引 i。
+/// This function treats the monotonically non-decreasing function `f` as an infinite sorted
+/// array and finds the first index i with `f(i) >= target`.
+///
+/// 与指数查找相同：上界从 1 开始不断翻倍，直到 `f(bound) >= target`，再在最后一段中二分查找。
+/// 只查找 `0..=MAX_INDEX`，调用 `f` 的索引都不会超过 [`MAX_INDEX`]；
+/// 需要其它上限时使用 [`unbounded_search_with_max`]。
+/// Like exponential search, the upper bound starts at 1 and doubles until `f(bound) >= target`,
+/// then the last stretch is binary searched. Only `0..=MAX_INDEX` is searched and `f` is never
+/// called past [`MAX_INDEX`]; use [`unbounded_search_with_max`] for another limit.
+///
+/// # Arguments
+///
+/// * `f` - 单调不减的函数，不需要预先计算出所有值
+///   A non-decreasing function whose values never need to be materialized.
+/// * `target` - 目标值
+///   The target value.
+///
+/// # Returns
+///
+/// 返回第一个满足 `f(i) >= target` 的索引，`MAX_INDEX` 之前都不满足时返回 None。
+/// Returns the first index with `f(i) >= target`, or None if there is none up to `MAX_INDEX`.
+///
+/// # Examples
+///
+/// ```
+/// // 第一个平方不小于 50 的数 (the first number whose square is at least 50)
+/// assert_eq!(unbounded_search(|i| (i * i) as i64, 50), Some(8));
+/// ```
+///
+/// # Time Complexity
+///
+/// O(log i) 次调用 `f`，其中 i 是返回的索引。
+/// O(log i) calls to `f`, where i is the returned index.
+pub fn unbounded_search<F: FnMut(u64) -> i64>(f: F, target: i64) -> Option<u64> {
+  unbounded_search_with_max(f, target, MAX_INDEX)
+}
+
+/// 与 [`unbounded_search`] 相同，但只查找 `0..=max_index`。
+/// The same as [`unbounded_search`], but only searches `0..=max_index`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(unbounded_search_with_max(|i| i as i64, 100, 10), None);
+/// ```
+pub fn unbounded_search_with_max<F: FnMut(u64) -> i64>(
+  mut f: F,
+  target: i64,
+  max_index: u64,
+) -> Option<u64> {
+  first_true_with_max(|i| f(i) >= target, max_index)
+}
+
+/// 查找第一个满足单调谓词 `pred` 的非负整数，`pred` 先全为 false，后全为 true。
+/// This function finds the first non-negative integer satisfying the monotone predicate `pred`,
+/// which is false...false, true...true.
+///
+/// 只查找 `0..=MAX_INDEX`，需要其它上限时使用 [`first_true_with_max`]。
+/// Only `0..=MAX_INDEX` is searched; use [`first_true_with_max`] for another limit.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(first_true(|i| i >= 1_000_000), Some(1_000_000));
+/// ```
+pub fn first_true<F: FnMut(u64) -> bool>(pred: F) -> Option<u64> {
+  first_true_with_max(pred, MAX_INDEX)
+}
+
+/// 与 [`first_true`] 相同，但只查找 `0..=max_index`，调用 `pred` 的索引都不会超过 `max_index`。
+/// The same as [`first_true`], but only searches `0..=max_index` and never calls `pred` past it.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(first_true_with_max(|i| i >= 20, 10), None);
+/// ```
+pub fn first_true_with_max<F: FnMut(u64) -> bool>(mut pred: F, max_index: u64) -> Option<u64> {
+  if pred(0) {
+    return Some(0);
+  }
+
+  // 循环不变式：pred(lo) 为 false
+  // loop invariant: pred(lo) is false
+  let mut lo = 0;
+  let mut hi = 1;
+
+  loop {
+    if hi >= max_index {
+      hi = max_index;
+
+      if lo == hi || !pred(hi) {
+        return None;
+      }
+
+      break;
+    }
+
+    if pred(hi) {
+      break;
+    }
+
+    lo = hi;
+    // max_index 超过 2^63 时翻倍会溢出，饱和之后会在下一轮被截断到 max_index
+    // doubling overflows once max_index is above 2^63; the saturated bound is clamped to
+    // max_index in the next round
+    hi = hi.saturating_mul(2).min(max_index);
+  }
+
+  // pred(lo) 为 false，pred(hi) 为 true，答案位于 (lo, hi] 中
+  // pred(lo) is false and pred(hi) is true, so the answer lies in (lo, hi]
+  Some(partition_point_u64(lo + 1, hi, pred))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn square_roots() {
+    let square = |i: u64| (i * i) as i64;
+
+    assert_eq!(unbounded_search(square, 0), Some(0));
+    assert_eq!(unbounded_search(square, 1), Some(1));
+    assert_eq!(unbounded_search(square, 49), Some(7));
+    assert_eq!(unbounded_search(square, 50), Some(8));
+    assert_eq!(unbounded_search(square, 1 << 40), Some(1 << 20));
+    assert_eq!(unbounded_search(square, (1 << 40) + 1), Some((1 << 20) + 1));
+  }
+
+  #[test]
+  fn target_below_first_value() {
+    assert_eq!(unbounded_search(|i| i as i64 + 100, 5), Some(0));
+    assert_eq!(unbounded_search(|i| i as i64 - 3, i64::MIN), Some(0));
+  }
+
+  #[test]
+  fn target_beyond_max_index() {
+    let mut largest = 0;
+    let f = |i: u64| {
+      largest = largest.max(i);
+      i as i64
+    };
+
+    assert_eq!(unbounded_search_with_max(f, 1_000, 100), None);
+    assert_eq!(largest, 100);
+
+    assert_eq!(unbounded_search_with_max(|i| i as i64, 100, 100), Some(100));
+    assert_eq!(unbounded_search_with_max(|i| i as i64, 1, 0), None);
+  }
+
+  #[test]
+  fn default_max_index_terminates() {
+    assert_eq!(unbounded_search(|i| i as i64, i64::MAX), None);
+    assert_eq!(first_true(|i| i == u64::MAX), None);
+    assert_eq!(first_true(|i| i >= MAX_INDEX), Some(MAX_INDEX));
+  }
+
+  #[test]
+  fn max_index_near_u64_max() {
+    assert_eq!(first_true_with_max(|_| false, u64::MAX), None);
+    assert_eq!(
+      first_true_with_max(|i| i == u64::MAX, u64::MAX),
+      Some(u64::MAX)
+    );
+    assert_eq!(
+      first_true_with_max(|i| i >= (1 << 63) + 5, u64::MAX),
+      Some((1 << 63) + 5)
+    );
+    assert_eq!(
+      unbounded_search_with_max(|i| (i >> 1) as i64, i64::MAX, u64::MAX),
+      Some(u64::MAX - 1)
+    );
+  }
+
+  #[test]
+  fn first_true_thresholds() {
+    for threshold in [0, 1, 2, 3, 7, 8, 9, 1_000, 123_456_789] {
+      let mut calls = 0;
+      let found = first_true(|i| {
+        calls += 1;
+        i >= threshold
+      });
+
+      assert_eq!(found, Some(threshold));
+      // 翻倍和二分各需要约 log2(threshold) 次调用
+      // doubling and bisection each take about log2(threshold) calls
+      assert!(
+        calls <= 2 * (64 - threshold.leading_zeros()) + 2,
+        "{}",
+        calls
+      );
+    }
+  }
+}
+
+fn main() {}
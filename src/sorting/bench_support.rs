@@ -0,0 +1,116 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::{all_sorters, Sorter};
+
+/// 固定的随机种子，保证每次运行的输入相同。 (Fixed seed so every run sees the same inputs.)
+const SEED: u64 = 0x5eed;
+
+/// 基准测试使用的输入规模。 (Input sizes used by the benchmarks.)
+pub const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+/// 随机的 u64。 (Uniformly random u64 values.)
+pub fn random(len: usize) -> Vec<u64> {
+  let mut rng = StdRng::seed_from_u64(SEED);
+  (0..len).map(|_| rng.gen()).collect()
+}
+
+/// 已经升序排列的数据。 (Data already in ascending order.)
+pub fn sorted(len: usize) -> Vec<u64> {
+  (0..len as u64).collect()
+}
+
+/// 降序排列的数据。 (Data in descending order.)
+pub fn reverse_sorted(len: usize) -> Vec<u64> {
+  (0..len as u64).rev().collect()
+}
+
+/// 只有 10 种不同取值的随机数据。 (Random data with only 10 distinct values.)
+pub fn few_unique(len: usize) -> Vec<u64> {
+  let mut rng = StdRng::seed_from_u64(SEED);
+  (0..len).map(|_| rng.gen_range(0..10)).collect()
+}
+
+/// 已排序的数据中随机交换约 1% 的元素对。 (Sorted data with about 1% of pairs swapped at random.)
+pub fn nearly_sorted(len: usize) -> Vec<u64> {
+  let mut rng = StdRng::seed_from_u64(SEED);
+  let mut arr = sorted(len);
+
+  if len > 1 {
+    for _ in 0..len / 100 + 1 {
+      let (i, j) = (rng.gen_range(0..len), rng.gen_range(0..len));
+      arr.swap(i, j);
+    }
+  }
+
+  arr
+}
+
+/// 根据长度生成输入数据的函数。 (A function generating input data of a given length.)
+pub type Generator = fn(usize) -> Vec<u64>;
+
+/// 所有输入生成器及其名称。 (Every input generator together with its name.)
+pub const INPUTS: [(&str, Generator); 5] = [
+  ("random", random),
+  ("sorted", sorted),
+  ("reverse_sorted", reverse_sorted),
+  ("few_unique", few_unique),
+  ("nearly_sorted", nearly_sorted),
+];
+
+/// 算法在基准测试中允许的最大输入规模，超过后跳过，以保证测试能在合理时间内结束。
+///
+/// The largest input size a sorter is benchmarked on; larger sizes are skipped so that the
+/// suite finishes in reasonable time.
+pub fn max_len(name: &str) -> usize {
+  match name {
+    // O(n^2.71)
+    "stooge_sort" => 1_000,
+    // O(n^2)，quick_sort 总是选择第一个元素作为基准，在有序输入上同样退化为 O(n^2)
+    // (O(n^2); quick_sort always pivots on the first element, so sorted input is quadratic too)
+    "bubble_sort"
+    | "cocktail_shaker_sort"
+    | "gnome_sort"
+    | "insertion_sort"
+    | "odd_even_sort"
+    | "quick_sort"
+    | "selection_sort" => 10_000,
+    _ => usize::MAX,
+  }
+}
+
+/// 需要在长度为 `len` 的输入上进行基准测试的排序算法。
+/// 算法来自 [`all_sorters`] 注册表，新注册的算法会自动参与基准测试。
+///
+/// The sorters to benchmark on inputs of length `len`. They come from the [`all_sorters`]
+/// registry, so newly registered algorithms are benchmarked automatically.
+pub fn sorters(len: usize) -> Vec<Box<dyn Sorter<u64>>> {
+  all_sorters()
+    .into_iter()
+    .filter(|sorter| len <= max_len(sorter.name()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{max_len, sorters, INPUTS, SIZES};
+
+  #[test]
+  fn generators_are_deterministic() {
+    for (name, input) in INPUTS {
+      assert_eq!(input(1_000), input(1_000), "{}", name);
+      assert_eq!(input(1_000).len(), 1_000, "{}", name);
+      assert!(input(0).is_empty(), "{}", name);
+    }
+  }
+
+  #[test]
+  fn slow_sorters_are_capped() {
+    let largest = SIZES[SIZES.len() - 1];
+    let names: Vec<&str> = sorters(largest).iter().map(|s| s.name()).collect();
+
+    assert!(!names.contains(&"stooge_sort"));
+    assert!(names.contains(&"merge_sort"));
+    assert_eq!(max_len("not_registered"), usize::MAX);
+  }
+}
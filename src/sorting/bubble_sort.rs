@@ -1,3 +1,5 @@
+use rust_algorithm::sorting::metrics::Metrics;
+
 pub fn main() {
   let mut arr = [4, 1, 3, 1, 5, 2];
   bubble_sort(&mut arr);
@@ -20,6 +22,13 @@ pub fn bubble_sort_without_flag<T: PartialOrd>(arr: &mut [T]) {
 
 // PartialOrd 用于实现对于可比较类型的值进行有序比较
 pub fn bubble_sort<T: PartialOrd>(arr: &mut [T]) {
+  bubble_sort_with_metrics(arr, &mut Metrics::default());
+}
+
+/// 与 [`bubble_sort`] 相同，同时把比较和交换次数累加到 `metrics` 中。
+///
+/// The same as [`bubble_sort`], adding the comparisons and swaps it performs to `metrics`.
+pub fn bubble_sort_with_metrics<T: PartialOrd>(arr: &mut [T], metrics: &mut Metrics) {
   if arr.len() <= 1 {
     return;
   }
@@ -31,8 +40,8 @@ pub fn bubble_sort<T: PartialOrd>(arr: &mut [T]) {
     let mut swapped = false;
 
     for j in 1..(size - i) {
-      if arr[j - 1] > arr[j] {
-        arr.swap(j - 1, j);
+      if metrics.gt(&arr[j - 1], &arr[j]) {
+        metrics.swap(arr, j - 1, j);
         swapped = true;
       }
     }
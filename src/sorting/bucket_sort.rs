@@ -1,48 +1,134 @@
-// 时间复杂度 O(n + k), n: 输入数组的长度，k: 桶的数量
+use rust_algorithm::sorting::insertion_sort::insertion_sort;
+use rust_algorithm::sorting::quick_sort::quick_sort;
+
+// 时间复杂度 O(n + k), n: 输入数组的长度，k: 桶的数量（元素分布均匀时）
 // 空间复杂度 O(n + k), n: 输入数组的长度，k: 桶的数量
 pub fn bucket_sort(arr: &[usize]) -> Vec<usize> {
-  // 判断输入数组 arr 是否为空，如果为空，则直接返回一个空的向量 vec![]，表示已经是有序状态
+  let mut result = arr.to_vec();
+  bucket_sort_in_place(&mut result);
+  result
+}
+
+// 就地版本的 bucket_sort，排序结果直接写回 arr，不需要额外返回一个 Vec
+pub fn bucket_sort_in_place(arr: &mut [usize]) {
+  // 默认使用 len + 1 个桶
+  distribute_and_sort(arr, arr.len() + 1);
+}
+
+/// 使用指定数量的桶进行桶排序，返回排好序的新向量。
+///
+/// Bucket sort with a caller-chosen number of buckets, returning a new sorted vector.
+///
+/// 桶越多，每个桶中的元素越少，但需要的内存越多；只有 1 个桶时退化为一次插入排序。
+///
+/// More buckets mean fewer elements per bucket at the cost of more memory; a single bucket
+/// degenerates into one insertion sort.
+///
+/// # Panics
+///
+/// `bucket_count` 为 0 时 panic。 (Panics if `bucket_count` is 0.)
+pub fn bucket_sort_with(arr: &[usize], bucket_count: usize) -> Vec<usize> {
+  assert!(bucket_count > 0, "bucket_count must be greater than 0");
+
+  let mut result = arr.to_vec();
+  distribute_and_sort(&mut result, bucket_count);
+  result
+}
+
+// 桶中元素超过这个数量时改用快速排序，避免插入排序的 O(n^2)
+const INSERTION_SORT_THRESHOLD: usize = 32;
+
+// 把 arr 中的元素分到 bucket_count 个桶中，分别排序后按顺序写回 arr
+fn distribute_and_sort(arr: &mut [usize], bucket_count: usize) {
+  // 判断输入数组 arr 是否为空，如果为空，则直接返回，表示已经是有序状态
   if arr.is_empty() {
-    return vec![];
+    return;
   }
 
-  // 获取数组中的最大值 max，以及数组长度 len
+  // 获取数组中的最大值 max
   let max = *arr.iter().max().unwrap();
-  let len = arr.len();
 
-  // 创建一个长度为 len + 1 的切片 buckets，用于存放桶，每个桶初始化为空向量
-  let mut buckets = vec![vec![]; len + 1];
+  // 最大值为 0 说明所有元素都是 0，已经有序；同时避免下面的除零
+  if max == 0 {
+    return;
+  }
+
+  // 创建 bucket_count 个桶，每个桶初始化为空向量
+  let mut buckets = vec![vec![]; bucket_count];
+  let last = (bucket_count - 1) as u128;
 
   // 遍历输入数组 arr 中的每个元素 i
-  // 计算元素 i 应该放入的桶的索引位置，使用公式 len * i / max
+  // 计算元素 i 应该放入的桶的索引位置，使用公式 (bucket_count - 1) * i / max，最大值落入最后一个桶
+  // 用 u128 计算乘积，避免 max 很大时乘法溢出
   // 将元素 i 加入对应桶的末尾
-  for i in arr {
-    buckets[len * *i / max].push(*i);
+  for &i in arr.iter() {
+    buckets[(last * i as u128 / max as u128) as usize].push(i);
   }
 
-  // 遍历每个桶 bucket，对其中的元素进行排序，这里使用了内置的排序方法 sort_by
+  // 遍历每个桶 bucket，对其中的元素进行排序
+  // 小桶使用插入排序，元素较多的桶（分布不均匀时）改用快速排序
   for bucket in buckets.iter_mut() {
-    // insertion_sort::insertion_sort(bucket);
-    // 使用任何排序方法都可以，这里使用内置的排序方法
-    bucket.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    if bucket.len() > INSERTION_SORT_THRESHOLD {
+      quick_sort(bucket);
+    } else {
+      insertion_sort(bucket);
+    }
+  }
+
+  // 遍历每个桶 bucket，将桶中的元素按顺序写回 arr 中
+  for (slot, x) in arr.iter_mut().zip(buckets.into_iter().flatten()) {
+    *slot = x;
   }
+}
+
+/// 对 `[0, 1)` 区间内的浮点数进行桶排序，返回排好序的新向量。
+///
+/// Bucket sort for floats in `[0, 1)`, returning a new sorted vector.
+///
+/// 把 `n` 个值按 `(x * n) as usize` 分到 `n` 个桶中，每个桶用 [`insertion_sort`] 排序后依次拼接。
+/// 区间外的值不会导致 panic：小于 0 的值放入第一个桶，大于等于 1 的值放入最后一个桶。
+/// 所有 NaN 按输入顺序放在结果末尾。
+///
+/// The `n` values are distributed into `n` buckets by `(x * n) as usize`, each bucket is sorted
+/// with [`insertion_sort`], and the buckets are concatenated. Out-of-range values do not panic:
+/// values below 0 go into the first bucket and values of 1 or more into the last one. All NaNs
+/// are placed at the end of the result, in input order.
+///
+/// 输入均匀分布时期望时间复杂度为 O(n)，最坏情况（所有值落入同一个桶）为 O(n^2)。
+/// (Expected O(n) time for uniformly distributed input, O(n^2) in the worst case where every
+/// value lands in the same bucket.)
+pub fn bucket_sort_f64(arr: &[f64]) -> Vec<f64> {
+  let n = arr.len();
+  let mut buckets: Vec<Vec<f64>> = vec![vec![]; n];
+  let mut nans = vec![];
 
-  // 创建一个新的向量 result，用于存放最终排序结果
-  let mut result = vec![];
-  // 遍历每个桶 bucket，将桶中的元素按顺序加入到 result 中
-  for bucket in buckets {
-    for x in bucket {
-      result.push(x);
+  for &x in arr {
+    if x.is_nan() {
+      nans.push(x);
+      continue;
     }
+
+    // `as usize` 会把负数饱和为 0，这里再把超出范围的下标限制到最后一个桶
+    // (`as usize` saturates negatives to 0; clamp indices past the end into the last bucket)
+    let index = ((x * n as f64) as usize).min(n - 1);
+    buckets[index].push(x);
+  }
+
+  let mut result = Vec::with_capacity(n);
+  for mut bucket in buckets {
+    insertion_sort(&mut bucket);
+    result.append(&mut bucket);
   }
 
+  result.append(&mut nans);
   result
 }
 
 #[cfg(test)]
 mod tests {
-  use super::bucket_sort;
+  use super::{bucket_sort, bucket_sort_f64, bucket_sort_in_place, bucket_sort_with};
   use crate::sorting::counting_sort::is_sorted;
+  use rand::Rng;
 
   #[test]
   fn empty() {
@@ -85,6 +171,102 @@ mod tests {
     let res = bucket_sort(&arr);
     assert!(is_sorted(&res));
   }
+
+  #[test]
+  fn all_zeros() {
+    let res = bucket_sort(&[0, 0, 0]);
+    assert_eq!(res, vec![0, 0, 0]);
+  }
+
+  #[test]
+  fn single_zero() {
+    let res = bucket_sort(&[0]);
+    assert_eq!(res, vec![0]);
+  }
+
+  #[test]
+  fn zero_with_large_max() {
+    let res = bucket_sort(&[usize::MAX / 2, 0, 7, usize::MAX, 0]);
+    assert_eq!(res, vec![0, 0, 7, usize::MAX / 2, usize::MAX]);
+  }
+
+  #[test]
+  fn in_place() {
+    let mut arr = vec![35, 53, 1, 0, 53, 2];
+    bucket_sort_in_place(&mut arr);
+    assert_eq!(arr, vec![0, 1, 2, 35, 53, 53]);
+
+    let mut zeros = [0, 0];
+    bucket_sort_in_place(&mut zeros);
+    assert_eq!(zeros, [0, 0]);
+  }
+
+  #[test]
+  fn with_one_bucket() {
+    let arr = [35, 53, 1, 0, 53, 2];
+    let res = bucket_sort_with(&arr, 1);
+    assert_eq!(res, vec![0, 1, 2, 35, 53, 53]);
+  }
+
+  #[test]
+  fn with_many_more_buckets_than_elements() {
+    let arr = [900, 3, 41, 3, 0];
+    let res = bucket_sort_with(&arr, 10_000);
+    assert_eq!(res, vec![0, 3, 3, 41, 900]);
+  }
+
+  #[test]
+  fn with_skewed_distribution() {
+    // 除了一个很大的值，其他元素都会落入第一个桶，并超过插入排序的阈值
+    let mut arr: Vec<usize> = (0..500).rev().collect();
+    arr.push(1_000_000_000);
+    let res = bucket_sort_with(&arr, 16);
+    assert_eq!(res.len(), arr.len());
+    assert!(is_sorted(&res));
+  }
+
+  #[test]
+  #[should_panic(expected = "bucket_count must be greater than 0")]
+  fn with_zero_buckets() {
+    bucket_sort_with(&[1, 2, 3], 0);
+  }
+
+  #[test]
+  fn f64_uniform_random() {
+    let mut rng = rand::thread_rng();
+    let arr: Vec<f64> = (0..100_000).map(|_| rng.gen::<f64>()).collect();
+    let res = bucket_sort_f64(&arr);
+    assert_eq!(res.len(), arr.len());
+    assert!(is_sorted(&res));
+  }
+
+  #[test]
+  fn f64_all_in_one_bucket() {
+    let arr: Vec<f64> = (0..2_000).rev().map(|i| i as f64 * 1e-12).collect();
+    let res = bucket_sort_f64(&arr);
+    assert_eq!(res.len(), arr.len());
+    assert!(is_sorted(&res));
+  }
+
+  #[test]
+  fn f64_out_of_range_is_clamped() {
+    let arr = [0.5, 1.0, -0.25, 7.5, 0.0, -3.0];
+    let res = bucket_sort_f64(&arr);
+    assert_eq!(res, vec![-3.0, -0.25, 0.0, 0.5, 1.0, 7.5]);
+  }
+
+  #[test]
+  fn f64_nans_go_last() {
+    let arr = [0.75, f64::NAN, 0.25, f64::NAN];
+    let res = bucket_sort_f64(&arr);
+    assert_eq!(&res[..2], &[0.25, 0.75]);
+    assert!(res[2].is_nan() && res[3].is_nan());
+  }
+
+  #[test]
+  fn f64_empty() {
+    assert!(bucket_sort_f64(&[]).is_empty());
+  }
 }
 
 pub fn main() {}
@@ -5,61 +5,67 @@ pub fn main() {
 }
 
 /// 鸡尾酒排序Cocktail Sort(双向冒泡排序Bidirectional Bubble Sort)
-/// 冒泡排序不同的地方在于，鸡尾酒排序通过从左到右和从右到左交
+/// 与冒泡排序不同的地方在于，鸡尾酒排序通过从左到右和从右到左交替地进行元素比较和交换，而不仅仅是从左到右
 /// 具体实现步骤如下：
 ///
-/// 1.初始化边界变量 start 和 end，分别指向待排序序列的起始和结束位置；
-/// 2.创建一个布尔型变量 swapped，用于标记本轮迭代是否有元素交换发生；
-/// 3.进入循环，直到一轮迭代没有任何交换发生为止：
-///   · 从 start 到 end-1，对当前位置和下一个位置的元素进行比较，如果前一个元素大于后一个元素，则交换它们，并将 swapped 设置为 true；
-///   · 如果 swapped 仍然为 false，表示本轮迭代没有元素交换，排序完成，退出循环；
-///   · 将 swapped 重置为 false；
-///   · 将 end 减小 1，表示已经将最大的元素放置到了正确的位置上；
-///   · 从 end 到 start+1，对当前位置和前一个位置的元素进行比较，如果前一个元素大于后一个元素，则交换它们，并将 swapped 设置为 true；
-///   · 将 swapped 重置为 false；
-///   · 将 start 增加 1，表示已经将最小的元素放置到了正确的位置上；替地进行元素比较和交换，而不仅仅是从左到右
+/// 1.初始化边界变量 start 和 end，待排序区间为 start..=end；
+/// 2.当 start < end 时循环：
+///   · 从左到右比较 start..end 中每个位置与下一个位置的元素，前一个元素更大时交换它们，并记录最后一次交换的位置 new_end（初始为 start）；
+///   · 将 end 缩小到 new_end，它之后的元素都已经放置到了正确的位置上；本轮没有交换时 end 等于 start，循环结束；
+///   · 从右到左同样比较 start..end 中每个位置与下一个位置的元素，并记录最后一次交换的位置 new_start（初始为 end）；
+///   · 将 start 增大到 new_start + 1，它之前的元素都已经放置到了正确的位置上；本轮没有交换时 start 越过 end，循环结束。
+///
 /// 鸡尾酒排序在某些情况下可以减少排序的回合数，特别是对于一些部分有序的数组，因为它可以从两个方向同时进行元素交换。
 /// 然而，需要注意的是，鸡尾酒排序的最坏时间复杂度仍然是O(n^2)，与冒泡排序相同。因此，在实际应用中，对于大数据集合，更常见的排序算法（如快速排序或归并排序）可能更具效率。
 pub fn cocktail_shaker_sort<T: Ord>(arr: &mut [T]) {
-  let len = arr.len();
-
-  if len == 0 {
+  if arr.len() < 2 {
     return;
   }
 
-  loop {
-    let mut swapped = false;
+  // 待排序区间为 start..=end，每一轮比较 arr[i] 与 arr[i + 1]
+  let mut start = 0;
+  let mut end = arr.len() - 1;
+
+  while start < end {
+    // 从左到右：记录最后一次交换的位置，没有交换时 end 收缩到 start，循环结束
+    let mut new_end = start;
 
-    // clamp() 方法用于将一个值限制在指定的范围内
-    for i in 0..(len - 1).clamp(0, len) {
+    for i in start..end {
       if arr[i] > arr[i + 1] {
         arr.swap(i, i + 1);
-        swapped = true;
+        new_end = i;
       }
     }
 
-    if !swapped {
-      break;
-    }
+    // 最后一次交换位置之后的元素都已经就位
+    end = new_end;
 
-    swapped = false;
+    // 从右到左：同样记录最后一次交换的位置，没有交换时 start 越过 end，循环结束
+    let mut new_start = end;
 
-    for i in (0..(len - 1)).rev() {
+    for i in (start..end).rev() {
       if arr[i] > arr[i + 1] {
         arr.swap(i, i + 1);
-        swapped = true;
+        new_start = i;
       }
     }
 
-    if !swapped {
-      break;
-    }
+    // 最后一次交换位置及之前的元素都已经就位
+    start = new_start + 1;
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use rust_algorithm::test_support::{count_comparisons, counted};
+
+  fn sort_comparisons(input: &[i32]) -> usize {
+    let mut arr = counted(input);
+    let comparisons = count_comparisons(|| cocktail_shaker_sort(&mut arr));
+    assert!(arr.windows(2).all(|w| w[0].0 <= w[1].0));
+    comparisons
+  }
 
   #[test]
   fn basic() {
@@ -88,4 +94,33 @@ mod tests {
     cocktail_shaker_sort(&mut arr);
     assert_eq!(arr, vec![1, 2, 3, 4, 5, 6]);
   }
+
+  #[test]
+  fn turtle() {
+    let mut arr = vec![2, 3, 4, 5, 1];
+    cocktail_shaker_sort(&mut arr);
+    assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+
+    // 每轮都扫描整个区间需要 3 轮共 12 次比较；收缩边界后为 4 + 3 + 2 次
+    assert_eq!(sort_comparisons(&[2, 3, 4, 5, 1]), 9);
+  }
+
+  #[test]
+  fn bounds_shrink_to_last_swap() {
+    // 只有开头两个元素需要交换，第一轮之后 end 就收缩到 0
+    let mut input: Vec<i32> = (0..100).collect();
+    input.swap(0, 1);
+    assert_eq!(sort_comparisons(&input), 99);
+  }
+
+  #[test]
+  fn duplicates_and_reverse() {
+    let mut arr = vec![3, 1, 3, 2, 1, 2, 3];
+    cocktail_shaker_sort(&mut arr);
+    assert_eq!(arr, vec![1, 1, 2, 2, 3, 3, 3]);
+
+    let mut arr: Vec<i32> = (0..50).rev().collect();
+    cocktail_shaker_sort(&mut arr);
+    assert_eq!(arr, (0..50).collect::<Vec<i32>>());
+  }
 }
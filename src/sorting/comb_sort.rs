@@ -12,6 +12,11 @@ pub fn main() {}
 /// 3. 重复上述步骤，缩小 gap 的值，直到最后一个 gap 为1，执行一次普通的冒泡排序。
 /// 4. 当 gap 为1时，排序完成
 pub fn comb_sort<T: Ord>(arr: &mut [T]) {
+  // 空数组无需排序，同时避免下面的 `arr.len() - gap` 下溢
+  if arr.is_empty() {
+    return;
+  }
+
   let mut gap = arr.len();
   let shrink = 1.3;
   let mut sorted = false;
@@ -62,4 +67,13 @@ mod tests {
       assert!(ve2[i] <= ve2[i + 1]);
     }
   }
+
+  #[test]
+  fn empty() {
+    let mut ve3: Vec<i32> = vec![];
+
+    comb_sort(&mut ve3);
+
+    assert!(ve3.is_empty());
+  }
 }
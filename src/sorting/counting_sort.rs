@@ -1,11 +1,25 @@
+use std::fmt;
 use std::ops::AddAssign;
 
 fn main() {}
 
-// 时间复杂度 O(n + maxval)
-// 空间复杂度 O(maxval)
-pub fn counting_sort(arr: &mut [u32], maxval: usize) {
-  let mut occurrences: Vec<usize> = vec![0; maxval + 1];
+/// 对 `u32` 切片进行计数排序，所有值都必须不大于 `maxval`。
+///
+/// Counting sort for `u32` slices whose values are all at most `maxval`.
+///
+/// 先检查输入，任何值超过 `maxval` 时不做修改并返回 [`CountingSortError::ValueOutOfRange`]；
+/// `maxval` 为 `usize::MAX` 时计数表的长度会溢出，返回 [`CountingSortError::MaxvalTooLarge`]。
+///
+/// The input is validated up front: if any value exceeds `maxval` the slice is left untouched
+/// and [`CountingSortError::ValueOutOfRange`] is returned. A `maxval` of `usize::MAX` would
+/// overflow the length of the occurrence table and gives [`CountingSortError::MaxvalTooLarge`].
+///
+/// 时间复杂度 O(n + maxval)，空间复杂度 O(maxval)。
+/// (Time complexity O(n + maxval), space complexity O(maxval).)
+pub fn counting_sort(arr: &mut [u32], maxval: usize) -> Result<(), CountingSortError> {
+  check_max(arr.iter().map(|&data| data as u64), maxval)?;
+
+  let mut occurrences: Vec<usize> = vec![0; table_len(maxval)?];
 
   for &data in arr.iter() {
     occurrences[data as usize] += 1;
@@ -18,13 +32,27 @@ pub fn counting_sort(arr: &mut [u32], maxval: usize) {
       i += 1;
     }
   }
+
+  Ok(())
 }
 
+/// 泛型版本的 [`counting_sort`]，同样要求所有值都不大于 `maxval`。
+///
+/// Generic version of [`counting_sort`], with the same requirement that all values are at most
+/// `maxval`.
+///
+/// 值先转换为 `u64` 再与 `maxval` 比较，因此在 32 位平台上超过 `usize::MAX` 的值会返回错误，
+/// 而不是被静默截断。
+///
+/// Values are compared with `maxval` as `u64`, so on 32-bit targets a value beyond
+/// `usize::MAX` is reported as an error instead of being silently truncated.
 pub fn generic_counting_sort<T: Into<u64> + From<u8> + AddAssign + Copy>(
   arr: &mut [T],
   maxval: usize,
-) {
-  let mut occurrences: Vec<usize> = vec![0; maxval + 1];
+) -> Result<(), CountingSortError> {
+  check_max(arr.iter().map(|&data| data.into()), maxval)?;
+
+  let mut occurrences: Vec<usize> = vec![0; table_len(maxval)?];
 
   for &data in arr.iter() {
     occurrences[data.into() as usize] += 1;
@@ -36,42 +64,420 @@ pub fn generic_counting_sort<T: Into<u64> + From<u8> + AddAssign + Copy>(
   let mut data = T::from(0);
   // This will iterate from 0 to the largest data point in `arr`
   // `number` contains the occurrences of the data point `data`
-  for &number in occurrences.iter() {
+  for (index, &number) in occurrences.iter().enumerate() {
+    // 所有元素都已写回时停止，`data` 不会越过最大的元素；`maxval` 可能超出 `T` 的取值范围
+    // Stop once every element is written back, so `data` never steps past the largest element;
+    // `maxval` itself may be out of `T`'s range
+    if i == arr.len() {
+      break;
+    }
+
+    // Advance before writing rather than after, so `data` never steps past `maxval`
+    if index > 0 {
+      data += T::from(1);
+    }
+
     for _ in 0..number {
       arr[i] = data;
       i += 1;
     }
+  }
 
-    data += T::from(1);
+  Ok(())
+}
+
+/// 自动取切片中的最大值作为 `maxval` 的 [`counting_sort`]。空切片直接返回，不分配内存。
+///
+/// [`counting_sort`] with `maxval` taken from the slice itself. An empty slice returns
+/// immediately without allocating.
+pub fn counting_sort_auto(arr: &mut [u32]) {
+  let maxval = match arr.iter().max() {
+    Some(&max) => max as usize,
+    None => return,
+  };
+
+  counting_sort(arr, maxval).expect("maxval is the maximum of the slice");
+}
+
+/// 按整数键对任意元素进行稳定的计数排序，键的取值范围为 `0..=max_key`。
+///
+/// Stable counting sort of arbitrary elements by an integer key in `0..=max_key`.
+///
+/// 先统计每个键出现的次数并计算前缀和，再从后向前把元素分散到输出缓冲区中，
+/// 因此相等键的元素保持输入顺序。这是对结构体进行 LSD 基数排序的基础。
+///
+/// Counts each key, turns the counts into prefix sums, then scatters the elements back to front
+/// into an output buffer, so elements with equal keys keep their input order. This is the
+/// building block of an LSD radix sort over structs.
+///
+/// 任何键大于 `max_key` 时不做修改并返回 [`CountingSortError::ValueOutOfRange`]。
+///
+/// If any key exceeds `max_key` the slice is left untouched and
+/// [`CountingSortError::ValueOutOfRange`] is returned.
+///
+/// 时间复杂度 O(n + max_key)，空间复杂度 O(n + max_key)。
+/// (Time complexity O(n + max_key), space complexity O(n + max_key).)
+pub fn counting_sort_by_key<T: Clone, F: Fn(&T) -> usize>(
+  arr: &mut [T],
+  max_key: usize,
+  key: F,
+) -> Result<(), CountingSortError> {
+  if arr.is_empty() {
+    return Ok(());
+  }
+
+  // 每个元素只计算一次键 (Compute every key exactly once)
+  let keys: Vec<usize> = arr.iter().map(&key).collect();
+  check_max(keys.iter().map(|&k| k as u64), max_key)?;
+
+  let mut counter: Vec<usize> = vec![0; table_len(max_key)?];
+
+  for &k in keys.iter() {
+    counter[k] += 1;
+  }
+
+  // 前缀和：counter[k] 为键 k 的最后一个位置之后的下标 (Prefix sums: counter[k] is one past the last slot of key k)
+  for k in 1..counter.len() {
+    counter[k] += counter[k - 1];
+  }
+
+  // 从后向前分散以保证稳定性 (Scatter back to front to keep the sort stable)
+  let mut output: Vec<Option<T>> = vec![None; arr.len()];
+  for (item, &k) in arr.iter().zip(keys.iter()).rev() {
+    counter[k] -= 1;
+    output[counter[k]] = Some(item.clone());
+  }
+
+  for (slot, item) in arr.iter_mut().zip(output) {
+    *slot = item.expect("every slot is filled exactly once");
+  }
+
+  Ok(())
+}
+
+/// 检查所有值都不大于 `maxval`。 (Checks that no value exceeds `maxval`.)
+fn check_max(values: impl Iterator<Item = u64>, maxval: usize) -> Result<(), CountingSortError> {
+  match values.max() {
+    Some(value) if value > maxval as u64 => {
+      Err(CountingSortError::ValueOutOfRange { value, maxval })
+    }
+    _ => Ok(()),
   }
 }
 
+/// 计数表的长度 `maxval + 1`，溢出 `usize` 时返回错误。
+/// (The length `maxval + 1` of the occurrence table, or an error if it overflows `usize`.)
+fn table_len(maxval: usize) -> Result<usize, CountingSortError> {
+  maxval
+    .checked_add(1)
+    .ok_or(CountingSortError::MaxvalTooLarge { maxval })
+}
+
+/// [`counting_sort_signed`] 默认允许的最大值域（最大值与最小值之差加一）。
+///
+/// The largest value range (max - min + 1) [`counting_sort_signed`] accepts by default.
+pub const DEFAULT_MAX_RANGE: usize = 1 << 24;
+
+/// 计数排序失败的原因。 (Why a counting sort refused to run.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CountingSortError {
+  /// 值域超过了允许的上限，计数表会过大。
+  /// (The value range exceeds the allowed limit, so the occurrence table would be too large.)
+  RangeTooLarge { range: u64, limit: usize },
+  /// 某个值大于调用者给出的 `maxval`。 (A value is greater than the caller-supplied `maxval`.)
+  ValueOutOfRange { value: u64, maxval: usize },
+  /// `maxval + 1` 超出了 `usize`，无法确定计数表的大小。
+  /// (`maxval + 1` overflows `usize`, so the occurrence table cannot be sized.)
+  MaxvalTooLarge { maxval: usize },
+}
+
+impl fmt::Display for CountingSortError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CountingSortError::RangeTooLarge { range, limit } => {
+        write!(f, "value range {} exceeds the limit of {}", range, limit)
+      }
+      CountingSortError::ValueOutOfRange { value, maxval } => {
+        write!(f, "value {} is greater than maxval {}", value, maxval)
+      }
+      CountingSortError::MaxvalTooLarge { maxval } => {
+        write!(
+          f,
+          "maxval {} leaves no room for an occurrence table",
+          maxval
+        )
+      }
+    }
+  }
+}
+
+impl std::error::Error for CountingSortError {}
+
+/// 对可能包含负数的 `i32` 切片进行计数排序，值域上限为 [`DEFAULT_MAX_RANGE`]。
+///
+/// Counting sort for `i32` slices that may contain negative values, with the value range
+/// capped at [`DEFAULT_MAX_RANGE`].
+///
+/// 见 [`counting_sort_signed_with_limit`]。 (See [`counting_sort_signed_with_limit`].)
+pub fn counting_sort_signed(arr: &mut [i32]) -> Result<(), CountingSortError> {
+  counting_sort_signed_with_limit(arr, DEFAULT_MAX_RANGE)
+}
+
+/// 对可能包含负数的 `i32` 切片进行计数排序。
+///
+/// Counting sort for `i32` slices that may contain negative values.
+///
+/// 先找出最小值和最大值，把每个值偏移 `-min` 后计入计数表，再按顺序写回。
+/// 当值域 `max - min + 1` 超过 `max_range` 时不做任何修改并返回错误，以避免意外的巨大内存分配。
+///
+/// Finds the minimum and maximum, counts each value offset by `-min`, then writes the values
+/// back in order. When the range `max - min + 1` exceeds `max_range` the slice is left untouched
+/// and an error is returned, avoiding surprise multi-gigabyte allocations.
+///
+/// 时间复杂度 O(n + range)，空间复杂度 O(range)。
+/// (Time complexity O(n + range), space complexity O(range).)
+pub fn counting_sort_signed_with_limit(
+  arr: &mut [i32],
+  max_range: usize,
+) -> Result<(), CountingSortError> {
+  let (min, max) = match (arr.iter().min(), arr.iter().max()) {
+    (Some(&min), Some(&max)) => (min, max),
+    _ => return Ok(()),
+  };
+
+  // 用 i64 计算，避免 i32::MIN..i32::MAX 时溢出 (Compute in i64 to avoid overflow for i32::MIN..i32::MAX)
+  let range = (max as i64 - min as i64) as u64 + 1;
+
+  if range > max_range as u64 {
+    return Err(CountingSortError::RangeTooLarge {
+      range,
+      limit: max_range,
+    });
+  }
+
+  let mut occurrences: Vec<usize> = vec![0; range as usize];
+
+  for &data in arr.iter() {
+    occurrences[(data as i64 - min as i64) as usize] += 1;
+  }
+
+  let mut i = 0;
+  for (offset, &number) in occurrences.iter().enumerate() {
+    for _ in 0..number {
+      arr[i] = (min as i64 + offset as i64) as i32;
+      i += 1;
+    }
+  }
+
+  Ok(())
+}
+
 pub fn is_sorted<T: PartialOrd>(arr: &[T]) -> bool {
   arr.windows(2).all(|pair| pair[0] <= pair[1])
 }
 
 #[cfg(test)]
 mod test {
-  use super::{counting_sort, generic_counting_sort, is_sorted};
+  use super::{
+    counting_sort, counting_sort_auto, counting_sort_by_key, counting_sort_signed,
+    counting_sort_signed_with_limit, generic_counting_sort, is_sorted, CountingSortError,
+  };
+  use rand::Rng;
 
   #[test]
   fn counting_sort_descending() {
     let mut ve1 = vec![6, 5, 4, 3, 2, 1];
-    counting_sort(&mut ve1, 6);
+    counting_sort(&mut ve1, 6).unwrap();
     assert!(is_sorted(&ve1));
   }
 
   #[test]
   fn counting_sort_pre_sorted() {
     let mut ve2 = vec![1, 2, 3, 4, 5, 6];
-    counting_sort(&mut ve2, 6);
+    counting_sort(&mut ve2, 6).unwrap();
     assert!(is_sorted(&ve2));
   }
 
   #[test]
   fn presorted_u64_counting_sort() {
     let mut ve2: Vec<u64> = vec![1, 2, 3, 4, 5, 6];
-    generic_counting_sort(&mut ve2, 6);
+    generic_counting_sort(&mut ve2, 6).unwrap();
     assert!(is_sorted(&ve2));
   }
+
+  #[test]
+  fn signed_all_negative() {
+    let mut v = vec![-3, -1, -7, -3, -100];
+    counting_sort_signed(&mut v).unwrap();
+    assert_eq!(v, vec![-100, -7, -3, -3, -1]);
+  }
+
+  #[test]
+  fn signed_spanning_zero() {
+    let mut v = vec![5, -5, 0, 3, -2, 5, -5];
+    counting_sort_signed(&mut v).unwrap();
+    assert_eq!(v, vec![-5, -5, -2, 0, 3, 5, 5]);
+  }
+
+  #[test]
+  fn signed_with_i32_min() {
+    let mut v = vec![i32::MIN + 2, i32::MIN, i32::MIN + 1, i32::MIN];
+    counting_sort_signed(&mut v).unwrap();
+    assert_eq!(v, vec![i32::MIN, i32::MIN, i32::MIN + 1, i32::MIN + 2]);
+  }
+
+  #[test]
+  fn signed_range_too_large() {
+    let mut v = vec![i32::MAX, 0, i32::MIN];
+    assert_eq!(
+      counting_sort_signed(&mut v),
+      Err(CountingSortError::RangeTooLarge {
+        range: 1 << 32,
+        limit: super::DEFAULT_MAX_RANGE,
+      })
+    );
+    // 出错时切片保持不变 (The slice is untouched on error)
+    assert_eq!(v, vec![i32::MAX, 0, i32::MIN]);
+
+    let mut v = vec![-5, 5];
+    assert!(counting_sort_signed_with_limit(&mut v, 10).is_err());
+    assert!(counting_sort_signed_with_limit(&mut v, 11).is_ok());
+  }
+
+  #[test]
+  fn signed_agrees_with_std_sort() {
+    let mut rng = rand::thread_rng();
+    let mut v: Vec<i32> = (0..10_000).map(|_| rng.gen_range(-5_000..5_000)).collect();
+    let mut expected = v.clone();
+    expected.sort();
+    counting_sort_signed(&mut v).unwrap();
+    assert_eq!(v, expected);
+  }
+
+  #[test]
+  fn signed_empty() {
+    let mut v: Vec<i32> = vec![];
+    assert!(counting_sort_signed(&mut v).is_ok());
+  }
+
+  #[test]
+  fn value_above_maxval() {
+    let mut v = vec![3, 9, 1];
+    assert_eq!(
+      counting_sort(&mut v, 5),
+      Err(CountingSortError::ValueOutOfRange {
+        value: 9,
+        maxval: 5
+      })
+    );
+    assert_eq!(v, vec![3, 9, 1]);
+  }
+
+  #[test]
+  fn generic_value_above_maxval() {
+    let mut v: Vec<u64> = vec![1, u64::MAX, 2];
+    assert_eq!(
+      generic_counting_sort(&mut v, 10),
+      Err(CountingSortError::ValueOutOfRange {
+        value: u64::MAX,
+        maxval: 10
+      })
+    );
+    assert_eq!(v, vec![1, u64::MAX, 2]);
+  }
+
+  #[test]
+  fn maxval_of_usize_max() {
+    let error = CountingSortError::MaxvalTooLarge { maxval: usize::MAX };
+
+    let mut v = vec![3, 1, 2];
+    assert_eq!(counting_sort(&mut v, usize::MAX), Err(error.clone()));
+    assert_eq!(v, vec![3, 1, 2]);
+
+    let mut v: Vec<u64> = vec![3, 1, 2];
+    assert_eq!(
+      generic_counting_sort(&mut v, usize::MAX),
+      Err(error.clone())
+    );
+    assert_eq!(v, vec![3, 1, 2]);
+
+    let mut v = vec![3usize, 1, 2];
+    assert_eq!(counting_sort_by_key(&mut v, usize::MAX, |&x| x), Err(error));
+    assert_eq!(v, vec![3, 1, 2]);
+  }
+
+  #[test]
+  fn generic_maxval_above_the_type_range() {
+    // 300 超出了 u8 的范围，但所有元素都不大于它
+    // 300 is beyond u8, but no element exceeds it
+    let mut v: Vec<u8> = vec![3, 1, 2];
+    generic_counting_sort(&mut v, 300).unwrap();
+    assert_eq!(v, vec![1, 2, 3]);
+
+    let mut v: Vec<u8> = vec![255, 0, 7, 255];
+    generic_counting_sort(&mut v, 1000).unwrap();
+    assert_eq!(v, vec![0, 7, 255, 255]);
+  }
+
+  #[test]
+  fn generic_full_u8_range() {
+    let mut v: Vec<u8> = vec![255, 0, 128, 255, 1];
+    generic_counting_sort(&mut v, 255).unwrap();
+    assert_eq!(v, vec![0, 1, 128, 255, 255]);
+  }
+
+  #[test]
+  fn empty_with_any_maxval() {
+    let mut v: Vec<u32> = vec![];
+    assert!(counting_sort(&mut v, 0).is_ok());
+  }
+
+  #[test]
+  fn auto_maxval() {
+    let mut v = vec![9, 0, 4, 9, 2];
+    counting_sort_auto(&mut v);
+    assert_eq!(v, vec![0, 2, 4, 9, 9]);
+  }
+
+  #[test]
+  fn auto_empty() {
+    let mut v: Vec<u32> = vec![];
+    counting_sort_auto(&mut v);
+    assert!(v.is_empty());
+  }
+
+  #[test]
+  fn by_key_is_stable() {
+    let mut v = vec![(2, 'a'), (0, 'b'), (2, 'c'), (1, 'd'), (0, 'e'), (2, 'f')];
+    counting_sort_by_key(&mut v, 2, |&(k, _)| k).unwrap();
+    assert_eq!(
+      v,
+      vec![(0, 'b'), (0, 'e'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')]
+    );
+  }
+
+  #[test]
+  fn by_key_strings() {
+    let mut v = vec![
+      String::from("Carol"),
+      String::from("Al"),
+      String::from("Bob"),
+      String::from("Dave"),
+    ];
+    counting_sort_by_key(&mut v, 10, |s| s.len()).unwrap();
+    assert_eq!(v, vec!["Al", "Bob", "Dave", "Carol"]);
+  }
+
+  #[test]
+  fn by_key_out_of_range() {
+    let mut v = vec![1usize, 7, 3];
+    assert_eq!(
+      counting_sort_by_key(&mut v, 5, |&x| x),
+      Err(CountingSortError::ValueOutOfRange {
+        value: 7,
+        maxval: 5
+      })
+    );
+    assert_eq!(v, vec![1, 7, 3]);
+  }
 }
@@ -1,20 +1,16 @@
-/// 地精排序（Gnome Sort），也称为Stupid Sort或Bogo Sort，是一种简单但非常低效的排序算法。它通过不断比较相邻的元素并交换它们，直到达到正确的顺序为止
+/// 地精排序（Gnome Sort），也称为 Stupid Sort，是一种简单但非常低效的排序算法。它通过不断比较相邻的元素并交换它们，直到达到正确的顺序为止
 ///
 /// # Arguments
 ///
 /// * `arr`:
 ///
-/// returns: Vec<T, Global>
-pub fn gnome_sort<T>(arr: &[T]) -> Vec<T>
-where
-  T: PartialEq + PartialOrd + Clone,
-{
-  let mut arr = arr.to_vec();
+/// returns: ()
+pub fn gnome_sort<T: Ord>(arr: &mut [T]) {
   let mut i: usize = 1;
   let mut j: usize = 2;
 
   while i < arr.len() {
-    if arr[i - 1] < arr[i] {
+    if arr[i - 1] <= arr[i] {
       i = j;
       j = i + 1;
     } else {
@@ -27,48 +23,100 @@ where
       }
     }
   }
+}
 
+/// 返回排好序的新向量，输入保持不变
+///
+/// # Arguments
+///
+/// * `arr`:
+///
+/// returns: Vec<T, Global>
+pub fn gnome_sort_copy<T: Ord + Clone>(arr: &[T]) -> Vec<T> {
+  let mut arr = arr.to_vec();
+  gnome_sort(&mut arr);
   arr
 }
 
 #[cfg(test)]
 mod tests {
-  use super::gnome_sort;
+  use super::{gnome_sort, gnome_sort_copy};
 
   #[test]
   fn basic() {
-    let res = gnome_sort(&vec![6, 5, -8, 3, 2, 3]);
-    assert_eq!(res, vec![-8, 2, 3, 3, 5, 6]);
+    let mut vec = vec![6, 5, -8, 3, 2, 3];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec![-8, 2, 3, 3, 5, 6]);
   }
 
   #[test]
   fn already_sorted() {
-    let res = gnome_sort(&vec!["a", "b", "c"]);
-    assert_eq!(res, vec!["a", "b", "c"]);
+    let mut vec = vec!["a", "b", "c"];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec!["a", "b", "c"]);
   }
 
   #[test]
   fn odd_number_of_elements() {
-    let res = gnome_sort(&vec!["d", "a", "c", "e", "b"]);
-    assert_eq!(res, vec!["a", "b", "c", "d", "e"]);
+    let mut vec = vec!["d", "a", "c", "e", "b"];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec!["a", "b", "c", "d", "e"]);
   }
 
   #[test]
   fn one_element() {
-    let res = gnome_sort(&vec![3]);
-    assert_eq!(res, vec![3]);
+    let mut vec = vec![3];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec![3]);
   }
 
   #[test]
   fn empty() {
-    let res = gnome_sort(&Vec::<u8>::new());
-    assert_eq!(res, vec![]);
+    let mut vec = Vec::<u8>::new();
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec![]);
   }
 
   #[test]
   fn test_gnome_sort_empty_string() {
-    let res = gnome_sort(&vec!["", "c", "", "a", "b", ""]);
-    assert_eq!(res, vec!["", "", "", "a", "b", "c"]);
+    let mut vec = vec!["", "c", "", "a", "b", ""];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec!["", "", "", "a", "b", "c"]);
+  }
+
+  #[test]
+  fn test_string_vec() {
+    let mut vec = vec![
+      String::from("Bob"),
+      String::from("David"),
+      String::from("Carol"),
+      String::from("Alice"),
+    ];
+    gnome_sort(&mut vec);
+    assert_eq!(
+      vec,
+      vec![
+        String::from("Alice"),
+        String::from("Bob"),
+        String::from("Carol"),
+        String::from("David"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_pre_sorted() {
+    let mut vec = vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78];
+    gnome_sort(&mut vec);
+    assert_eq!(vec, vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78]);
+  }
+
+  #[test]
+  fn copy_leaves_input_untouched() {
+    let arr = [6, 5, -8, 3, 2, 3];
+    let res = gnome_sort_copy(&arr);
+    assert_eq!(res, vec![-8, 2, 3, 3, 5, 6]);
+    assert_eq!(arr, [6, 5, -8, 3, 2, 3]);
   }
 }
 
@@ -1,3 +1,5 @@
+use rust_algorithm::sorting::metrics::Metrics;
+
 pub fn main() {}
 
 /// 堆排序（Heap Sort）是一种高效的排序算法，它利用二叉堆这种数据结构进行排序。堆排序分为两个主要步骤：建堆和排序
@@ -10,45 +12,126 @@ pub fn main() {}
 ///
 /// returns: ()
 pub fn heap_sort<T: PartialOrd>(arr: &mut [T]) {
+  heap_sort_with_metrics(arr, &mut Metrics::default());
+}
+
+/// 与 [`heap_sort`] 相同，同时把比较和交换次数累加到 `metrics` 中。
+///
+/// The same as [`heap_sort`], adding the comparisons and swaps it performs to `metrics`.
+pub fn heap_sort_with_metrics<T: PartialOrd>(arr: &mut [T], metrics: &mut Metrics) {
   let size = arr.len();
 
   // 构建最大堆
   for i in (0..size / 2).rev() {
-    heapify(arr, i, size);
+    heapify_with_metrics(arr, i, size, metrics);
   }
 
   // 每轮循环将堆顶元素（也就是最大元素）放到最后
   for i in (1..size).rev() {
-    arr.swap(0, i);
+    metrics.swap(arr, 0, i);
     // 恢复最大堆
-    heapify(arr, 0, i);
+    heapify_with_metrics(arr, 0, i, metrics);
   }
 }
 
-fn heapify<T: PartialOrd>(arr: &mut [T], root: usize, end: usize) {
+/// 对 `arr[..end]` 中以 `root` 为根的子树执行下沉操作，使其满足最大堆性质
+/// 前提是 `root` 的左右子树本身已经是最大堆；`end` 之后的元素不会被访问
+///
+/// Sift `arr[root]` down so that the subtree rooted at `root` within `arr[..end]` is a max-heap.
+/// Both child subtrees must already be max-heaps; elements at or after `end` are never touched.
+///
+/// # Arguments
+///
+/// * `arr`: 堆所在的切片 (the slice holding the heap)
+/// * `root`: 需要下沉的节点下标 (index of the node to sift down)
+/// * `end`: 堆的长度，只有 `arr[..end]` 属于堆 (heap length; only `arr[..end]` is part of the heap)
+///
+/// returns: ()
+pub fn heapify<T: PartialOrd>(arr: &mut [T], root: usize, end: usize) {
+  heapify_with_metrics(arr, root, end, &mut Metrics::default());
+}
+
+/// 与 [`heapify`] 相同，同时把比较和交换次数累加到 `metrics` 中。
+///
+/// The same as [`heapify`], adding the comparisons and swaps it performs to `metrics`.
+pub fn heapify_with_metrics<T: PartialOrd>(
+  arr: &mut [T],
+  root: usize,
+  end: usize,
+  metrics: &mut Metrics,
+) {
   // 记录父节点和左右节点中最大元素的索引位置
   let mut largest = root;
   let left_child = 2 * root + 1;
 
-  if left_child < end && arr[left_child] > arr[largest] {
+  if left_child < end && metrics.gt(&arr[left_child], &arr[largest]) {
     largest = left_child;
   }
 
   let right_child = left_child + 1;
 
-  if right_child < end && arr[right_child] > arr[largest] {
+  if right_child < end && metrics.gt(&arr[right_child], &arr[largest]) {
     largest = right_child;
   }
 
   if largest != root {
-    arr.swap(root, largest);
-    heapify(arr, largest, end);
+    metrics.swap(arr, root, largest);
+    heapify_with_metrics(arr, largest, end, metrics);
+  }
+}
+
+/// 部分排序：把最小的 k 个元素按升序放到切片前部，其余元素的顺序不做保证
+/// 在前 k 个元素上维护一个大小为 k 的最大堆，遍历其余元素，比堆顶小就替换堆顶并恢复堆，
+/// 最后对堆本身做一次堆排序。时间复杂度 O(n log k)
+///
+/// Partial sort: place the k smallest elements, in ascending order, at the front of the slice,
+/// leaving the rest in unspecified order. A bounded max-heap of size k is kept over the first k
+/// elements; every later element smaller than the heap top replaces it, and finally the heap
+/// itself is heap-sorted. Runs in O(n log k).
+///
+/// `k >= arr.len()` 等同于完整排序，`k == 0` 不做任何操作
+/// (`k >= arr.len()` is a full sort and `k == 0` is a no-op.)
+///
+/// # Arguments
+///
+/// * `arr`: 待排序的切片 (the slice to partially sort)
+/// * `k`: 需要排好序的前缀长度 (length of the sorted prefix)
+///
+/// returns: ()
+pub fn partial_sort<T: Ord>(arr: &mut [T], k: usize) {
+  if k >= arr.len() {
+    heap_sort(arr);
+    return;
+  }
+
+  if k == 0 {
+    return;
+  }
+
+  // 在前 k 个元素上构建最大堆
+  for i in (0..k / 2).rev() {
+    heapify(arr, i, k);
+  }
+
+  // 比堆顶（当前第 k 小的元素）更小的元素替换堆顶
+  for i in k..arr.len() {
+    if arr[i] < arr[0] {
+      arr.swap(0, i);
+      heapify(arr, 0, k);
+    }
+  }
+
+  // 堆中正好是最小的 k 个元素，对它们排序
+  for i in (1..k).rev() {
+    arr.swap(0, i);
+    heapify(arr, 0, i);
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use super::heap_sort;
+  use super::{heap_sort, heapify, partial_sort};
+  use rand::Rng;
 
   #[test]
   fn test_empty_vec() {
@@ -83,4 +166,46 @@ mod tests {
       ]
     );
   }
+
+  #[test]
+  fn test_heapify_respects_end() {
+    let mut vec = vec![1, 5, 3, 9, 0];
+    heapify(&mut vec, 0, 3);
+    // 只有前 3 个元素参与，end 之后的 9 和 0 保持不动
+    assert_eq!(vec, vec![5, 1, 3, 9, 0]);
+  }
+
+  #[test]
+  fn test_partial_sort() {
+    let mut rng = rand::thread_rng();
+    let vec: Vec<i32> = (0..200).map(|_| rng.gen_range(-50..50)).collect();
+    let mut sorted = vec.clone();
+    sorted.sort();
+
+    for k in [0, 1, 2, 7, 100, 199, 200, 1_000] {
+      let mut partial = vec.clone();
+      partial_sort(&mut partial, k);
+
+      let k = k.min(vec.len());
+      assert_eq!(partial[..k], sorted[..k]);
+
+      // 剩余的元素仍然是原来的那些元素
+      partial.sort();
+      assert_eq!(partial, sorted);
+    }
+  }
+
+  #[test]
+  fn test_partial_sort_k_zero_is_noop() {
+    let mut vec = vec![3, 1, 2];
+    partial_sort(&mut vec, 0);
+    assert_eq!(vec, vec![3, 1, 2]);
+  }
+
+  #[test]
+  fn test_partial_sort_empty() {
+    let mut empty_vec: Vec<i32> = vec![];
+    partial_sort(&mut empty_vec, 3);
+    assert!(empty_vec.is_empty());
+  }
 }
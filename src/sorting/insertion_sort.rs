@@ -17,19 +17,47 @@ pub fn insertion_sort<T: PartialOrd>(arr: &mut [T]) {
   }
 }
 
-// 这里需要 T: Ord 是因为 binary_search() 方法的限制
+/// 与 [`binary_insertion_sort`] 相同。以前用 `binary_search` 查找插入位置，遇到相等元素时插入位置不确定，
+/// 排序不稳定；现在直接调用 [`binary_insertion_sort`]，因此也是稳定的。
+///
+/// The same as [`binary_insertion_sort`]. It used to find the insertion point with
+/// `binary_search`, which may land anywhere among equal elements and made the sort unstable; it
+/// now calls [`binary_insertion_sort`] and is stable as well.
 pub fn insertion_sort_binary_search<T: Ord>(arr: &mut [T]) {
-  // 从第二个元素开始排序
+  binary_insertion_sort(arr);
+}
+
+/// 二分插入排序：用二分查找确定插入位置，比较次数降为 O(n log n)，移动次数仍为 O(n^2)
+/// 插入位置取在所有相等元素之后，因此排序是稳定的；[`insertion_sort_binary_search`] 是同一排序的旧名称
+///
+/// Binary insertion sort: the insertion point is found by binary search, cutting comparisons to
+/// O(n log n) while moves stay O(n^2). Elements are inserted after any equal ones, so the sort
+/// is stable.
+/// [`insertion_sort_binary_search`] is an older name for the same sort.
+pub fn binary_insertion_sort<T: Ord>(arr: &mut [T]) {
   for i in 1..arr.len() {
-    // 利用二分查找获取 arr[i] 应该插入的位置
-    let pos = arr[..i].binary_search(&arr[i]).unwrap_or_else(|pos| pos);
-    let mut j = i;
+    let pos = upper_bound(&arr[..i], &arr[i]);
 
-    while j > pos {
-      arr.swap(j - 1, j);
-      j -= 1;
+    // 把 arr[i] 移到 pos，pos..i 之间的元素整体右移一位
+    arr[pos..=i].rotate_right(1);
+  }
+}
+
+// 返回 sorted 中第一个大于 target 的元素的下标，即相等元素之后的插入位置
+fn upper_bound<T: Ord>(sorted: &[T], target: &T) -> usize {
+  let (mut lo, mut hi) = (0, sorted.len());
+
+  while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+
+    if sorted[mid] <= *target {
+      lo = mid + 1;
+    } else {
+      hi = mid;
     }
   }
+
+  lo
 }
 
 #[cfg(test)]
@@ -109,4 +137,80 @@ mod tests {
       );
     }
   }
+
+  mod binary_insertion_sort {
+    use super::super::{binary_insertion_sort, insertion_sort};
+    use rust_algorithm::test_support::{count_comparisons, counted};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_empty_vec() {
+      let mut empty_vec: Vec<String> = vec![];
+      binary_insertion_sort(&mut empty_vec);
+      assert_eq!(empty_vec, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_number_vec() {
+      let mut vec = vec![7, 49, 73, 58, 30, 72, 44, 78, 23, 9];
+      binary_insertion_sort(&mut vec);
+      assert_eq!(vec, vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78]);
+    }
+
+    // 只按 key 比较的 (key, idx) 对
+    #[derive(Debug, Clone, Copy)]
+    struct Pair(u8, usize);
+
+    impl PartialEq for Pair {
+      fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+      }
+    }
+
+    impl Eq for Pair {}
+
+    impl PartialOrd for Pair {
+      fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+      }
+    }
+
+    impl Ord for Pair {
+      fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+      }
+    }
+
+    #[test]
+    fn test_stable() {
+      let keys = [3, 1, 2, 3, 1, 0, 2, 2, 3, 1, 0, 3];
+      let mut vec: Vec<Pair> = keys
+        .iter()
+        .enumerate()
+        .map(|(idx, &key)| Pair(key, idx))
+        .collect();
+      binary_insertion_sort(&mut vec);
+
+      let pairs: Vec<(u8, usize)> = vec.iter().map(|p| (p.0, p.1)).collect();
+      let mut expected = pairs.clone();
+      expected.sort();
+      assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn test_fewer_comparisons_on_reverse_sorted() {
+      let reversed: Vec<i32> = (0..500).rev().collect();
+
+      let mut vec = counted(&reversed);
+      let binary = count_comparisons(|| binary_insertion_sort(&mut vec));
+      assert!(vec.windows(2).all(|w| w[0].0 <= w[1].0));
+
+      let mut vec = counted(&reversed);
+      let plain = count_comparisons(|| insertion_sort(&mut vec));
+
+      // 普通插入排序在逆序输入上需要 n(n-1)/2 次比较
+      assert_eq!(plain, 500 * 499 / 2);
+      assert!(binary < plain / 10);
+    }
+  }
 }
@@ -1,3 +1,5 @@
+use rust_algorithm::sorting::metrics::Metrics;
+
 /// 归并排序是一种常见的排序算法，它采用分治的思想实现。具体步骤如下：
 ///
 /// 1. 分解：将待排序的数组分解成两个子数组，取中间位置将数组分为左右两部分
@@ -36,11 +38,22 @@
 /// 泛型类型参数 `T` 使用 trait 约束来表示它必须支持部分排序（`PartialOrd`）、克隆（`Clone`）
 /// 并具有默认值（`Default`）。
 pub fn merge_sort<T>(arr: &mut [T])
+where
+  T: PartialOrd + Clone + Default,
+{
+  merge_sort_with_metrics(arr, &mut Metrics::default());
+}
+
+/// 与 [`merge_sort`] 相同，同时把比较次数和写回原数组的次数（记作 `swaps`）累加到 `metrics` 中。
+///
+/// The same as [`merge_sort`], adding the comparisons it performs and the writes back into the
+/// slice (counted as `swaps`) to `metrics`.
+pub fn merge_sort_with_metrics<T>(arr: &mut [T], metrics: &mut Metrics)
 where
   T: PartialOrd + Clone + Default,
 {
   if arr.len() > 1 {
-    merge_sort_range(arr, 0, arr.len() - 1);
+    merge_sort_range(arr, 0, arr.len() - 1, metrics);
   }
 }
 
@@ -54,6 +67,7 @@ where
 /// * `arr` - A mutable reference to the entire array.
 /// * `lo` - The lower index of the range to sort.
 /// * `hi` - The higher index of the range to sort.
+/// * `metrics` - Accumulates the comparisons and writes.
 ///
 /// 递归地对数组中的一系列元素执行归并排序。
 ///
@@ -64,7 +78,8 @@ where
 /// * `arr` - 对整个数组的可变引用。
 /// * `lo` - 要排序范围的下限索引。
 /// * `hi` - 要排序范围的上限索引。
-fn merge_sort_range<T>(arr: &mut [T], lo: usize, hi: usize)
+/// * `metrics` - 累加比较和写回次数。
+fn merge_sort_range<T>(arr: &mut [T], lo: usize, hi: usize, metrics: &mut Metrics)
 where
   T: PartialOrd + Clone + Default,
 {
@@ -74,9 +89,9 @@ where
     // 当前子数组的中间索引
     let mid = lo + ((hi - lo) >> 1);
 
-    merge_sort_range(arr, lo, mid);
-    merge_sort_range(arr, mid + 1, hi);
-    merge_two_arrays(arr, lo, mid, hi);
+    merge_sort_range(arr, lo, mid, metrics);
+    merge_sort_range(arr, mid + 1, hi, metrics);
+    merge_two_arrays(arr, lo, mid, hi, metrics);
   }
 }
 
@@ -90,6 +105,7 @@ where
 /// * `lo` - The lower index of the first sorted array.
 /// * `mid` - The higher index of the first sorted array and the lower index of the second sorted array.
 /// * `hi` - The higher index of the second sorted array.
+/// * `metrics` - Accumulates the comparisons and writes.
 ///
 /// 在指定范围内合并两个已排序数组。
 ///
@@ -101,7 +117,8 @@ where
 /// * `lo` - 第一个已排序数组的下限索引。
 /// * `mid` - 第一个已排序数组的上限索引和第二个已排序数组的下限索引。
 /// * `hi` - 第二个已排序数组的上限索引。
-fn merge_two_arrays<T>(arr: &mut [T], lo: usize, mid: usize, hi: usize)
+/// * `metrics` - 累加比较和写回次数。
+fn merge_two_arrays<T>(arr: &mut [T], lo: usize, mid: usize, hi: usize, metrics: &mut Metrics)
 where
   T: PartialOrd + Clone + Default,
 {
@@ -111,14 +128,15 @@ where
   let mut arr2 = arr[mid + 1..=hi].to_vec();
   let (mut i, mut j) = (0, 0);
 
-  // Merge the two arrays back into the main array
-  // 将两个数组合并回主数组
+  // Merge the two arrays back into the main array.
+  // Taking from the first array on ties keeps the sort stable.
+  // 将两个数组合并回主数组，相等时优先取第一个数组的元素以保证稳定性
   while i < arr1.len() && j < arr2.len() {
-    if arr1[i] < arr2[j] {
-      arr[i + j + lo] = std::mem::take(&mut arr1[i]);
+    if metrics.le(&arr1[i], &arr2[j]) {
+      metrics.write(&mut arr[i + j + lo], std::mem::take(&mut arr1[i]));
       i += 1;
     } else {
-      arr[i + j + lo] = std::mem::take(&mut arr2[j]);
+      metrics.write(&mut arr[i + j + lo], std::mem::take(&mut arr2[j]));
       j += 1;
     }
   }
@@ -126,14 +144,14 @@ where
   // Append any remaining elements from the first array
   // 从第一个数组追加剩余元素
   while i < arr1.len() {
-    arr[i + j + lo] = std::mem::take(&mut arr1[i]);
+    metrics.write(&mut arr[i + j + lo], std::mem::take(&mut arr1[i]));
     i += 1;
   }
 
   // Append any remaining elements from the second array
   // 从第二个数组追加剩余元素
   while j < arr2.len() {
-    arr[i + j + lo] = std::mem::take(&mut arr2[j]);
+    metrics.write(&mut arr[i + j + lo], std::mem::take(&mut arr2[j]));
     j += 1;
   }
 }
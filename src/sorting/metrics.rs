@@ -0,0 +1,279 @@
+use rust_algorithm::sorting::bubble_sort::bubble_sort_with_metrics;
+use rust_algorithm::sorting::heap_sort::heap_sort_with_metrics;
+use rust_algorithm::sorting::merge_sort::merge_sort_with_metrics;
+use rust_algorithm::sorting::quick_sort::quick_sort_with_metrics;
+use std::cmp::Ordering;
+
+/// 一次排序执行的比较次数和交换次数。
+///
+/// The number of comparisons and swaps performed by one run of a sort.
+///
+/// 对于不交换元素的归并排序，`swaps` 记录的是写回原数组的次数。
+///
+/// Merge sort never exchanges elements, so for it `swaps` counts the writes back into the
+/// original slice instead.
+///
+/// 计数由排序本身完成：`bubble_sort` 等函数调用各自的 `*_with_metrics` 版本，这里的函数只是传入一个新的计数器。
+///
+/// The counting is done by the sorts themselves: `bubble_sort` and the others call their
+/// `*_with_metrics` variants, and the functions here only pass in a fresh counter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Metrics {
+  pub comparisons: u64,
+  pub swaps: u64,
+}
+
+impl Metrics {
+  fn cmp<T: PartialOrd>(&mut self, a: &T, b: &T) -> Option<Ordering> {
+    self.comparisons += 1;
+    a.partial_cmp(b)
+  }
+
+  /// 计一次比较，返回 `a > b`。 (Counts one comparison and returns `a > b`.)
+  pub fn gt<T: PartialOrd>(&mut self, a: &T, b: &T) -> bool {
+    matches!(self.cmp(a, b), Some(Ordering::Greater))
+  }
+
+  /// 计一次比较，返回 `a >= b`。 (Counts one comparison and returns `a >= b`.)
+  pub fn ge<T: PartialOrd>(&mut self, a: &T, b: &T) -> bool {
+    matches!(self.cmp(a, b), Some(Ordering::Greater | Ordering::Equal))
+  }
+
+  /// 计一次比较，返回 `a <= b`。 (Counts one comparison and returns `a <= b`.)
+  pub fn le<T: PartialOrd>(&mut self, a: &T, b: &T) -> bool {
+    matches!(self.cmp(a, b), Some(Ordering::Less | Ordering::Equal))
+  }
+
+  /// 计一次交换，并交换 `arr[i]` 与 `arr[j]`。 (Counts one swap and swaps `arr[i]` with `arr[j]`.)
+  pub fn swap<T>(&mut self, arr: &mut [T], i: usize, j: usize) {
+    self.swaps += 1;
+    arr.swap(i, j);
+  }
+
+  /// 把 `value` 写入 `slot`，计为一次交换。 (Writes `value` into `slot`, counted as a swap.)
+  pub fn write<T>(&mut self, slot: &mut T, value: T) {
+    self.swaps += 1;
+    *slot = value;
+  }
+}
+
+/// 运行 [`bubble_sort`](super::bubble_sort::bubble_sort)，返回比较和交换次数。
+///
+/// Runs [`bubble_sort`](super::bubble_sort::bubble_sort), returning the number of comparisons
+/// and swaps.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let metrics = bubble_sort_metrics(&mut [3, 2, 1]);
+/// assert_eq!(metrics, Metrics { comparisons: 3, swaps: 3 });
+/// ```
+pub fn bubble_sort_metrics<T: PartialOrd>(arr: &mut [T]) -> Metrics {
+  let mut metrics = Metrics::default();
+  bubble_sort_with_metrics(arr, &mut metrics);
+  metrics
+}
+
+/// 运行 [`quick_sort`](super::quick_sort::quick_sort)（以第一个元素为基准），返回比较和交换次数。
+///
+/// Runs [`quick_sort`](super::quick_sort::quick_sort) (pivoting on the first element),
+/// returning the number of comparisons and swaps.
+pub fn quick_sort_metrics<T: PartialOrd>(arr: &mut [T]) -> Metrics {
+  let mut metrics = Metrics::default();
+  quick_sort_with_metrics(arr, &mut metrics);
+  metrics
+}
+
+/// 运行 [`merge_sort`](super::merge_sort::merge_sort)，返回比较次数和写回次数。
+///
+/// Runs [`merge_sort`](super::merge_sort::merge_sort), returning the number of comparisons and
+/// of writes back into the slice (reported as `swaps`).
+pub fn merge_sort_metrics<T>(arr: &mut [T]) -> Metrics
+where
+  T: PartialOrd + Clone + Default,
+{
+  let mut metrics = Metrics::default();
+  merge_sort_with_metrics(arr, &mut metrics);
+  metrics
+}
+
+/// 运行 [`heap_sort`](super::heap_sort::heap_sort)，返回比较和交换次数。
+///
+/// Runs [`heap_sort`](super::heap_sort::heap_sort), returning the number of comparisons and
+/// swaps.
+pub fn heap_sort_metrics<T: PartialOrd>(arr: &mut [T]) -> Metrics {
+  let mut metrics = Metrics::default();
+  heap_sort_with_metrics(arr, &mut metrics);
+  metrics
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    bubble_sort_metrics, heap_sort_metrics, merge_sort_metrics, quick_sort_metrics, Metrics,
+  };
+  use crate::sorting::bubble_sort::bubble_sort;
+  use crate::sorting::heap_sort::heap_sort;
+  use crate::sorting::merge_sort::merge_sort;
+  use crate::sorting::quick_sort::quick_sort;
+  use crate::test_support::{count_comparisons, counted, Counted};
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+
+  #[test]
+  fn bubble_sort_known_cases() {
+    let mut arr = [3, 2, 1];
+    let metrics = bubble_sort_metrics(&mut arr);
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 3,
+        swaps: 3
+      }
+    );
+
+    // 已排序的输入只需一轮 n - 1 次比较
+    let metrics = bubble_sort_metrics(&mut [1, 2, 3, 4]);
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 3,
+        swaps: 0
+      }
+    );
+  }
+
+  #[test]
+  fn quick_sort_known_cases() {
+    let mut arr = [2, 1, 3];
+    let metrics = quick_sort_metrics(&mut arr);
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 3,
+        swaps: 1
+      }
+    );
+
+    // 以第一个元素为基准时，已排序的输入需要 n(n-1)/2 次比较，但不需要交换
+    let metrics = quick_sort_metrics(&mut [1, 2, 3, 4]);
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 6,
+        swaps: 0
+      }
+    );
+  }
+
+  #[test]
+  fn merge_sort_known_cases() {
+    let mut arr = [4, 3, 2, 1];
+    let metrics = merge_sort_metrics(&mut arr);
+    assert_eq!(arr, [1, 2, 3, 4]);
+    // 两次长度为 2 的合并各 1 次比较，最后一次合并 2 次比较；每层写回 4 次
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 4,
+        swaps: 8
+      }
+    );
+  }
+
+  #[test]
+  fn heap_sort_known_cases() {
+    let mut arr = [1, 2, 3];
+    let metrics = heap_sort_metrics(&mut arr);
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!(
+      metrics,
+      Metrics {
+        comparisons: 3,
+        swaps: 4
+      }
+    );
+  }
+
+  #[test]
+  fn empty_and_one_element() {
+    for arr in [&mut [][..], &mut [1][..]] {
+      assert_eq!(bubble_sort_metrics(arr), Metrics::default());
+      assert_eq!(quick_sort_metrics(arr), Metrics::default());
+      assert_eq!(merge_sort_metrics(arr), Metrics::default());
+      assert_eq!(heap_sort_metrics(arr), Metrics::default());
+    }
+  }
+
+  #[test]
+  fn deterministic() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let input: Vec<i32> = (0..500).map(|_| rng.gen_range(-100..100)).collect();
+
+    assert_eq!(
+      bubble_sort_metrics(&mut input.clone()),
+      bubble_sort_metrics(&mut input.clone())
+    );
+    assert_eq!(
+      quick_sort_metrics(&mut input.clone()),
+      quick_sort_metrics(&mut input.clone())
+    );
+  }
+
+  // 直接在比较时计数的元素上运行排序，用来确认排序的每一次比较都经过了 `Metrics`
+  // (Runs a sort on elements that count their comparisons, used to check that every comparison
+  // of the sorts goes through `Metrics`)
+  fn sort_comparisons(input: &[i32], sort: fn(&mut [Counted<i32>])) -> u64 {
+    let mut arr = counted(input);
+    count_comparisons(|| sort(&mut arr)) as u64
+  }
+
+  #[test]
+  fn comparisons_match_the_original_algorithms() {
+    let mut rng = StdRng::seed_from_u64(2);
+
+    for len in [0, 1, 2, 10, 100] {
+      let input: Vec<i32> = (0..len).map(|_| rng.gen_range(-20..20)).collect();
+
+      assert_eq!(
+        bubble_sort_metrics(&mut input.clone()).comparisons,
+        sort_comparisons(&input, bubble_sort)
+      );
+      assert_eq!(
+        quick_sort_metrics(&mut input.clone()).comparisons,
+        sort_comparisons(&input, quick_sort)
+      );
+      assert_eq!(
+        merge_sort_metrics(&mut input.clone()).comparisons,
+        sort_comparisons(&input, merge_sort)
+      );
+      assert_eq!(
+        heap_sort_metrics(&mut input.clone()).comparisons,
+        sort_comparisons(&input, heap_sort)
+      );
+    }
+  }
+
+  #[test]
+  fn sorts_correctly() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let input: Vec<i32> = (0..300).map(|_| rng.gen_range(-50..50)).collect();
+    let mut expected = input.clone();
+    expected.sort();
+
+    let sorts: [fn(&mut [i32]) -> Metrics; 4] = [
+      bubble_sort_metrics,
+      quick_sort_metrics,
+      merge_sort_metrics,
+      heap_sort_metrics,
+    ];
+
+    for sort in sorts {
+      let mut arr = input.clone();
+      sort(&mut arr);
+      assert_eq!(arr, expected);
+    }
+  }
+}
@@ -1,3 +1,5 @@
+pub mod bench_support;
+
 pub mod bubble_sort;
 
 pub mod bucket_sort;
@@ -16,6 +18,8 @@ pub mod insertion_sort;
 
 pub mod merge_sort;
 
+pub mod metrics;
+
 pub mod odd_even_sort;
 
 pub mod quick_sort;
@@ -26,6 +30,301 @@ pub mod selection_sort;
 
 pub mod shell_sort;
 
+#[cfg(feature = "fun")]
+pub mod sleep_sort;
+
+pub mod stability;
+
 pub mod stooge_sort;
 
 pub mod tim_sort;
+
+pub mod wiggle_sort;
+
+use stability::Stability;
+
+/// 排序算法的统一接口，便于在基准测试或可视化工具中互换使用各个算法。
+///
+/// A uniform interface over the comparison sorts in this module, so that algorithms can be
+/// swapped for one another (e.g. by a benchmark or visualization tool).
+///
+/// 每个实现者都是零大小类型，`sort` 直接委托给对应模块中的自由函数。
+/// 由于 trait 对象不能拥有泛型方法，元素类型 `T` 放在 trait 本身上。
+///
+/// Every implementor is a zero-sized type whose `sort` delegates to the free function of the
+/// matching module. The element type `T` is a parameter of the trait itself rather than of
+/// `sort`, because trait objects cannot have generic methods.
+pub trait Sorter<T> {
+  /// 对切片进行升序排序。 (Sorts the slice in ascending order.)
+  fn sort(&self, arr: &mut [T]);
+
+  /// 算法名称，与模块名一致。 (The name of the algorithm, matching its module name.)
+  fn name(&self) -> &'static str;
+
+  /// 算法是否稳定，可用 [`stability::is_stable_sort`] 验证。
+  /// (Whether the algorithm is stable; verifiable with [`stability::is_stable_sort`].)
+  fn stability(&self) -> Stability;
+}
+
+pub struct BubbleSort;
+
+pub struct CocktailShakerSort;
+
+pub struct CombSort;
+
+pub struct GnomeSort;
+
+pub struct HeapSort;
+
+pub struct InsertionSort;
+
+pub struct MergeSort;
+
+pub struct OddEvenSort;
+
+pub struct QuickSort;
+
+pub struct SelectionSort;
+
+pub struct ShellSort;
+
+pub struct StoogeSort;
+
+pub struct TimSort;
+
+impl<T: Ord> Sorter<T> for BubbleSort {
+  fn sort(&self, arr: &mut [T]) {
+    bubble_sort::bubble_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "bubble_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord> Sorter<T> for CocktailShakerSort {
+  fn sort(&self, arr: &mut [T]) {
+    cocktail_shaker_sort::cocktail_shaker_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "cocktail_shaker_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord> Sorter<T> for CombSort {
+  fn sort(&self, arr: &mut [T]) {
+    comb_sort::comb_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "comb_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord> Sorter<T> for GnomeSort {
+  fn sort(&self, arr: &mut [T]) {
+    gnome_sort::gnome_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "gnome_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord> Sorter<T> for HeapSort {
+  fn sort(&self, arr: &mut [T]) {
+    heap_sort::heap_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "heap_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord> Sorter<T> for InsertionSort {
+  fn sort(&self, arr: &mut [T]) {
+    insertion_sort::insertion_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "insertion_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord + Clone + Default> Sorter<T> for MergeSort {
+  fn sort(&self, arr: &mut [T]) {
+    merge_sort::merge_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "merge_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord> Sorter<T> for OddEvenSort {
+  fn sort(&self, arr: &mut [T]) {
+    odd_even_sort::odd_even_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "odd_even_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+impl<T: Ord> Sorter<T> for QuickSort {
+  fn sort(&self, arr: &mut [T]) {
+    quick_sort::quick_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "quick_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord> Sorter<T> for SelectionSort {
+  fn sort(&self, arr: &mut [T]) {
+    selection_sort::selection_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "selection_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord> Sorter<T> for ShellSort {
+  fn sort(&self, arr: &mut [T]) {
+    shell_sort::shell_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "shell_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord> Sorter<T> for StoogeSort {
+  fn sort(&self, arr: &mut [T]) {
+    stooge_sort::stooge_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "stooge_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Unstable
+  }
+}
+
+impl<T: Ord + Clone> Sorter<T> for TimSort {
+  fn sort(&self, arr: &mut [T]) {
+    tim_sort::tim_sort(arr);
+  }
+
+  fn name(&self) -> &'static str {
+    "tim_sort"
+  }
+
+  fn stability(&self) -> Stability {
+    Stability::Stable
+  }
+}
+
+/// 返回所有实现了 [`Sorter`] 的排序算法。
+///
+/// Returns every sorting algorithm that implements [`Sorter`] for `T`.
+///
+/// `T` 的约束是所有实现者约束的并集（`merge_sort` 需要 `Clone + Default`）。
+///
+/// The bounds on `T` are the union of the implementors' bounds (`merge_sort` needs
+/// `Clone + Default`).
+pub fn all_sorters<T: Ord + Clone + Default>() -> Vec<Box<dyn Sorter<T>>> {
+  vec![
+    Box::new(BubbleSort),
+    Box::new(CocktailShakerSort),
+    Box::new(CombSort),
+    Box::new(GnomeSort),
+    Box::new(HeapSort),
+    Box::new(InsertionSort),
+    Box::new(MergeSort),
+    Box::new(OddEvenSort),
+    Box::new(QuickSort),
+    Box::new(SelectionSort),
+    Box::new(ShellSort),
+    Box::new(StoogeSort),
+    Box::new(TimSort),
+  ]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::all_sorters;
+  use rand::Rng;
+
+  #[test]
+  fn all_sorters_agree_on_random_input() {
+    let mut rng = rand::thread_rng();
+    let input: Vec<i32> = (0..300).map(|_| rng.gen_range(-100..100)).collect();
+    let mut expected = input.clone();
+    expected.sort();
+
+    for sorter in all_sorters() {
+      let mut arr = input.clone();
+      sorter.sort(&mut arr);
+      assert_eq!(arr, expected, "{} disagrees with std sort", sorter.name());
+    }
+  }
+
+  #[test]
+  fn sorter_names_are_unique() {
+    let mut names: Vec<&str> = all_sorters::<i32>().iter().map(|s| s.name()).collect();
+    let len = names.len();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), len);
+  }
+}
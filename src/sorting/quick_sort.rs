@@ -1,4 +1,5 @@
 use rand::Rng;
+use rust_algorithm::sorting::metrics::Metrics;
 
 pub fn main() {}
 
@@ -19,8 +20,15 @@ pub fn main() {}
 /// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
 ///
 pub fn quick_sort<T: PartialOrd>(arr: &mut [T]) {
+  quick_sort_with_metrics(arr, &mut Metrics::default());
+}
+
+/// 与 [`quick_sort`] 相同，同时把比较和交换次数累加到 `metrics` 中。
+///
+/// The same as [`quick_sort`], adding the comparisons and swaps it performs to `metrics`.
+pub fn quick_sort_with_metrics<T: PartialOrd>(arr: &mut [T], metrics: &mut Metrics) {
   if arr.len() > 1 {
-    quick_sort_range(arr, 0, arr.len() - 1);
+    quick_sort_range(arr, 0, arr.len() - 1, metrics);
   }
 }
 
@@ -33,15 +41,16 @@ pub fn quick_sort<T: PartialOrd>(arr: &mut [T]) {
 /// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
 /// * `lo`: 排序范围的低索引。 (The low index of the range to be sorted.)
 /// * `hi`: 排序范围的高索引。 (The high index of the range to be sorted.)
+/// * `metrics`: 累加比较和交换次数。 (Accumulates the comparisons and swaps.)
 ///
-fn quick_sort_range<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) {
+fn quick_sort_range<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize, metrics: &mut Metrics) {
   if lo < hi {
-    let pos = partition(arr, lo, hi);
+    let pos = partition_with_metrics(arr, lo, hi, metrics);
 
     if pos != 0 {
-      quick_sort_range(arr, lo, pos.wrapping_sub(1)); // 使用 wrapping_sub 避免下溢 (Using wrapping_sub to avoid underflow)
+      quick_sort_range(arr, lo, pos.wrapping_sub(1), metrics); // 使用 wrapping_sub 避免下溢 (Using wrapping_sub to avoid underflow)
     }
-    quick_sort_range(arr, pos + 1, hi);
+    quick_sort_range(arr, pos + 1, hi, metrics);
   }
 }
 
@@ -77,10 +86,28 @@ fn quick_sort_range<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) {
 /// Time Complexity: O(n)
 /// Space Complexity: O(1)
 pub fn partition<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) -> usize {
+  partition_with_metrics(arr, lo, hi, &mut Metrics::default())
+}
+
+/// 与 [`partition`] 相同，同时把比较和交换次数累加到 `metrics` 中。
+///
+/// The same as [`partition`], adding the comparisons and swaps it performs to `metrics`.
+pub fn partition_with_metrics<T: PartialOrd>(
+  arr: &mut [T],
+  lo: usize,
+  hi: usize,
+  metrics: &mut Metrics,
+) -> usize {
   // 默认选择 `lo` 作为基准 (Default to choosing `lo` as the pivot)
   let pivot = lo;
-  let partition_pos = partition_helper(arr, pivot, lo, hi);
-  arr.swap(pivot, partition_pos);
+  let partition_pos = partition_helper(arr, pivot, lo, hi, metrics);
+
+  // 基准已经在最终位置时不需要交换，也不计为一次交换
+  // (No swap, and none counted, when the pivot is already in its final position)
+  if partition_pos != pivot {
+    metrics.swap(arr, pivot, partition_pos);
+  }
+
   partition_pos
 }
 
@@ -98,12 +125,24 @@ pub fn partition<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) -> usize {
 ///
 /// 分区完成后基准元素的最终索引。 (The final index of the pivot element after partitioning.)
 ///
-fn partition_random<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) -> usize {
-  let mut rng = rand::thread_rng();
+pub fn partition_random<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) -> usize {
+  partition_random_with(arr, lo, hi, &mut rand::thread_rng())
+}
+
+/// 与 [`partition_random`] 相同，但使用给定的随机数生成器选择基准元素，便于得到可复现的结果。
+///
+/// The same as [`partition_random`], but picks the pivot with the given random number
+/// generator, which makes the result reproducible.
+pub fn partition_random_with<T: PartialOrd, R: Rng + ?Sized>(
+  arr: &mut [T],
+  lo: usize,
+  hi: usize,
+  rng: &mut R,
+) -> usize {
   let pivot = rng.gen_range(lo..=hi);
 
   arr.swap(lo, pivot);
-  let partition_pos = partition_helper(arr, lo, lo, hi);
+  let partition_pos = partition_helper(arr, lo, lo, hi, &mut Metrics::default());
   arr.swap(lo, partition_pos);
   partition_pos
 }
@@ -118,24 +157,31 @@ fn partition_random<T: PartialOrd>(arr: &mut [T], lo: usize, hi: usize) -> usize
 /// * `pivot`: 基准元素的索引。 (The index of the pivot element.)
 /// * `lo`: 分区范围的低索引。 (The low index of the range to be partitioned.)
 /// * `hi`: 分区范围的高索引。 (The high index of the range to be partitioned.)
+/// * `metrics`: 累加比较和交换次数。 (Accumulates the comparisons and swaps.)
 ///
 /// # 返回 (Returns)
 ///
 /// 分区完成后基准元素的最终索引。 (The final index of the pivot element after partitioning.)
 ///
-fn partition_helper<T: PartialOrd>(arr: &mut [T], pivot: usize, lo: usize, hi: usize) -> usize {
+fn partition_helper<T: PartialOrd>(
+  arr: &mut [T],
+  pivot: usize,
+  lo: usize,
+  hi: usize,
+  metrics: &mut Metrics,
+) -> usize {
   let mut left = lo;
   let mut right = hi;
 
   while left < right {
-    while left < right && arr[right] >= arr[pivot] {
+    while left < right && metrics.ge(&arr[right], &arr[pivot]) {
       right -= 1;
     }
-    while left < right && arr[left] <= arr[pivot] {
+    while left < right && metrics.le(&arr[left], &arr[pivot]) {
       left += 1;
     }
     if left != right {
-      arr.swap(left, right);
+      metrics.swap(arr, left, right);
     }
   }
 
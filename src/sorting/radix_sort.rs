@@ -12,61 +12,237 @@
 /// 时间复杂度为 `O((n + b) * logb(k))`，其中 `n` 是元素个数，
 /// `b` 是基数，`k` 是最大元素。当 `n` 和 `b` 大致相同时，该算法具有线性时间复杂度。
 ///
-/// 空间复杂度为 `O(n + b)`。
+/// 空间复杂度为 `O(n + b)`，整个排序只分配一个缓冲区和一个计数数组。
 ///
 /// Time complexity is `O((n + b) * logb(k))`, where `n` is the number of elements,
 /// `b` is the base (the radix), and `k` is the largest element.
 /// When `n` and `b` are roughly the same maginitude, this algorithm runs in linear time.
 ///
-/// Space complexity is `O(n + b)`.
+/// Space complexity is `O(n + b)`; the whole sort allocates exactly one scratch buffer and one
+/// counter table.
+///
+/// `T` 可以是任意无符号整数类型（`u8`、`u16`、`u32`、`u64`、`u128`、`usize`）。
+/// 每一位的提取都通过移位和掩码在 `T` 自身的宽度内完成，不会截断较宽的类型。
+///
+/// `T` can be any unsigned integer type (`u8`, `u16`, `u32`, `u64`, `u128`, `usize`).
+/// Digits are extracted with shifts and masks in `T`'s own width, so wide types are never
+/// truncated.
+///
+/// 有符号整数没有实现 [`RadixKey`]，请使用 [`radix_sort_signed`] 或 [`radix_sort_i32`]。
+///
+/// Signed integers do not implement [`RadixKey`]; use [`radix_sort_signed`] or
+/// [`radix_sort_i32`] instead:
+///
+/// ```compile_fail
+/// use rust_algorithm::sorting::radix_sort::radix_sort;
+///
+/// let mut v = vec![3i64, -1, 2];
+/// radix_sort(&mut v);
+/// ```
 ///
 /// # 参数 (Arguments)
 ///
 /// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
 ///
-pub fn radix_sort(arr: &mut [u64]) {
+pub fn radix_sort<T: RadixKey>(arr: &mut [T]) {
   // 找到数组中的最大值，以确定排序的次数 (Find the maximum value in the array to determine the number of sorts)
-  let max: usize = match arr.iter().max() {
-    Some(&x) => x as usize,
+  let max_bits = match arr.iter().max() {
+    Some(&x) => x.bit_len(),
     None => return,
   };
 
-  // 选择一个接近数组长度的2的幂作为基数，以优化运行时间 (Choose a power of 2 close to array length as radix for optimal runtime)
-  let radix = arr.len().next_power_of_two();
+  // 选择一个接近数组长度的2的幂作为基数，以优化运行时间；基数至少为 2，且不超过 2^16 和 T 的宽度
+  // (Choose a power of 2 close to array length as radix for optimal runtime; the radix is at
+  // least 2 and at most 2^16 or the width of T)
+  let radix_bits = arr
+    .len()
+    .next_power_of_two()
+    .trailing_zeros()
+    .clamp(1, MAX_RADIX_BITS)
+    .min(T::BITS);
+  let radix = 1 << radix_bits;
+  let mask = radix - 1;
+  // 预先分配一个缓冲区和计数数组，之后每一轮在 `arr` 和缓冲区之间来回写入；计数数组只有 radix 项，每一轮都复用
+  // (Allocate one scratch buffer and one counter up front, then ping-pong between `arr` and the
+  // buffer; the counter only holds radix entries and is reused by every pass)
+  let mut buffer = arr.to_vec();
+  let mut counter = vec![0; radix];
+  let mut passes = 0;
   // 从最低有效位到最高有效位逐位进行计数排序 (Counting sort by each digit from least to most significant)
-  let mut place = 1;
+  let mut shift = 0;
 
-  while place <= max {
-    // 获取数字的某位数 (Get the digit at a certain place)
-    let digit_of = |x| x as usize / place % radix;
-    // 计算每个位上数字出现的次数 (Count digit occurrences)
-    let mut counter = vec![0; radix];
+  {
+    let (mut src, mut dst) = (&mut *arr, &mut buffer[..]);
 
-    for &x in arr.iter() {
-      counter[digit_of(x)] += 1;
-    }
+    while shift < max_bits {
+      // 获取数字的某位数 (Get the digit at a certain place)
+      let digit_of = |x: T| x.digit(shift, mask);
+      // 计算每个位上数字出现的次数 (Count digit occurrences)
+      counter.fill(0);
 
-    // 计算每个位上数字的最后一个索引 (Compute last index of each digit)
-    for i in 1..radix {
-      counter[i] += counter[i - 1];
-    }
+      for &x in src.iter() {
+        counter[digit_of(x)] += 1;
+      }
 
-    // 将元素按位重新排序到正确的位置 (Write elements to their new indices)
-    let arr_copy = arr.to_owned(); // 为了在迭代过程中避免 borrow 错误 (To avoid borrow errors during iteration)
-    for &x in arr_copy.iter().rev() {
-      counter[digit_of(x)] -= 1;
-      arr[counter[digit_of(x)]] = x;
+      // 计算每个位上数字的最后一个索引 (Compute last index of each digit)
+      for i in 1..radix {
+        counter[i] += counter[i - 1];
+      }
+
+      // 将元素按位重新排序到另一个缓冲区的正确位置 (Write elements to their new indices in the other buffer)
+      for &x in src.iter().rev() {
+        let digit = digit_of(x);
+        counter[digit] -= 1;
+        dst[counter[digit]] = x;
+      }
+
+      // 交换两个缓冲区，转到下一位 (Swap the buffers and move to the next place)
+      std::mem::swap(&mut src, &mut dst);
+      shift += radix_bits;
+      passes += 1;
     }
+  }
 
-    // 转到下一位 (Move to the next place)
-    place *= radix;
+  // 奇数轮之后结果位于缓冲区中 (After an odd number of passes the result lives in the buffer)
+  if passes % 2 == 1 {
+    arr.copy_from_slice(&buffer);
+  }
+}
+
+/// 每一位最多使用的比特数，限制计数数组的大小。 (The most bits used per digit, bounding the counter array.)
+const MAX_RADIX_BITS: u32 = 16;
+
+/// 可以用 [`radix_sort`] 排序的无符号整数键。
+///
+/// An unsigned integer key that can be sorted by [`radix_sort`].
+pub trait RadixKey: Copy + Ord {
+  /// 类型的比特宽度。 (The width of the type in bits.)
+  const BITS: u32;
+
+  /// 去掉前导零后的比特数。 (The number of bits once leading zeros are dropped.)
+  fn bit_len(self) -> u32;
+
+  /// 返回 `(self >> shift) & mask`，在 `Self` 的宽度内计算。
+  /// (Returns `(self >> shift) & mask`, computed in the width of `Self`.)
+  fn digit(self, shift: u32, mask: usize) -> usize;
+}
+
+macro_rules! impl_radix_key {
+  ($($t:ty),*) => {
+    $(
+      impl RadixKey for $t {
+        const BITS: u32 = <$t>::BITS;
+
+        fn bit_len(self) -> u32 {
+          <$t>::BITS - self.leading_zeros()
+        }
+
+        fn digit(self, shift: u32, mask: usize) -> usize {
+          ((self >> shift) & mask as $t) as usize
+        }
+      }
+    )*
+  };
+}
+
+impl_radix_key!(u8, u16, u32, u64, u128, usize);
+
+/// 对 `i64` 切片进行基数排序。
+///
+/// Sorts a slice of `i64` with radix sort.
+///
+/// 将每个值的符号位取反后映射为 `u64`，使负数排在正数之前，
+/// 然后复用无符号的 [`radix_sort`]，最后再映射回 `i64`。
+///
+/// Each value is mapped to a `u64` by flipping its sign bit, which places negative numbers
+/// before positive ones, then sorted with the unsigned [`radix_sort`] and mapped back.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
+///
+pub fn radix_sort_signed(arr: &mut [i64]) {
+  const SIGN_BIT: u64 = 1 << 63;
+
+  let mut keys: Vec<u64> = arr.iter().map(|&x| x as u64 ^ SIGN_BIT).collect();
+  radix_sort(&mut keys);
+
+  for (x, key) in arr.iter_mut().zip(keys) {
+    *x = (key ^ SIGN_BIT) as i64;
+  }
+}
+
+/// 对 `i32` 切片进行基数排序，映射方式与 [`radix_sort_signed`] 相同。
+///
+/// Sorts a slice of `i32` with radix sort, using the same mapping as [`radix_sort_signed`].
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
+///
+pub fn radix_sort_i32(arr: &mut [i32]) {
+  const SIGN_BIT: u32 = 1 << 31;
+
+  let mut keys: Vec<u32> = arr.iter().map(|&x| x as u32 ^ SIGN_BIT).collect();
+  radix_sort(&mut keys);
+
+  for (x, key) in arr.iter_mut().zip(keys) {
+    *x = (key ^ SIGN_BIT) as i32;
+  }
+}
+
+/// 对 `f64` 切片进行基数排序，所有 NaN 按输入顺序放在末尾。
+///
+/// Sorts a slice of `f64` with radix sort. All NaNs are moved to the end, in input order.
+///
+/// 每个非 NaN 的值都被映射为一个保持顺序的 `u64` 键：负数翻转所有位，非负数只翻转符号位。
+/// 得到的顺序与 [`f64::total_cmp`] 一致，因此 `-0.0` 排在 `0.0` 之前。
+///
+/// Every non-NaN value is mapped to an order-preserving `u64` key: negative values have all
+/// their bits flipped, non-negative values only their sign bit. The resulting order agrees with
+/// [`f64::total_cmp`], so `-0.0` sorts before `0.0`.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
+///
+pub fn radix_sort_f64(arr: &mut [f64]) {
+  const SIGN_BIT: u64 = 1 << 63;
+
+  let nans: Vec<f64> = arr.iter().copied().filter(|x| x.is_nan()).collect();
+  let mut keys: Vec<u64> = arr
+    .iter()
+    .filter(|x| !x.is_nan())
+    .map(|x| {
+      let bits = x.to_bits();
+      if bits & SIGN_BIT != 0 {
+        !bits
+      } else {
+        bits ^ SIGN_BIT
+      }
+    })
+    .collect();
+  radix_sort(&mut keys);
+
+  let sorted = keys.into_iter().map(|key| {
+    let bits = if key & SIGN_BIT != 0 {
+      key ^ SIGN_BIT
+    } else {
+      !key
+    };
+    f64::from_bits(bits)
+  });
+
+  for (x, value) in arr.iter_mut().zip(sorted.chain(nans)) {
+    *x = value;
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use super::radix_sort;
+  use super::{radix_sort, radix_sort_f64, radix_sort_i32, radix_sort_signed};
   use crate::sorting::counting_sort::is_sorted;
+  use rand::Rng;
 
   #[test]
   fn empty() {
@@ -77,16 +253,182 @@ mod tests {
 
   #[test]
   fn descending() {
-    let mut v = vec![201, 127, 64, 37, 24, 4, 1];
+    let mut v: Vec<u64> = vec![201, 127, 64, 37, 24, 4, 1];
     radix_sort(&mut v);
     assert!(is_sorted(&v));
   }
 
   #[test]
   fn ascending() {
-    let mut v = vec![1, 4, 24, 37, 64, 127, 201];
+    let mut v: Vec<u64> = vec![1, 4, 24, 37, 64, 127, 201];
+    radix_sort(&mut v);
+    assert!(is_sorted(&v));
+  }
+
+  #[test]
+  fn unsigned_near_max() {
+    let mut v = vec![u64::MAX, 0, u64::MAX - 1, 1 << 63, 42];
+    radix_sort(&mut v);
+    assert_eq!(v, vec![0, 42, 1 << 63, u64::MAX - 1, u64::MAX]);
+  }
+
+  #[test]
+  fn signed_extremes() {
+    let mut v = vec![0, i64::MAX, -1, i64::MIN, 1, i64::MIN + 1, i64::MAX - 1];
+    radix_sort_signed(&mut v);
+    assert_eq!(
+      v,
+      vec![i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX]
+    );
+  }
+
+  #[test]
+  fn signed_mixed() {
+    let mut v = vec![5, -3, 12, -300, 0, 7, -3, 41, -1];
+    let mut expected = v.clone();
+    expected.sort();
+    radix_sort_signed(&mut v);
+    assert_eq!(v, expected);
+  }
+
+  #[test]
+  fn signed_all_negative() {
+    let mut v = vec![-1, -50, -7, -1000, -2, -50];
+    radix_sort_signed(&mut v);
+    assert_eq!(v, vec![-1000, -50, -50, -7, -2, -1]);
+  }
+
+  #[test]
+  fn signed_empty() {
+    let mut v: Vec<i64> = vec![];
+    radix_sort_signed(&mut v);
+    assert!(v.is_empty());
+  }
+
+  #[test]
+  fn i32_extremes() {
+    let mut v = vec![i32::MAX, 3, i32::MIN, -3, 0, -1];
+    radix_sort_i32(&mut v);
+    assert_eq!(v, vec![i32::MIN, -3, -1, 0, 3, i32::MAX]);
+  }
+
+  #[test]
+  fn f64_signed_zeros() {
+    let mut v = vec![0.0, -0.0, 0.0, -0.0];
+    radix_sort_f64(&mut v);
+    let signs: Vec<bool> = v.iter().map(|x| x.is_sign_negative()).collect();
+    assert_eq!(signs, vec![true, true, false, false]);
+  }
+
+  #[test]
+  fn f64_infinities_and_subnormals() {
+    let tiny = f64::from_bits(1);
+    let mut v = vec![
+      f64::INFINITY,
+      1.5,
+      -tiny,
+      f64::NEG_INFINITY,
+      tiny,
+      f64::MIN_POSITIVE,
+      -1.5,
+      0.0,
+    ];
+    radix_sort_f64(&mut v);
+    assert_eq!(
+      v,
+      vec![
+        f64::NEG_INFINITY,
+        -1.5,
+        -tiny,
+        0.0,
+        tiny,
+        f64::MIN_POSITIVE,
+        1.5,
+        f64::INFINITY
+      ]
+    );
+  }
+
+  #[test]
+  fn f64_nans_go_last() {
+    let mut v = vec![f64::NAN, 3.0, -f64::NAN, -1.0, f64::INFINITY];
+    radix_sort_f64(&mut v);
+    assert_eq!(&v[..3], &[-1.0, 3.0, f64::INFINITY]);
+    assert!(v[3].is_nan() && v[3].is_sign_positive());
+    assert!(v[4].is_nan() && v[4].is_sign_negative());
+  }
+
+  #[test]
+  fn f64_agrees_with_total_cmp() {
+    let mut rng = rand::thread_rng();
+    let mut v: Vec<f64> = (0..10_000)
+      .map(|_| rng.gen_range(-1e6..1e6) * rng.gen_range(0.0..1.0))
+      .collect();
+    let mut expected = v.clone();
+    expected.sort_by(|a, b| a.total_cmp(b));
+    radix_sort_f64(&mut v);
+    assert_eq!(v, expected);
+  }
+
+  #[test]
+  fn one_element() {
+    let mut v = vec![42u64];
+    radix_sort(&mut v);
+    assert_eq!(v, vec![42]);
+  }
+
+  #[test]
+  fn u8_more_elements_than_values() {
+    let mut v: Vec<u8> = (0..1000).map(|i| (i * 37 % 256) as u8).collect();
+    v.push(u8::MAX);
     radix_sort(&mut v);
     assert!(is_sorted(&v));
+    assert_eq!(v[v.len() - 1], u8::MAX);
+  }
+
+  #[test]
+  fn u16_near_max() {
+    let mut v = vec![u16::MAX, 0, u16::MAX - 1, 1 << 15, 300];
+    radix_sort(&mut v);
+    assert_eq!(v, vec![0, 300, 1 << 15, u16::MAX - 1, u16::MAX]);
+  }
+
+  #[test]
+  fn u32_near_max() {
+    let mut v = vec![u32::MAX, 7, u32::MAX - 1, 1 << 31, 0];
+    radix_sort(&mut v);
+    assert_eq!(v, vec![0, 7, 1 << 31, u32::MAX - 1, u32::MAX]);
+  }
+
+  #[test]
+  fn u128_near_max() {
+    let mut v = vec![
+      u128::MAX,
+      1 << 64,
+      u128::MAX - 1,
+      u64::MAX as u128,
+      0,
+      1 << 127,
+    ];
+    radix_sort(&mut v);
+    assert_eq!(
+      v,
+      vec![
+        0,
+        u64::MAX as u128,
+        1 << 64,
+        1 << 127,
+        u128::MAX - 1,
+        u128::MAX
+      ]
+    );
+  }
+
+  #[test]
+  fn usize_near_max() {
+    let mut v = vec![usize::MAX, 3, usize::MAX / 2, 0];
+    radix_sort(&mut v);
+    assert_eq!(v, vec![0, 3, usize::MAX / 2, usize::MAX]);
   }
 }
 
@@ -49,9 +49,67 @@ pub fn selection_sort<T: PartialOrd>(arr: &mut [T]) {
   }
 }
 
+/// 双向选择排序：每一轮在一次扫描中同时找出未排序区间的最小值和最大值，
+/// 分别交换到区间的两端，因此轮数减半，但时间复杂度仍为 `O(n^2)`。
+///
+/// Double Selection Sort finds both the minimum and the maximum of the unsorted window in a
+/// single scan and swaps them to the two ends of the window, halving the number of passes. The
+/// time complexity is still `O(n^2)`.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be sorted.)
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut numbers = vec![7, 49, 73, 58, 30, 72, 44, 78, 23, 9];
+/// double_selection_sort(&mut numbers);
+/// assert_eq!(numbers, vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78]);
+/// ```
+pub fn double_selection_sort<T: PartialOrd>(arr: &mut [T]) {
+  // 如果数组长度小于等于 1，则无需排序 (If the length of the array is less than or equal to 1, no sorting is needed)
+  if arr.len() <= 1 {
+    return;
+  }
+
+  // 未排序区间为 left..=right (The unsorted window is left..=right)
+  let mut left = 0;
+  let mut right = arr.len() - 1;
+
+  while left < right {
+    let mut min_index = left;
+    let mut max_index = left;
+
+    // 一次扫描同时找到最小和最大元素的索引 (Find the indices of both the minimum and the maximum in one scan)
+    for j in (left + 1)..=right {
+      if arr[j] < arr[min_index] {
+        min_index = j;
+      }
+
+      if arr[j] > arr[max_index] {
+        max_index = j;
+      }
+    }
+
+    arr.swap(left, min_index);
+
+    // 如果最大元素原本在 left，它刚刚被换到了 min_index (If the maximum was at `left`, it has just been moved to `min_index`)
+    if max_index == left {
+      max_index = min_index;
+    }
+
+    arr.swap(right, max_index);
+
+    left += 1;
+    right -= 1;
+  }
+}
+
 #[cfg(test)]
 mod tests {
-  use super::selection_sort;
+  use super::{double_selection_sort, selection_sort};
+  use rand::Rng;
 
   #[test]
   fn test_empty_vec() {
@@ -86,6 +144,55 @@ mod tests {
       ]
     );
   }
+
+  #[test]
+  fn test_double_max_at_left() {
+    // 最大值正好位于最小值要被换到的位置 (The maximum sits where the minimum gets swapped to)
+    let mut vec = vec![3, 1, 2];
+    double_selection_sort(&mut vec);
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec = vec![5, 4, 3, 2, 1];
+    double_selection_sort(&mut vec);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+  }
+
+  #[test]
+  fn test_double_duplicates() {
+    let mut vec = vec![2, 2, 1, 3, 3, 1, 2];
+    double_selection_sort(&mut vec);
+    assert_eq!(vec, vec![1, 1, 2, 2, 2, 3, 3]);
+
+    let mut vec = vec![4, 4, 4];
+    double_selection_sort(&mut vec);
+    assert_eq!(vec, vec![4, 4, 4]);
+  }
+
+  #[test]
+  fn test_double_empty_and_one_element() {
+    let mut empty_vec: Vec<String> = vec![];
+    double_selection_sort(&mut empty_vec);
+    assert_eq!(empty_vec, Vec::<String>::new());
+
+    let mut vec = vec![1];
+    double_selection_sort(&mut vec);
+    assert_eq!(vec, vec![1]);
+  }
+
+  #[test]
+  fn test_double_matches_selection_sort() {
+    let mut rng = rand::thread_rng();
+
+    for len in 0..50 {
+      let input: Vec<i32> = (0..len).map(|_| rng.gen_range(-10..10)).collect();
+      let mut expected = input.clone();
+      selection_sort(&mut expected);
+
+      let mut vec = input.clone();
+      double_selection_sort(&mut vec);
+      assert_eq!(vec, expected, "input: {:?}", input);
+    }
+  }
 }
 
 fn main() {
@@ -12,9 +12,14 @@
 /// which is better on average than the basic insertion sort. Shell Sort is not stable, meaning that the relative
 /// order of equal elements may change.
 ///
+/// 默认使用 [`GapSequence::Ciura`] 间隔序列，可以通过 [`shell_sort_with_gaps`] 选择其他序列。
+///
+/// The [`GapSequence::Ciura`] gap sequence is used by default; [`shell_sort_with_gaps`] lets the
+/// caller choose another one.
+///
 /// # 参数 (Arguments)
 ///
-/// * `values`: 待排序的可变向量的引用。 (A mutable reference to the vector to be sorted.)
+/// * `arr`: 待排序的可变切片。 (A mutable reference to the slice to be sorted.)
 ///
 /// # 示例 (Examples)
 ///
@@ -23,37 +28,120 @@
 /// shell_sort(&mut numbers);
 /// assert_eq!(numbers, vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78]);
 /// ```
-pub fn shell_sort<T: Ord + Copy>(values: &mut Vec<T>) {
-  // 插入排序的变种，通过交换指定间隔的值并减小间隔至 1 来进行排序
-  fn insertion<T: Ord + Copy>(values: &mut Vec<T>, start: usize, gap: usize) {
-    for i in ((start + gap)..values.len()).step_by(gap) {
-      let val_current = values[i]; // 当前需要插入的元素
-      let mut pos = i; // 当前元素的位置
+pub fn shell_sort<T: Ord>(arr: &mut [T]) {
+  shell_sort_with_gaps(arr, GapSequence::Ciura);
+}
 
-      // 向前比较并交换，使元素归位
-      while pos >= gap && values[pos - gap] > val_current {
-        values[pos] = values[pos - gap];
-        pos -= gap;
-      }
+/// 希尔排序使用的间隔序列。 (The gap sequence used by Shell Sort.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapSequence {
+  /// Shell 最初提出的序列：`n/2, n/4, ..., 1`，最坏情况 `O(n^2)`。
+  /// (Shell's original sequence `n/2, n/4, ..., 1`; `O(n^2)` in the worst case.)
+  Shell,
+  /// Knuth 序列：`1, 4, 13, 40, ...`，即 `(3^k - 1) / 2`，最坏情况 `O(n^(3/2))`。
+  /// (Knuth's sequence `1, 4, 13, 40, ...`, i.e. `(3^k - 1) / 2`; `O(n^(3/2))` in the worst case.)
+  Knuth,
+  /// Ciura 通过实验得到的序列：`1, 4, 10, 23, 57, 132, 301, 701`，之后每项乘以 2.25 扩展。
+  /// (Ciura's empirically derived sequence `1, 4, 10, 23, 57, 132, 301, 701`, extended by
+  /// multiplying by 2.25 beyond the table.)
+  Ciura,
+}
 
-      values[pos] = val_current; // 将元素插入到正确的位置
+impl GapSequence {
+  /// 返回长度为 `len` 的数组所使用的间隔，从大到小排列，最后一个间隔总是 1。
+  ///
+  /// Returns the gaps used for an array of length `len`, in decreasing order. The last gap is
+  /// always 1 (the list is empty when `len < 2`).
+  pub fn gaps(self, len: usize) -> Vec<usize> {
+    if len < 2 {
+      return vec![];
     }
+
+    let mut gaps = match self {
+      GapSequence::Shell => {
+        let mut gaps = vec![];
+        let mut gap = len / 2;
+
+        while gap > 0 {
+          gaps.push(gap);
+          gap /= 2;
+        }
+
+        gaps.reverse();
+        gaps
+      }
+      GapSequence::Knuth => {
+        let mut gaps = vec![];
+        let mut gap = 1;
+
+        while gap < len {
+          gaps.push(gap);
+          gap = 3 * gap + 1;
+        }
+
+        gaps
+      }
+      GapSequence::Ciura => {
+        const CIURA: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+        let mut gaps: Vec<usize> = CIURA.iter().copied().take_while(|&gap| gap < len).collect();
+
+        // 超出表格范围后，每项乘以 2.25 继续扩展
+        if gaps.len() == CIURA.len() {
+          let mut gap = CIURA[CIURA.len() - 1];
+
+          loop {
+            gap = (gap as f64 * 2.25) as usize;
+            if gap >= len {
+              break;
+            }
+            gaps.push(gap);
+          }
+        }
+
+        gaps
+      }
+    };
+
+    gaps.reverse();
+    gaps
   }
+}
 
-  let mut count_sublist = values.len() / 2; // 将间隔初始化为数组长度的一半
+/// 使用指定的间隔序列进行希尔排序。
+///
+/// Shell Sort with a caller-chosen gap sequence.
+///
+/// 元素通过交换移动，因此不需要 `Copy` 或 `Clone`。
+///
+/// Elements are moved with swaps, so neither `Copy` nor `Clone` is required.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片。 (A mutable reference to the slice to be sorted.)
+/// * `gaps`: 使用的间隔序列。 (The gap sequence to use.)
+pub fn shell_sort_with_gaps<T: Ord>(arr: &mut [T], gaps: GapSequence) {
+  for gap in gaps.gaps(arr.len()) {
+    // 对间隔为 gap 的所有子序列同时进行插入排序
+    for i in gap..arr.len() {
+      let mut pos = i; // 当前元素的位置
 
-  while count_sublist > 0 {
-    for pos_start in 0..count_sublist {
-      insertion(values, pos_start, count_sublist); // 对每个子序列进行插入排序
+      // 向前比较并交换，使元素归位
+      while pos >= gap && arr[pos - gap] > arr[pos] {
+        arr.swap(pos - gap, pos);
+        pos -= gap;
+      }
     }
-
-    count_sublist /= 2; // 将间隔减半，继续排序
   }
 }
 
 #[cfg(test)]
 mod test {
-  use super::shell_sort;
+  use super::{shell_sort, shell_sort_with_gaps, GapSequence};
+  use rand::rngs::StdRng;
+  use rand::{Rng, SeedableRng};
+  use rust_algorithm::test_support::{count_comparisons, counted};
+
+  const SEQUENCES: [GapSequence; 3] = [GapSequence::Shell, GapSequence::Knuth, GapSequence::Ciura];
 
   #[test]
   fn basic() {
@@ -96,6 +184,78 @@ mod test {
       assert!(vec[i] <= vec[i + 1]);
     }
   }
+
+  #[test]
+  fn gaps() {
+    assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+    assert_eq!(GapSequence::Knuth.gaps(20), vec![13, 4, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(20), vec![10, 4, 1]);
+    assert_eq!(
+      GapSequence::Ciura.gaps(5_000),
+      vec![3548, 1577, 701, 301, 132, 57, 23, 10, 4, 1]
+    );
+
+    for gaps in SEQUENCES {
+      assert!(gaps.gaps(0).is_empty());
+      assert!(gaps.gaps(1).is_empty());
+      assert_eq!(gaps.gaps(2), vec![1]);
+    }
+  }
+
+  #[test]
+  fn every_gap_sequence() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let inputs: Vec<Vec<i32>> = vec![
+      vec![],
+      vec![1],
+      vec![3, 5, 6, 3, 1, 4],
+      (0..1_000).rev().collect(),
+      (0..1_000).map(|_| rng.gen_range(-50..50)).collect(),
+    ];
+
+    for input in inputs {
+      let mut expected = input.clone();
+      expected.sort();
+
+      for gaps in SEQUENCES {
+        let mut vec = input.clone();
+        shell_sort_with_gaps(&mut vec, gaps);
+        assert_eq!(vec, expected, "{:?}", gaps);
+      }
+    }
+  }
+
+  #[test]
+  fn array_and_sub_slice() {
+    let mut arr = [String::from("c"), String::from("a"), String::from("b")];
+    shell_sort(&mut arr);
+    assert_eq!(
+      arr,
+      [String::from("a"), String::from("b"), String::from("c")]
+    );
+
+    let mut vec = vec![9, 8, 3, 2, 1, 0];
+    shell_sort(&mut vec[1..5]);
+    assert_eq!(vec, vec![9, 1, 2, 3, 8, 0]);
+  }
+
+  fn sort_comparisons(input: &[i32], gaps: GapSequence) -> usize {
+    let mut vec = counted(input);
+    let comparisons = count_comparisons(|| shell_sort_with_gaps(&mut vec, gaps));
+    assert!(vec.windows(2).all(|w| w[0].0 <= w[1].0));
+    comparisons
+  }
+
+  #[test]
+  fn ciura_beats_halving() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let input: Vec<i32> = (0..10_000).map(|_| rng.gen()).collect();
+
+    let shell = sort_comparisons(&input, GapSequence::Shell);
+    let ciura = sort_comparisons(&input, GapSequence::Ciura);
+
+    assert!(ciura < shell, "ciura: {}, shell: {}", ciura, shell);
+  }
 }
 
 fn main() {}
@@ -0,0 +1,205 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use rust_algorithm::sorting::counting_sort::is_sorted;
+use rust_algorithm::sorting::quick_sort::quick_sort;
+use rust_algorithm::sorting::tim_sort::merge;
+
+/// 同时存在的线程数上限，超过后按块分批排序
+/// (Upper bound on concurrently running threads; longer inputs are sorted in chunks)
+pub const MAX_THREADS: usize = 64;
+
+/// 整次排序归一化后的睡眠步数预算，由所有块平分，总耗时约为 `MAX_STEPS * unit`
+/// (Budget of normalized sleep steps for a whole sort, shared evenly by all chunks, so the total
+/// time is about `MAX_STEPS * unit`)
+pub const MAX_STEPS: u64 = 1_000;
+
+/// 睡眠排序（Sleep Sort）：为每个元素启动一个线程，睡眠与元素值成正比的时间后把元素写入结果，
+/// 越小的元素越早醒来。这只是一个玩笑性质的算法，需要开启 `fun` feature。
+///
+/// Sleep Sort spawns one thread per element, which sleeps for a time proportional to the
+/// element's value and then pushes it into the result, so smaller elements wake up first. It is
+/// a novelty algorithm and needs the `fun` feature.
+///
+/// 为了控制线程数和耗时：
+///
+/// - 每块最多 [`MAX_THREADS`] 个元素，各块依次排序，最后一次性归并；
+/// - [`MAX_STEPS`] 由所有块平分（每块至少一步），元素值按块内的最小值和最大值归一化到
+///   `0..=MAX_STEPS / 块数` 步，每步睡眠 `unit`。
+///
+/// To bound the thread count and the running time:
+///
+/// - chunks of at most [`MAX_THREADS`] elements are sorted one after another and merged once at
+///   the end;
+/// - [`MAX_STEPS`] is split evenly across the chunks (at least one step each), and values are
+///   normalized to `0..=MAX_STEPS / chunk_count` steps using the chunk's minimum and maximum,
+///   each step sleeping for `unit`.
+///
+/// 结果的正确性依赖于线程调度，只是概率性的：`unit` 太小或系统繁忙时，醒来的顺序可能出错，
+/// 归一化后相差不到一步的元素也可能乱序。因此每块排序后都会用 [`is_sorted`] 检查，
+/// 失败时退回到 [`quick_sort`]，保证返回的结果总是有序的。
+///
+/// Correctness depends on thread scheduling and is only probabilistic: with a tiny `unit` or a
+/// busy system threads may wake up out of order, and values less than one step apart after
+/// normalization may swap places. Every chunk is therefore checked with [`is_sorted`] and falls
+/// back to [`quick_sort`] on failure, so the returned vector is always sorted.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的切片。 (The slice to sort.)
+/// * `unit`: 每一步睡眠的时间。 (How long each step sleeps.)
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let sorted = sleep_sort(&[3, 1, 2], Duration::from_millis(1));
+/// assert_eq!(sorted, vec![1, 2, 3]);
+/// ```
+pub fn sleep_sort(arr: &[u64], unit: Duration) -> Vec<u64> {
+  sleep_sort_with_fallbacks(arr, unit).0
+}
+
+/// 与 [`sleep_sort`] 相同，另外返回退回到 [`quick_sort`] 的块数
+/// (Same as [`sleep_sort`], but also returns how many chunks fell back to [`quick_sort`])
+fn sleep_sort_with_fallbacks(arr: &[u64], unit: Duration) -> (Vec<u64>, usize) {
+  let chunk_count = arr.len().div_ceil(MAX_THREADS) as u64;
+  let steps = (MAX_STEPS / chunk_count.max(1)).max(1);
+
+  let mut result = Vec::with_capacity(arr.len());
+  let mut fallbacks = 0;
+
+  for chunk in arr.chunks(MAX_THREADS) {
+    let (sorted, fell_back) = sleep_sort_chunk(chunk, unit, steps);
+    result.extend(sorted);
+    fallbacks += fell_back as usize;
+  }
+
+  // 各块已经有序，自底向上两两归并，总共 O(n log(块数))
+  // (Every chunk is sorted; merge them bottom-up in pairs, O(n log(chunk_count)) in total)
+  let n = result.len();
+  let mut width = MAX_THREADS;
+  while width < n {
+    for left in (0..n).step_by(2 * width) {
+      let mid = left + width;
+      if mid < n {
+        let right = (mid + width).min(n) - 1;
+        merge(&mut result, left, mid - 1, right);
+      }
+    }
+    width *= 2;
+  }
+
+  (result, fallbacks)
+}
+
+/// 用最多 `steps` 步睡眠排序一块，返回结果以及是否退回到了 [`quick_sort`]
+/// (Sleep-sorts one chunk within `steps` steps, returning the result and whether it fell back
+/// to [`quick_sort`])
+fn sleep_sort_chunk(chunk: &[u64], unit: Duration, steps: u64) -> (Vec<u64>, bool) {
+  let (min, max) = match (chunk.iter().min(), chunk.iter().max()) {
+    (Some(&min), Some(&max)) => (min, max),
+    _ => return (vec![], false),
+  };
+
+  // 所有元素都相等，已经有序
+  // (Every element is equal, so the chunk is already sorted)
+  let span = max - min;
+  if span == 0 {
+    return (chunk.to_vec(), false);
+  }
+
+  let output = Mutex::new(Vec::with_capacity(chunk.len()));
+
+  thread::scope(|scope| {
+    for &value in chunk {
+      let output = &output;
+
+      scope.spawn(move || {
+        let step = (value - min) as u128 * steps as u128 / span as u128;
+        thread::sleep(unit * step as u32);
+        output.lock().unwrap().push(value);
+      });
+    }
+  });
+
+  let mut output = output.into_inner().unwrap();
+
+  let fell_back = !is_sorted(&output);
+  if fell_back {
+    quick_sort(&mut output);
+  }
+
+  (output, fell_back)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{is_sorted, sleep_sort, sleep_sort_with_fallbacks, MAX_THREADS};
+  use rand::Rng;
+  use std::time::Duration;
+
+  #[test]
+  fn small_distinct_values() {
+    let sorted = sleep_sort(&[30, 10, 50, 20, 40], Duration::from_millis(1));
+    assert_eq!(sorted, vec![10, 20, 30, 40, 50]);
+  }
+
+  #[test]
+  fn empty_and_equal() {
+    assert!(sleep_sort(&[], Duration::from_millis(1)).is_empty());
+    assert_eq!(
+      sleep_sort(&[7, 7, 7], Duration::from_secs(60)),
+      vec![7, 7, 7]
+    );
+  }
+
+  #[test]
+  fn huge_values_are_normalized() {
+    let arr = [u64::MAX, 0, u64::MAX / 2];
+    let sorted = sleep_sort(&arr, Duration::from_micros(10));
+    assert_eq!(sorted, vec![0, u64::MAX / 2, u64::MAX]);
+  }
+
+  #[test]
+  fn tight_unit_falls_back() {
+    // 不睡眠时醒来的顺序是任意的，只能依靠退回到 quick_sort 保证有序
+    // (Without sleeping the wake-up order is arbitrary, so only the fallback keeps it sorted)
+    let mut rng = rand::thread_rng();
+    let arr: Vec<u64> = (0..200).map(|_| rng.gen_range(0..1_000)).collect();
+    let mut expected = arr.clone();
+    expected.sort();
+
+    let (sorted, fallbacks) = sleep_sort_with_fallbacks(&arr, Duration::ZERO);
+
+    assert!(fallbacks > 0);
+    assert!(is_sorted(&sorted));
+    assert_eq!(sorted, expected);
+  }
+
+  #[test]
+  fn more_elements_than_threads() {
+    let arr: Vec<u64> = (0..(MAX_THREADS as u64 * 3 + 5)).rev().collect();
+    let sorted = sleep_sort(&arr, Duration::from_micros(1));
+    assert_eq!(
+      sorted,
+      (0..(MAX_THREADS as u64 * 3 + 5)).collect::<Vec<u64>>()
+    );
+  }
+
+  #[test]
+  fn many_chunks_are_merged() {
+    // 几十个块平分步数预算，最后归并成一个整体有序的结果
+    // (Dozens of chunks share the step budget and are merged into one sorted result)
+    let mut rng = rand::thread_rng();
+    let arr: Vec<u64> = (0..MAX_THREADS * 37 + 11)
+      .map(|_| rng.gen_range(0..u64::MAX))
+      .collect();
+    let mut expected = arr.clone();
+    expected.sort();
+
+    assert_eq!(sleep_sort(&arr, Duration::from_micros(1)), expected);
+  }
+}
+
+fn main() {}
@@ -0,0 +1,147 @@
+use std::cmp::Ordering;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// 排序算法的稳定性：相等元素在排序后是否保持原有的相对顺序。
+///
+/// Whether a sorting algorithm keeps equal elements in their original relative order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stability {
+  /// 相等元素保持输入顺序。 (Equal elements keep their input order.)
+  Stable,
+  /// 相等元素的相对顺序可能改变。 (Equal elements may be reordered.)
+  Unstable,
+}
+
+/// 稳定性检测使用的元素：只按 `key` 比较，`index` 记录元素在输入中的原始位置。
+///
+/// The element used by the stability harness: it is compared by `key` only, while `index`
+/// records its original position in the input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Item {
+  pub key: u8,
+  pub index: u32,
+}
+
+impl PartialEq for Item {
+  fn eq(&self, other: &Self) -> bool {
+    self.key == other.key
+  }
+}
+
+impl Eq for Item {}
+
+impl PartialOrd for Item {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Item {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.key.cmp(&other.key)
+  }
+}
+
+/// 检测给定的排序函数是否稳定。
+///
+/// Checks whether the given sorting function is stable.
+///
+/// 在许多随机生成、包含大量重复键的数组上运行 `sorter`，并检查结果既按键有序，
+/// 又保持了相等键元素的原始下标顺序。随机数种子是固定的，因此结果可复现。
+///
+/// Runs `sorter` over many randomized multisets with heavily repeated keys and checks that
+/// every result is ordered by key and that elements with equal keys keep their original index
+/// order. The random seed is fixed, so the outcome is reproducible.
+///
+/// # 返回值 (Returns)
+///
+/// 所有输入都通过检查时返回 `true`。 (`true` if every input passed the check.)
+pub fn is_stable_sort<F: Fn(&mut [Item])>(sorter: F) -> bool {
+  let mut rng = StdRng::seed_from_u64(0x5eed);
+
+  for _ in 0..200 {
+    let len = rng.gen_range(0..64);
+    // 键的取值范围很小，保证有足够多的相等元素 (A small key range guarantees plenty of equal keys)
+    let max_key = rng.gen_range(1..8);
+    let mut items: Vec<Item> = (0..len)
+      .map(|index| Item {
+        key: rng.gen_range(0..max_key),
+        index,
+      })
+      .collect();
+
+    sorter(&mut items);
+
+    let ordered = items.windows(2).all(|pair| {
+      pair[0].key < pair[1].key || (pair[0].key == pair[1].key && pair[0].index < pair[1].index)
+    });
+
+    if !ordered {
+      return false;
+    }
+  }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{is_stable_sort, Item, Stability};
+  use crate::sorting::all_sorters;
+  use crate::sorting::bubble_sort::bubble_sort;
+  use crate::sorting::comb_sort::comb_sort;
+  use crate::sorting::heap_sort::heap_sort;
+  use crate::sorting::insertion_sort::{
+    binary_insertion_sort, insertion_sort, insertion_sort_binary_search,
+  };
+  use crate::sorting::merge_sort::merge_sort;
+  use crate::sorting::quick_sort::quick_sort;
+  use crate::sorting::selection_sort::selection_sort;
+  use crate::sorting::shell_sort::shell_sort;
+  use crate::sorting::tim_sort::tim_sort;
+
+  #[test]
+  fn std_sort_is_stable() {
+    assert!(is_stable_sort(|arr| arr.sort()));
+  }
+
+  #[test]
+  fn reversing_is_not_a_sort() {
+    assert!(!is_stable_sort(|arr: &mut [Item]| arr.reverse()));
+  }
+
+  #[test]
+  fn stable_sorts() {
+    assert!(is_stable_sort(merge_sort));
+    assert!(is_stable_sort(insertion_sort));
+    assert!(is_stable_sort(binary_insertion_sort));
+    assert!(is_stable_sort(insertion_sort_binary_search));
+    assert!(is_stable_sort(bubble_sort));
+    assert!(is_stable_sort(tim_sort));
+  }
+
+  #[test]
+  fn unstable_sorts() {
+    assert!(!is_stable_sort(selection_sort));
+    assert!(!is_stable_sort(quick_sort));
+    assert!(!is_stable_sort(heap_sort));
+    assert!(!is_stable_sort(shell_sort));
+    assert!(!is_stable_sort(comb_sort));
+  }
+
+  #[test]
+  fn registered_sorters_match_their_stability_flag() {
+    for sorter in all_sorters::<Item>() {
+      let expected = sorter.stability() == Stability::Stable;
+      assert_eq!(
+        is_stable_sort(|arr| sorter.sort(arr)),
+        expected,
+        "{} is documented as {:?}",
+        sorter.name(),
+        sorter.stability()
+      );
+    }
+  }
+}
@@ -29,27 +29,107 @@ pub fn find_min_run(mut n: usize) -> usize {
 /// * `right`: Ending index of the range.
 pub fn insert_sort<T>(arr: &mut [T], left: usize, right: usize)
 where
-  T: PartialOrd + Copy,
+  T: PartialOrd,
 {
   // 在指定的范围内执行插入排序
   // Iterate through the range and insert elements in sorted order
   for i in left + 1..right + 1 {
-    let element = arr[i];
-    let mut j = i - 1;
+    let mut j = i;
 
-    // 使用插入排序找到当前元素的正确位置
-    // Find the correct position for the current element using insertion sort
-    while element < arr[j] && j >= left {
-      arr[j + 1] = arr[j];
+    // 使用插入排序找到当前元素的正确位置，通过交换移动元素，因此不需要 Copy
+    // 先检查 j > left 再递减，避免 left == 0 时 usize 下溢
+    // Find the correct position for the current element using insertion sort, moving it with
+    // swaps so that `T` need not be `Copy`.
+    // Check `j > left` before decrementing so `j` never underflows when `left == 0`
+    while j > left && arr[j] < arr[j - 1] {
+      arr.swap(j - 1, j);
       j -= 1;
     }
+  }
+}
+
+/// 连续胜出多少次后进入 galloping 模式
+/// How many consecutive wins by one side switch the merge into galloping mode.
+const MIN_GALLOP: usize = 7;
+
+/// Find the length of the run starting at `start`, reversing it in place if it is strictly
+/// descending so that every run ends up ascending.
+///
+/// 只反转严格递减的 run，以保证排序的稳定性。
+/// Only strictly descending runs are reversed, which keeps the sort stable.
+///
+/// # Parameters
+///
+/// * `arr`: Mutable reference to the array.
+/// * `start`: Starting index of the run.
+///
+/// Returns the length of the run.
+pub fn count_run_and_make_ascending<T: PartialOrd>(arr: &mut [T], start: usize) -> usize {
+  let n = arr.len();
+  let mut end = start + 1;
+
+  if end >= n {
+    return n - start;
+  }
+
+  if arr[end] < arr[end - 1] {
+    // 严格递减的 run
+    // Strictly descending run
+    end += 1;
+    while end < n && arr[end] < arr[end - 1] {
+      end += 1;
+    }
 
-    arr[j + 1] = element;
+    arr[start..end].reverse();
+  } else {
+    // 非递减的 run
+    // Non-descending run
+    end += 1;
+    while end < n && arr[end] >= arr[end - 1] {
+      end += 1;
+    }
   }
+
+  end - start
+}
+
+/// Count how many leading elements of `slice` satisfy `pred`, assuming `pred` holds for a
+/// prefix of the slice and fails for the rest.
+///
+/// 先以 1, 2, 4, ... 的步长指数探测，再在最后一段中二分查找，因此当结果为 k 时只需 O(log k) 次比较。
+/// Probes exponentially at offsets 1, 2, 4, ... and then binary searches the last interval, so
+/// a result of k costs only O(log k) comparisons.
+fn gallop<T, F>(slice: &[T], pred: F) -> usize
+where
+  F: Fn(&T) -> bool,
+{
+  if slice.is_empty() || !pred(&slice[0]) {
+    return 0;
+  }
+
+  let mut bound = 1;
+  while bound < slice.len() && pred(&slice[bound]) {
+    bound *= 2;
+  }
+
+  // 此时 pred(slice[bound / 2]) 成立，答案位于 (bound / 2, min(bound, len)] 之间
+  // pred(slice[bound / 2]) holds here, so the answer lies in (bound / 2, min(bound, len)]
+  let lo = bound / 2 + 1;
+  let hi = min(bound, slice.len());
+
+  lo + slice[lo..hi].partition_point(pred)
 }
 
 /// Merge two sorted subarrays of an array.
 ///
+/// 左侧已经不大于右侧第一个元素的元素，以及右侧已经不小于左侧最后一个元素的元素，
+/// 都已经在最终位置上，会先被跳过；当一侧连续胜出 `MIN_GALLOP` 次后，
+/// 使用 galloping 一次性移动该侧的一整段元素。
+/// Elements of the left run that are no greater than the first element of the right run, and
+/// elements of the right run that are no less than the last element of the left run, are
+/// already in place and are skipped. Once one side wins `MIN_GALLOP` times in a row, the merge
+/// gallops to move a whole block from that side at once.
+///
 /// # Parameters
 ///
 /// * `arr`: Mutable reference to the array.
@@ -58,94 +138,186 @@ where
 /// * `right`: Ending index of the right subarray.
 pub fn merge<T>(arr: &mut [T], left: usize, mid: usize, right: usize)
 where
-  T: PartialOrd + Copy,
+  T: PartialOrd + Clone,
 {
-  // 创建左右子数组的临时向量
-  // Create temporary vectors for the left and right subarrays
-  let array_length1 = mid - left + 1;
-  let array_length2 = right - mid;
-  let left_arr = Vec::from(&arr[left..left + array_length1]);
-  let right_arr = Vec::from(&arr[mid + 1..mid + 1 + array_length2]);
-  let (mut i, mut j, mut k) = (0, 0, left);
+  if mid >= right {
+    return;
+  }
+
+  // 跳过左侧已经就位的元素
+  // Skip the prefix of the left run that is already in place
+  let left = left + gallop(&arr[left..=mid], |x| *x <= arr[mid + 1]);
+  if left > mid {
+    return;
+  }
+
+  // 跳过右侧已经就位的元素
+  // Skip the suffix of the right run that is already in place
+  let right = mid + gallop(&arr[mid + 1..=right], |x| *x < arr[mid]);
+
+  // 只把左侧需要合并的部分克隆到临时向量中，右侧的元素通过交换移动
+  // Clone only the part of the left run that still needs merging; right-hand elements move by swaps
+  let mut left_arr = arr[left..=mid].to_vec();
+  let (mut i, mut j, mut k) = (0, mid + 1, left);
+  let (mut left_wins, mut right_wins) = (0, 0);
 
   // 将左右子数组合并回主数组
   // Merge the left and right subarrays back into the main array
-  while j < array_length2 && i < array_length1 {
-    // If the element in the left subarray is smaller or equal, copy it to the main array
-    if left_arr[i] <= right_arr[j] {
-      arr[k] = left_arr[i];
+  while i < left_arr.len() && j <= right {
+    if left_wins >= MIN_GALLOP {
+      // 左侧连续胜出：一次移动所有不大于 arr[j] 的左侧元素
+      // The left side keeps winning: move every left element no greater than arr[j] at once
+      let count = gallop(&left_arr[i..], |x| *x <= arr[j]);
+      for _ in 0..count {
+        std::mem::swap(&mut arr[k], &mut left_arr[i]);
+        i += 1;
+        k += 1;
+      }
+      left_wins = 0;
+      continue;
+    }
+
+    if right_wins >= MIN_GALLOP {
+      // 右侧连续胜出：一次移动所有小于 left_arr[i] 的右侧元素
+      // The right side keeps winning: move every right element less than left_arr[i] at once
+      let count = gallop(&arr[j..=right], |x| *x < left_arr[i]);
+      for _ in 0..count {
+        arr.swap(k, j);
+        j += 1;
+        k += 1;
+      }
+      right_wins = 0;
+      continue;
+    }
+
+    // If the element in the left subarray is smaller or equal, move it to the main array
+    if left_arr[i] <= arr[j] {
+      std::mem::swap(&mut arr[k], &mut left_arr[i]);
       i += 1;
+      left_wins += 1;
+      right_wins = 0;
     } else {
-      // If the element in the right subarray is smaller, copy it to the main array
-      arr[k] = right_arr[j];
+      // If the element in the right subarray is smaller, move it to the main array
+      arr.swap(k, j);
       j += 1;
+      right_wins += 1;
+      left_wins = 0;
     }
 
     k += 1;
   }
 
-  // 将剩余的元素从左右子数组复制回主数组
-  // Copy any remaining elements from the left and right subarrays back into the main array
-  while i < array_length1 {
-    arr[k] = left_arr[i];
+  // 将剩余的左侧元素移回主数组；剩余的右侧元素已经在正确位置上
+  // Move any remaining left elements back; remaining right elements are already in place
+  while i < left_arr.len() {
+    std::mem::swap(&mut arr[k], &mut left_arr[i]);
     k += 1;
     i += 1;
   }
+}
 
-  while j < array_length2 {
-    arr[k] = right_arr[j];
-    k += 1;
-    j += 1;
+/// Merge the runs at `runs[i]` and `runs[i + 1]`.
+fn merge_at<T: PartialOrd + Clone>(arr: &mut [T], runs: &mut Vec<(usize, usize)>, i: usize) {
+  let (start1, len1) = runs[i];
+  let (start2, len2) = runs[i + 1];
+
+  merge(arr, start1, start1 + len1 - 1, start2 + len2 - 1);
+
+  runs[i] = (start1, len1 + len2);
+  runs.remove(i + 1);
+}
+
+/// Merge runs on the stack until the invariants hold again, where X, Y and Z are the three
+/// topmost run lengths:
+///
+/// 1. |Z| > |Y| + |X|
+/// 2. |Y| > |X|
+fn merge_collapse<T: PartialOrd + Clone>(arr: &mut [T], runs: &mut Vec<(usize, usize)>) {
+  while runs.len() > 1 {
+    let mut n = runs.len() - 2;
+
+    if (n > 0 && runs[n - 1].1 <= runs[n].1 + runs[n + 1].1)
+      || (n > 1 && runs[n - 2].1 <= runs[n - 1].1 + runs[n].1)
+    {
+      if runs[n - 1].1 < runs[n + 1].1 {
+        n -= 1;
+      }
+      merge_at(arr, runs, n);
+    } else if runs[n].1 <= runs[n + 1].1 {
+      merge_at(arr, runs, n);
+    } else {
+      break;
+    }
+  }
+}
+
+/// Merge all remaining runs on the stack into one.
+fn merge_force_collapse<T: PartialOrd + Clone>(arr: &mut [T], runs: &mut Vec<(usize, usize)>) {
+  while runs.len() > 1 {
+    let mut n = runs.len() - 2;
+
+    if n > 0 && runs[n - 1].1 < runs[n + 1].1 {
+      n -= 1;
+    }
+    merge_at(arr, runs, n);
   }
 }
 
 /// Perform Tim Sort on an array.
 ///
+/// 先把数组切分为自然的 run（严格递减的 run 会被反转），不足 `min_run` 的 run 用插入排序补齐；
+/// 每个 run 被压入栈中，并按照 TimSort 的栈不变式合并，合并时使用 galloping。
+/// 因此对已经有序或基本有序的数据只需接近线性的比较次数。
+/// The array is cut into natural runs (strictly descending runs are reversed), and runs shorter
+/// than `min_run` are extended with insertion sort. Each run is pushed onto a stack and merged
+/// according to the TimSort stack invariants, using galloping merges. Sorted and nearly-sorted
+/// data therefore need close to a linear number of comparisons.
+///
 /// # Parameters
 ///
 /// * `arr`: Mutable reference to the array to be sorted.
 pub fn tim_sort<T>(arr: &mut [T])
 where
-  T: PartialOrd + Copy,
+  T: Ord + Clone,
 {
   let n = arr.len();
-  let min_run = find_min_run(n);
 
-  // 对每个最小长度的 run 执行插入排序
-  // Insertion sort each run of minimum length
-  for start in (0..n).filter(|x| x % min_run == 0) {
-    let end = min(start + min_run - 1, n - 1);
-
-    insert_sort(arr, start, end)
+  if n < 2 {
+    return;
   }
 
-  let mut size = min_run;
+  let min_run = find_min_run(n);
+  // 待合并的 run 栈，每一项为 (起始下标, 长度)
+  // Stack of pending runs, each stored as (start index, length)
+  let mut runs: Vec<(usize, usize)> = vec![];
+  let mut start = 0;
 
-  // 合并相邻的 run，直到整个数组排序完成
-  // Merge adjacent runs until the entire array is sorted
-  while size < n {
-    // 合并相邻的 run
-    // Merge adjacent runs
-    for left in (0..n).filter(|x| x % (2 * size) == 0) {
-      // 计算中间索引和右侧索引
-      // Calculate the middle index and the right index
-      let mid = min(n - 1, left + size - 1);
-      let right = min(left + 2 * size - 1, n - 1);
+  while start < n {
+    let mut run_len = count_run_and_make_ascending(arr, start);
 
-      // 合并两个子数组
-      // Merge the two subarrays
-      merge(arr, left, mid, right)
+    // 用插入排序把过短的 run 补齐到 min_run
+    // Extend short runs to min_run with insertion sort
+    if run_len < min_run {
+      let forced = min(min_run, n - start);
+      insert_sort(arr, start, start + forced - 1);
+      run_len = forced;
     }
 
-    // 增大 run 的大小，以便继续合并相邻的 run
-    // Increase the size of the run for the next iteration
-    size = 2 * size;
+    runs.push((start, run_len));
+    merge_collapse(arr, &mut runs);
+
+    start += run_len;
   }
+
+  merge_force_collapse(arr, &mut runs);
 }
 
 #[cfg(test)]
 mod tests {
-  use super::{find_min_run, insert_sort, tim_sort};
+  use super::{
+    count_run_and_make_ascending, find_min_run, insert_sort, merge, tim_sort, MIN_GALLOP,
+  };
+  use rust_algorithm::test_support::{count_comparisons, counted};
 
   #[test]
   fn cal_min() {
@@ -173,6 +345,166 @@ mod tests {
 
     assert_eq!(vec, vec![7, 9, 23, 30, 44, 49, 58, 72, 73, 78]);
   }
+
+  #[test]
+  fn element_belongs_at_index_0() {
+    let mut vec = vec![2, 1];
+
+    tim_sort(&mut vec);
+
+    assert_eq!(vec, vec![1, 2]);
+  }
+
+  #[test]
+  fn reverse_sorted_multiple_runs() {
+    // 200 个元素会被切分为多个 run，每个 run 的最小元素都要移动到 run 的左边界
+    // 200 elements are split into several runs, and each run's minimum must move to its left boundary
+    let mut vec: Vec<i32> = (0..200).rev().collect();
+
+    tim_sort(&mut vec);
+
+    assert_eq!(vec, (0..200).collect::<Vec<i32>>());
+  }
+
+  #[test]
+  fn insert_with_left_above_zero() {
+    let mut vec = vec![9, 8, 7, 5, 4, 3, 2, 1, 0];
+
+    insert_sort(&mut vec, 3, 6);
+
+    assert_eq!(vec, vec![9, 8, 7, 2, 3, 4, 5, 1, 0]);
+  }
+
+  #[test]
+  fn test_string_vec() {
+    let mut vec = vec![
+      String::from("Bob"),
+      String::from("David"),
+      String::from("Carol"),
+      String::from("Alice"),
+    ];
+
+    tim_sort(&mut vec);
+
+    assert_eq!(
+      vec,
+      vec![
+        String::from("Alice"),
+        String::from("Bob"),
+        String::from("Carol"),
+        String::from("David"),
+      ]
+    );
+  }
+
+  #[test]
+  fn non_copy_struct() {
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Record {
+      id: u32,
+      tags: Vec<String>,
+    }
+
+    let mut vec: Vec<Record> = (0..150)
+      .rev()
+      .map(|id| Record {
+        id,
+        tags: vec![id.to_string()],
+      })
+      .collect();
+
+    tim_sort(&mut vec);
+
+    assert!(vec.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(vec[0].tags, vec![String::from("0")]);
+  }
+
+  fn sort_comparisons(values: &[i32]) -> usize {
+    let mut vec = counted(values);
+    let comparisons = count_comparisons(|| tim_sort(&mut vec));
+
+    assert!(vec.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    comparisons
+  }
+
+  #[test]
+  fn descending_run_is_reversed() {
+    let mut vec = vec![5, 4, 3, 1, 2, 2];
+
+    assert_eq!(count_run_and_make_ascending(&mut vec, 0), 4);
+    assert_eq!(vec, vec![1, 3, 4, 5, 2, 2]);
+    assert_eq!(count_run_and_make_ascending(&mut vec, 4), 2);
+  }
+
+  #[test]
+  fn merge_with_gallop() {
+    let mut vec: Vec<i32> = (0..100).step_by(2).chain(0..30).collect();
+    let mut expected = vec.clone();
+    expected.sort();
+
+    merge(&mut vec, 0, 49, 79);
+
+    assert_eq!(vec, expected);
+  }
+
+  #[test]
+  fn organ_pipe() {
+    let mut vec: Vec<i32> = (0..5_000).chain((0..5_000).rev()).collect();
+    let mut expected = vec.clone();
+    expected.sort();
+
+    tim_sort(&mut vec);
+
+    assert_eq!(vec, expected);
+  }
+
+  #[test]
+  fn nearly_sorted_with_a_few_swaps() {
+    let mut vec: Vec<i32> = (0..10_000).collect();
+    for &(a, b) in &[
+      (10, 9_000),
+      (500, 501),
+      (4_321, 1_234),
+      (7_777, 42),
+      (9_999, 0),
+    ] {
+      vec.swap(a, b);
+    }
+
+    tim_sort(&mut vec);
+
+    assert_eq!(vec, (0..10_000).collect::<Vec<i32>>());
+  }
+
+  #[test]
+  fn adaptive_comparison_count() {
+    let n = 10_000;
+
+    // 已排序的数据只形成一个 run，只需 n - 1 次比较
+    // Sorted data forms a single run and needs exactly n - 1 comparisons
+    let sorted: Vec<i32> = (0..n).collect();
+    assert_eq!(sort_comparisons(&sorted), n as usize - 1);
+
+    // 逆序数据被识别为一个严格递减的 run 并反转
+    // Reverse-sorted data is detected as one strictly descending run and reversed
+    let reversed: Vec<i32> = (0..n).rev().collect();
+    assert_eq!(sort_comparisons(&reversed), n as usize - 1);
+
+    // 固定长度分块的插入排序加自底向上合并大约需要 n * log2(n / min_run) 次比较，
+    // 而识别自然 run 并使用 galloping 后，少量交换只带来少量额外比较
+    // Blocked insertion sort plus bottom-up merging needs roughly n * log2(n / min_run)
+    // comparisons; with natural runs and galloping, a few swaps only cost a few extra ones
+    let mut nearly_sorted = sorted.clone();
+    nearly_sorted.swap(100, 8_000);
+    nearly_sorted.swap(5_000, 5_001);
+    assert!(sort_comparisons(&nearly_sorted) < 2 * n as usize);
+
+    // 风琴管数据是一个递增 run 加一个递减 run：扫描约 n 次比较，两个交错的 run 合并约 n 次比较
+    // Organ-pipe data is one ascending and one descending run: about n comparisons to scan them
+    // and about n more to merge the two fully interleaved runs
+    let organ_pipe: Vec<i32> = (0..n / 2).chain((0..n / 2).rev()).collect();
+    assert!(sort_comparisons(&organ_pipe) <= 2 * n as usize + MIN_GALLOP * 4);
+  }
 }
 
 fn main() {}
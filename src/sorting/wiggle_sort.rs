@@ -0,0 +1,218 @@
+use rust_algorithm::search::kth_smallest::kth_smallest;
+
+/// 摆动排序（Wiggle Sort）：重排数组使其满足 `arr[0] <= arr[1] >= arr[2] <= arr[3] ...`。
+///
+/// Wiggle Sort reorders the slice so that `arr[0] <= arr[1] >= arr[2] <= arr[3] ...`.
+///
+/// 只需一次遍历：奇数位置的元素应不小于前一个元素，偶数位置的元素应不大于前一个元素，
+/// 不满足时交换相邻两个元素。交换不会破坏之前已经满足的关系，因此时间复杂度为 `O(n)`。
+///
+/// A single pass is enough: an element at an odd index must be no less than its predecessor
+/// and one at an even index no greater, and adjacent elements are swapped when this fails. A
+/// swap never breaks the relation already established to its left, so the time complexity is
+/// `O(n)`.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be reordered.)
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut numbers = vec![3, 5, 2, 1, 6, 4];
+/// wiggle_sort(&mut numbers);
+/// assert_eq!(numbers, vec![3, 5, 1, 6, 2, 4]);
+/// ```
+pub fn wiggle_sort<T: Ord>(arr: &mut [T]) {
+  for i in 1..arr.len() {
+    if (i % 2 == 1 && arr[i] < arr[i - 1]) || (i % 2 == 0 && arr[i] > arr[i - 1]) {
+      arr.swap(i - 1, i);
+    }
+  }
+}
+
+/// 严格摆动排序：重排数组使其满足 `arr[0] < arr[1] > arr[2] < arr[3] ...`。
+///
+/// Strict Wiggle Sort reorders the slice so that `arr[0] < arr[1] > arr[2] < arr[3] ...`.
+///
+/// 先用 [`kth_smallest`] 找到中位数，再通过虚拟下标 `(1 + 2 * i) % (n | 1)` 进行三路划分：
+/// 大于中位数的元素依次放到奇数位置，小于中位数的元素从后往前放到偶数位置，中位数填在中间。
+/// 时间复杂度平均为 `O(n)`。
+///
+/// The median is found with [`kth_smallest`], then the slice is three-way partitioned through
+/// the virtual index `(1 + 2 * i) % (n | 1)`: elements larger than the median fill the odd
+/// positions from the front, smaller ones fill the even positions from the back, and copies of
+/// the median go in between. The average time complexity is `O(n)`.
+///
+/// # 参数 (Arguments)
+///
+/// * `arr`: 待排序的可变切片的引用。 (A mutable reference to the slice to be reordered.)
+///
+/// # 返回值 (Returns)
+///
+/// 成功时返回 `true`。重复元素太多而无法满足严格的摆动关系时返回 `false`，此时切片仍是原元素的一个排列，
+/// 但顺序不做保证。
+///
+/// Returns `true` on success. Returns `false` when too many duplicates make the strict pattern
+/// impossible; the slice is then still a permutation of its elements, in unspecified order.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut numbers = vec![1, 1, 1, 2, 2, 2];
+/// assert!(wiggle_sort_strict(&mut numbers));
+/// assert_eq!(numbers, vec![1, 2, 1, 2, 1, 2]);
+/// ```
+pub fn wiggle_sort_strict<T: Ord + Clone>(arr: &mut [T]) -> bool {
+  let n = arr.len();
+
+  if n < 2 {
+    return true;
+  }
+
+  // 在引用上做快速选择，不需要 `T: Copy`，也不会打乱 arr
+  // (Quickselect over references, which needs no `T: Copy` and leaves `arr` untouched)
+  let median = {
+    let mut refs: Vec<&T> = arr.iter().collect();
+    kth_smallest(&mut refs, n / 2 + 1).unwrap().clone()
+  };
+
+  // 虚拟下标：0, 1, 2, ... 依次映射到 1, 3, 5, ..., 0, 2, 4, ...
+  // (Virtual index: 0, 1, 2, ... map to 1, 3, 5, ..., 0, 2, 4, ...)
+  let index = |i: usize| (1 + 2 * i) % (n | 1);
+
+  // 三路划分：[0, lo) 大于中位数，[lo, mid) 等于中位数，[hi, n) 小于中位数
+  // (Three-way partition: [0, lo) is greater, [lo, mid) equal and [hi, n) less than the median)
+  let (mut lo, mut mid, mut hi) = (0, 0, n);
+
+  while mid < hi {
+    if arr[index(mid)] > median {
+      arr.swap(index(lo), index(mid));
+      lo += 1;
+      mid += 1;
+    } else if arr[index(mid)] < median {
+      hi -= 1;
+      arr.swap(index(mid), index(hi));
+    } else {
+      mid += 1;
+    }
+  }
+
+  is_wiggled_strict(arr)
+}
+
+fn is_wiggled_strict<T: Ord>(arr: &[T]) -> bool {
+  (1..arr.len()).all(|i| {
+    if i % 2 == 1 {
+      arr[i - 1] < arr[i]
+    } else {
+      arr[i - 1] > arr[i]
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{is_wiggled_strict, wiggle_sort, wiggle_sort_strict};
+  use rand::seq::SliceRandom;
+  use rand::Rng;
+
+  fn is_wiggled<T: Ord>(arr: &[T]) -> bool {
+    (1..arr.len()).all(|i| {
+      if i % 2 == 1 {
+        arr[i - 1] <= arr[i]
+      } else {
+        arr[i - 1] >= arr[i]
+      }
+    })
+  }
+
+  fn is_permutation(a: &[i32], b: &[i32]) -> bool {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    a.sort();
+    b.sort();
+    a == b
+  }
+
+  #[test]
+  fn empty_and_one_element() {
+    let mut empty: Vec<i32> = vec![];
+    wiggle_sort(&mut empty);
+    assert!(wiggle_sort_strict(&mut empty));
+
+    let mut one = vec![1];
+    wiggle_sort(&mut one);
+    assert!(wiggle_sort_strict(&mut one));
+    assert_eq!(one, vec![1]);
+  }
+
+  #[test]
+  fn basic() {
+    let mut vec = vec![3, 5, 2, 1, 6, 4];
+    wiggle_sort(&mut vec);
+    assert_eq!(vec, vec![3, 5, 1, 6, 2, 4]);
+  }
+
+  #[test]
+  fn random_inputs() {
+    let mut rng = rand::thread_rng();
+
+    for len in 0..100 {
+      let input: Vec<i32> = (0..len).map(|_| rng.gen_range(0..10)).collect();
+
+      let mut vec = input.clone();
+      wiggle_sort(&mut vec);
+      assert!(is_wiggled(&vec), "{:?}", vec);
+      assert!(is_permutation(&vec, &input));
+
+      let mut vec = input.clone();
+      let ok = wiggle_sort_strict(&mut vec);
+      assert_eq!(ok, is_wiggled_strict(&vec), "{:?}", vec);
+      assert!(is_permutation(&vec, &input));
+    }
+  }
+
+  #[test]
+  fn strict_distinct_always_succeeds() {
+    let mut rng = rand::thread_rng();
+
+    for len in 0..100 {
+      let mut vec: Vec<i32> = (0..len).collect();
+      vec.shuffle(&mut rng);
+      assert!(wiggle_sort_strict(&mut vec), "{:?}", vec);
+    }
+  }
+
+  #[test]
+  fn strict_with_half_duplicates() {
+    let mut vec = vec![1, 1, 1, 2, 2, 2];
+    assert!(wiggle_sort_strict(&mut vec));
+    assert_eq!(vec, vec![1, 2, 1, 2, 1, 2]);
+
+    let mut vec = vec![4, 5, 5, 6];
+    assert!(wiggle_sort_strict(&mut vec));
+    assert!(is_wiggled_strict(&vec));
+  }
+
+  #[test]
+  fn strict_impossible() {
+    let mut vec = vec![1, 1, 1, 1, 2, 2];
+    assert!(!wiggle_sort_strict(&mut vec));
+    assert!(is_permutation(&vec, &[1, 1, 1, 1, 2, 2]));
+
+    let mut vec = vec![7, 7];
+    assert!(!wiggle_sort_strict(&mut vec));
+  }
+
+  #[test]
+  fn strict_strings() {
+    let mut vec: Vec<String> = ["b", "a", "c", "a", "b", "c"]
+      .iter()
+      .map(|s| s.to_string())
+      .collect();
+    assert!(wiggle_sort_strict(&mut vec));
+    assert!(is_wiggled_strict(&vec));
+  }
+}
+
+fn main() {}
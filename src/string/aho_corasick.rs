@@ -0,0 +1,250 @@
+use std::collections::{HashMap, VecDeque};
+
+/// 字典树的根结点。 (The root node of the trie.)
+const ROOT: usize = 0;
+
+/// 字典树的结点。 (A node of the trie.)
+#[derive(Default)]
+struct Node {
+  /// 按字节索引的子结点。 (Children keyed by byte.)
+  children: HashMap<u8, usize>,
+  /// 失配链接：当前结点所表示字符串的最长真后缀在字典树中对应的结点。
+  /// The failure link: the node of the longest proper suffix of this node's string in the trie.
+  fail: usize,
+  /// 字典链接：沿失配链接可以到达的、最近的以某个模式结尾的结点。
+  /// The dictionary link: the nearest node along the failure links at which some pattern ends.
+  dict: Option<usize>,
+  /// 在此结点结尾的模式的索引。 (Indices of the patterns ending at this node.)
+  outputs: Vec<usize>,
+}
+
+/// 实现了 Aho-Corasick 算法，一次扫描文本即可找到多个模式的所有出现位置。
+/// Implements the Aho-Corasick algorithm, which finds all occurrences of many patterns in a
+/// single pass over the text.
+///
+/// 所有模式先被插入一棵字典树，然后用广度优先搜索为每个结点计算失配链接和字典链接。
+/// 扫描文本时沿字典树前进，失配时沿失配链接回退，每到一个结点就沿字典链接输出所有以当前位置结尾的模式，
+/// 因此作为其他模式后缀的模式以及相互重叠的匹配都不会遗漏。
+/// All patterns are inserted into a trie, then a breadth-first search computes the failure and
+/// dictionary links of every node. The scan walks the trie, falling back along failure links on
+/// a mismatch, and at each node follows the dictionary links to report every pattern ending at
+/// the current position, so patterns that are suffixes of others and overlapping matches are
+/// all found.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let matcher = AhoCorasick::new(&["he", "she", "his", "hers"]);
+/// assert_eq!(matcher.find_all("ushers"), vec![(1, 1), (2, 0), (2, 3)]);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// Building takes O(m) time and space, where m is the total length of the patterns. A search
+/// takes O(n + z) time, where n is the length of the text and z the number of matches.
+/// 构建的时间和空间复杂度为 O(m)，其中 m 是所有模式的总长度。查找的时间复杂度为 O(n + z)，
+/// 其中 n 是文本的长度，z 是匹配的个数。
+pub struct AhoCorasick {
+  nodes: Vec<Node>,
+  /// 每个模式的字节长度，用于由结束位置计算起始位置。
+  /// The byte length of each pattern, used to turn end positions into start positions.
+  lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+  /// 由给定的模式构建匹配器，空模式不会产生任何匹配。
+  /// Builds a matcher for the given patterns. Empty patterns never match.
+  pub fn new(patterns: &[&str]) -> Self {
+    let mut nodes = vec![Node::default()];
+
+    // 构建字典树 (Build the trie)
+    for (index, pattern) in patterns.iter().enumerate() {
+      if pattern.is_empty() {
+        continue;
+      }
+
+      let mut node = ROOT;
+
+      for &c in pattern.as_bytes() {
+        node = match nodes[node].children.get(&c) {
+          Some(&child) => child,
+          None => {
+            nodes.push(Node::default());
+            let child = nodes.len() - 1;
+            nodes[node].children.insert(c, child);
+            child
+          }
+        };
+      }
+
+      nodes[node].outputs.push(index);
+    }
+
+    // 按广度优先的顺序计算失配链接和字典链接，处理一个结点时，更浅的结点都已经处理完毕
+    // Compute failure and dictionary links breadth first, so all shallower nodes are done first
+    let mut queue: VecDeque<usize> = nodes[ROOT].children.values().copied().collect();
+
+    while let Some(node) = queue.pop_front() {
+      let children: Vec<(u8, usize)> = nodes[node].children.iter().map(|(&c, &n)| (c, n)).collect();
+
+      for (c, child) in children {
+        // 子结点的失配链接：沿父结点的失配链接找到第一个有 c 子结点的结点
+        // The child's failure link: the first node along the parent's failure links with a child c
+        let mut fail = nodes[node].fail;
+
+        let target = loop {
+          if let Some(&next) = nodes[fail].children.get(&c) {
+            break next;
+          }
+
+          if fail == ROOT {
+            break ROOT;
+          }
+
+          fail = nodes[fail].fail;
+        };
+
+        nodes[child].fail = target;
+        nodes[child].dict = if nodes[target].outputs.is_empty() {
+          nodes[target].dict
+        } else {
+          Some(target)
+        };
+
+        queue.push_back(child);
+      }
+    }
+
+    AhoCorasick {
+      nodes,
+      lengths: patterns.iter().map(|pattern| pattern.len()).collect(),
+    }
+  }
+
+  /// 返回所有匹配的 `(起始索引, 模式索引)`，起始索引为字节索引。
+  /// 结果按匹配的结束位置排序，结束位置相同时较长的模式在前。
+  /// Returns `(start_index, pattern_index)` for every match, with byte start indices. Results are
+  /// ordered by end position, longer patterns first among matches ending at the same position.
+  pub fn find_all(&self, text: &str) -> Vec<(usize, usize)> {
+    let mut ret = vec![];
+    let mut node = ROOT;
+
+    for (i, &c) in text.as_bytes().iter().enumerate() {
+      // 沿失配链接回退，直到可以用 c 前进或回到根结点
+      // Fall back along failure links until c can be followed or the root is reached
+      while node != ROOT && !self.nodes[node].children.contains_key(&c) {
+        node = self.nodes[node].fail;
+      }
+
+      node = self.nodes[node].children.get(&c).copied().unwrap_or(ROOT);
+
+      // 输出当前结点以及字典链接上所有结点的模式
+      // Report the patterns of this node and of every node on its dictionary links
+      let mut current = Some(node);
+
+      while let Some(n) = current {
+        for &index in &self.nodes[n].outputs {
+          ret.push((i + 1 - self.lengths[index], index));
+        }
+
+        current = self.nodes[n].dict;
+      }
+    }
+
+    ret
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+  use rust_algorithm::string::knuth_morris_pratt::knuth_morris_pratt;
+
+  #[test]
+  fn classic_example() {
+    let matcher = AhoCorasick::new(&["he", "she", "his", "hers"]);
+
+    assert_eq!(matcher.find_all("ushers"), vec![(1, 1), (2, 0), (2, 3)]);
+  }
+
+  #[test]
+  fn overlapping_and_nested() {
+    let matcher = AhoCorasick::new(&["a", "aa", "aaa"]);
+
+    assert_eq!(
+      matcher.find_all("aaa"),
+      vec![(0, 0), (0, 1), (1, 0), (0, 2), (1, 1), (2, 0)]
+    );
+  }
+
+  #[test]
+  fn duplicate_patterns() {
+    let matcher = AhoCorasick::new(&["ab", "ab", "b"]);
+
+    assert_eq!(
+      matcher.find_all("abab"),
+      vec![(0, 0), (0, 1), (1, 2), (2, 0), (2, 1), (3, 2)]
+    );
+  }
+
+  #[test]
+  fn empty_pattern_list() {
+    let matcher = AhoCorasick::new(&[]);
+
+    assert_eq!(matcher.find_all("anything"), vec![]);
+  }
+
+  #[test]
+  fn empty_pattern_and_text() {
+    let matcher = AhoCorasick::new(&["", "x"]);
+
+    assert_eq!(matcher.find_all("axa"), vec![(1, 1)]);
+    assert_eq!(matcher.find_all(""), vec![]);
+  }
+
+  #[test]
+  fn pattern_is_whole_text() {
+    let matcher = AhoCorasick::new(&["abcdef", "zzz"]);
+
+    assert_eq!(matcher.find_all("abcdef"), vec![(0, 0)]);
+  }
+
+  #[test]
+  fn agrees_with_kmp_per_pattern() {
+    let mut rng = rand::thread_rng();
+    let random_string = |rng: &mut rand::rngs::ThreadRng, len: usize| -> String {
+      (0..len).map(|_| rng.gen_range('a'..='c')).collect()
+    };
+
+    for _ in 0..200 {
+      let patterns: Vec<String> = (0..rng.gen_range(1..8))
+        .map(|_| {
+          let len = rng.gen_range(1..5);
+          random_string(&mut rng, len)
+        })
+        .collect();
+      let len = rng.gen_range(0..60);
+      let text = random_string(&mut rng, len);
+
+      let refs: Vec<&str> = patterns.iter().map(String::as_str).collect();
+      let mut actual = AhoCorasick::new(&refs).find_all(&text);
+      actual.sort();
+
+      let mut expected: Vec<(usize, usize)> = patterns
+        .iter()
+        .enumerate()
+        .flat_map(|(index, pattern)| {
+          knuth_morris_pratt(text.clone(), pattern.clone())
+            .into_iter()
+            .map(move |start| (start, index))
+        })
+        .collect();
+      expected.sort();
+
+      assert_eq!(actual, expected, "patterns {patterns:?} text {text:?}");
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,437 @@
+use std::ops::Range;
+
+/// 对齐结果中表示空位的字符。 (The char marking a gap in an alignment.)
+pub const GAP: char = '-';
+
+/// 序列比对的打分规则。 (The scoring scheme of a sequence alignment.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scoring {
+  /// 两个字符相同时的得分。 (The score for two equal chars.)
+  pub match_score: i32,
+  /// 两个字符不同时的得分，通常为负。 (The score for two different chars, usually negative.)
+  pub mismatch: i32,
+  /// 一个字符对齐到空位时的得分，通常为负。 (The score for a char aligned to a gap, usually negative.)
+  pub gap: i32,
+}
+
+impl Default for Scoring {
+  /// 常用的打分规则：匹配 +1，失配 -1，空位 -1。
+  /// (The common scheme: +1 for a match, -1 for a mismatch, -1 for a gap.)
+  fn default() -> Self {
+    Scoring {
+      match_score: 1,
+      mismatch: -1,
+      gap: -1,
+    }
+  }
+}
+
+/// 全局比对的结果。 (The result of a global alignment.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+  /// 比对的总得分。 (The total score of the alignment.)
+  pub score: i32,
+  /// 插入空位后的 `a`，与 `aligned_b` 的字符数相同。
+  /// (`a` with gaps inserted, as many chars long as `aligned_b`.)
+  pub aligned_a: String,
+  /// 插入空位后的 `b`，与 `aligned_a` 的字符数相同。
+  /// (`b` with gaps inserted, as many chars long as `aligned_a`.)
+  pub aligned_b: String,
+}
+
+/// 局部比对的结果。 (The result of a local alignment.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalAlignment {
+  /// 比对的得分，不小于 0。 (The score of the alignment, never negative.)
+  pub score: i32,
+  /// 参与比对的 `a` 的子串的字节范围。 (The byte range of the aligned substring of `a`.)
+  pub a_range: Range<usize>,
+  /// 参与比对的 `b` 的子串的字节范围。 (The byte range of the aligned substring of `b`.)
+  pub b_range: Range<usize>,
+  /// 插入空位后的 `a[a_range]`。 (`a[a_range]` with gaps inserted.)
+  pub aligned_a: String,
+  /// 插入空位后的 `b[b_range]`。 (`b[b_range]` with gaps inserted.)
+  pub aligned_b: String,
+}
+
+/// 使用 Needleman-Wunsch 算法对两个字符串做全局比对，返回最高得分以及一种达到该得分的比对。
+/// Globally aligns two strings with the Needleman-Wunsch algorithm, returning the best score
+/// and one alignment achieving it.
+///
+/// 与编辑距离不同，打分规则由调用者给出，空位在结果中用 [`GAP`] 显式标出。比对按字符进行。
+/// Unlike edit distance, the scoring is supplied by the caller and gaps are spelled out with
+/// [`GAP`] in the result. Alignment works on chars.
+///
+/// # 参数 (Arguments)
+///
+/// * `a` - 第一个序列。
+///   The first sequence.
+/// * `b` - 第二个序列。
+///   The second sequence.
+/// * `scoring` - 匹配、失配和空位的得分。
+///   The scores for matches, mismatches and gaps.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let alignment = needleman_wunsch("GATTACA", "GCATGCU", Scoring::default());
+/// assert_eq!(alignment.score, 0);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// `f[i][j]` is the best score aligning the first i chars of `a` with the first j chars of `b`:
+/// the best of pairing `a[i]` with `b[j]`, or aligning either one to a gap. The traceback walks
+/// from the bottom-right corner, preferring a pairing, then a gap in `b`, then a gap in `a`.
+/// `f[i][j]` 是 `a` 的前 i 个字符与 `b` 的前 j 个字符比对的最高得分：取 `a[i]` 与 `b[j]` 配对，
+/// 或者其中之一对齐到空位，三者中的最大值。回溯从右下角开始，依次优先配对、在 `b` 中插入空位、在 `a` 中插入空位。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(|a| * |b|).
+/// 时间复杂度和空间复杂度都是 O(|a| * |b|)。
+pub fn needleman_wunsch(a: &str, b: &str, scoring: Scoring) -> Alignment {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+  let (la, lb) = (a.len(), b.len());
+
+  let pair = |x: char, y: char| {
+    if x == y {
+      scoring.match_score
+    } else {
+      scoring.mismatch
+    }
+  };
+
+  let mut f = vec![vec![0; lb + 1]; la + 1];
+
+  for (i, row) in f.iter_mut().enumerate() {
+    row[0] = i as i32 * scoring.gap;
+  }
+
+  for (j, cell) in f[0].iter_mut().enumerate() {
+    *cell = j as i32 * scoring.gap;
+  }
+
+  for i in 1..=la {
+    for j in 1..=lb {
+      f[i][j] = (f[i - 1][j - 1] + pair(a[i - 1], b[j - 1]))
+        .max(f[i - 1][j] + scoring.gap)
+        .max(f[i][j - 1] + scoring.gap);
+    }
+  }
+
+  // 从 (la, lb) 回溯到 (0, 0)，得到的列是逆序的
+  // Trace back from (la, lb) to (0, 0), collecting columns in reverse
+  let mut aligned_a = Vec::with_capacity(la + lb);
+  let mut aligned_b = Vec::with_capacity(la + lb);
+  let (mut i, mut j) = (la, lb);
+
+  while i > 0 || j > 0 {
+    if i > 0 && j > 0 && f[i][j] == f[i - 1][j - 1] + pair(a[i - 1], b[j - 1]) {
+      aligned_a.push(a[i - 1]);
+      aligned_b.push(b[j - 1]);
+      i -= 1;
+      j -= 1;
+    } else if i > 0 && f[i][j] == f[i - 1][j] + scoring.gap {
+      aligned_a.push(a[i - 1]);
+      aligned_b.push(GAP);
+      i -= 1;
+    } else {
+      aligned_a.push(GAP);
+      aligned_b.push(b[j - 1]);
+      j -= 1;
+    }
+  }
+
+  Alignment {
+    score: f[la][lb],
+    aligned_a: aligned_a.into_iter().rev().collect(),
+    aligned_b: aligned_b.into_iter().rev().collect(),
+  }
+}
+
+/// 使用 Smith-Waterman 算法对两个字符串做局部比对，返回得分最高的一对子串及其比对。
+/// Locally aligns two strings with the Smith-Waterman algorithm, returning the best-scoring pair
+/// of substrings and their alignment.
+///
+/// 如果任何一对字符都无法得到正分（例如所有字符都不同），最优的局部比对为空：得分为 0，两个范围都为空。
+/// If no pair of chars scores positively (say, every char differs), the best local alignment is
+/// empty: the score is 0 and both ranges are empty.
+///
+/// 比对按字符进行，返回的范围是字节范围，可以直接用来切片原字符串。
+/// Alignment works on chars; the returned ranges are byte ranges that slice the original strings
+/// directly.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let local = smith_waterman("xxACGTyy", "zACGTz", Scoring::default());
+/// assert_eq!(local.score, 4);
+/// assert_eq!(&"xxACGTyy"[local.a_range], "ACGT");
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// The table is filled as in [`needleman_wunsch`], except that no cell drops below 0: a
+/// negative prefix is better abandoned, starting a fresh alignment. The best alignment ends at the
+/// highest cell and the traceback stops at the first 0.
+/// 表格的填写与 [`needleman_wunsch`] 相同，只是任何格子都不会低于 0：得分为负的前缀不如放弃，重新开始比对。
+/// 最优比对在得分最高的格子结束，回溯到第一个 0 为止。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(|a| * |b|).
+/// 时间复杂度和空间复杂度都是 O(|a| * |b|)。
+pub fn smith_waterman(a_str: &str, b_str: &str, scoring: Scoring) -> LocalAlignment {
+  let a: Vec<char> = a_str.chars().collect();
+  let b: Vec<char> = b_str.chars().collect();
+  let (la, lb) = (a.len(), b.len());
+
+  let pair = |x: char, y: char| {
+    if x == y {
+      scoring.match_score
+    } else {
+      scoring.mismatch
+    }
+  };
+
+  let mut h = vec![vec![0; lb + 1]; la + 1];
+  let (mut best, mut end) = (0, (0, 0));
+
+  for i in 1..=la {
+    for j in 1..=lb {
+      h[i][j] = (h[i - 1][j - 1] + pair(a[i - 1], b[j - 1]))
+        .max(h[i - 1][j] + scoring.gap)
+        .max(h[i][j - 1] + scoring.gap)
+        .max(0);
+
+      if h[i][j] > best {
+        best = h[i][j];
+        end = (i, j);
+      }
+    }
+  }
+
+  // 从得分最高的格子回溯到第一个 0，得到的列是逆序的
+  // Trace back from the highest cell to the first 0, collecting columns in reverse
+  let mut aligned_a = vec![];
+  let mut aligned_b = vec![];
+  let (mut i, mut j) = end;
+
+  while i > 0 && j > 0 && h[i][j] > 0 {
+    if h[i][j] == h[i - 1][j - 1] + pair(a[i - 1], b[j - 1]) {
+      aligned_a.push(a[i - 1]);
+      aligned_b.push(b[j - 1]);
+      i -= 1;
+      j -= 1;
+    } else if h[i][j] == h[i - 1][j] + scoring.gap {
+      aligned_a.push(a[i - 1]);
+      aligned_b.push(GAP);
+      i -= 1;
+    } else {
+      aligned_a.push(GAP);
+      aligned_b.push(b[j - 1]);
+      j -= 1;
+    }
+  }
+
+  LocalAlignment {
+    score: best,
+    a_range: byte_offset(a_str, i)..byte_offset(a_str, end.0),
+    b_range: byte_offset(b_str, j)..byte_offset(b_str, end.1),
+    aligned_a: aligned_a.into_iter().rev().collect(),
+    aligned_b: aligned_b.into_iter().rev().collect(),
+  }
+}
+
+/// 第 `chars` 个字符在 `s` 中的字节偏移，`chars` 等于字符个数时为 `s.len()`。
+/// The byte offset of char number `chars` in `s`, or `s.len()` when `chars` is the char count.
+fn byte_offset(s: &str, chars: usize) -> usize {
+  s.char_indices()
+    .nth(chars)
+    .map_or(s.len(), |(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 按列重新计算比对的得分
+  /// Recomputes the score of an alignment column by column
+  fn rescore(alignment: &Alignment, scoring: Scoring) -> i32 {
+    alignment
+      .aligned_a
+      .chars()
+      .zip(alignment.aligned_b.chars())
+      .map(|(x, y)| match (x, y) {
+        (GAP, _) | (_, GAP) => scoring.gap,
+        _ if x == y => scoring.match_score,
+        _ => scoring.mismatch,
+      })
+      .sum()
+  }
+
+  fn without_gaps(s: &str) -> String {
+    s.chars().filter(|&c| c != GAP).collect()
+  }
+
+  fn check(a: &str, b: &str, scoring: Scoring) -> Alignment {
+    let alignment = needleman_wunsch(a, b, scoring);
+
+    assert_eq!(
+      alignment.aligned_a.chars().count(),
+      alignment.aligned_b.chars().count()
+    );
+    assert_eq!(without_gaps(&alignment.aligned_a), a);
+    assert_eq!(without_gaps(&alignment.aligned_b), b);
+    assert_eq!(rescore(&alignment, scoring), alignment.score);
+
+    alignment
+  }
+
+  #[test]
+  fn gattaca() {
+    let alignment = check("GATTACA", "GCATGCU", Scoring::default());
+
+    assert_eq!(alignment.score, 0);
+  }
+
+  #[test]
+  fn identical() {
+    let alignment = check("ACGT", "ACGT", Scoring::default());
+
+    assert_eq!(alignment.score, 4);
+    assert_eq!(alignment.aligned_a, "ACGT");
+    assert_eq!(alignment.aligned_b, "ACGT");
+  }
+
+  #[test]
+  fn explicit_gap() {
+    let alignment = check("ACGT", "AGT", Scoring::default());
+
+    assert_eq!(alignment.score, 2);
+    assert_eq!(alignment.aligned_a, "ACGT");
+    assert_eq!(alignment.aligned_b, "A-GT");
+  }
+
+  #[test]
+  fn swapping_keeps_score() {
+    let scorings = [
+      Scoring::default(),
+      Scoring {
+        match_score: 2,
+        mismatch: -3,
+        gap: -2,
+      },
+      Scoring {
+        match_score: 5,
+        mismatch: -1,
+        gap: -10,
+      },
+    ];
+
+    for scoring in scorings {
+      for (a, b) in [("GATTACA", "GCATGCU"), ("AAAC", "CAAAA"), ("x", "xyz")] {
+        assert_eq!(check(a, b, scoring).score, check(b, a, scoring).score);
+      }
+    }
+  }
+
+  #[test]
+  fn empty_input_aligns_to_gaps() {
+    let scoring = Scoring {
+      match_score: 1,
+      mismatch: -1,
+      gap: -2,
+    };
+    let alignment = check("", "ACG", scoring);
+
+    assert_eq!(alignment.score, -6);
+    assert_eq!(alignment.aligned_a, "---");
+    assert_eq!(alignment.aligned_b, "ACG");
+
+    let alignment = check("ACG", "", scoring);
+    assert_eq!(alignment.aligned_b, "---");
+
+    assert_eq!(check("", "", scoring).score, 0);
+  }
+
+  #[test]
+  fn unicode() {
+    let alignment = check("你好世界", "你世界", Scoring::default());
+
+    assert_eq!(alignment.aligned_b, "你-世界");
+  }
+
+  fn check_local(a: &str, b: &str, scoring: Scoring) -> LocalAlignment {
+    let local = smith_waterman(a, b, scoring);
+
+    assert_eq!(
+      local.aligned_a.chars().count(),
+      local.aligned_b.chars().count()
+    );
+    assert_eq!(without_gaps(&local.aligned_a), &a[local.a_range.clone()]);
+    assert_eq!(without_gaps(&local.aligned_b), &b[local.b_range.clone()]);
+    assert!(local.score >= 0);
+
+    let as_global = Alignment {
+      score: local.score,
+      aligned_a: local.aligned_a.clone(),
+      aligned_b: local.aligned_b.clone(),
+    };
+    assert_eq!(rescore(&as_global, scoring), local.score);
+
+    local
+  }
+
+  #[test]
+  fn local_common_region_in_noise() {
+    let scoring = Scoring {
+      match_score: 3,
+      mismatch: -3,
+      gap: -2,
+    };
+    let local = check_local("TGTTACGG", "GGTTGACTA", scoring);
+
+    assert_eq!(local.score, 13);
+    assert_eq!(local.aligned_a, "GTT-AC");
+    assert_eq!(local.aligned_b, "GTTGAC");
+    assert_eq!(local.a_range, 1..6);
+    assert_eq!(local.b_range, 1..7);
+  }
+
+  #[test]
+  fn local_identical_is_global() {
+    let local = check_local("GATTACA", "GATTACA", Scoring::default());
+    let global = needleman_wunsch("GATTACA", "GATTACA", Scoring::default());
+
+    assert_eq!(local.score, global.score);
+    assert_eq!(local.a_range, 0..7);
+    assert_eq!(local.aligned_a, global.aligned_a);
+  }
+
+  #[test]
+  fn local_empty_alignment() {
+    let local = check_local("AAAA", "CCC", Scoring::default());
+
+    assert_eq!(local.score, 0);
+    assert!(local.a_range.is_empty() && local.b_range.is_empty());
+    assert!(local.aligned_a.is_empty() && local.aligned_b.is_empty());
+
+    assert_eq!(check_local("", "ACG", Scoring::default()).score, 0);
+  }
+
+  #[test]
+  fn local_multi_byte_ranges() {
+    let a = "噪声🦀你好世界🦀噪";
+    let b = "é你好世界ü";
+    let local = check_local(a, b, Scoring::default());
+
+    assert_eq!(local.score, 4);
+    assert_eq!(&a[local.a_range], "你好世界");
+    assert_eq!(&b[local.b_range], "你好世界");
+  }
+}
+
+fn main() {}
@@ -0,0 +1,401 @@
+use std::fmt;
+
+/// RFC 4648 的标准 Base64 字母表。 (The standard Base64 alphabet of RFC 4648.)
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// RFC 4648 第 5 节的 URL 安全字母表。 (The URL-safe alphabet of RFC 4648, section 5.)
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const PAD: u8 = b'=';
+
+/// Base64 解码失败时返回的错误，位置均为输入中的字节偏移。
+/// The error returned when Base64 decoding fails, positions being byte offsets into the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base64Error {
+  /// 在 `position` 处遇到不属于字母表的字符 `found`（包括空白字符）。
+  /// (`found`, which is not in the alphabet, at `position`; whitespace included.)
+  InvalidChar { position: usize, found: char },
+  /// 去掉填充后剩下的字符数除以 4 余 1，不可能是合法的编码。
+  /// (The chars left without padding number 1 mod 4, which no encoding produces.)
+  InvalidLength { length: usize },
+  /// 从 `position` 开始的填充缺失、过多或不应出现。
+  /// (The padding starting at `position` is missing, too long or not allowed.)
+  InvalidPadding { position: usize },
+  /// 填充之后在 `position` 处还有其他数据。 (More data follows the padding at `position`.)
+  TrailingData { position: usize },
+  /// `position` 处的最后一个字符含有未使用但不为 0 的低位，所以编码不规范。
+  /// (The last char at `position` has unused low bits that are not zero, so the encoding is not
+  /// canonical.)
+  TrailingBits { position: usize },
+}
+
+impl fmt::Display for Base64Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Base64Error::InvalidChar { position, found } => {
+        write!(f, "invalid char {found:?} at position {position}")
+      }
+      Base64Error::InvalidLength { length } => {
+        write!(f, "{length} chars without padding cannot be valid base64")
+      }
+      Base64Error::InvalidPadding { position } => {
+        write!(f, "invalid padding at position {position}")
+      }
+      Base64Error::TrailingData { position } => {
+        write!(f, "trailing data after padding at position {position}")
+      }
+      Base64Error::TrailingBits { position } => {
+        write!(f, "non-zero trailing bits at position {position}")
+      }
+    }
+  }
+}
+
+impl std::error::Error for Base64Error {}
+
+/// 用 RFC 4648 的标准字母表（`+` 和 `/`）把字节编码为 Base64，并用 `=` 填充到 4 的倍数。
+/// Encodes bytes as Base64 with the standard RFC 4648 alphabet (`+` and `/`), padding with `=`
+/// to a multiple of 4.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+/// assert_eq!(encode(b"fo"), "Zm8=");
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+pub fn encode(data: &[u8]) -> String {
+  encode_with(data, STANDARD, true)
+}
+
+/// 解码用标准字母表编码、带 `=` 填充的 Base64 字符串。
+/// Decodes a Base64 string in the standard alphabet with `=` padding.
+///
+/// 解码是严格的：空白字符、不属于字母表的字符、缺失或多余的填充、填充之后的数据，
+/// 以及最后一个字符中不为 0 的未使用位都会返回对应的 [`Base64Error`]，不会 panic。
+/// Decoding is strict: whitespace, chars outside the alphabet, missing or extra padding, data
+/// after the padding and non-zero unused bits in the last char all return the matching
+/// [`Base64Error`] instead of panicking.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(decode("Zm9vYmFy").unwrap(), b"foobar");
+/// assert!(decode("Zm9v YmFy").is_err());
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+pub fn decode(s: &str) -> Result<Vec<u8>, Base64Error> {
+  decode_with(s, STANDARD, true)
+}
+
+/// 用 URL 安全的字母表（`-` 和 `_`）编码，不加填充，结果可以直接放进 URL 和文件名中。
+/// Encodes with the URL-safe alphabet (`-` and `_`) and without padding, so the result can go
+/// straight into URLs and file names.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(encode_url(&[0xFB, 0xFF]), "-_8");
+/// ```
+pub fn encode_url(data: &[u8]) -> String {
+  encode_with(data, URL_SAFE, false)
+}
+
+/// 解码用 URL 安全的字母表编码的字符串。填充是可选的，但如果出现就必须正确。
+/// Decodes a string in the URL-safe alphabet. Padding is optional, but must be correct if
+/// present.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(decode_url("-_8").unwrap(), vec![0xFB, 0xFF]);
+/// assert_eq!(decode_url("-_8=").unwrap(), vec![0xFB, 0xFF]);
+/// ```
+pub fn decode_url(s: &str) -> Result<Vec<u8>, Base64Error> {
+  decode_with(s, URL_SAFE, false)
+}
+
+fn encode_with(data: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+  let mut ret = String::with_capacity(data.len().div_ceil(3) * 4);
+
+  for chunk in data.chunks(3) {
+    // 把最多 3 个字节放进 24 位的整数，再每 6 位取一个字符
+    // Pack up to 3 bytes into 24 bits, then take one char per 6 bits
+    let bits = chunk
+      .iter()
+      .enumerate()
+      .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+
+    for i in 0..=chunk.len() {
+      ret.push(alphabet[(bits >> (18 - 6 * i) & 0x3F) as usize] as char);
+    }
+
+    if pad {
+      for _ in chunk.len()..3 {
+        ret.push(PAD as char);
+      }
+    }
+  }
+
+  ret
+}
+
+fn decode_with(
+  s: &str,
+  alphabet: &[u8; 64],
+  require_padding: bool,
+) -> Result<Vec<u8>, Base64Error> {
+  let mut lookup = [None; 256];
+  for (value, &c) in alphabet.iter().enumerate() {
+    lookup[c as usize] = Some(value as u32);
+  }
+
+  let bytes = s.as_bytes();
+  let data_len = bytes.iter().position(|&b| b == PAD).unwrap_or(bytes.len());
+
+  let values = bytes[..data_len]
+    .iter()
+    .enumerate()
+    .map(|(position, &b)| {
+      lookup[b as usize].ok_or_else(|| Base64Error::InvalidChar {
+        position,
+        found: s[position..].chars().next().unwrap(),
+      })
+    })
+    .collect::<Result<Vec<u32>, _>>()?;
+
+  if let Some(position) = (data_len..bytes.len()).find(|&i| bytes[i] != PAD) {
+    return Err(Base64Error::TrailingData { position });
+  }
+
+  let rem = data_len % 4;
+  if rem == 1 {
+    return Err(Base64Error::InvalidLength { length: data_len });
+  }
+
+  // 需要的填充长度；可选填充时也允许完全不填充
+  // The padding length needed; with optional padding, none at all is fine too
+  let pad_len = bytes.len() - data_len;
+  let expected = (4 - rem) % 4;
+  if pad_len != expected && (require_padding || pad_len != 0) {
+    return Err(Base64Error::InvalidPadding { position: data_len });
+  }
+
+  let mut ret = Vec::with_capacity(data_len / 4 * 3 + 2);
+
+  for chunk in values.chunks(4) {
+    let bits = chunk
+      .iter()
+      .enumerate()
+      .fold(0u32, |acc, (i, &v)| acc | v << (18 - 6 * i));
+
+    for i in 0..chunk.len() - 1 {
+      ret.push((bits >> (16 - 8 * i)) as u8);
+    }
+  }
+
+  // 不完整的最后一组中，没有组成字节的低位必须为 0
+  // In an incomplete last group, the low bits that make up no byte must be zero
+  let unused = match rem {
+    2 => 0xF,
+    3 => 0x3,
+    _ => 0,
+  };
+  if values.last().is_some_and(|&v| v & unused != 0) {
+    return Err(Base64Error::TrailingBits {
+      position: data_len - 1,
+    });
+  }
+
+  Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  const VECTORS: [(&str, &str); 7] = [
+    ("", ""),
+    ("f", "Zg=="),
+    ("fo", "Zm8="),
+    ("foo", "Zm9v"),
+    ("foob", "Zm9vYg=="),
+    ("fooba", "Zm9vYmE="),
+    ("foobar", "Zm9vYmFy"),
+  ];
+
+  #[test]
+  fn rfc_test_vectors() {
+    for (plain, encoded) in VECTORS {
+      assert_eq!(encode(plain.as_bytes()), encoded);
+      assert_eq!(decode(encoded).unwrap(), plain.as_bytes());
+    }
+  }
+
+  #[test]
+  fn url_safe_test_vectors() {
+    for (plain, encoded) in VECTORS {
+      let unpadded = encoded.trim_end_matches('=');
+
+      assert_eq!(encode_url(plain.as_bytes()), unpadded);
+      assert_eq!(decode_url(unpadded).unwrap(), plain.as_bytes());
+      assert_eq!(decode_url(encoded).unwrap(), plain.as_bytes());
+    }
+  }
+
+  #[test]
+  fn alphabets_differ_in_two_chars() {
+    let data = [0xFB, 0xFF, 0xBF];
+
+    assert_eq!(encode(&data), "+/+/");
+    assert_eq!(encode_url(&data), "-_-_");
+    assert_eq!(
+      decode("-_-_"),
+      Err(Base64Error::InvalidChar {
+        position: 0,
+        found: '-'
+      })
+    );
+    assert_eq!(
+      decode_url("+/+/"),
+      Err(Base64Error::InvalidChar {
+        position: 0,
+        found: '+'
+      })
+    );
+  }
+
+  #[test]
+  fn invalid_chars() {
+    assert_eq!(
+      decode("Zm9v YmFy"),
+      Err(Base64Error::InvalidChar {
+        position: 4,
+        found: ' '
+      })
+    );
+    assert_eq!(
+      decode("Zm9v\n"),
+      Err(Base64Error::InvalidChar {
+        position: 4,
+        found: '\n'
+      })
+    );
+    assert_eq!(
+      decode("Zmé="),
+      Err(Base64Error::InvalidChar {
+        position: 2,
+        found: 'é'
+      })
+    );
+  }
+
+  #[test]
+  fn bad_padding() {
+    assert_eq!(
+      decode("Zg"),
+      Err(Base64Error::InvalidPadding { position: 2 })
+    );
+    assert_eq!(
+      decode("Zg="),
+      Err(Base64Error::InvalidPadding { position: 2 })
+    );
+    assert_eq!(
+      decode("Zg==="),
+      Err(Base64Error::InvalidPadding { position: 2 })
+    );
+    assert_eq!(
+      decode("Zm9v===="),
+      Err(Base64Error::InvalidPadding { position: 4 })
+    );
+    assert_eq!(
+      decode("===="),
+      Err(Base64Error::InvalidPadding { position: 0 })
+    );
+    assert_eq!(
+      decode_url("Zg="),
+      Err(Base64Error::InvalidPadding { position: 2 })
+    );
+    assert_eq!(
+      decode("Z==="),
+      Err(Base64Error::InvalidLength { length: 1 })
+    );
+    assert_eq!(
+      decode_url("Zm9vY"),
+      Err(Base64Error::InvalidLength { length: 5 })
+    );
+  }
+
+  #[test]
+  fn trailing_garbage() {
+    assert_eq!(
+      decode("Zg==Zm8="),
+      Err(Base64Error::TrailingData { position: 4 })
+    );
+    assert_eq!(
+      decode("Zg=A"),
+      Err(Base64Error::TrailingData { position: 3 })
+    );
+    assert_eq!(
+      decode("Zh=="),
+      Err(Base64Error::TrailingBits { position: 1 })
+    );
+    assert_eq!(
+      decode("Zm9="),
+      Err(Base64Error::TrailingBits { position: 2 })
+    );
+    assert_eq!(
+      Base64Error::TrailingData { position: 4 }.to_string(),
+      "trailing data after padding at position 4"
+    );
+  }
+
+  #[test]
+  fn zero_bytes() {
+    assert_eq!(encode(&[0, 0, 0]), "AAAA");
+    assert_eq!(encode(&[0]), "AA==");
+    assert_eq!(decode("AAAA").unwrap(), vec![0, 0, 0]);
+  }
+
+  /// 偏向 0xFB 和 0xFF 的字节，它们的编码会用到两种字母表不同的字符
+  /// Bytes biased towards 0xFB and 0xFF, whose encodings use the chars the alphabets differ in
+  fn high_bytes() -> impl Strategy<Value = u8> {
+    prop_oneof![Just(0xFB), Just(0xFF), any::<u8>()]
+  }
+
+  proptest! {
+    #[test]
+    fn round_trip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+      let encoded = encode(&data);
+
+      prop_assert_eq!(encoded.len() % 4, 0);
+      prop_assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn url_round_trip(data in proptest::collection::vec(high_bytes(), 0..256)) {
+      let encoded = encode_url(&data);
+
+      prop_assert!(!encoded.contains(['+', '/', '=']));
+      prop_assert_eq!(decode_url(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn decode_never_panics(s in "\\PC{0,32}") {
+      if let Ok(data) = decode(&s) {
+        prop_assert_eq!(encode(&data), s);
+      }
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,271 @@
+/// 实现了 Boyer-Moore 算法，用于在文本中查找子字符串的所有出现位置（包括相互重叠的位置）。
+/// Implements the Boyer-Moore algorithm, finding all occurrences of a substring in a text,
+/// overlapping ones included.
+///
+/// Boyer-Moore 算法从右向左比较模式与文本，失配时同时使用坏字符规则和好后缀规则，取两者中较大的移动距离。
+/// 模式越长、字母表越大，跳过的文本越多，通常只需检查文本的一小部分字符。
+/// The Boyer-Moore algorithm compares the pattern against the text from right to left and, on a
+/// mismatch, shifts by the larger of the bad-character and good-suffix rules. The longer the
+/// pattern and the larger the alphabet, the more text is skipped; typically only a fraction of the
+/// text's characters are ever inspected.
+///
+/// # 参数 (Arguments)
+///
+/// * `text` - 要在其中搜索模式的文本字符串。
+///   The text string in which to search for the pattern.
+/// * `pattern` - 要在文本中搜索的模式字符串。
+///   The pattern string to search for within the text.
+///
+/// # 返回值 (Returns)
+///
+/// A vector containing the starting (byte) indices of all occurrences of the pattern within the
+/// text, the same as `knuth_morris_pratt` returns.
+/// 包含所有模式出现位置起始（字节）索引的向量，与 `knuth_morris_pratt` 的返回值相同。
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let indices = boyer_moore("ABABDABACDABABCABAB", "ABABCABAB");
+/// assert_eq!(indices, vec![10]);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// The bad-character rule aligns the mismatched text byte with its last occurrence in the
+/// pattern, using a 256-entry table. The good-suffix rule aligns the already matched suffix with
+/// its rightmost other occurrence in the pattern that is preceded by a different byte, or else
+/// with the longest prefix of the pattern that is a suffix of the match. After a full match the
+/// pattern shifts by its period, so overlapping matches are not missed.
+/// 坏字符规则使用 256 项的表，将失配的文本字节与它在模式中最后一次出现的位置对齐。
+/// 好后缀规则将已匹配的后缀与它在模式中前面字节不同的、最靠右的另一次出现对齐，
+/// 若不存在，则与模式中同时是已匹配部分后缀的最长前缀对齐。
+/// 完整匹配后模式按其周期移动，因此不会遗漏相互重叠的匹配。
+///
+/// # 复杂度 (Complexity)
+///
+/// Preprocessing takes O(m + 256) time and space. The search takes O(n / m) comparisons in the
+/// best case and O(n * m) in the worst case, where n is the length of the text and m is the length
+/// of the pattern.
+/// 预处理的时间和空间复杂度为 O(m + 256)。查找在最好情况下需要 O(n / m) 次比较，最坏情况下为 O(n * m)，
+/// 其中 n 是文本的长度，m 是模式的长度。
+pub fn boyer_moore(text: &str, pattern: &str) -> Vec<usize> {
+  search(text.as_bytes(), pattern.as_bytes()).0
+}
+
+/// 查找所有匹配，同时返回字符比较的次数。
+/// Finds all matches and also returns the number of byte comparisons made.
+fn search(text: &[u8], pattern: &[u8]) -> (Vec<usize>, usize) {
+  let (n, m) = (text.len(), pattern.len());
+
+  // 如果模式为空或比文本长，则没有匹配
+  // There is no match if the pattern is empty or longer than the text
+  if m == 0 || m > n {
+    return (vec![], 0);
+  }
+
+  let last = bad_character_table(pattern);
+  let good_suffix = good_suffix_table(pattern);
+
+  let mut ret = vec![];
+  let mut comparisons = 0;
+  let mut s = 0;
+
+  while s + m <= n {
+    // 从右向左比较，j 是尚未匹配的前缀的长度
+    // Compare from right to left; j is the length of the prefix not yet matched
+    let mut j = m;
+
+    while j > 0 {
+      comparisons += 1;
+
+      if pattern[j - 1] != text[s + j - 1] {
+        break;
+      }
+
+      j -= 1;
+    }
+
+    if j == 0 {
+      ret.push(s);
+      // 按模式的周期移动，以免遗漏重叠的匹配
+      // Shift by the pattern's period so overlapping matches are not missed
+      s += good_suffix[0];
+    } else {
+      // 坏字符规则可能给出非正的移动距离，好后缀规则总是至少移动 1
+      // The bad-character shift may be non-positive; the good-suffix shift is always at least 1
+      let bad_character = (j - 1) as isize - last[text[s + j - 1] as usize];
+      s += good_suffix[j].max(bad_character.max(0) as usize);
+    }
+  }
+
+  (ret, comparisons)
+}
+
+/// 坏字符表：每个字节在模式中最后一次出现的位置，未出现时为 -1。
+/// The bad-character table: the last position of each byte in the pattern, or -1 if absent.
+fn bad_character_table(pattern: &[u8]) -> [isize; 256] {
+  let mut last = [-1; 256];
+
+  for (i, &c) in pattern.iter().enumerate() {
+    last[c as usize] = i as isize;
+  }
+
+  last
+}
+
+/// 好后缀表：`shift[j]` 是模式中 `pattern[j..]` 已经匹配而 `pattern[j - 1]` 失配时的移动距离，
+/// `shift[0]` 是完整匹配后的移动距离，即模式的周期。
+/// The good-suffix table: `shift[j]` is the shift when `pattern[j..]` has matched and
+/// `pattern[j - 1]` mismatched; `shift[0]` is the shift after a full match, the pattern's period.
+fn good_suffix_table(pattern: &[u8]) -> Vec<usize> {
+  let m = pattern.len();
+  let mut shift = vec![0; m + 1];
+
+  // border[i] 是后缀 pattern[i..] 的最长真边界（既是前缀又是后缀）的起始位置
+  // border[i] is the start of the widest proper border (both prefix and suffix) of pattern[i..]
+  let mut border = vec![0; m + 1];
+
+  // 第一种情况：已匹配的后缀在模式中的另一次出现，且前面的字节不同
+  // Case 1: another occurrence of the matched suffix, preceded by a different byte
+  let mut i = m;
+  let mut j = m + 1;
+  border[i] = j;
+
+  while i > 0 {
+    while j <= m && pattern[i - 1] != pattern[j - 1] {
+      // 边界无法向左扩展，失配于 j - 1 时可以移动 j - i
+      // The border cannot be extended to the left, so a mismatch at j - 1 can shift by j - i
+      if shift[j] == 0 {
+        shift[j] = j - i;
+      }
+
+      j = border[j];
+    }
+
+    i -= 1;
+    j -= 1;
+    border[i] = j;
+  }
+
+  // 第二种情况：只有已匹配部分的一个后缀是模式的前缀
+  // Case 2: only a suffix of the matched part is a prefix of the pattern
+  j = border[0];
+
+  for (i, s) in shift.iter_mut().enumerate() {
+    if *s == 0 {
+      *s = j;
+    }
+
+    // 后缀比当前的边界短时，改用下一个更窄的边界
+    // Once the suffix is shorter than the current border, fall back to the next narrower one
+    if i == j {
+      j = border[j];
+    }
+  }
+
+  shift
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_algorithm::string::knuth_morris_pratt::knuth_morris_pratt;
+
+  #[test]
+  fn each_letter_matches() {
+    assert_eq!(boyer_moore("aaa", "a"), vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn a_few_separate_matches() {
+    assert_eq!(boyer_moore("abababa", "ab"), vec![0, 2, 4]);
+  }
+
+  #[test]
+  fn one_match() {
+    assert_eq!(boyer_moore("ABC ABCDAB ABCDABCDABDE", "ABCDABD"), vec![15]);
+  }
+
+  #[test]
+  fn lots_of_matches() {
+    assert_eq!(
+      boyer_moore("aaabaabaaaaa", "aa"),
+      vec![0, 1, 4, 7, 8, 9, 10]
+    );
+  }
+
+  #[test]
+  fn lots_of_intricate_matches() {
+    assert_eq!(boyer_moore("ababababa", "aba"), vec![0, 2, 4, 6]);
+  }
+
+  #[test]
+  fn not_found0() {
+    assert_eq!(boyer_moore("abcde", "f"), vec![]);
+  }
+
+  #[test]
+  fn not_found1() {
+    assert_eq!(boyer_moore("abcde", "ac"), vec![]);
+  }
+
+  #[test]
+  fn not_found2() {
+    assert_eq!(boyer_moore("ababab", "bababa"), vec![]);
+  }
+
+  #[test]
+  fn empty_string() {
+    assert_eq!(boyer_moore("", "abcdef"), vec![]);
+  }
+
+  #[test]
+  fn empty_pattern() {
+    assert_eq!(boyer_moore("abcdef", ""), vec![]);
+    assert_eq!(boyer_moore("", ""), vec![]);
+  }
+
+  #[test]
+  fn pattern_longer_than_text() {
+    assert_eq!(boyer_moore("abc", "abcd"), vec![]);
+  }
+
+  #[test]
+  fn good_suffix_tables() {
+    // 周期为 2 的模式，完整匹配后移动 2
+    // A pattern with period 2 shifts by 2 after a full match
+    assert_eq!(good_suffix_table(b"ABAB"), vec![2, 2, 2, 4, 1]);
+    assert_eq!(
+      good_suffix_table(b"ANPANMAN"),
+      vec![6, 6, 6, 6, 6, 6, 3, 8, 1]
+    );
+  }
+
+  #[test]
+  fn agrees_with_kmp() {
+    let text = "abaabaabbabaabaababaabaabaababaab";
+
+    for pattern in [
+      "a", "ab", "aba", "abaab", "baaba", "abaabaab", "bb", "ababa", "aabaa",
+    ] {
+      assert_eq!(
+        boyer_moore(text, pattern),
+        knuth_morris_pratt(text.to_string(), pattern.to_string()),
+        "pattern {pattern:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn sublinear_on_english_text() {
+    let sentence = "the quick brown fox jumps over the lazy dog while the cat sleeps. ";
+    let text = sentence.repeat(200) + "a needle in the haystack";
+
+    let (matches, comparisons) = search(text.as_bytes(), b"needle in the haystack");
+
+    assert_eq!(matches, vec![sentence.len() * 200 + 2]);
+    assert!(comparisons < text.len() / 4, "{comparisons} comparisons");
+  }
+}
+
+fn main() {}
@@ -1,3 +1,42 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/// 带哨兵的 Burrows-Wheeler 变换失败的原因。
+/// (Why a Burrows-Wheeler transform with a sentinel could not be computed or inverted.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BwtError {
+  /// 待变换的输入中已经包含哨兵字节，变换结果将有歧义。
+  /// (The input to transform already contains the sentinel byte, which would make the result
+  /// ambiguous.)
+  SentinelInInput { sentinel: u8, position: usize },
+  /// 待还原的数据中哨兵字节没有恰好出现一次。
+  /// (The sentinel byte does not occur exactly once in the data to invert.)
+  SentinelCount { sentinel: u8, count: usize },
+}
+
+impl fmt::Display for BwtError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BwtError::SentinelInInput { sentinel, position } => {
+        write!(
+          f,
+          "sentinel {:#04x} occurs in the input at position {}",
+          sentinel, position
+        )
+      }
+      BwtError::SentinelCount { sentinel, count } => {
+        write!(
+          f,
+          "sentinel {:#04x} occurs {} times instead of once",
+          sentinel, count
+        )
+      }
+    }
+  }
+}
+
+impl std::error::Error for BwtError {}
+
 /// Applies the Burrows-Wheeler Transform (BWT) to a given input string.
 /// 对给定的输入字符串应用 Burrows-Wheeler 变换（BWT）。
 ///
@@ -30,58 +69,23 @@
 ///
 /// ```
 /// let (encoded, index) = burrows_wheeler_transform("banana".to_owned());
-/// assert_eq!(encoded, "annb$aa");
-/// assert_eq!(index, 4);
+/// assert_eq!(encoded, "nnbaaa");
+/// assert_eq!(index, 3);
 /// ```
 ///
 /// # Complexity
 /// # 复杂度
 ///
-/// The time complexity of this function is O(n^2 * log n), where n is the length of the input string.
-/// This is because sorting the rotations takes O(n^2 * log n) time.
-/// 此函数的时间复杂度为 O(n^2 * log n)，其中 n 是输入字符串的长度。排序旋转表需要 O(n^2 * log n) 的时间。
-///
-/// The space complexity is O(n^2), due to the space used for the table of rotations.
-/// 空间复杂度为 O(n^2)，因为需要用于存储旋转字符串表的空间。
-
+/// The rotations are sorted by prefix doubling without ever being materialized: each round
+/// ranks the rotations by their first 2k characters using the ranks of the first k, so the time
+/// complexity is O(n log n) and the space complexity O(n), where n is the number of characters.
+/// 旋转字符串不会被实际构造出来，而是用倍增法排序：每一轮利用前 k 个字符的排名求出前 2k 个字符的排名，
+/// 因此时间复杂度为 O(n log n)，空间复杂度为 O(n)，其中 n 是字符个数。
 pub fn burrows_wheeler_transform(input: String) -> (String, usize) {
-  let len = input.len();
-  // Create a vector with pre-allocated capacity for storing the table of rotated strings
-  // 创建一个容量预分配的字符串向量，用于存储旋转字符串表
-  let mut table = Vec::<String>::with_capacity(len);
-
-  // Generate rotations and populate the table
-  // 生成旋转字符串并填充表格
-  for i in 0..len {
-    table.push(input[i..].to_owned() + &input[..i]);
-  }
-
-  // Sort the rotations lexicographically
-  // 对旋转字符串表进行词典排序
-  table.sort_by_key(|a| a.to_lowercase());
-
-  // Build the transformed string and find the index of the original string
-  // 构建变换后的字符串和找到原始字符串的索引
-  let mut encoded = String::new();
-  let mut index: usize = 0;
-
-  // Iterate over the table of rotated strings, enumerate them with their indices,
-  // 对旋转字符串表进行迭代，同时为它们的索引加上枚举标识，
-  // and take only the first `len` elements for further processing.
-  // 并仅处理前 `len` 个元素以进行后续处理。
-  for (i, item) in table.iter().enumerate().take(len) {
-    encoded.push(item.chars().last().unwrap());
-
-    // Check if the current rotated string matches the original input string
-    // 检查当前旋转字符串是否与原始输入字符串匹配，
-    // and store its index for later retrieval
-    // 并将其索引存储以便稍后检索
-    if item.eq(&input) {
-      index = i;
-    }
-  }
+  let chars: Vec<char> = input.chars().collect();
+  let (encoded, index) = transform(&chars);
 
-  (encoded, index)
+  (encoded.into_iter().collect(), index)
 }
 
 /// Reverses the Burrows-Wheeler Transform to retrieve the original string.
@@ -107,7 +111,7 @@ pub fn burrows_wheeler_transform(input: String) -> (String, usize) {
 /// # 示例
 ///
 /// ```
-/// let transformed = ("annb$aa".to_owned(), 4);
+/// let transformed = ("nnbaaa".to_owned(), 3);
 /// let original = inv_burrows_wheeler_transform(transformed);
 /// assert_eq!(original, "banana");
 /// ```
@@ -115,43 +119,309 @@ pub fn burrows_wheeler_transform(input: String) -> (String, usize) {
 /// # Complexity
 /// # 复杂度
 ///
-/// The time complexity of this function is O(n^2), where n is the length of the transformed string.
-/// This is because sorting the index table takes O(n^2) time.
-/// 此函数的时间复杂度为 O(n^2)，其中 n 是变换后的字符串的长度。排序索引表需要 O(n^2) 的时间。
+/// The permutation back to the original is the LF-mapping, computed by counting each character
+/// and taking prefix sums instead of sorting, so the time complexity is O(n + σ log σ) and the
+/// space complexity O(n), where n is the number of characters and σ the number of distinct ones.
+/// 还原所需的置换即 LF 映射，通过统计每个字符的个数并求前缀和得到而无需排序，
+/// 因此时间复杂度为 O(n + σ log σ)，空间复杂度为 O(n)，其中 n 是字符个数，σ 是不同字符的个数。
+pub fn inv_burrows_wheeler_transform(input: (String, usize)) -> String {
+  let chars: Vec<char> = input.0.chars().collect();
+
+  if chars.is_empty() {
+    return String::new();
+  }
+
+  // 每个字符在第一列中的起始行：比它小的字符的个数
+  // The first row of each char in the first column: the number of smaller chars
+  let mut counts = HashMap::<char, usize>::new();
+
+  for &c in &chars {
+    *counts.entry(c).or_default() += 1;
+  }
+
+  let mut alphabet: Vec<char> = counts.keys().copied().collect();
+  alphabet.sort_unstable();
+
+  let mut starts = HashMap::with_capacity(alphabet.len());
+  let mut total = 0;
+
+  for c in alphabet {
+    starts.insert(c, total);
+    total += counts[&c];
+  }
+
+  // LF 映射：最后一列第 i 行的字符在第一列中对应的行。相同字符在两列中的相对顺序一致
+  // The LF-mapping: the first-column row of the char in row i of the last column. Equal chars
+  // keep the same relative order in both columns
+  let lf: Vec<usize> = chars
+    .iter()
+    .map(|c| {
+      let next = starts.get_mut(c).unwrap();
+      *next += 1;
+      *next - 1
+    })
+    .collect();
+
+  // 从原字符串所在的行开始，沿 LF 映射从后向前还原
+  // Starting from the original's row, walk the LF-mapping to rebuild the string back to front
+  let mut decoded = vec!['\0'; chars.len()];
+  let mut row = input.1;
+
+  for slot in decoded.iter_mut().rev() {
+    *slot = chars[row];
+    row = lf[row];
+  }
+
+  decoded.into_iter().collect()
+}
+
+/// 对字节序列应用 Burrows-Wheeler 变换，返回变换结果和原序列在排序旋转表中的索引。
+/// Applies the Burrows-Wheeler Transform to a byte sequence, returning the transformed bytes and
+/// the index of the original sequence within the sorted rotations.
+///
+/// 与 [`burrows_wheeler_transform`] 相同，只是按字节而不是按字符处理，适用于任意二进制数据。
+/// The same as [`burrows_wheeler_transform`], but over bytes rather than chars, so it suits
+/// arbitrary binary data.
+///
+/// # Examples
+/// # 示例
 ///
-/// The space complexity is O(n^2), due to the space used for the index table.
-/// 空间复杂度为 O(n^2)，因为需要用于存储索引表的空间。
+/// ```
+/// let (encoded, index) = bwt_bytes(b"banana");
+/// assert_eq!(encoded, b"nnbaaa");
+/// assert_eq!(inv_bwt_bytes(&encoded, index), b"banana");
+/// ```
+pub fn bwt_bytes(input: &[u8]) -> (Vec<u8>, usize) {
+  transform(input)
+}
 
-pub fn inv_burrows_wheeler_transform(input: (String, usize)) -> String {
-  let len = input.0.len();
-  let mut table = Vec::<(usize, char)>::with_capacity(len);
+/// 反转 [`bwt_bytes`]，恢复原始字节序列。 (Reverses [`bwt_bytes`] to retrieve the original bytes.)
+///
+/// # Complexity
+/// # 复杂度
+///
+/// O(n + 256) time and O(n) space: the LF-mapping is computed by counting, not sorting.
+/// 时间复杂度为 O(n + 256)，空间复杂度为 O(n)：LF 映射通过计数而不是排序求出。
+pub fn inv_bwt_bytes(encoded: &[u8], index: usize) -> Vec<u8> {
+  if encoded.is_empty() {
+    return vec![];
+  }
 
-  // Build the table of indices and characters
-  // 构建索引表和字符表
-  for i in 0..len {
-    table.push((i, input.0.chars().nth(i).unwrap()));
+  // 每个字节在第一列中的起始行：比它小的字节的个数
+  // The first row of each byte in the first column: the number of smaller bytes
+  let mut starts = [0usize; 256];
+
+  for &b in encoded {
+    starts[b as usize] += 1;
   }
 
-  // Sort the table based on characters
-  // 根据字符对表进行排序
-  table.sort_by(|a, b| a.1.cmp(&b.1));
+  let mut total = 0;
 
-  // Build the decoded string using the table of indices
-  // 根据索引表构建解码后的字符串
-  let mut decoded = String::new();
-  let mut idx = input.1;
+  for start in starts.iter_mut() {
+    let count = *start;
+    *start = total;
+    total += count;
+  }
 
-  for _ in 0..len {
-    decoded.push(table[idx].1);
-    idx = table[idx].0;
+  // LF 映射：最后一列第 i 行的字节在第一列中对应的行
+  // The LF-mapping: the first-column row of the byte in row i of the last column
+  let mut seen = [0usize; 256];
+  let lf: Vec<usize> = encoded
+    .iter()
+    .map(|&b| {
+      let row = starts[b as usize] + seen[b as usize];
+      seen[b as usize] += 1;
+      row
+    })
+    .collect();
+
+  // 从原序列所在的行开始，沿 LF 映射从后向前还原
+  // Starting from the original's row, walk the LF-mapping to rebuild the sequence back to front
+  let mut decoded = vec![0; encoded.len()];
+  let mut row = index;
+
+  for slot in decoded.iter_mut().rev() {
+    *slot = encoded[row];
+    row = lf[row];
   }
 
   decoded
 }
 
+/// 在字节序列末尾追加哨兵字节后应用 Burrows-Wheeler 变换。
+/// Appends a sentinel byte to a byte sequence and applies the Burrows-Wheeler Transform.
+///
+/// 哨兵在结果中恰好出现一次，它所在的行就是原序列所在的行，因此不需要单独返回索引，
+/// 用 [`inv_bwt_with_sentinel`] 即可还原。旋转按普通的字节顺序排序，哨兵不会被特殊对待，
+/// 所以任何不在输入中出现的字节都可以作为哨兵；输入中包含哨兵时返回 [`BwtError::SentinelInInput`]。
+/// The sentinel occurs exactly once in the result, and its row is the row of the original, so no
+/// separate index is returned and [`inv_bwt_with_sentinel`] inverts it on its own. Rotations are
+/// sorted in plain byte order without special-casing the sentinel, so any byte absent from the
+/// input works; an input that contains the sentinel returns [`BwtError::SentinelInInput`].
+///
+/// # 参数 (Arguments)
+///
+/// * `input` - 要变换的字节序列。
+///   The bytes to transform.
+/// * `sentinel` - 作为结束标记的字节，不能出现在 `input` 中。
+///   The end-marker byte, which must not occur in `input`.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(bwt_with_sentinel(b"banana", b'$').unwrap(), b"annb$aa");
+/// assert!(bwt_with_sentinel(b"a$b", b'$').is_err());
+/// ```
+pub fn bwt_with_sentinel(input: &[u8], sentinel: u8) -> Result<Vec<u8>, BwtError> {
+  if let Some(position) = input.iter().position(|&b| b == sentinel) {
+    return Err(BwtError::SentinelInInput { sentinel, position });
+  }
+
+  let mut terminated = Vec::with_capacity(input.len() + 1);
+  terminated.extend_from_slice(input);
+  terminated.push(sentinel);
+
+  Ok(transform(&terminated).0)
+}
+
+/// 反转 [`bwt_with_sentinel`]，恢复不含哨兵的原始字节序列。
+/// Reverses [`bwt_with_sentinel`] to retrieve the original bytes, without the sentinel.
+///
+/// 以哨兵所在的行作为起点，因此不需要主索引。哨兵没有恰好出现一次时返回 [`BwtError::SentinelCount`]。
+/// Decoding starts from the sentinel's row, so no primary index is needed. Returns
+/// [`BwtError::SentinelCount`] if the sentinel does not occur exactly once.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(inv_bwt_with_sentinel(b"annb$aa", b'$').unwrap(), b"banana");
+/// ```
+pub fn inv_bwt_with_sentinel(encoded: &[u8], sentinel: u8) -> Result<Vec<u8>, BwtError> {
+  let count = encoded.iter().filter(|&&b| b == sentinel).count();
+
+  if count != 1 {
+    return Err(BwtError::SentinelCount { sentinel, count });
+  }
+
+  // 唯一以哨兵结尾的旋转就是追加了哨兵的原序列本身
+  // The only rotation ending in the sentinel is the sentinel-terminated original itself
+  let index = encoded.iter().position(|&b| b == sentinel).unwrap();
+  let mut decoded = inv_bwt_bytes(encoded, index);
+  decoded.pop();
+
+  Ok(decoded)
+}
+
+/// 对任意可比较的序列应用 Burrows-Wheeler 变换。
+/// Applies the Burrows-Wheeler Transform to any sequence of comparable items.
+fn transform<T: Ord + Copy>(input: &[T]) -> (Vec<T>, usize) {
+  let n = input.len();
+  let rotations = sort_rotations(input);
+
+  // 每个旋转的最后一个元素，即旋转起点的前一个元素
+  // The last item of each rotation, i.e. the item just before the rotation's start
+  let encoded = rotations.iter().map(|&r| input[(r + n - 1) % n]).collect();
+  let index = rotations.iter().position(|&r| r == 0).unwrap_or(0);
+
+  (encoded, index)
+}
+
+/// 用倍增法对序列的所有循环旋转排序，返回按字典序排列的旋转起点。
+/// Sorts all cyclic rotations of a sequence by prefix doubling, returning the rotation starts in
+/// lexicographic order.
+///
+/// 第 k 轮结束时，`class[i]` 是从 i 开始、长为 2^k 的循环子串的排名。按 (class[i], class[i + 2^k])
+/// 排序即可得到长为 2^(k+1) 的子串的顺序；由于上一轮已经按第二关键字有序，只需对第一关键字做一次计数排序。
+/// After round k, `class[i]` ranks the cyclic substring of length 2^k starting at i. Ordering by
+/// (class[i], class[i + 2^k]) ranks the substrings of length 2^(k+1); as the previous order
+/// already sorts by the second key, one counting sort on the first key suffices.
+fn sort_rotations<T: Ord>(input: &[T]) -> Vec<usize> {
+  let n = input.len();
+
+  if n == 0 {
+    return vec![];
+  }
+
+  let mut order: Vec<usize> = (0..n).collect();
+  order.sort_by(|&a, &b| input[a].cmp(&input[b]));
+
+  let mut class = vec![0; n];
+  let mut classes = 1;
+
+  for i in 1..n {
+    if input[order[i]] != input[order[i - 1]] {
+      classes += 1;
+    }
+
+    class[order[i]] = classes - 1;
+  }
+
+  let mut len = 1;
+
+  while len < n && classes < n {
+    // 按第二关键字排好序：把每个起点向前移 len
+    // Already sorted by the second key: shift every start back by len
+    let shifted: Vec<usize> = order.iter().map(|&i| (i + n - len) % n).collect();
+
+    // 按第一关键字做稳定的计数排序
+    // A stable counting sort on the first key
+    let mut count = vec![0; classes];
+
+    for &i in &shifted {
+      count[class[i]] += 1;
+    }
+
+    for c in 1..classes {
+      count[c] += count[c - 1];
+    }
+
+    for &i in shifted.iter().rev() {
+      count[class[i]] -= 1;
+      order[count[class[i]]] = i;
+    }
+
+    // 重新计算排名 (Recompute the ranks)
+    let key = |i: usize| (class[i], class[(i + len) % n]);
+    let mut next = vec![0; n];
+    classes = 1;
+
+    for i in 1..n {
+      if key(order[i]) != key(order[i - 1]) {
+        classes += 1;
+      }
+
+      next[order[i]] = classes - 1;
+    }
+
+    class = next;
+    len *= 2;
+  }
+
+  order
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use proptest::prelude::*;
+  use rand::Rng;
+  use std::time::{Duration, Instant};
+
+  /// 构造所有旋转字符串再按字典序排序的朴素实现，用作对照
+  /// The naive implementation, materializing every rotation and sorting them, as a reference
+  fn naive_bwt(input: &str) -> (String, usize) {
+    let len = input.len();
+    let mut table: Vec<String> = (0..len)
+      .map(|i| input[i..].to_owned() + &input[..i])
+      .collect();
+
+    table.sort();
+
+    let encoded = table.iter().map(|r| r.chars().last().unwrap()).collect();
+    let index = table.iter().rposition(|r| r == input).unwrap_or(0);
+
+    (encoded, index)
+  }
 
   #[test]
   fn basic() {
@@ -200,6 +470,211 @@ mod tests {
       ""
     );
   }
+
+  #[test]
+  fn mixed_case() {
+    for input in [
+      "Aa",
+      "aA",
+      "Banana",
+      "aAaAaA",
+      "The Quick Brown Fox jumps over the Lazy Dog",
+      "RuSt Is FuN, rUsT iS fUn",
+    ] {
+      assert_eq!(
+        inv_burrows_wheeler_transform(burrows_wheeler_transform(input.to_string())),
+        input
+      );
+    }
+
+    // 大写字母排在小写字母之前
+    // Uppercase letters sort before lowercase ones
+    assert_eq!(
+      burrows_wheeler_transform("Banana".to_string()),
+      ("annBaa".to_string(), 0)
+    );
+  }
+
+  #[test]
+  fn matches_naive_implementation() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let len = rng.gen_range(0..30);
+      let input: String = (0..len)
+        .map(|_| b"abAB"[rng.gen_range(0..4)] as char)
+        .collect();
+      let (encoded, index) = burrows_wheeler_transform(input.clone());
+      let (expected, expected_index) = naive_bwt(&input);
+
+      assert_eq!(encoded, expected, "{input}");
+
+      // 周期性的输入有多个相同的旋转，索引可以指向其中任意一个
+      // Periodic inputs have several equal rotations, and the index may point at any of them
+      let rotation = |i: usize| input[i..].to_owned() + &input[..i];
+      let mut rotations: Vec<String> = (0..len).map(rotation).collect();
+      rotations.sort();
+      if len > 0 {
+        assert_eq!(rotations[index], rotations[expected_index]);
+      }
+    }
+  }
+
+  #[test]
+  fn bytes() {
+    assert_eq!(bwt_bytes(b""), (vec![], 0));
+    assert_eq!(bwt_bytes(b"banana"), (b"nnbaaa".to_vec(), 3));
+    assert_eq!(inv_bwt_bytes(b"nnbaaa", 3), b"banana");
+
+    let binary = [0xFF, 0x00, 0x7F, 0x00, 0xFF, 0x80];
+    let (encoded, index) = bwt_bytes(&binary);
+    assert_eq!(inv_bwt_bytes(&encoded, index), binary);
+  }
+
+  #[test]
+  fn documented_banana() {
+    assert_eq!(
+      burrows_wheeler_transform("banana".to_owned()),
+      ("nnbaaa".to_owned(), 3)
+    );
+    assert_eq!(
+      inv_burrows_wheeler_transform(("nnbaaa".to_owned(), 3)),
+      "banana"
+    );
+    assert_eq!(bwt_with_sentinel(b"banana", b'$').unwrap(), b"annb$aa");
+    assert_eq!(inv_bwt_with_sentinel(b"annb$aa", b'$').unwrap(), b"banana");
+  }
+
+  #[test]
+  fn sentinel_collision() {
+    assert_eq!(
+      bwt_with_sentinel(b"ba$nana", b'$'),
+      Err(BwtError::SentinelInInput {
+        sentinel: b'$',
+        position: 2
+      })
+    );
+    assert_eq!(
+      inv_bwt_with_sentinel(b"annbaa", b'$'),
+      Err(BwtError::SentinelCount {
+        sentinel: b'$',
+        count: 0
+      })
+    );
+    assert_eq!(
+      inv_bwt_with_sentinel(b"a$nb$aa", b'$'),
+      Err(BwtError::SentinelCount {
+        sentinel: b'$',
+        count: 2
+      })
+    );
+    assert_eq!(
+      BwtError::SentinelInInput {
+        sentinel: 0,
+        position: 5
+      }
+      .to_string(),
+      "sentinel 0x00 occurs in the input at position 5"
+    );
+  }
+
+  #[test]
+  fn sentinel_empty_input() {
+    assert_eq!(bwt_with_sentinel(b"", b'$').unwrap(), b"$");
+    assert_eq!(inv_bwt_with_sentinel(b"$", b'$').unwrap(), b"");
+    assert!(inv_bwt_with_sentinel(b"", b'$').is_err());
+  }
+
+  #[test]
+  fn sentinel_binary_round_trip() {
+    let inputs: [&[u8]; 4] = [
+      &[0xFF, 0x00, 0xFF, 0x00, 0x01],
+      &[0x00, 0x00, 0x00],
+      &[0xFF; 10],
+      &[0x80, 0x7F, 0xFE, 0x01, 0x00, 0xFF],
+    ];
+
+    for input in inputs {
+      // 哨兵可以是最小、最大或中间的字节，只要不出现在输入中
+      // The sentinel may be the smallest, the largest or a middle byte, as long as it is absent
+      for sentinel in [0x00, 0xFF, 0x42] {
+        if input.contains(&sentinel) {
+          assert!(bwt_with_sentinel(input, sentinel).is_err());
+          continue;
+        }
+
+        let encoded = bwt_with_sentinel(input, sentinel).unwrap();
+
+        assert_eq!(encoded.len(), input.len() + 1);
+        assert_eq!(inv_bwt_with_sentinel(&encoded, sentinel).unwrap(), input);
+      }
+    }
+  }
+
+  #[test]
+  fn periodic_input() {
+    let (encoded, index) = bwt_bytes(b"abababab");
+
+    assert_eq!(encoded, b"bbbbaaaa");
+    assert_eq!(inv_bwt_bytes(&encoded, index), b"abababab");
+  }
+
+  #[test]
+  fn one_megabyte_round_trip() {
+    let mut rng = rand::thread_rng();
+    let words: [&[u8]; 6] = [b"the ", b"quick ", b"brown ", b"fox ", b"jumps ", b"over "];
+    let mut input = Vec::with_capacity(1 << 20);
+
+    while input.len() < 1 << 20 {
+      input.extend_from_slice(words[rng.gen_range(0..words.len())]);
+    }
+
+    let (encoded, index) = bwt_bytes(&input);
+
+    assert_eq!(encoded.len(), input.len());
+    assert_eq!(inv_bwt_bytes(&encoded, index), input);
+  }
+
+  #[test]
+  fn large_inverse_is_fast() {
+    let mut rng = rand::thread_rng();
+    let input: String = (0..500_000)
+      .map(|_| ['a', 'b', 'c', 'é', '字', '🦀'][rng.gen_range(0..6)])
+      .collect();
+    let transformed = burrows_wheeler_transform(input.clone());
+
+    let start = Instant::now();
+    let decoded = inv_burrows_wheeler_transform(transformed);
+
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert_eq!(decoded, input);
+  }
+
+  proptest! {
+    #[test]
+    fn mixed_case_round_trip(input in "[a-zA-Z ]{0,64}") {
+      let (encoded, index) = burrows_wheeler_transform(input.clone());
+      prop_assert_eq!(inv_burrows_wheeler_transform((encoded, index)), input);
+    }
+
+    #[test]
+    fn unicode_round_trip(input in "\\PC{0,64}") {
+      let (encoded, index) = burrows_wheeler_transform(input.clone());
+      prop_assert_eq!(inv_burrows_wheeler_transform((encoded, index)), input);
+    }
+
+    #[test]
+    fn sentinel_round_trip(input in proptest::collection::vec(1..=255u8, 0..128)) {
+      let encoded = bwt_with_sentinel(&input, 0).unwrap();
+      prop_assert_eq!(inv_bwt_with_sentinel(&encoded, 0).unwrap(), input);
+    }
+
+    #[test]
+    fn bytes_round_trip(input in proptest::collection::vec(any::<u8>(), 0..128)) {
+      let (encoded, index) = bwt_bytes(&input);
+      prop_assert_eq!(inv_bwt_bytes(&encoded, index), input);
+    }
+  }
 }
 
 fn main() {}
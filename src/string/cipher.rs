@@ -0,0 +1,284 @@
+use std::fmt;
+
+/// 维吉尼亚密码的密钥无效时返回的错误。
+/// The error returned when a Vigenère key is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CipherError {
+  /// 密钥为空。 (The key is empty.)
+  EmptyKey,
+  /// 密钥在第 `position` 个字符处含有非 ASCII 字母的字符 `found`。
+  /// (The key has `found`, which is no ASCII letter, at char index `position`.)
+  InvalidKeyChar { position: usize, found: char },
+}
+
+impl fmt::Display for CipherError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CipherError::EmptyKey => write!(f, "key is empty"),
+      CipherError::InvalidKeyChar { position, found } => {
+        write!(f, "key has non-letter {found:?} at position {position}")
+      }
+    }
+  }
+}
+
+impl std::error::Error for CipherError {}
+
+/// 用凯撒密码加密：每个 ASCII 字母在字母表中向后移动 `shift` 位（模 26），保持大小写，其他字符不变。
+/// Encrypts with the Caesar cipher: every ASCII letter moves `shift` places forward in the
+/// alphabet (mod 26), keeping its case, and every other char is left as it is.
+///
+/// # 参数 (Arguments)
+///
+/// * `text` - 要加密的明文。
+///   The plaintext to encrypt.
+/// * `shift` - 移动的位数，26 与 0 等价。
+///   The number of places to shift, 26 being the same as 0.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(caesar_encrypt("Hello, World!", 3), "Khoor, Zruog!");
+/// ```
+pub fn caesar_encrypt(text: &str, shift: u8) -> String {
+  text.chars().map(|c| shift_letter(c, shift % 26)).collect()
+}
+
+/// 解密 [`caesar_encrypt`] 用同一个 `shift` 加密的密文。
+/// Decrypts a ciphertext that [`caesar_encrypt`] encrypted with the same `shift`.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(caesar_decrypt("Khoor, Zruog!", 3), "Hello, World!");
+/// ```
+pub fn caesar_decrypt(text: &str, shift: u8) -> String {
+  caesar_encrypt(text, 26 - shift % 26)
+}
+
+/// 列出凯撒密文在全部 26 种移位下的解密结果，按移位从 0 到 25 排列，用于在不知道密钥时逐个尝试。
+/// Lists the decryptions of a Caesar ciphertext under all 26 shifts, ordered from 0 to 25, for
+/// trying them one by one when the key is unknown.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let candidates = caesar_brute_force("Khoor");
+/// assert_eq!(candidates.len(), 26);
+/// assert_eq!(candidates[3], (3, "Hello".to_string()));
+/// ```
+pub fn caesar_brute_force(text: &str) -> Vec<(u8, String)> {
+  (0..26)
+    .map(|shift| (shift, caesar_decrypt(text, shift)))
+    .collect()
+}
+
+/// 用维吉尼亚密码加密：第 i 个字母按密钥中第 i 个字母（循环使用）移动，A/a 表示移动 0 位。
+/// Encrypts with the Vigenère cipher: the i-th letter is shifted by the i-th letter of the key
+/// (used cyclically), A/a meaning a shift of 0.
+///
+/// 只有 ASCII 字母会被移动并保持大小写，其他字符原样保留并且不消耗密钥。密钥不区分大小写。
+/// Only ASCII letters are shifted, keeping their case; every other char is left as it is and does
+/// not advance the key. The key is case-insensitive.
+///
+/// # 参数 (Arguments)
+///
+/// * `text` - 要加密的明文。
+///   The plaintext to encrypt.
+/// * `key` - 密钥，必须非空且只含 ASCII 字母。
+///   The key, which must be non-empty and consist of ASCII letters only.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(vigenere_encrypt("ATTACK AT DAWN", "LEMON").unwrap(), "LXFOPV EF RNHR");
+/// assert!(vigenere_encrypt("text", "").is_err());
+/// ```
+pub fn vigenere_encrypt(text: &str, key: &str) -> Result<String, CipherError> {
+  vigenere(text, key, false)
+}
+
+/// 解密 [`vigenere_encrypt`] 用同一个密钥加密的密文。
+/// Decrypts a ciphertext that [`vigenere_encrypt`] encrypted with the same key.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(vigenere_decrypt("LXFOPV EF RNHR", "lemon").unwrap(), "ATTACK AT DAWN");
+/// ```
+pub fn vigenere_decrypt(text: &str, key: &str) -> Result<String, CipherError> {
+  vigenere(text, key, true)
+}
+
+fn vigenere(text: &str, key: &str, decrypt: bool) -> Result<String, CipherError> {
+  if key.is_empty() {
+    return Err(CipherError::EmptyKey);
+  }
+
+  let shifts = key
+    .chars()
+    .enumerate()
+    .map(|(position, found)| {
+      if found.is_ascii_alphabetic() {
+        let shift = found.to_ascii_lowercase() as u8 - b'a';
+        Ok(if decrypt { (26 - shift) % 26 } else { shift })
+      } else {
+        Err(CipherError::InvalidKeyChar { position, found })
+      }
+    })
+    .collect::<Result<Vec<u8>, _>>()?;
+
+  // 密钥只在遇到字母时前进
+  // The key only advances on letters
+  let mut next = shifts.iter().cycle();
+
+  Ok(
+    text
+      .chars()
+      .map(|c| {
+        if c.is_ascii_alphabetic() {
+          shift_letter(c, *next.next().unwrap())
+        } else {
+          c
+        }
+      })
+      .collect(),
+  )
+}
+
+/// 把 ASCII 字母向后移动 `shift`（小于 26）位并保持大小写，其他字符原样返回。
+/// Shifts an ASCII letter `shift` (below 26) places forward keeping its case, returning any other
+/// char as it is.
+fn shift_letter(c: char, shift: u8) -> char {
+  let base = match c {
+    'a'..='z' => b'a',
+    'A'..='Z' => b'A',
+    _ => return c,
+  };
+
+  ((c as u8 - base + shift) % 26 + base) as char
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  #[test]
+  fn caesar_known_example() {
+    let plain = "The quick brown fox jumps over the lazy dog.";
+    let cipher = "Wkh txlfn eurzq ira mxpsv ryhu wkh odcb grj.";
+
+    assert_eq!(caesar_encrypt(plain, 3), cipher);
+    assert_eq!(caesar_decrypt(cipher, 3), plain);
+    assert_eq!(caesar_encrypt("xyz XYZ", 3), "abc ABC");
+  }
+
+  #[test]
+  fn caesar_identity_shifts() {
+    let text = "Hello, World! 123";
+
+    assert_eq!(caesar_encrypt(text, 0), text);
+    assert_eq!(caesar_encrypt(text, 26), text);
+    assert_eq!(caesar_encrypt(text, 29), caesar_encrypt(text, 3));
+    assert_eq!(caesar_decrypt(text, 26), text);
+  }
+
+  #[test]
+  fn non_letters_untouched() {
+    let text = "2024-01-01: ¿Qué tal? 你好!";
+    let encrypted = caesar_encrypt(text, 5);
+
+    assert_eq!(encrypted, "2024-01-01: ¿Vzé yfq? 你好!");
+    assert_eq!(
+      vigenere_encrypt("12 + 34 = 46", "key").unwrap(),
+      "12 + 34 = 46"
+    );
+  }
+
+  #[test]
+  fn brute_force_lists_all_shifts() {
+    let candidates = caesar_brute_force(&caesar_encrypt("Attack at dawn", 11));
+
+    assert_eq!(candidates.len(), 26);
+    assert!(candidates
+      .iter()
+      .enumerate()
+      .all(|(i, (s, _))| *s as usize == i));
+    assert_eq!(candidates[11].1, "Attack at dawn");
+    assert_eq!(candidates[0].1, caesar_encrypt("Attack at dawn", 11));
+  }
+
+  #[test]
+  fn vigenere_known_example() {
+    assert_eq!(
+      vigenere_encrypt("ATTACKATDAWN", "LEMON").unwrap(),
+      "LXFOPVEFRNHR"
+    );
+    assert_eq!(
+      vigenere_decrypt("LXFOPVEFRNHR", "LEMON").unwrap(),
+      "ATTACKATDAWN"
+    );
+  }
+
+  #[test]
+  fn vigenere_preserves_case_and_skips_non_letters() {
+    // 空格和标点不消耗密钥，所以与去掉它们后的加密结果一致
+    // Spaces and punctuation do not advance the key, so the letters match the stripped encryption
+    assert_eq!(
+      vigenere_encrypt("Attack, at dawn!", "Lemon").unwrap(),
+      "Lxfopv, ef rnhr!"
+    );
+  }
+
+  #[test]
+  fn invalid_keys() {
+    assert_eq!(vigenere_encrypt("text", ""), Err(CipherError::EmptyKey));
+    assert_eq!(
+      vigenere_encrypt("text", "ab1"),
+      Err(CipherError::InvalidKeyChar {
+        position: 2,
+        found: '1'
+      })
+    );
+    assert_eq!(
+      vigenere_decrypt("text", "clé"),
+      Err(CipherError::InvalidKeyChar {
+        position: 2,
+        found: 'é'
+      })
+    );
+    assert_eq!(
+      CipherError::InvalidKeyChar {
+        position: 2,
+        found: '1'
+      }
+      .to_string(),
+      "key has non-letter '1' at position 2"
+    );
+  }
+
+  proptest! {
+    #[test]
+    fn caesar_round_trip(text in "\\PC{0,64}", shift in any::<u8>()) {
+      prop_assert_eq!(caesar_decrypt(&caesar_encrypt(&text, shift), shift), text);
+    }
+
+    #[test]
+    fn vigenere_round_trip(text in "\\PC{0,64}", key in "[a-zA-Z]{1,10}") {
+      let encrypted = vigenere_encrypt(&text, &key).unwrap();
+
+      prop_assert_eq!(encrypted.chars().count(), text.chars().count());
+      prop_assert_eq!(vigenere_decrypt(&encrypted, &key).unwrap(), text);
+    }
+
+    #[test]
+    fn single_letter_key_is_caesar(text in "[a-zA-Z .,!]{0,64}", shift in 0..26u8) {
+      let key = ((b'a' + shift) as char).to_string();
+
+      prop_assert_eq!(vigenere_encrypt(&text, &key).unwrap(), caesar_encrypt(&text, shift));
+    }
+  }
+}
+
+fn main() {}
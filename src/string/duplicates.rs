@@ -0,0 +1,193 @@
+use rust_algorithm::string::hashing::PrefixHasher;
+use std::collections::HashMap;
+
+/// 找出在字符串中至少出现两次（允许重叠）的最长子串；没有重复的子串时返回 `None`。
+/// Finds the longest substring occurring at least twice (possibly overlapping), or `None` if no
+/// substring repeats.
+///
+/// 长度按字符计，有多个最长的重复子串时返回最先出现的那个。
+/// Lengths count chars, and the one occurring first is returned if several are longest.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要查找重复子串的字符串。
+///   The string to search for duplicated substrings.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(longest_duplicated_substring("banana"), Some("ana".to_string()));
+/// assert_eq!(longest_duplicated_substring("abc"), None);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 如果长为 k 的子串重复出现，那么它长为 k - 1 的前缀也重复出现，所以可以二分答案的长度。
+/// 对每个候选长度，用 [`PrefixHasher`] 在 O(1) 时间内求出每个窗口的哈希值并放入 HashMap，
+/// 哈希值相同时再逐字节验证，排除哈希冲突。
+/// If a substring of length k repeats, so does its prefix of length k - 1, so the answer length
+/// can be binary searched. For each candidate length, [`PrefixHasher`] gives the hash of every
+/// window in O(1) and the hashes go into a HashMap; equal hashes are verified byte by byte to
+/// rule out collisions.
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n log n) expected time and O(n) space.
+/// 期望时间复杂度为 O(n log n)，空间复杂度为 O(n)。
+pub fn longest_duplicated_substring(s: &str) -> Option<String> {
+  // 每个字符的起始字节位置，末尾再加上字符串的长度
+  // The byte offset of every char, followed by the length of the string
+  let bounds: Vec<usize> = s
+    .char_indices()
+    .map(|(i, _)| i)
+    .chain(std::iter::once(s.len()))
+    .collect();
+  let hasher = PrefixHasher::new(s.as_bytes());
+
+  let (mut lo, mut hi) = (0, bounds.len() - 1);
+  let mut found = None;
+
+  while lo < hi {
+    let mid = (lo + hi).div_ceil(2);
+
+    match duplicate_of_len(&hasher, &bounds, mid) {
+      Some(start) => {
+        lo = mid;
+        found = Some(start);
+      }
+      None => hi = mid - 1,
+    }
+  }
+
+  found.map(|start| s[bounds[start]..bounds[start + lo]].to_string())
+}
+
+/// 返回第一个在前面已经出现过的、长为 `len` 个字符的窗口的起始字符索引。
+/// Returns the starting char index of the first window of `len` chars that already occurred
+/// earlier.
+fn duplicate_of_len(hasher: &PrefixHasher, bounds: &[usize], len: usize) -> Option<usize> {
+  let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+
+  for i in 0..bounds.len() - len {
+    let window = bounds[i]..bounds[i + len];
+    let candidates = seen
+      .entry(hasher.hash_range(window.start, window.end))
+      .or_default();
+
+    if let Some(&first) = candidates
+      .iter()
+      .find(|&&j| hasher.equals(bounds[j]..bounds[j + len], window.clone()))
+    {
+      return Some(first);
+    }
+
+    candidates.push(i);
+  }
+
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  /// 逐对比较所有子串的暴力解法，返回最长重复子串的长度。
+  /// (The brute force comparing every pair of substrings, returning the longest repeat's length.)
+  fn brute_force_len(s: &str) -> usize {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+    let mut best = 0;
+
+    for i in 0..n {
+      for j in i + 1..n {
+        let common = (0..n - j)
+          .take_while(|&k| chars[i + k] == chars[j + k])
+          .count();
+        best = best.max(common);
+      }
+    }
+
+    best
+  }
+
+  #[test]
+  fn banana() {
+    assert_eq!(
+      longest_duplicated_substring("banana"),
+      Some("ana".to_string())
+    );
+  }
+
+  #[test]
+  fn no_repeats() {
+    assert_eq!(longest_duplicated_substring("abcdefg"), None);
+    assert_eq!(longest_duplicated_substring("a"), None);
+    assert_eq!(longest_duplicated_substring(""), None);
+  }
+
+  #[test]
+  fn overlapping_duplicates() {
+    assert_eq!(
+      longest_duplicated_substring("aaaaa"),
+      Some("aaaa".to_string())
+    );
+    assert_eq!(
+      longest_duplicated_substring("abababa"),
+      Some("ababa".to_string())
+    );
+  }
+
+  #[test]
+  fn first_occurrence_wins() {
+    assert_eq!(
+      longest_duplicated_substring("abxcdxabycdy"),
+      Some("ab".to_string())
+    );
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    assert_eq!(
+      longest_duplicated_substring("你好你好吗"),
+      Some("你好".to_string())
+    );
+    assert_eq!(longest_duplicated_substring("éaéb"), Some("é".to_string()));
+  }
+
+  #[test]
+  fn large_periodic_input_is_fast() {
+    let s = "abcdefg".repeat(500_000 / 7);
+    let start = std::time::Instant::now();
+    let dup = longest_duplicated_substring(&s).unwrap();
+
+    assert_eq!(dup.len(), s.len() - 7);
+    assert!(s.starts_with(&dup));
+    assert!(start.elapsed().as_secs() < 5, "took {:?}", start.elapsed());
+  }
+
+  #[test]
+  fn matches_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+      let s: String = (0..rng.gen_range(0..40))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let dup = longest_duplicated_substring(&s);
+
+      assert_eq!(
+        dup.as_ref().map_or(0, |d| d.len()),
+        brute_force_len(&s),
+        "{s}"
+      );
+
+      if let Some(dup) = dup {
+        let first = s.find(&dup).unwrap();
+        assert!(s[first + 1..].contains(&dup), "{s}: {dup}");
+      }
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,604 @@
+use std::collections::HashMap;
+
+/// 计算两个字符串之间的 Levenshtein 编辑距离：把 `a` 变成 `b` 所需的最少插入、删除和替换次数。
+/// Computes the Levenshtein edit distance between two strings: the minimum number of insertions,
+/// deletions and substitutions turning `a` into `b`.
+///
+/// 距离按字符（`char`）而不是字节计算，所以一个多字节字符的替换只算一次。
+/// The distance counts chars rather than bytes, so substituting a multi-byte character counts
+/// once.
+///
+/// # 参数 (Arguments)
+///
+/// * `a` - 源字符串。
+///   The source string.
+/// * `b` - 目标字符串。
+///   The target string.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// `d[i][j]` is the distance between the first i chars of `a` and the first j chars of `b`. Each
+/// row depends only on the previous one, so two rows over the shorter string suffice.
+/// `d[i][j]` 是 `a` 的前 i 个字符与 `b` 的前 j 个字符之间的距离。每一行只依赖上一行，
+/// 因此只需在较短的字符串上保留两行。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(|a| * |b|) and the space complexity is O(min(|a|, |b|)).
+/// 时间复杂度为 O(|a| * |b|)，空间复杂度为 O(min(|a|, |b|))。
+pub fn levenshtein(a: &str, b: &str) -> usize {
+  let (a, b) = shorter_last(a, b);
+
+  let mut prev: Vec<usize> = (0..=b.len()).collect();
+  let mut cur = vec![0; b.len() + 1];
+
+  for (i, ca) in a.iter().enumerate() {
+    cur[0] = i + 1;
+
+    for (j, cb) in b.iter().enumerate() {
+      let substitute = prev[j] + usize::from(ca != cb);
+      cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+    }
+
+    std::mem::swap(&mut prev, &mut cur);
+  }
+
+  prev[b.len()]
+}
+
+/// 计算两个字符串之间的 Levenshtein 编辑距离，距离超过 `max` 时提前放弃并返回 None。
+/// Computes the Levenshtein edit distance between two strings, giving up early and returning
+/// None once the distance exceeds `max`.
+///
+/// 适用于模糊匹配：大多数候选字符串与查询相差很远，只需要知道距离是否在 `max` 以内。
+/// This suits fuzzy matching, where most candidates are far from the query and all that matters
+/// is whether the distance is within `max`.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(levenshtein_bounded("kitten", "sitting", 3), Some(3));
+/// assert_eq!(levenshtein_bounded("kitten", "sitting", 2), None);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// A path through `d[i][j]` with `|i - j| > max` already costs more than `max`, so each row only
+/// fills the band of width 2 * max + 1 around the diagonal; everything outside counts as
+/// "too far". Once a whole band row exceeds `max`, later rows cannot come back under it.
+/// 经过 `|i - j| > max` 的格子的路径代价已经超过 `max`，所以每一行只计算对角线两侧宽为 2 * max + 1 的带，
+/// 带外的格子都视为"太远"。一旦带内整行都超过 `max`，之后的行也不可能回到 `max` 以内。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(min(|a|, |b|) * max) and the space complexity is O(min(|a|, |b|)).
+/// 时间复杂度为 O(min(|a|, |b|) * max)，空间复杂度为 O(min(|a|, |b|))。
+pub fn levenshtein_bounded(a: &str, b: &str, max: usize) -> Option<usize> {
+  let (a, b) = shorter_last(a, b);
+
+  // 长度之差是距离的下界
+  // The difference in length is a lower bound on the distance
+  if a.len() - b.len() > max {
+    return None;
+  }
+
+  // 超出界限的值统一记为 max + 1
+  // Every value beyond the bound is recorded as max + 1
+  let too_far = max.saturating_add(1);
+
+  let mut prev: Vec<usize> = (0..=b.len()).map(|j| j.min(too_far)).collect();
+  let mut cur = vec![too_far; b.len() + 1];
+
+  for (i, ca) in a.iter().enumerate() {
+    let i = i + 1;
+
+    // 带的范围 [lo, hi]，带外左右两侧的格子视为太远
+    // The band [lo, hi]; cells just outside it on either side count as too far
+    let lo = i.saturating_sub(max).max(1);
+    let hi = i.saturating_add(max).min(b.len());
+
+    cur[0] = i.min(too_far);
+    cur[lo - 1] = if lo == 1 { cur[0] } else { too_far };
+
+    if hi < b.len() {
+      cur[hi + 1] = too_far;
+    }
+
+    let mut row_min = cur[lo - 1];
+
+    for j in lo..=hi {
+      let substitute = prev[j - 1] + usize::from(*ca != b[j - 1]);
+      let value = substitute.min(prev[j] + 1).min(cur[j - 1] + 1).min(too_far);
+
+      cur[j] = value;
+      row_min = row_min.min(value);
+    }
+
+    if row_min > max {
+      return None;
+    }
+
+    std::mem::swap(&mut prev, &mut cur);
+  }
+
+  Some(prev[b.len()]).filter(|&distance| distance <= max)
+}
+
+/// 计算两个字符串之间的最优字符串对齐（OSA）距离：在 Levenshtein 距离的基础上，交换两个相邻字符也只算一次编辑。
+/// Computes the optimal string alignment (OSA) distance between two strings: Levenshtein distance
+/// where swapping two adjacent characters also counts as a single edit.
+///
+/// OSA 是 Damerau-Levenshtein 距离的受限版本：每个子串最多只能被编辑一次，
+/// 所以不能先交换两个字符再在它们之间插入字符。例如 "CA" 到 "ABC" 的 OSA 距离是 3，
+/// 而真正的 Damerau-Levenshtein 距离是 2（"CA" -> "AC" -> "ABC"）。OSA 也因此不满足三角不等式。
+/// OSA is the restricted form of Damerau-Levenshtein distance: no substring is edited more than
+/// once, so two characters cannot be swapped and then have characters inserted between them. For
+/// example, "CA" to "ABC" is 3 under OSA but 2 under true Damerau-Levenshtein ("CA" -> "AC" ->
+/// "ABC"). For the same reason OSA does not satisfy the triangle inequality.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(osa_distance("abcd", "abdc"), 1);
+/// assert_eq!(osa_distance("CA", "ABC"), 3);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(|a| * |b|) and the space complexity is O(min(|a|, |b|)).
+/// 时间复杂度为 O(|a| * |b|)，空间复杂度为 O(min(|a|, |b|))。
+pub fn osa_distance(a: &str, b: &str) -> usize {
+  let (a, b) = shorter_last(a, b);
+
+  // 交换需要回看两行，所以保留三行
+  // A transposition looks two rows back, so three rows are kept
+  let mut before: Vec<usize> = vec![0; b.len() + 1];
+  let mut prev: Vec<usize> = (0..=b.len()).collect();
+  let mut cur = vec![0; b.len() + 1];
+
+  for i in 0..a.len() {
+    cur[0] = i + 1;
+
+    for j in 0..b.len() {
+      let substitute = prev[j] + usize::from(a[i] != b[j]);
+      cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+
+      if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+        cur[j + 1] = cur[j + 1].min(before[j - 1] + 1);
+      }
+    }
+
+    std::mem::swap(&mut before, &mut prev);
+    std::mem::swap(&mut prev, &mut cur);
+  }
+
+  prev[b.len()]
+}
+
+/// 计算两个字符串之间的 Damerau-Levenshtein 距离：允许插入、删除、替换和交换相邻字符，每种操作算一次编辑。
+/// Computes the Damerau-Levenshtein distance between two strings: insertions, deletions,
+/// substitutions and transpositions of adjacent characters each count as one edit.
+///
+/// 与 [`osa_distance`] 不同，交换后的字符之间仍然可以插入或删除字符，所以这是一个真正的度量，
+/// 且结果不会超过 [`osa_distance`] 和 [`levenshtein`]。
+/// Unlike [`osa_distance`], characters may still be inserted or deleted between transposed ones,
+/// so this is a true metric and never exceeds [`osa_distance`] or [`levenshtein`].
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(damerau_levenshtein("abcd", "abdc"), 1);
+/// assert_eq!(damerau_levenshtein("CA", "ABC"), 2);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// This is the Lowrance-Wagner algorithm. Besides the usual moves, `d[i][j]` may come from the
+/// last row k where `b[j]` appeared in `a` and the last column l where `a[i]` appeared in `b`:
+/// transpose those two characters and delete or insert everything between them, at a cost of
+/// `d[k - 1][l - 1] + (i - k - 1) + 1 + (j - l - 1)`. This needs the full table, kept with a
+/// sentinel row and column.
+/// 这是 Lowrance-Wagner 算法。除了通常的转移之外，`d[i][j]` 还可以来自 `b[j]` 在 `a` 中最后出现的行 k
+/// 以及 `a[i]` 在 `b` 中最后出现的列 l：交换这两个字符，并删除或插入它们之间的所有字符，
+/// 代价为 `d[k - 1][l - 1] + (i - k - 1) + 1 + (j - l - 1)`。这需要完整的表，并带有一行一列哨兵。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(|a| * |b|).
+/// 时间复杂度和空间复杂度都是 O(|a| * |b|)。
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+  let (la, lb) = (a.len(), b.len());
+
+  // 哨兵值大于任何可能的距离
+  // The sentinel exceeds any possible distance
+  let sentinel = la + lb;
+
+  // d[i + 1][j + 1] 是 a 的前 i 个字符与 b 的前 j 个字符之间的距离，第 0 行和第 0 列是哨兵
+  // d[i + 1][j + 1] is the distance between the first i chars of a and the first j chars of b;
+  // row 0 and column 0 hold the sentinel
+  let mut d = vec![vec![0; lb + 2]; la + 2];
+  d[0][0] = sentinel;
+
+  for i in 0..=la {
+    d[i + 1][0] = sentinel;
+    d[i + 1][1] = i;
+  }
+
+  for j in 0..=lb {
+    d[0][j + 1] = sentinel;
+    d[1][j + 1] = j;
+  }
+
+  // 每个字符在 a 中最后出现的行（从 1 开始，0 表示未出现）
+  // The last row (1-based, 0 if none) in which each char appeared in a
+  let mut last_row: HashMap<char, usize> = HashMap::new();
+
+  for i in 1..=la {
+    // 当前行中 a[i - 1] 在 b 中最后匹配的列
+    // The last column in this row where a[i - 1] matched b
+    let mut last_col = 0;
+
+    for j in 1..=lb {
+      let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+      let l = last_col;
+
+      let cost = if a[i - 1] == b[j - 1] {
+        last_col = j;
+        0
+      } else {
+        1
+      };
+
+      d[i + 1][j + 1] = (d[i][j] + cost)
+        .min(d[i + 1][j] + 1)
+        .min(d[i][j + 1] + 1)
+        .min(d[k][l] + (i - k - 1) + 1 + (j - l - 1));
+    }
+
+    last_row.insert(a[i - 1], i);
+  }
+
+  d[la + 1][lb + 1]
+}
+
+/// 编辑脚本中的一步操作。 (One step of an edit script.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+  /// 保留 `a` 中的字符。 (Keeps a char of `a`.)
+  Keep(char),
+  /// 插入 `b` 中的字符。 (Inserts a char of `b`.)
+  Insert(char),
+  /// 删除 `a` 中的字符。 (Deletes a char of `a`.)
+  Delete(char),
+  /// 把 `a` 中的字符替换为 `b` 中的字符。 (Replaces a char of `a` with a char of `b`.)
+  Substitute(char, char),
+}
+
+/// 返回把 `a` 变成 `b` 的一个最短编辑脚本，按从前到后的顺序依次作用于 `a` 即可得到 `b`。
+/// Returns a shortest edit script turning `a` into `b`; applying its operations front to back to
+/// `a` yields `b`.
+///
+/// 脚本中除 [`EditOp::Keep`] 之外的操作个数等于 [`levenshtein`] 距离。回溯时字符相同则优先保留，
+/// 其次依次是替换、删除和插入，使得到的差异看起来更自然。
+/// The number of operations other than [`EditOp::Keep`] equals the [`levenshtein`] distance.
+/// The traceback prefers keeping equal chars, then substituting, deleting and inserting, in that
+/// order, so diffs look natural.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let script = edit_script("cat", "cut");
+/// assert_eq!(script, vec![EditOp::Keep('c'), EditOp::Substitute('a', 'u'), EditOp::Keep('t')]);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// The full Levenshtein table is filled in, then walked back from the bottom-right corner; each
+/// step moves to a neighbouring cell the current value could have come from.
+/// 先填写完整的 Levenshtein 表，再从右下角回溯，每一步都移动到当前值可能来自的相邻格子。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(|a| * |b|).
+/// 时间复杂度和空间复杂度都是 O(|a| * |b|)。
+pub fn edit_script(a: &str, b: &str) -> Vec<EditOp> {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+  let (la, lb) = (a.len(), b.len());
+
+  let mut d = vec![vec![0; lb + 1]; la + 1];
+
+  for (i, row) in d.iter_mut().enumerate() {
+    row[0] = i;
+  }
+
+  for (j, cell) in d[0].iter_mut().enumerate() {
+    *cell = j;
+  }
+
+  for i in 1..=la {
+    for j in 1..=lb {
+      let substitute = d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+      d[i][j] = substitute.min(d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+    }
+  }
+
+  // 从 (la, lb) 回溯到 (0, 0)，得到的操作是逆序的
+  // Trace back from (la, lb) to (0, 0), collecting operations in reverse
+  let mut script = Vec::with_capacity(la.max(lb));
+  let (mut i, mut j) = (la, lb);
+
+  while i > 0 || j > 0 {
+    if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d[i][j] == d[i - 1][j - 1] {
+      script.push(EditOp::Keep(a[i - 1]));
+      i -= 1;
+      j -= 1;
+    } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+      script.push(EditOp::Substitute(a[i - 1], b[j - 1]));
+      i -= 1;
+      j -= 1;
+    } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+      script.push(EditOp::Delete(a[i - 1]));
+      i -= 1;
+    } else {
+      script.push(EditOp::Insert(b[j - 1]));
+      j -= 1;
+    }
+  }
+
+  script.reverse();
+  script
+}
+
+/// 按字符拆分两个字符串，较短的放在后面，使每一行的长度最小。
+/// Splits both strings into chars with the shorter one last, keeping each row as short as possible.
+fn shorter_last(a: &str, b: &str) -> (Vec<char>, Vec<char>) {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+
+  if a.len() < b.len() {
+    (b, a)
+  } else {
+    (a, b)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  #[test]
+  fn known_pairs() {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("sitting", "kitten"), 3);
+    assert_eq!(levenshtein("flaw", "lawn"), 2);
+    assert_eq!(levenshtein("intention", "execution"), 5);
+  }
+
+  #[test]
+  fn empty_strings() {
+    assert_eq!(levenshtein("", ""), 0);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("abc", ""), 3);
+  }
+
+  #[test]
+  fn identical_strings() {
+    assert_eq!(levenshtein("algorithm", "algorithm"), 0);
+  }
+
+  #[test]
+  fn transposition_counts_as_two() {
+    assert_eq!(levenshtein("ab", "ba"), 2);
+  }
+
+  #[test]
+  fn multi_byte_characters() {
+    assert_eq!(levenshtein("café", "cafe"), 1);
+    assert_eq!(levenshtein("你好世界", "你好"), 2);
+    assert_eq!(levenshtein("🦀", "🐍"), 1);
+  }
+
+  #[test]
+  fn bounded_within_bound() {
+    assert_eq!(levenshtein_bounded("kitten", "sitting", 3), Some(3));
+    assert_eq!(levenshtein_bounded("kitten", "sitting", 10), Some(3));
+    assert_eq!(levenshtein_bounded("", "", 0), Some(0));
+    assert_eq!(levenshtein_bounded("same", "same", 0), Some(0));
+  }
+
+  #[test]
+  fn bounded_exceeded() {
+    assert_eq!(levenshtein_bounded("kitten", "sitting", 2), None);
+    assert_eq!(levenshtein_bounded("a", "abcdef", 3), None);
+    assert_eq!(levenshtein_bounded("abcdef", "uvwxyz", 5), None);
+  }
+
+  #[test]
+  fn bounded_agrees_with_unbounded() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let a: String = (0..rng.gen_range(0..12))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let b: String = (0..rng.gen_range(0..12))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let max = rng.gen_range(0..8);
+      let distance = levenshtein(&a, &b);
+
+      let expected = Some(distance).filter(|&d| d <= max);
+      assert_eq!(
+        levenshtein_bounded(&a, &b, max),
+        expected,
+        "{a:?} {b:?} {max}"
+      );
+    }
+  }
+
+  #[test]
+  fn adjacent_transposition_costs_one() {
+    assert_eq!(osa_distance("abcd", "abdc"), 1);
+    assert_eq!(damerau_levenshtein("abcd", "abdc"), 1);
+    assert_eq!(levenshtein("abcd", "abdc"), 2);
+  }
+
+  #[test]
+  fn osa_and_damerau_levenshtein_disagree() {
+    assert_eq!(osa_distance("CA", "ABC"), 3);
+    assert_eq!(damerau_levenshtein("CA", "ABC"), 2);
+  }
+
+  #[test]
+  fn transposition_empty_strings() {
+    assert_eq!(osa_distance("", ""), 0);
+    assert_eq!(osa_distance("", "abc"), 3);
+    assert_eq!(damerau_levenshtein("", ""), 0);
+    assert_eq!(damerau_levenshtein("abc", ""), 3);
+    assert_eq!(damerau_levenshtein("", "abc"), 3);
+  }
+
+  #[test]
+  fn transposition_multi_byte_characters() {
+    assert_eq!(osa_distance("你好", "好你"), 1);
+    assert_eq!(damerau_levenshtein("🦀🐍", "🐍🦀"), 1);
+  }
+
+  #[test]
+  fn damerau_levenshtein_is_at_most_osa_and_levenshtein() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let a: String = (0..rng.gen_range(0..10))
+        .map(|_| rng.gen_range('a'..='d'))
+        .collect();
+      let b: String = (0..rng.gen_range(0..10))
+        .map(|_| rng.gen_range('a'..='d'))
+        .collect();
+
+      let dl = damerau_levenshtein(&a, &b);
+      let osa = osa_distance(&a, &b);
+      let lev = levenshtein(&a, &b);
+
+      assert!(dl <= osa && osa <= lev, "{a:?} {b:?}: {dl} {osa} {lev}");
+      assert_eq!(dl, damerau_levenshtein(&b, &a));
+    }
+  }
+
+  /// 把编辑脚本作用于 `a`，并检查脚本中记录的源字符与 `a` 一致
+  /// Applies an edit script to `a`, checking the source chars it records against `a`
+  fn apply(a: &str, script: &[EditOp]) -> String {
+    let mut source = a.chars();
+    let mut out = String::new();
+
+    for op in script {
+      match *op {
+        EditOp::Keep(c) => {
+          assert_eq!(source.next(), Some(c));
+          out.push(c);
+        }
+        EditOp::Insert(c) => out.push(c),
+        EditOp::Delete(c) => assert_eq!(source.next(), Some(c)),
+        EditOp::Substitute(from, to) => {
+          assert_eq!(source.next(), Some(from));
+          assert_ne!(from, to);
+          out.push(to);
+        }
+      }
+    }
+
+    assert_eq!(source.next(), None);
+    out
+  }
+
+  fn edits(script: &[EditOp]) -> usize {
+    script
+      .iter()
+      .filter(|op| !matches!(op, EditOp::Keep(_)))
+      .count()
+  }
+
+  #[test]
+  fn script_pure_insert() {
+    assert_eq!(
+      edit_script("", "ab"),
+      vec![EditOp::Insert('a'), EditOp::Insert('b')]
+    );
+    assert_eq!(
+      edit_script("ac", "abc"),
+      vec![EditOp::Keep('a'), EditOp::Insert('b'), EditOp::Keep('c')]
+    );
+  }
+
+  #[test]
+  fn script_pure_delete() {
+    assert_eq!(
+      edit_script("ab", ""),
+      vec![EditOp::Delete('a'), EditOp::Delete('b')]
+    );
+    assert_eq!(
+      edit_script("abc", "ac"),
+      vec![EditOp::Keep('a'), EditOp::Delete('b'), EditOp::Keep('c')]
+    );
+  }
+
+  #[test]
+  fn script_prefers_keep() {
+    let script = edit_script("kitten", "sitting");
+
+    assert_eq!(
+      script,
+      vec![
+        EditOp::Substitute('k', 's'),
+        EditOp::Keep('i'),
+        EditOp::Keep('t'),
+        EditOp::Keep('t'),
+        EditOp::Substitute('e', 'i'),
+        EditOp::Keep('n'),
+        EditOp::Insert('g'),
+      ]
+    );
+    assert!(edit_script("same", "same")
+      .iter()
+      .all(|op| matches!(op, EditOp::Keep(_))));
+  }
+
+  #[test]
+  fn script_unicode() {
+    let script = edit_script("你好世界", "你们好世");
+
+    assert_eq!(apply("你好世界", &script), "你们好世");
+    assert_eq!(edits(&script), 2);
+  }
+
+  #[test]
+  fn script_round_trip() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let a: String = (0..rng.gen_range(0..12))
+        .map(|_| rng.gen_range('a'..='d'))
+        .collect();
+      let b: String = (0..rng.gen_range(0..12))
+        .map(|_| rng.gen_range('a'..='d'))
+        .collect();
+      let script = edit_script(&a, &b);
+
+      assert_eq!(apply(&a, &script), b);
+      assert_eq!(edits(&script), levenshtein(&a, &b));
+    }
+  }
+}
+
+fn main() {}
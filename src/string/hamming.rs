@@ -0,0 +1,157 @@
+use std::fmt;
+
+/// 计算汉明距离失败的原因。 (Why a Hamming distance could not be computed.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HammingError {
+  /// 两个输入的长度（字符串按字符计，字节切片按字节计）不同。
+  /// (The inputs differ in length, counted in chars for strings and in bytes for byte slices.)
+  LengthMismatch { left: usize, right: usize },
+}
+
+impl fmt::Display for HammingError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      HammingError::LengthMismatch { left, right } => {
+        write!(f, "inputs have different lengths {} and {}", left, right)
+      }
+    }
+  }
+}
+
+impl std::error::Error for HammingError {}
+
+/// 计算两个字符串之间的汉明距离：对应位置上不同字符的个数。
+/// Computes the Hamming distance between two strings: the number of positions at which their
+/// chars differ.
+///
+/// 按字符而不是字节比较，所以字节长度不同但字符个数相同的字符串也可以比较。
+/// 字符个数不同时返回 [`HammingError::LengthMismatch`]，而不是截断到较短的长度。
+/// Chars rather than bytes are compared, so strings with different byte lengths but the same
+/// number of chars can be compared. Differing char counts return
+/// [`HammingError::LengthMismatch`] rather than truncating to the shorter length.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(hamming_distance("karolin", "kathrin"), Ok(3));
+/// assert!(hamming_distance("abc", "ab").is_err());
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(n) and the space complexity is O(1).
+/// 时间复杂度为 O(n)，空间复杂度为 O(1)。
+pub fn hamming_distance(a: &str, b: &str) -> Result<usize, HammingError> {
+  let (left, right) = (a.chars().count(), b.chars().count());
+
+  if left != right {
+    return Err(HammingError::LengthMismatch { left, right });
+  }
+
+  Ok(a.chars().zip(b.chars()).filter(|(x, y)| x != y).count())
+}
+
+/// 计算两个字节切片之间的汉明距离：对应位置上不同字节的个数。长度不同时返回错误。
+/// Computes the Hamming distance between two byte slices: the number of positions at which
+/// their bytes differ. Differing lengths return an error.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(hamming_distance_bytes(&[1, 2, 3], &[1, 0, 3]), Ok(1));
+/// ```
+pub fn hamming_distance_bytes(a: &[u8], b: &[u8]) -> Result<usize, HammingError> {
+  if a.len() != b.len() {
+    return Err(HammingError::LengthMismatch {
+      left: a.len(),
+      right: b.len(),
+    });
+  }
+
+  Ok(a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+/// 计算两个 64 位整数之间的汉明距离：不同比特的个数，适用于定长指纹（如 SimHash）。
+/// Computes the Hamming distance between two 64-bit integers: the number of differing bits,
+/// as used for fixed-width fingerprints such as SimHash.
+///
+/// 异或之后不同的比特为 1，再用 `count_ones`（通常编译为一条 popcount 指令）统计。
+/// XOR leaves a 1 exactly at the differing bits, which `count_ones` (usually a single popcount
+/// instruction) then counts.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(hamming_distance_u64(0b1011, 0b1001), 1);
+/// ```
+pub fn hamming_distance_u64(a: u64, b: u64) -> u32 {
+  (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  #[test]
+  fn equal_strings() {
+    assert_eq!(hamming_distance("", ""), Ok(0));
+    assert_eq!(hamming_distance("rust", "rust"), Ok(0));
+  }
+
+  #[test]
+  fn completely_different_strings() {
+    assert_eq!(hamming_distance("abcd", "wxyz"), Ok(4));
+    assert_eq!(hamming_distance("karolin", "kathrin"), Ok(3));
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    // "é" 占两个字节，"e" 占一个字节，但字符个数相同
+    // "é" takes two bytes and "e" one, but the char counts match
+    assert_eq!(hamming_distance("café", "cafe"), Ok(1));
+    assert_eq!(hamming_distance("你好", "你们"), Ok(1));
+  }
+
+  #[test]
+  fn length_mismatch() {
+    assert_eq!(
+      hamming_distance("abc", "ab"),
+      Err(HammingError::LengthMismatch { left: 3, right: 2 })
+    );
+    assert_eq!(
+      hamming_distance_bytes(b"a", b"abc"),
+      Err(HammingError::LengthMismatch { left: 1, right: 3 })
+    );
+    assert_eq!(
+      HammingError::LengthMismatch { left: 3, right: 2 }.to_string(),
+      "inputs have different lengths 3 and 2"
+    );
+  }
+
+  #[test]
+  fn bytes() {
+    assert_eq!(hamming_distance_bytes(b"", b""), Ok(0));
+    assert_eq!(hamming_distance_bytes(&[0, 255, 7], &[0, 254, 8]), Ok(2));
+    // 按字节比较时 "é" 与 "e" 的长度不同
+    // Compared as bytes, "é" and "e" differ in length
+    assert!(hamming_distance_bytes("é".as_bytes(), "e".as_bytes()).is_err());
+  }
+
+  #[test]
+  fn u64_matches_bit_by_bit() {
+    let bit_by_bit =
+      |a: u64, b: u64| (0..64).filter(|i| (a >> i) & 1 != (b >> i) & 1).count() as u32;
+    let mut rng = rand::thread_rng();
+
+    assert_eq!(hamming_distance_u64(0, u64::MAX), 64);
+    assert_eq!(hamming_distance_u64(42, 42), 0);
+
+    for _ in 0..1_000 {
+      let (a, b) = (rng.gen(), rng.gen());
+      assert_eq!(hamming_distance_u64(a, b), bit_by_bit(a, b));
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,260 @@
+use rust_algorithm::string::rabin_karp::{BASE, MODULUS};
+use std::ops::Range;
+
+/// 预先计算前缀哈希的多项式哈希器，可以在 O(1) 时间内求出任意子串的哈希值。
+/// A polynomial hasher with precomputed prefix hashes, answering the hash of any substring in
+/// O(1).
+///
+/// 子串 `text[start..end]` 的哈希值与 [`polynomial_hash`] 直接对该子串计算的结果相同：
+/// `prefix[end] - prefix[start] * base^(end - start)`（模 `modulus`）。
+/// The hash of `text[start..end]` equals what [`polynomial_hash`] computes on that substring
+/// directly: `prefix[end] - prefix[start] * base^(end - start)` (mod `modulus`).
+///
+/// 哈希值相同不代表子串相同，需要确定相等时使用 [`PrefixHasher::equals`]，它会在哈希相同时再逐字节验证。
+/// Equal hashes do not imply equal substrings; [`PrefixHasher::equals`] verifies the bytes
+/// whenever the hashes agree.
+///
+/// [`polynomial_hash`]: rust_algorithm::string::rabin_karp::polynomial_hash
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let hasher = PrefixHasher::new(b"abcabc");
+/// assert_eq!(hasher.hash_range(0, 3), hasher.hash_range(3, 6));
+/// assert!(hasher.equals(0..3, 3..6));
+/// assert!(!hasher.equals(0..3, 1..4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefixHasher {
+  text: Vec<u8>,
+  base: u64,
+  modulus: u64,
+  /// `prefix[i]` 是 `text[..i]` 的哈希值。 (`prefix[i]` is the hash of `text[..i]`.)
+  prefix: Vec<u64>,
+  /// `powers[i]` 是 `base^i mod modulus`。 (`powers[i]` is `base^i mod modulus`.)
+  powers: Vec<u64>,
+}
+
+impl PrefixHasher {
+  /// 用 [`rabin_karp`](rust_algorithm::string::rabin_karp) 的基数和模数构建哈希器。
+  /// Builds a hasher with the base and modulus that
+  /// [`rabin_karp`](rust_algorithm::string::rabin_karp) uses.
+  pub fn new(text: &[u8]) -> Self {
+    Self::with_params(text, BASE, MODULUS)
+  }
+
+  /// 用给定的基数和模数构建哈希器，需要 O(n) 的时间和空间。
+  /// Builds a hasher with the given base and modulus, in O(n) time and space.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `modulus` is 0.
+  /// `modulus` 为 0 时 panic。
+  pub fn with_params(text: &[u8], base: u64, modulus: u64) -> Self {
+    assert!(modulus > 0, "modulus must not be 0");
+
+    let mut prefix = Vec::with_capacity(text.len() + 1);
+    let mut powers = Vec::with_capacity(text.len() + 1);
+    prefix.push(0);
+    powers.push(1 % modulus);
+
+    for (i, &b) in text.iter().enumerate() {
+      prefix.push(add(mul(prefix[i], base, modulus), b as u64, modulus));
+      powers.push(mul(powers[i], base, modulus));
+    }
+
+    PrefixHasher {
+      text: text.to_vec(),
+      base,
+      modulus,
+      prefix,
+      powers,
+    }
+  }
+
+  /// 文本的字节长度。 (The length of the text in bytes.)
+  pub fn len(&self) -> usize {
+    self.text.len()
+  }
+
+  /// 文本是否为空。 (Whether the text is empty.)
+  pub fn is_empty(&self) -> bool {
+    self.text.is_empty()
+  }
+
+  /// 返回子串 `text[start..end]` 的哈希值，时间复杂度为 O(1)。空范围的哈希值为 0。
+  /// Returns the hash of the substring `text[start..end]` in O(1). Empty ranges hash to 0.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `start > end` or `end` exceeds the length of the text.
+  /// `start > end` 或 `end` 超过文本长度时 panic。
+  pub fn hash_range(&self, start: usize, end: usize) -> u64 {
+    assert!(
+      start <= end && end <= self.text.len(),
+      "range {start}..{end} out of bounds for length {}",
+      self.text.len()
+    );
+
+    let shifted = mul(self.prefix[start], self.powers[end - start], self.modulus);
+    sub(self.prefix[end], shifted, self.modulus)
+  }
+
+  /// 判断两个子串是否相同：长度或哈希值不同时直接返回 false，哈希值相同时再逐字节验证，所以不会误判。
+  /// Tells whether two substrings are equal: differing lengths or hashes return false right away,
+  /// and equal hashes are verified byte by byte, so there are no false positives.
+  ///
+  /// # Panics
+  ///
+  /// Panics if either range is out of bounds.
+  /// 任一范围越界时 panic。
+  pub fn equals(&self, r1: Range<usize>, r2: Range<usize>) -> bool {
+    if r1.len() != r2.len() {
+      return false;
+    }
+
+    if self.hash_range(r1.start, r1.end) != self.hash_range(r2.start, r2.end) {
+      return false;
+    }
+
+    self.text[r1] == self.text[r2]
+  }
+
+  /// 构建时使用的基数。 (The base the hasher was built with.)
+  pub fn base(&self) -> u64 {
+    self.base
+  }
+
+  /// 构建时使用的模数。 (The modulus the hasher was built with.)
+  pub fn modulus(&self) -> u64 {
+    self.modulus
+  }
+}
+
+fn mul(a: u64, b: u64, modulus: u64) -> u64 {
+  (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+fn add(a: u64, b: u64, modulus: u64) -> u64 {
+  ((a as u128 + b as u128) % modulus as u128) as u64
+}
+
+/// `a - b (mod modulus)`，其中 `a` 和 `b` 都已经小于模数。
+/// `a - b (mod modulus)`, with both `a` and `b` already below the modulus.
+fn sub(a: u64, b: u64, modulus: u64) -> u64 {
+  if a >= b {
+    a - b
+  } else {
+    modulus - (b - a)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+  use rust_algorithm::string::rabin_karp::polynomial_hash;
+
+  #[test]
+  fn equal_and_unequal_ranges() {
+    let hasher = PrefixHasher::new(b"abracadabra");
+
+    assert!(hasher.equals(0..4, 7..11));
+    assert!(hasher.equals(0..1, 3..4));
+    assert!(!hasher.equals(0..4, 1..5));
+    assert!(!hasher.equals(0..4, 0..3));
+    assert_eq!(hasher.hash_range(0, 4), hasher.hash_range(7, 11));
+    assert_ne!(hasher.hash_range(0, 4), hasher.hash_range(1, 5));
+  }
+
+  #[test]
+  fn ranges_at_boundaries() {
+    let text = b"abcab";
+    let hasher = PrefixHasher::new(text);
+
+    assert_eq!(hasher.len(), 5);
+    assert_eq!(
+      hasher.hash_range(0, 5),
+      polynomial_hash(text, BASE, MODULUS)
+    );
+    assert_eq!(hasher.hash_range(0, 0), 0);
+    assert_eq!(hasher.hash_range(5, 5), 0);
+    assert!(hasher.equals(0..2, 3..5));
+    assert!(hasher.equals(5..5, 0..0));
+
+    let empty = PrefixHasher::new(b"");
+    assert!(empty.is_empty());
+    assert_eq!(empty.hash_range(0, 0), 0);
+  }
+
+  #[test]
+  #[should_panic(expected = "out of bounds")]
+  fn range_out_of_bounds() {
+    PrefixHasher::new(b"abc").hash_range(1, 4);
+  }
+
+  #[test]
+  fn matches_direct_hash() {
+    let mut rng = rand::thread_rng();
+    let params = [
+      (BASE, MODULUS),
+      (131, 998_244_353),
+      (257, u64::MAX - 58),
+      (2, 7),
+    ];
+
+    for (base, modulus) in params {
+      let text: Vec<u8> = (0..200).map(|_| rng.gen()).collect();
+      let hasher = PrefixHasher::with_params(&text, base, modulus);
+
+      assert_eq!((hasher.base(), hasher.modulus()), (base, modulus));
+
+      for _ in 0..200 {
+        let start = rng.gen_range(0..=text.len());
+        let end = rng.gen_range(start..=text.len());
+
+        assert_eq!(
+          hasher.hash_range(start, end),
+          polynomial_hash(&text[start..end], base, modulus)
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn forced_collision_is_caught() {
+    // 模 101 时 "hi" 与 "jb" 冲突：104 * 256 + 105 ≡ 106 * 256 + 98 (mod 101)
+    // Modulo 101, "hi" collides with "jb": 104 * 256 + 105 ≡ 106 * 256 + 98 (mod 101)
+    let hasher = PrefixHasher::with_params(b"hi jb", 256, 101);
+
+    assert_eq!(hasher.hash_range(0, 2), hasher.hash_range(3, 5));
+    assert!(!hasher.equals(0..2, 3..5));
+  }
+
+  #[test]
+  fn longest_repeated_substring() {
+    // 二分答案的长度，用子串哈希判断是否有重复
+    // Binary search the length, using substring hashes to detect a repeat
+    let text = b"banana";
+    let hasher = PrefixHasher::new(text);
+    let repeated = |len: usize| -> Option<usize> {
+      (0..=text.len() - len)
+        .find(|&i| (i + 1..=text.len() - len).any(|j| hasher.equals(i..i + len, j..j + len)))
+    };
+
+    let (mut lo, mut hi) = (0, text.len());
+    while lo < hi {
+      let mid = (lo + hi).div_ceil(2);
+      if repeated(mid).is_some() {
+        lo = mid;
+      } else {
+        hi = mid - 1;
+      }
+    }
+
+    let start = repeated(lo).unwrap();
+    assert_eq!(&text[start..start + lo], b"ana");
+  }
+}
+
+fn main() {}
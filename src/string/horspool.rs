@@ -0,0 +1,177 @@
+/// 实现了 Boyer-Moore-Horspool 算法，用于在文本中查找子字符串的所有出现位置（包括相互重叠的位置）。
+/// Implements the Boyer-Moore-Horspool algorithm, finding all occurrences of a substring in a
+/// text, overlapping ones included.
+///
+/// Horspool 算法是 Boyer-Moore 算法的简化版本：只使用一张 256 项的移动表，并且不论在哪里失配，
+/// 都按当前窗口最后一个字节查表决定移动距离，不需要好后缀规则。
+/// The Horspool algorithm simplifies Boyer-Moore: it keeps a single 256-entry shift table and,
+/// wherever the mismatch happens, looks up the shift by the last byte of the current window. There
+/// is no good-suffix rule.
+///
+/// # 参数 (Arguments)
+///
+/// * `text` - 要在其中搜索模式的文本字符串。
+///   The text string in which to search for the pattern.
+/// * `pattern` - 要在文本中搜索的模式字符串。
+///   The pattern string to search for within the text.
+///
+/// # 返回值 (Returns)
+///
+/// A vector containing the starting (byte) indices of all occurrences of the pattern within the
+/// text, the same as `knuth_morris_pratt` returns.
+/// 包含所有模式出现位置起始（字节）索引的向量，与 `knuth_morris_pratt` 的返回值相同。
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let indices = horspool("ABABDABACDABABCABAB", "ABAB");
+/// assert_eq!(indices, vec![0, 10, 15]);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// For every byte `c`, the table holds the distance from the last occurrence of `c` in
+/// `pattern[..m - 1]` to the end of the pattern, or m if `c` does not occur there. The pattern's
+/// final byte is left out on purpose, so a window ending in that byte still moves forward. Since a
+/// shift never passes the previous occurrence of the window's last byte, no match, overlapping or
+/// not, is skipped.
+/// 对每个字节 `c`，移动表记录 `c` 在 `pattern[..m - 1]` 中最后一次出现的位置到模式末尾的距离，
+/// 若不出现则为 m。模式的最后一个字节被有意排除，因此以它结尾的窗口仍然会向前移动。
+/// 每次移动都不会越过窗口最后一个字节的前一次出现，所以不会跳过任何匹配，包括相互重叠的匹配。
+///
+/// # 复杂度 (Complexity)
+///
+/// Preprocessing takes O(m + 256) time and O(256) space. The search takes O(n / m) comparisons in
+/// the best case and O(n * m) in the worst case, where n is the length of the text and m is the
+/// length of the pattern.
+/// 预处理的时间复杂度为 O(m + 256)，空间复杂度为 O(256)。查找在最好情况下需要 O(n / m) 次比较，
+/// 最坏情况下为 O(n * m)，其中 n 是文本的长度，m 是模式的长度。
+pub fn horspool(text: &str, pattern: &str) -> Vec<usize> {
+  let text = text.as_bytes();
+  let pattern = pattern.as_bytes();
+  let (n, m) = (text.len(), pattern.len());
+
+  // 如果模式为空或比文本长，则没有匹配
+  // There is no match if the pattern is empty or longer than the text
+  if m == 0 || m > n {
+    return vec![];
+  }
+
+  let shift = shift_table(pattern);
+
+  let mut ret = vec![];
+  let mut s = 0;
+
+  while s + m <= n {
+    let last = text[s + m - 1];
+
+    // 先比较最后一个字节，再比较其余部分
+    // Compare the last byte first, then the rest
+    if last == pattern[m - 1] && text[s..s + m - 1] == pattern[..m - 1] {
+      ret.push(s);
+    }
+
+    s += shift[last as usize];
+  }
+
+  ret
+}
+
+/// 移动表：按窗口的最后一个字节决定窗口移动的距离。
+/// The shift table: how far the window moves, keyed by the window's last byte.
+fn shift_table(pattern: &[u8]) -> [usize; 256] {
+  let m = pattern.len();
+  let mut shift = [m; 256];
+
+  // 不包括最后一个字节，否则它的移动距离会是 0
+  // The last byte is excluded; otherwise its shift would be 0
+  for (i, &c) in pattern[..m - 1].iter().enumerate() {
+    shift[c as usize] = m - 1 - i;
+  }
+
+  shift
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn each_letter_matches() {
+    assert_eq!(horspool("aaa", "a"), vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn a_few_separate_matches() {
+    assert_eq!(horspool("abababa", "ab"), vec![0, 2, 4]);
+  }
+
+  #[test]
+  fn one_match() {
+    assert_eq!(horspool("ABC ABCDAB ABCDABCDABDE", "ABCDABD"), vec![15]);
+  }
+
+  #[test]
+  fn lots_of_matches() {
+    assert_eq!(horspool("aaabaabaaaaa", "aa"), vec![0, 1, 4, 7, 8, 9, 10]);
+  }
+
+  #[test]
+  fn lots_of_intricate_matches() {
+    assert_eq!(horspool("ababababa", "aba"), vec![0, 2, 4, 6]);
+  }
+
+  #[test]
+  fn not_found0() {
+    assert_eq!(horspool("abcde", "f"), vec![]);
+  }
+
+  #[test]
+  fn not_found1() {
+    assert_eq!(horspool("abcde", "ac"), vec![]);
+  }
+
+  #[test]
+  fn not_found2() {
+    assert_eq!(horspool("ababab", "bababa"), vec![]);
+  }
+
+  #[test]
+  fn empty_string() {
+    assert_eq!(horspool("", "abcdef"), vec![]);
+    assert_eq!(horspool("abcdef", ""), vec![]);
+  }
+
+  #[test]
+  fn pattern_longer_than_text() {
+    assert_eq!(horspool("abc", "abcd"), vec![]);
+  }
+
+  #[test]
+  fn pattern_bytes_absent_from_text() {
+    assert_eq!(horspool("aaaaaaaa", "xyz"), vec![]);
+  }
+
+  #[test]
+  fn repeated_final_byte() {
+    assert_eq!(shift_table(b"abb")[b'b' as usize], 1);
+    assert_eq!(horspool("abbbabbb", "abb"), vec![0, 4]);
+    assert_eq!(horspool("bbbbb", "bbb"), vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn bytes_above_127() {
+    // "é" 编码为 0xC3 0xA9，"ÿ" 编码为 0xC3 0xBF，索引是字节索引
+    // "é" encodes as 0xC3 0xA9 and "ÿ" as 0xC3 0xBF; indices are byte indices
+    assert_eq!(horspool("éÿéÿé", "ÿé"), vec![2, 6]);
+    assert_eq!(horspool("\u{ff}\u{ff}\u{ff}", "\u{ff}"), vec![0, 2, 4]);
+    assert_eq!(horspool("aé€é", "€é"), vec![3]);
+
+    let table = shift_table("€é".as_bytes());
+    assert_eq!(table[0xE2], 4);
+    assert_eq!(table[0xC3], 1);
+    assert_eq!(table[0xFF], 5);
+  }
+}
+
+fn main() {}
@@ -0,0 +1,423 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// 码表序列化后的字节数：每个字节值的码长各占一个字节。
+/// The size of a serialized code table: one code length byte per byte value.
+pub const TABLE_LEN: usize = 256;
+
+/// 支持的最长码长，编码以 `u64` 存储。 (The longest supported code, as codes are stored in a `u64`.)
+pub const MAX_CODE_LEN: u8 = 64;
+
+/// 字节序列的哈夫曼编码，以范式哈夫曼码（canonical Huffman code）的形式保存。
+/// A Huffman code over bytes, kept in canonical form.
+///
+/// 范式哈夫曼码只由每个字节的码长决定：码长相同的字节按字节值顺序分配连续的编码。
+/// 因此只需保存 256 个码长就能完整地还原码表，这就是 [`HuffmanTree::to_bytes`] 的格式。
+/// A canonical Huffman code is determined by the code length of every byte alone: bytes of the
+/// same length get consecutive codes in byte order. The 256 code lengths thus fully describe the
+/// table, and they are what [`HuffmanTree::to_bytes`] writes.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let data = b"abracadabra";
+/// let tree = HuffmanTree::build(data).unwrap();
+/// let (bits, bit_len) = tree.encode(data);
+/// assert_eq!(tree.decode(&bits, bit_len), data);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HuffmanTree {
+  /// 每个字节的码长，0 表示该字节不出现。 (The code length of each byte, 0 if it does not occur.)
+  lengths: [u8; 256],
+  /// 每个字节的编码，低 `lengths[b]` 位有效。 (The code of each byte, in its low `lengths[b]` bits.)
+  codes: [u64; 256],
+  /// 按 (码长, 字节值) 排序的字节。 (The bytes sorted by (code length, byte value).)
+  symbols: Vec<u8>,
+  /// `first[l]` 是码长为 l 的第一个编码。 (`first[l]` is the first code of length l.)
+  first: Vec<u64>,
+  /// `count[l]` 是码长为 l 的编码个数。 (`count[l]` is the number of codes of length l.)
+  count: Vec<usize>,
+  /// `offset[l]` 是码长为 l 的第一个字节在 `symbols` 中的位置。
+  /// (`offset[l]` is the position in `symbols` of the first byte of length l.)
+  offset: Vec<usize>,
+}
+
+impl HuffmanTree {
+  /// 按字节频率构建哈夫曼编码，输入为空时返回 None。
+  /// Builds a Huffman code from the byte frequencies of `data`, or None if `data` is empty.
+  ///
+  /// 只有一种字节时，它的码长为 1，使每个字节至少占用一个比特。
+  /// A single distinct byte gets a code of length 1, so every byte still takes at least one bit.
+  pub fn build(data: &[u8]) -> Option<Self> {
+    let mut freq = [0u64; 256];
+
+    for &b in data {
+      freq[b as usize] += 1;
+    }
+
+    // 结点 0..256 是叶子，之后是合并得到的内部结点
+    // Nodes 0..256 are leaves; merged internal nodes follow
+    let mut parent: Vec<Option<usize>> = vec![None; 256];
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = (0..256)
+      .filter(|&b| freq[b] > 0)
+      .map(|b| Reverse((freq[b], b)))
+      .collect();
+
+    if heap.is_empty() {
+      return None;
+    }
+
+    // 每次合并频率最小的两个结点
+    // Repeatedly merge the two least frequent nodes
+    while heap.len() > 1 {
+      let Reverse((f1, n1)) = heap.pop().unwrap();
+      let Reverse((f2, n2)) = heap.pop().unwrap();
+      let merged = parent.len();
+
+      parent.push(None);
+      parent[n1] = Some(merged);
+      parent[n2] = Some(merged);
+      heap.push(Reverse((f1 + f2, merged)));
+    }
+
+    let mut lengths = [0u8; 256];
+
+    for b in (0..256).filter(|&b| freq[b] > 0) {
+      let mut depth = 0;
+      let mut node = b;
+
+      while let Some(p) = parent[node] {
+        depth += 1;
+        node = p;
+      }
+
+      // 只有一种字节时树只有根结点，码长仍记为 1
+      // With a single distinct byte the tree is just the root; its code length is still 1
+      lengths[b] = depth.max(1);
+    }
+
+    Self::from_lengths(lengths)
+  }
+
+  /// 由每个字节的码长还原范式哈夫曼码。码长不构成合法的前缀码（或全部为 0、超过 [`MAX_CODE_LEN`]）时返回 None。
+  /// Rebuilds the canonical code from the code length of every byte. Returns None if the lengths
+  /// do not form a valid prefix code (or are all 0, or exceed [`MAX_CODE_LEN`]).
+  pub fn from_lengths(lengths: [u8; 256]) -> Option<Self> {
+    if lengths.iter().all(|&l| l == 0) || lengths.iter().any(|&l| l > MAX_CODE_LEN) {
+      return None;
+    }
+
+    let max_len = *lengths.iter().max().unwrap() as usize;
+    let mut count = vec![0usize; max_len + 1];
+
+    for &l in lengths.iter().filter(|&&l| l > 0) {
+      count[l as usize] += 1;
+    }
+
+    // Kraft 不等式：各码长 l 的 2^-l 之和不能超过 1，否则不存在这样的前缀码
+    // Kraft's inequality: the sum of 2^-l over all lengths l may not exceed 1 for a prefix code
+    if !kraft_holds(&count) {
+      return None;
+    }
+
+    let mut symbols: Vec<u8> = (0..=255u8).filter(|&b| lengths[b as usize] > 0).collect();
+    symbols.sort_by_key(|&b| (lengths[b as usize], b));
+
+    // 按 (码长, 字节值) 的顺序分配连续的编码，码长增加时左移
+    // Assign consecutive codes in (length, byte) order, shifting left as the length grows
+    let mut codes = [0u64; 256];
+    let mut first = vec![0u64; max_len + 1];
+    let mut offset = vec![0usize; max_len + 1];
+    let mut code = 0u64;
+    let mut len = lengths[symbols[0] as usize];
+
+    for (i, &b) in symbols.iter().enumerate() {
+      let l = lengths[b as usize];
+
+      if l != len {
+        code <<= l - len;
+        len = l;
+      }
+
+      if i == 0 || lengths[symbols[i - 1] as usize] != l {
+        first[l as usize] = code;
+        offset[l as usize] = i;
+      }
+
+      codes[b as usize] = code;
+      code = code.wrapping_add(1);
+    }
+
+    Some(HuffmanTree {
+      lengths,
+      codes,
+      symbols,
+      first,
+      count,
+      offset,
+    })
+  }
+
+  /// 把码表序列化为 [`TABLE_LEN`] 个字节（每个字节值的码长）。
+  /// Serializes the code table as [`TABLE_LEN`] bytes, the code length of every byte value.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    self.lengths.to_vec()
+  }
+
+  /// 从 [`HuffmanTree::to_bytes`] 的结果还原码表，数据不合法时返回 None。
+  /// Restores a code table written by [`HuffmanTree::to_bytes`], or None if the data is invalid.
+  pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    Self::from_lengths(bytes.try_into().ok()?)
+  }
+
+  /// 返回字节的码长，0 表示该字节不在码表中。 (Returns a byte's code length, 0 if it is not in the table.)
+  pub fn code_len(&self, byte: u8) -> u8 {
+    self.lengths[byte as usize]
+  }
+
+  /// 编码数据，返回按高位在前打包的比特以及有效比特数。
+  /// Encodes `data`, returning the bits packed most significant first and the number of bits used.
+  ///
+  /// # Panics
+  ///
+  /// 数据中含有码表中没有的字节时 panic。 (Panics if `data` holds a byte missing from the table.)
+  pub fn encode(&self, data: &[u8]) -> (Vec<u8>, usize) {
+    let mut bits = vec![];
+    let mut bit_len = 0;
+
+    for &b in data {
+      let len = self.lengths[b as usize];
+      assert!(len > 0, "byte {b} is not in the code table");
+
+      let code = self.codes[b as usize];
+
+      for shift in (0..len).rev() {
+        if bit_len % 8 == 0 {
+          bits.push(0);
+        }
+
+        if (code >> shift) & 1 == 1 {
+          *bits.last_mut().unwrap() |= 0x80 >> (bit_len % 8);
+        }
+
+        bit_len += 1;
+      }
+    }
+
+    (bits, bit_len)
+  }
+
+  /// 解码前 `bit_len` 个比特（最多为 `bits.len() * 8`），末尾不完整的编码被忽略。
+  /// Decodes the first `bit_len` bits (at most `bits.len() * 8`); a trailing incomplete code is
+  /// ignored.
+  pub fn decode(&self, bits: &[u8], bit_len: usize) -> Vec<u8> {
+    let mut out = vec![];
+    let (mut code, mut len) = (0u64, 0usize);
+
+    for i in 0..bit_len.min(bits.len() * 8) {
+      let bit = (bits[i / 8] >> (7 - i % 8)) & 1;
+      code = (code << 1) | bit as u64;
+      len += 1;
+
+      // 范式码中，码长为 len 的编码是从 first[len] 开始的 count[len] 个连续值
+      // In a canonical code, the codes of length len are the count[len] values from first[len]
+      if len < self.count.len()
+        && self.count[len] > 0
+        && code >= self.first[len]
+        && code - self.first[len] < self.count[len] as u64
+      {
+        out.push(self.symbols[self.offset[len] + (code - self.first[len]) as usize]);
+        code = 0;
+        len = 0;
+      }
+    }
+
+    out
+  }
+}
+
+/// 检查各码长的个数是否满足 Kraft 不等式 `sum(count[l] * 2^-l) <= 1`。
+/// Checks that the length counts satisfy Kraft's inequality `sum(count[l] * 2^-l) <= 1`.
+fn kraft_holds(count: &[usize]) -> bool {
+  // 以 2^-max_len 为单位累加，max_len 不超过 64，u128 不会溢出
+  // Sum in units of 2^-max_len; with max_len at most 64 a u128 cannot overflow
+  let max_len = count.len() - 1;
+  let total: u128 = count
+    .iter()
+    .enumerate()
+    .map(|(l, &c)| (c as u128) << (max_len - l))
+    .sum();
+
+  total <= 1u128 << max_len
+}
+
+/// 压缩数据，输出自包含：码表、比特数（8 字节小端）和编码后的比特。空输入得到空输出。
+/// Compresses `data` into a self-contained output: the code table, the bit count (8 bytes,
+/// little-endian) and the encoded bits. Empty input gives empty output.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let data = b"mississippi river".repeat(10);
+/// assert_eq!(decompress(&compress(&data)), Some(data));
+/// ```
+pub fn compress(data: &[u8]) -> Vec<u8> {
+  let Some(tree) = HuffmanTree::build(data) else {
+    return vec![];
+  };
+
+  let (bits, bit_len) = tree.encode(data);
+
+  let mut out = tree.to_bytes();
+  out.extend_from_slice(&(bit_len as u64).to_le_bytes());
+  out.extend_from_slice(&bits);
+  out
+}
+
+/// 解压 [`compress`] 的输出，数据不完整或不合法时返回 None。
+/// Decompresses the output of [`compress`], or returns None if it is truncated or invalid.
+pub fn decompress(compressed: &[u8]) -> Option<Vec<u8>> {
+  if compressed.is_empty() {
+    return Some(vec![]);
+  }
+
+  let tree = HuffmanTree::from_bytes(compressed.get(..TABLE_LEN)?)?;
+  let bit_len = u64::from_le_bytes(compressed.get(TABLE_LEN..TABLE_LEN + 8)?.try_into().ok()?);
+  let bits = &compressed[TABLE_LEN + 8..];
+
+  if bit_len > bits.len() as u64 * 8 {
+    return None;
+  }
+
+  Some(tree.decode(bits, bit_len as usize))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+  use rust_algorithm::string::burrows_wheeler_transform::{
+    burrows_wheeler_transform, inv_burrows_wheeler_transform,
+  };
+  use rust_algorithm::string::move_to_front::{mtf_decode_str, mtf_encode_str};
+
+  /// 游程编码：每段连续相同的字节写成 (字节, 长度)，长度不超过 255
+  /// Run-length encoding: each run of a byte becomes (byte, length), with lengths up to 255
+  fn rle_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![];
+
+    for &b in data {
+      match out.len() {
+        n if n >= 2 && out[n - 2] == b && out[n - 1] < u8::MAX => out[n - 1] += 1,
+        _ => out.extend_from_slice(&[b, 1]),
+      }
+    }
+
+    out
+  }
+
+  fn rle_decode(data: &[u8]) -> Vec<u8> {
+    data
+      .chunks_exact(2)
+      .flat_map(|pair| std::iter::repeat_n(pair[0], pair[1] as usize))
+      .collect()
+  }
+
+  #[test]
+  fn empty_input() {
+    assert_eq!(HuffmanTree::build(b""), None);
+    assert_eq!(compress(b""), vec![]);
+    assert_eq!(decompress(&[]), Some(vec![]));
+  }
+
+  #[test]
+  fn single_symbol() {
+    let data = [b'z'; 10];
+    let tree = HuffmanTree::build(&data).unwrap();
+
+    assert_eq!(tree.code_len(b'z'), 1);
+
+    let (bits, bit_len) = tree.encode(&data);
+    assert_eq!(bit_len, 10);
+    assert_eq!(tree.decode(&bits, bit_len), data);
+    assert_eq!(decompress(&compress(&data)), Some(data.to_vec()));
+  }
+
+  #[test]
+  fn all_256_symbols() {
+    let data: Vec<u8> = (0..=255).collect();
+    let tree = HuffmanTree::build(&data).unwrap();
+
+    // 频率相同时得到一棵完全平衡的树
+    // Equal frequencies give a perfectly balanced tree
+    assert!((0..=255).all(|b| tree.code_len(b) == 8));
+    assert_eq!(decompress(&compress(&data)), Some(data));
+  }
+
+  #[test]
+  fn frequent_bytes_get_shorter_codes() {
+    let data = b"aaaaaaaaaaaaaaaabbbbbbbbccccdde";
+    let tree = HuffmanTree::build(data).unwrap();
+
+    assert_eq!(tree.code_len(b'a'), 1);
+    assert!(tree.code_len(b'b') <= tree.code_len(b'c'));
+    assert!(tree.code_len(b'c') <= tree.code_len(b'e'));
+    assert_eq!(tree.code_len(b'x'), 0);
+  }
+
+  #[test]
+  fn table_round_trip() {
+    let tree = HuffmanTree::build(b"hello huffman").unwrap();
+    let bytes = tree.to_bytes();
+
+    assert_eq!(bytes.len(), TABLE_LEN);
+    assert_eq!(HuffmanTree::from_bytes(&bytes), Some(tree));
+  }
+
+  #[test]
+  fn invalid_tables() {
+    assert_eq!(HuffmanTree::from_bytes(&[0; TABLE_LEN]), None);
+    assert_eq!(HuffmanTree::from_bytes(&[1; 10]), None);
+
+    // 三个长度为 1 的编码违反 Kraft 不等式
+    // Three codes of length 1 violate Kraft's inequality
+    let mut lengths = [0u8; 256];
+    lengths[..3].fill(1);
+    assert_eq!(HuffmanTree::from_lengths(lengths), None);
+
+    assert_eq!(decompress(&[1; 20]), None);
+  }
+
+  #[test]
+  fn random_round_trip() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+      let len = rng.gen_range(1..500);
+      let alphabet = rng.gen_range(1..=255u8);
+      let data: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=alphabet)).collect();
+
+      assert_eq!(decompress(&compress(&data)), Some(data));
+    }
+  }
+
+  #[test]
+  fn bwt_mtf_rle_huffman_pipeline() {
+    let text = "the quick brown fox jumps over the lazy dog. ".repeat(40);
+
+    let (transformed, index) = burrows_wheeler_transform(text.clone());
+    let moved = mtf_encode_str(&transformed);
+    let runs = rle_encode(&moved);
+    let compressed = compress(&runs);
+
+    assert!(compressed.len() < text.len() / 2);
+
+    let runs = decompress(&compressed).unwrap();
+    let moved = rle_decode(&runs);
+    let transformed = mtf_decode_str(&moved).unwrap();
+
+    assert_eq!(inv_burrows_wheeler_transform((transformed, index)), text);
+  }
+}
+
+fn main() {}
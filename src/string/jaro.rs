@@ -0,0 +1,229 @@
+/// Jaro-Winkler 相似度中公共前缀的缩放系数。 (The common prefix scaling factor of Jaro-Winkler.)
+pub const PREFIX_SCALE: f64 = 0.1;
+
+/// Jaro-Winkler 相似度最多计入的公共前缀长度。
+/// (The longest common prefix Jaro-Winkler similarity takes into account.)
+pub const MAX_PREFIX: usize = 4;
+
+/// Jaro 相似度超过此阈值时才按公共前缀加分。
+/// (The Jaro similarity above which the common prefix bonus applies.)
+pub const BOOST_THRESHOLD: f64 = 0.7;
+
+/// 计算两个字符串之间的 Jaro 相似度，结果在 [0, 1] 之间，1 表示完全相同。
+/// Computes the Jaro similarity between two strings, in [0, 1] with 1 meaning identical.
+///
+/// 两个字符相同且位置相差不超过 `max(|a|, |b|) / 2 - 1` 时算作匹配。设匹配的字符数为 m，
+/// 按顺序比较两边的匹配字符，顺序不同的个数的一半为 t，则相似度为 `(m / |a| + m / |b| + (m - t) / m) / 3`。
+/// 两个字符串都为空时视为相同（1.0），只有一个为空时为 0.0。
+/// Two chars match if they are equal and at most `max(|a|, |b|) / 2 - 1` positions apart. With m
+/// matching chars, and t half the number of matched chars that appear in a different order on
+/// the two sides, the similarity is `(m / |a| + m / |b| + (m - t) / m) / 3`. Two empty strings
+/// are identical (1.0); a single empty string gives 0.0.
+///
+/// 按字符而不是字节计算。贪心匹配总是从较短（长度相同时字典序较小）的字符串出发，所以结果与参数顺序无关。
+/// Chars rather than bytes are compared. Greedy matching always starts from the shorter string
+/// (the lexicographically smaller one on equal lengths), so the result does not depend on argument
+/// order.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert!((jaro("MARTHA", "MARHTA") - 0.9444).abs() < 1e-4);
+/// assert_eq!(jaro("", "abc"), 0.0);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(|a| * w), where w is the matching window, and the space complexity is
+/// O(|a| + |b|).
+/// 时间复杂度为 O(|a| * w)，其中 w 是匹配窗口的大小，空间复杂度为 O(|a| + |b|)。
+pub fn jaro(a: &str, b: &str) -> f64 {
+  let (a, b) = ordered(a, b);
+  jaro_chars(&a, &b)
+}
+
+/// 计算两个字符串之间的 Jaro-Winkler 相似度：在 Jaro 相似度的基础上，奖励相同的前缀。
+/// Computes the Jaro-Winkler similarity between two strings: Jaro similarity with a bonus for a
+/// shared prefix.
+///
+/// Jaro 相似度 j 超过 [`BOOST_THRESHOLD`] 时，结果为 `j + l * PREFIX_SCALE * (1 - j)`，
+/// 其中 l 是公共前缀的长度，最多为 [`MAX_PREFIX`]；否则结果就是 j。结果仍在 [0, 1] 之间。
+/// When the Jaro similarity j exceeds [`BOOST_THRESHOLD`], the result is
+/// `j + l * PREFIX_SCALE * (1 - j)`, where l is the common prefix length capped at
+/// [`MAX_PREFIX`]; otherwise it is j. The result stays within [0, 1].
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert!((jaro_winkler("MARTHA", "MARHTA") - 0.9611).abs() < 1e-4);
+/// assert_eq!(jaro_winkler("same", "same"), 1.0);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+  let (a, b) = ordered(a, b);
+  let j = jaro_chars(&a, &b);
+
+  if j <= BOOST_THRESHOLD {
+    return j;
+  }
+
+  let prefix = a
+    .iter()
+    .zip(&b)
+    .take(MAX_PREFIX)
+    .take_while(|(x, y)| x == y)
+    .count();
+
+  j + prefix as f64 * PREFIX_SCALE * (1.0 - j)
+}
+
+/// 按字符拆分两个字符串，较短的（长度相同时字典序较小的）在前。
+/// Splits both strings into chars, the shorter (or, on equal lengths, the smaller) one first.
+fn ordered(a: &str, b: &str) -> (Vec<char>, Vec<char>) {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+
+  if (a.len(), &a) <= (b.len(), &b) {
+    (a, b)
+  } else {
+    (b, a)
+  }
+}
+
+/// 在字符切片上计算 Jaro 相似度，贪心匹配从 `a` 出发。
+/// Computes the Jaro similarity over char slices, matching greedily from `a`.
+fn jaro_chars(a: &[char], b: &[char]) -> f64 {
+  if a.is_empty() && b.is_empty() {
+    return 1.0;
+  }
+
+  if a.is_empty() || b.is_empty() {
+    return 0.0;
+  }
+
+  // 匹配窗口：两个匹配字符的位置最多相差 window
+  // The matching window: matched chars may be at most `window` positions apart
+  let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+  let mut a_matched = vec![false; a.len()];
+  let mut b_matched = vec![false; b.len()];
+  let mut matches = 0;
+
+  for (i, c) in a.iter().enumerate() {
+    let lo = i.saturating_sub(window);
+    let hi = (i + window + 1).min(b.len());
+
+    // 在窗口内找第一个尚未匹配的相同字符
+    // Take the first not yet matched equal char within the window
+    if let Some(j) = (lo..hi).find(|&j| !b_matched[j] && b[j] == *c) {
+      a_matched[i] = true;
+      b_matched[j] = true;
+      matches += 1;
+    }
+  }
+
+  if matches == 0 {
+    return 0.0;
+  }
+
+  // 按顺序比较两边的匹配字符，顺序不同的个数的一半即为换位数
+  // Compare the matched chars of both sides in order; half the mismatches are transpositions
+  let a_chars = a.iter().zip(&a_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+  let b_chars = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+  let transpositions = a_chars.zip(b_chars).filter(|(x, y)| x != y).count() / 2;
+
+  let m = matches as f64;
+  (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_close(actual: f64, expected: f64) {
+    assert!(
+      (actual - expected).abs() < 1e-4,
+      "expected {expected:.4}, got {actual:.4}"
+    );
+  }
+
+  #[test]
+  fn canonical_jaro() {
+    assert_close(jaro("MARTHA", "MARHTA"), 0.9444);
+    assert_close(jaro("DIXON", "DICKSONX"), 0.7667);
+    assert_close(jaro("DWAYNE", "DUANE"), 0.8222);
+  }
+
+  #[test]
+  fn canonical_jaro_winkler() {
+    assert_close(jaro_winkler("MARTHA", "MARHTA"), 0.9611);
+    assert_close(jaro_winkler("DIXON", "DICKSONX"), 0.8133);
+    assert_close(jaro_winkler("DWAYNE", "DUANE"), 0.8400);
+  }
+
+  #[test]
+  fn identical_and_disjoint() {
+    assert_eq!(jaro("abc", "abc"), 1.0);
+    assert_eq!(jaro_winkler("abc", "abc"), 1.0);
+    assert_eq!(jaro("abc", "xyz"), 0.0);
+    assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+  }
+
+  #[test]
+  fn empty_strings() {
+    assert_eq!(jaro("", ""), 1.0);
+    assert_eq!(jaro("", "abc"), 0.0);
+    assert_eq!(jaro("abc", ""), 0.0);
+    assert_eq!(jaro_winkler("", "abc"), 0.0);
+    assert_eq!(jaro_winkler("", ""), 1.0);
+  }
+
+  #[test]
+  fn below_threshold_gets_no_boost() {
+    // 前缀相同，但 Jaro 相似度不超过阈值
+    // The prefix is shared, but the Jaro similarity is not above the threshold
+    let j = jaro("abcdefgh", "abxxxxxxxxxxxx");
+
+    assert!(j <= BOOST_THRESHOLD);
+    assert_eq!(jaro_winkler("abcdefgh", "abxxxxxxxxxxxx"), j);
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    assert_eq!(jaro("你好", "你好"), 1.0);
+    assert_close(jaro("café", "cafe"), (0.75 + 0.75 + 1.0) / 3.0);
+  }
+
+  #[test]
+  fn symmetric() {
+    let pairs = [
+      ("MARTHA", "MARHTA"),
+      ("DIXON", "DICKSONX"),
+      ("DWAYNE", "DUANE"),
+      ("abcab", "bcaab"),
+      ("aab", "abaa"),
+      ("crate", "trace"),
+    ];
+
+    for (a, b) in pairs {
+      assert_eq!(jaro(a, b), jaro(b, a), "{a} {b}");
+      assert_eq!(jaro_winkler(a, b), jaro_winkler(b, a), "{a} {b}");
+    }
+  }
+
+  #[test]
+  fn within_unit_interval() {
+    for (a, b) in [
+      ("a", "aaaaaaaa"),
+      ("ab", "ba"),
+      ("aaaa", "aaab"),
+      ("x", "y"),
+    ] {
+      let (j, jw) = (jaro(a, b), jaro_winkler(a, b));
+
+      assert!((0.0..=1.0).contains(&j) && (0.0..=1.0).contains(&jw));
+      assert!(j <= jw);
+    }
+  }
+}
+
+fn main() {}
@@ -8,14 +8,15 @@
 /// # 参数 (Arguments)
 ///
 /// * `st` - 要在其中搜索模式的文本字符串。
-///          The text string in which to search for the pattern.
+///   The text string in which to search for the pattern.
 /// * `pat` - 要在文本中搜索的模式字符串。
-///          The pattern string to search for within the text.
+///   The pattern string to search for within the text.
 ///
 /// # 返回值 (Returns)
 ///
-/// A vector containing the starting indices of all occurrences of the pattern within the text.
-/// 包含所有模式出现位置起始索引的向量。
+/// A vector containing the starting byte offsets of all occurrences of the pattern within the
+/// text. To search borrowed strings, use [`kmp_search`], which returns the same offsets.
+/// 包含所有模式出现位置起始字节索引的向量。搜索借用的字符串时使用 [`kmp_search`]，返回的位置相同。
 ///
 /// # 示例 (Examples)
 ///
@@ -56,16 +57,234 @@
 /// KMP算法使用两个指针，`i` 和 `j`，分别在文本和模式中导航。
 /// 算法遍历文本，使用部分匹配表调整`j`指针，并在找到完整模式匹配时更新`ret`向量。
 pub fn knuth_morris_pratt(st: String, pat: String) -> Vec<usize> {
+  kmp_search(&st, &pat)
+}
+
+/// 借用字符串的 KMP 搜索，返回所有匹配的起始字节位置。
+/// KMP search over borrowed strings, returning the starting byte offsets of all matches.
+///
+/// 按字节匹配，但合法的 UTF-8 模式不会从一个多字节字符的中间开始匹配，所以每个返回的位置都在字符边界上，
+/// 可以直接用于 `&text[i..]`。匹配以 Unicode 标量值为单位，而不是字形簇：模式 "e" 也会匹配 "e\u{301}"（é）中的 e。
+/// Bytes are matched, but a valid UTF-8 pattern cannot match starting inside a multi-byte char,
+/// so every returned offset lies on a char boundary and can be used as `&text[i..]` directly.
+/// Matching works on Unicode scalar values, not grapheme clusters: the pattern "e" also matches
+/// the e of "e\u{301}" (é).
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let text = "héllo wörld";
+/// let indices = kmp_search(text, "ö");
+/// assert_eq!(indices, vec![8]);
+/// assert!(text[8..].starts_with("ö"));
+/// ```
+pub fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+  search(text.as_bytes(), pattern.as_bytes())
+}
+
+/// 在字节切片上做 KMP 搜索，返回所有匹配的起始字节位置。
+/// KMP search over byte slices, returning the starting byte offsets of all matches.
+pub fn kmp_search_bytes(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+  search(text, pattern)
+}
+
+/// 按字符做 KMP 搜索，返回所有匹配的起始字符位置（第几个字符），而不是字节位置。
+/// KMP search over chars, returning the starting char positions of all matches (counted in
+/// chars) rather than byte offsets.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(kmp_search_chars("héllo wörld", "ö"), vec![7]);
+/// ```
+pub fn kmp_search_chars(text: &str, pattern: &str) -> Vec<usize> {
+  let text: Vec<char> = text.chars().collect();
+  let pattern: Vec<char> = pattern.chars().collect();
+
+  search(&text, &pattern)
+}
+
+/// 计算 KMP 的部分匹配表（失配函数）：`table[i]` 是 `pattern[..=i]` 既是真前缀又是后缀的最长子串的长度。
+/// Computes the KMP partial match table (failure function): `table[i]` is the length of the
+/// longest proper prefix of `pattern[..=i]` that is also a suffix of it.
+///
+/// 除了用于搜索，这张表还可以求字符串的周期（见 [`string_period`]）、判断字符串是否由重复的子串构成等。
+/// Beyond searching, the table gives a string's period (see [`string_period`]), tells whether a
+/// string is a repeated substring, and so on.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(kmp_failure_table(b"ababaca"), vec![0, 0, 1, 2, 3, 0, 1]);
+/// assert_eq!(kmp_failure_table(b""), vec![]);
+/// ```
+pub fn kmp_failure_table(pattern: &[u8]) -> Vec<usize> {
+  partial_table(pattern)
+}
+
+/// 返回字符串的最小周期：最小的 p > 0，使得对所有合法的 i 都有 `s[i] == s[i + p]`，按字符计。
+/// Returns the smallest period of a string: the smallest p > 0 such that `s[i] == s[i + p]` for
+/// every valid i, counted in chars.
+///
+/// 周期等于长度减去整个字符串的最长真边界（既是前缀又是后缀的最长真子串）。周期不一定整除长度，
+/// 例如 "abcab" 的周期为 3；只有周期整除长度时字符串才由某个子串重复构成。空字符串的周期为 0。
+/// The period is the length minus the longest proper border (a proper prefix that is also a
+/// suffix) of the whole string. It need not divide the length, e.g. "abcab" has period 3; only
+/// when it does is the string a repetition of a substring. The empty string has period 0.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(string_period("abcabcabc"), 3);
+/// assert_eq!(string_period("abcd"), 4);
+/// ```
+pub fn string_period(s: &str) -> usize {
+  let chars: Vec<char> = s.chars().collect();
+
+  match partial_table(&chars).last() {
+    Some(&border) => chars.len() - border,
+    None => 0,
+  }
+}
+
+/// 流式的 KMP 匹配器：部分匹配表只构建一次，数据可以分块送入，跨块边界的匹配也能找到。
+/// A streaming KMP matcher: the partial match table is built once and data can be fed in chunks,
+/// with matches spanning chunk boundaries still found.
+///
+/// 匹配器在两次 [`feed`](KmpMatcher::feed) 之间只保存当前已匹配的模式前缀长度和已读入的字节数，
+/// 所以不需要把所有块拼接起来。
+/// Between calls to [`feed`](KmpMatcher::feed) the matcher only keeps the length of the pattern
+/// prefix matched so far and the number of bytes consumed, so the chunks never need to be
+/// concatenated.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut matcher = KmpMatcher::new(b"needle");
+/// assert_eq!(matcher.feed(b"hay nee"), vec![]);
+/// assert_eq!(matcher.feed(b"dle hay"), vec![4]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmpMatcher {
+  pattern: Vec<u8>,
+  partial: Vec<usize>,
+  /// 当前已匹配的模式前缀长度。 (The length of the pattern prefix matched so far.)
+  matched: usize,
+  /// 目前为止送入的字节总数。 (The total number of bytes fed so far.)
+  consumed: usize,
+}
+
+impl KmpMatcher {
+  /// 为给定的模式构建匹配器。空模式永远不会匹配，与 [`kmp_search`] 一致。
+  /// Builds a matcher for the given pattern. An empty pattern never matches, as in
+  /// [`kmp_search`].
+  pub fn new(pattern: &[u8]) -> Self {
+    KmpMatcher {
+      pattern: pattern.to_vec(),
+      partial: partial_table(pattern),
+      matched: 0,
+      consumed: 0,
+    }
+  }
+
+  /// 送入下一块数据，返回在这一块中结束的所有匹配的起始位置。
+  /// 位置是相对于整个数据流（第一次送入的第一个字节为 0）的绝对位置，可能落在之前的块中。
+  /// Feeds the next chunk, returning the starting positions of all matches that end within it.
+  /// Positions are absolute within the whole stream, counting from the first byte ever fed, and
+  /// may lie in earlier chunks.
+  pub fn feed(&mut self, chunk: &[u8]) -> Vec<usize> {
+    let mut ret = vec![];
+
+    if !self.pattern.is_empty() {
+      for (i, c) in chunk.iter().enumerate() {
+        let (matched, found) = step(&self.pattern, &self.partial, self.matched, c);
+        self.matched = matched;
+
+        if found {
+          ret.push(self.consumed + i + 1 - self.pattern.len());
+        }
+      }
+    }
+
+    self.consumed += chunk.len();
+    ret
+  }
+
+  /// 清除已读入的状态，之后送入的数据视为一个新的数据流，位置重新从 0 开始。
+  /// Clears the state, so data fed afterwards starts a new stream with positions counted from 0.
+  pub fn reset(&mut self) {
+    self.matched = 0;
+    self.consumed = 0;
+  }
+
+  /// 在单个字符串中惰性地查找所有匹配的起始字节位置，不影响 [`feed`](KmpMatcher::feed) 的状态。
+  /// Lazily finds the starting byte offsets of all matches in a single string, without touching
+  /// the state of [`feed`](KmpMatcher::feed).
+  ///
+  /// # 示例 (Examples)
+  ///
+  /// ```
+  /// let matcher = KmpMatcher::new(b"aba");
+  /// assert_eq!(matcher.find_iter("ababa").collect::<Vec<_>>(), vec![0, 2]);
+  /// ```
+  pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
+    let len = self.pattern.len();
+    let mut matched = 0;
+
+    text
+      .as_bytes()
+      .iter()
+      .enumerate()
+      .filter(move |_| len > 0)
+      .filter_map(move |(i, c)| {
+        let (next, found) = step(&self.pattern, &self.partial, matched, c);
+        matched = next;
+        found.then(|| i + 1 - len)
+      })
+  }
+}
+
+/// KMP 搜索的核心，适用于任意可比较相等的元素序列。
+/// The core of the KMP search, over any sequence of items comparable for equality.
+fn search<T: Eq>(string: &[T], pattern: &[T]) -> Vec<usize> {
   // 如果文本或模式为空，则返回一个空向量
   // Return an empty vector if either the text or pattern is empty
-  if st.is_empty() || pat.is_empty() {
+  if string.is_empty() || pattern.is_empty() {
     return vec![];
   }
 
-  // 将输入字符串转换为字节数组以实现高效索引
-  // Convert the input strings to byte arrays for efficient indexing
-  let string = st.into_bytes();
-  let pattern = pat.into_bytes();
+  let partial = partial_table(pattern);
+
+  // 初始化向量以存储出现位置起始索引
+  // Initialize the vector to store starting indices of occurrences
+  let mut ret = vec![];
+  // 将模式指针j初始化为0
+  // Initialize the pattern pointer j to 0
+  let mut j = 0;
+
+  // 遍历文本以查找模式的出现位置
+  // Iterate through the text to find pattern occurrences
+  for (i, c) in string.iter().enumerate() {
+    let (next, found) = step(pattern, &partial, j, c);
+    j = next;
+
+    // 如果找到完整模式匹配，更新结果向量
+    // If a full pattern match is found, update the result vector
+    if found {
+      ret.push(i + 1 - pattern.len());
+    }
+  }
+
+  ret
+}
+
+/// 构建部分匹配表：`partial[i]` 是 `pattern[..=i]` 既是真前缀又是真后缀的最长子串的长度。
+/// Builds the partial match table: `partial[i]` is the length of the longest proper prefix of
+/// `pattern[..=i]` that is also a suffix of it.
+fn partial_table<T: Eq>(pattern: &[T]) -> Vec<usize> {
+  if pattern.is_empty() {
+    return vec![];
+  }
 
   // 使用第一个元素初始化部分匹配表
   // Initialize the partial match table with the first element
@@ -89,44 +308,39 @@ pub fn knuth_morris_pratt(st: String, pat: String) -> Vec<usize> {
     partial.push(if pattern[j] == pattern[i] { j + 1 } else { j });
   }
 
-  // 初始化向量以存储出现位置起始索引
-  // Initialize the vector to store starting indices of occurrences
-  let mut ret = vec![];
-  // 将模式指针j初始化为0
-  // Initialize the pattern pointer j to 0
-  let mut j = 0;
-
-  // 遍历文本以查找模式的出现位置
-  // Iterate through the text to find pattern occurrences
-  for (i, &c) in string.iter().enumerate() {
-    // 使用部分匹配表调整模式指针j，直到找到合适的位置
-    // Use the partial match table to adjust the pattern pointer j, finding a proper position
-    while j > 0 && c != pattern[j] {
-      j = partial[j - 1];
-    }
+  partial
+}
 
-    // 如果字符匹配，增加模式指针j
-    // If the characters match, increment the pattern pointer j
-    if c == pattern[j] {
-      j += 1;
-    }
+/// 读入一个元素，把模式指针从 `j` 推进到新的位置，并返回是否刚好完成一次完整匹配。模式不能为空。
+/// Consumes one item, advancing the pattern pointer from `j`, and returns the new pointer and
+/// whether a full match was just completed. The pattern must not be empty.
+fn step<T: Eq>(pattern: &[T], partial: &[usize], mut j: usize, c: &T) -> (usize, bool) {
+  // 使用部分匹配表调整模式指针j，直到找到合适的位置
+  // Use the partial match table to adjust the pattern pointer j, finding a proper position
+  while j > 0 && *c != pattern[j] {
+    j = partial[j - 1];
+  }
 
-    // 如果找到完整模式匹配，更新结果向量
-    // If a full pattern match is found, update the result vector
-    if j == pattern.len() {
-      ret.push(i + 1 - j);
-      // 使用部分匹配表更新模式指针j
-      // Update the pattern pointer j using the partial match table
-      j = partial[j - 1];
-    }
+  // 如果字符匹配，增加模式指针j
+  // If the characters match, increment the pattern pointer j
+  if *c == pattern[j] {
+    j += 1;
   }
 
-  ret
+  if j == pattern.len() {
+    // 使用部分匹配表更新模式指针j，以便继续查找重叠的匹配
+    // Update the pattern pointer j using the partial match table to keep finding overlapping
+    // matches
+    (partial[j - 1], true)
+  } else {
+    (j, false)
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use rand::Rng;
 
   #[test]
   fn each_letter_matches() {
@@ -190,6 +404,195 @@ mod tests {
 
     assert_eq!(index, vec![]);
   }
+
+  #[test]
+  fn borrowed_str() {
+    let text = String::from("abababa");
+
+    assert_eq!(kmp_search(&text, "aba"), vec![0, 2, 4]);
+    assert_eq!(kmp_search("", "a"), vec![]);
+    assert_eq!(kmp_search("abc", ""), vec![]);
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    let text = "héllo wörld";
+
+    assert_eq!(kmp_search(text, "ö"), vec![8]);
+    assert_eq!(kmp_search_chars(text, "ö"), vec![7]);
+    assert_eq!(kmp_search_bytes(text.as_bytes(), "ö".as_bytes()), vec![8]);
+    assert_eq!(kmp_search("你好，你好", "你好"), vec![0, 9]);
+    assert_eq!(kmp_search_chars("你好，你好", "你好"), vec![0, 3]);
+  }
+
+  #[test]
+  fn combining_characters() {
+    // 第一个 é 是 e 加组合重音符，第二个是预组合的 U+00E9
+    // The first é is e plus a combining acute accent, the second the precomposed U+00E9
+    let text = "cafe\u{301} caf\u{e9} cafe";
+
+    assert_eq!(kmp_search(text, "e\u{301}"), vec![3]);
+    assert_eq!(kmp_search(text, "\u{e9}"), vec![10]);
+    assert_eq!(kmp_search(text, "e"), vec![3, 16]);
+    assert_eq!(kmp_search(text, "\u{301}"), vec![4]);
+    assert_eq!(kmp_search_chars(text, "e"), vec![3, 14]);
+  }
+
+  #[test]
+  fn failure_table() {
+    let table = kmp_failure_table(b"ababaca");
+
+    assert_eq!(table.len(), 7);
+    assert_eq!(table[0], 0);
+    assert_eq!(table[1], 0);
+    assert_eq!(table[2], 1);
+    assert_eq!(table[3], 2);
+    assert_eq!(table[4], 3);
+    assert_eq!(table[5], 0);
+    assert_eq!(table[6], 1);
+
+    assert_eq!(kmp_failure_table(b"aaaa"), vec![0, 1, 2, 3]);
+    assert_eq!(kmp_failure_table(b"a"), vec![0]);
+    assert_eq!(kmp_failure_table(b""), vec![]);
+  }
+
+  #[test]
+  fn period() {
+    assert_eq!(string_period("abcabcabc"), 3);
+    assert_eq!(string_period("aaaa"), 1);
+    assert_eq!(string_period("abcab"), 3);
+    assert_eq!(string_period("ababaca"), 6);
+    assert_eq!(string_period("abcd"), 4);
+    assert_eq!(string_period("a"), 1);
+    assert_eq!(string_period(""), 0);
+    assert_eq!(string_period("你好你好"), 2);
+  }
+
+  #[test]
+  fn period_matches_definition() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+      let s: Vec<u8> = (0..rng.gen_range(1..20))
+        .map(|_| rng.gen_range(b'a'..=b'b'))
+        .collect();
+      let expected = (1..=s.len())
+        .find(|&p| (0..s.len() - p).all(|i| s[i] == s[i + p]))
+        .unwrap();
+
+      assert_eq!(string_period(std::str::from_utf8(&s).unwrap()), expected);
+    }
+  }
+
+  #[test]
+  fn matcher_pattern_split_across_two_feeds() {
+    let mut matcher = KmpMatcher::new(b"ABCDABD");
+
+    assert_eq!(matcher.feed(b"ABC ABCDAB ABCDA"), vec![]);
+    assert_eq!(matcher.feed(b"BCDABDE"), vec![15]);
+  }
+
+  #[test]
+  fn matcher_pattern_split_across_three_feeds() {
+    let mut matcher = KmpMatcher::new(b"needle");
+
+    assert_eq!(matcher.feed(b"hay ne"), vec![]);
+    assert_eq!(matcher.feed(b"ed"), vec![]);
+    assert_eq!(matcher.feed(b"le hay"), vec![4]);
+    assert_eq!(matcher.feed(b""), vec![]);
+    assert_eq!(matcher.feed(b"n"), vec![]);
+    assert_eq!(matcher.feed(b"e"), vec![]);
+    assert_eq!(matcher.feed(b"edle"), vec![14]);
+  }
+
+  #[test]
+  fn matcher_overlapping_across_boundary() {
+    let mut matcher = KmpMatcher::new(b"aba");
+
+    assert_eq!(matcher.feed(b"abab"), vec![0]);
+    // 第二个匹配跨越边界，第三个完全在新块中但与第二个重叠
+    // The second match spans the boundary; the third lies in the new chunk but overlaps it
+    assert_eq!(matcher.feed(b"aba"), vec![2, 4]);
+  }
+
+  #[test]
+  fn matcher_reset() {
+    let mut matcher = KmpMatcher::new(b"abc");
+
+    assert_eq!(matcher.feed(b"xxab"), vec![]);
+    matcher.reset();
+
+    // 重置后不会接上之前的 "ab"，位置也重新从 0 开始
+    // After a reset the earlier "ab" is forgotten and positions restart from 0
+    assert_eq!(matcher.feed(b"c abc"), vec![2]);
+  }
+
+  #[test]
+  fn matcher_empty_pattern() {
+    let mut matcher = KmpMatcher::new(b"");
+
+    assert_eq!(matcher.feed(b"abc"), vec![]);
+    assert_eq!(matcher.find_iter("abc").count(), 0);
+  }
+
+  #[test]
+  fn find_iter_is_lazy_and_matches_batch() {
+    let matcher = KmpMatcher::new(b"aa");
+
+    assert_eq!(
+      matcher.find_iter("aaabaabaaaaa").collect::<Vec<_>>(),
+      vec![0, 1, 4, 7, 8, 9, 10]
+    );
+    assert_eq!(matcher.find_iter("aaabaabaaaaa").next(), Some(0));
+  }
+
+  #[test]
+  fn matcher_agrees_with_batch() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+      let pattern: String = (0..rng.gen_range(1..5))
+        .map(|_| rng.gen_range('a'..='b'))
+        .collect();
+      let chunks: Vec<String> = (0..rng.gen_range(0..8))
+        .map(|_| {
+          (0..rng.gen_range(0..6))
+            .map(|_| rng.gen_range('a'..='b'))
+            .collect()
+        })
+        .collect();
+      let text = chunks.concat();
+
+      let mut matcher = KmpMatcher::new(pattern.as_bytes());
+      let streamed: Vec<usize> = chunks
+        .iter()
+        .flat_map(|chunk| matcher.feed(chunk.as_bytes()))
+        .collect();
+      let expected = kmp_search(&text, &pattern);
+
+      assert_eq!(streamed, expected);
+      assert_eq!(matcher.find_iter(&text).collect::<Vec<_>>(), expected);
+    }
+  }
+
+  #[test]
+  fn indices_slice_back_to_pattern() {
+    let text = "ö, öö and e\u{301}ö — 🦀ö🦀, crab ö";
+
+    for pattern in ["ö", "öö", "🦀", "🦀ö", "e\u{301}", "\u{301}ö", " ", "—"] {
+      let indices = kmp_search(text, pattern);
+      let chars = kmp_search_chars(text, pattern);
+
+      assert!(!indices.is_empty(), "{pattern}");
+      assert_eq!(indices.len(), chars.len());
+
+      for (&i, &c) in indices.iter().zip(&chars) {
+        assert!(text.is_char_boundary(i));
+        assert_eq!(&text[i..i + pattern.len()], pattern);
+        assert_eq!(text.char_indices().nth(c).unwrap().0, i);
+      }
+    }
+  }
 }
 
 fn main() {
@@ -0,0 +1,217 @@
+/// 用 Duval 算法计算字符串的 Lyndon 分解。
+/// Computes the Lyndon factorization of a string with Duval's algorithm.
+///
+/// Lyndon 串是严格小于自身所有非平凡旋转的非空字符串，例如 "ab"、"aab"、"abb"。
+/// 每个字符串都可以唯一地分解为一串字典序不增的 Lyndon 串 w1 >= w2 >= ... >= wk。按字符比较。
+/// A Lyndon word is a non-empty string strictly smaller than all of its non-trivial rotations,
+/// e.g. "ab", "aab" or "abb". Every string factors uniquely into a non-increasing sequence of
+/// Lyndon words w1 >= w2 >= ... >= wk. Chars are compared.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要分解的字符串。
+///   The string to factorize.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(duval("banana"), vec!["b", "an", "an", "a"]);
+/// assert_eq!(duval("aab"), vec!["aab"]);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 维护三个指针：i 是当前未分解部分的起点，j 是正在检查的字符，k 是 j 在前缀周期中对应的字符。
+/// s[j] > s[k] 时前面的部分合并为一个更长的 Lyndon 串（k 回到 i）；s[j] == s[k] 时周期继续（k 前进）；
+/// s[j] < s[k] 时，把已读部分中长为 j - k 的完整周期逐个输出为因子。
+/// Three pointers are kept: i starts the part not yet factorized, j is the char being examined
+/// and k the char it is compared with in the current period. If s[j] > s[k], everything read so
+/// far merges into one longer Lyndon word (k returns to i); if s[j] == s[k], the period continues
+/// (k advances); if s[j] < s[k], each complete period of length j - k read so far is emitted as a
+/// factor.
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(n), as each char is read at most a constant number of times, and the
+/// extra space is O(1) besides the output.
+/// 时间复杂度为 O(n)，每个字符最多被读取常数次；除输出外额外空间复杂度为 O(1)。
+pub fn duval(s: &str) -> Vec<String> {
+  let chars: Vec<char> = s.chars().collect();
+  let mut start = 0;
+
+  factor_ends(&chars)
+    .into_iter()
+    .map(|end| {
+      let factor = chars[start..end].iter().collect();
+      start = end;
+      factor
+    })
+    .collect()
+}
+
+/// 利用 Lyndon 分解求字典序最小的旋转的起始字符索引；有多个时返回最小的索引，空字符串返回 0。
+/// Uses the Lyndon factorization to find the starting char index of the lexicographically
+/// smallest rotation, the smallest such index if there are several, or 0 for the empty string.
+///
+/// 对 s + s 做 Duval 分解，起点在前半部分的最后一组相同因子的第一个因子，就是最小旋转的起点。
+/// Running Duval's algorithm on s + s, the first factor of the last group of equal factors that
+/// starts in the first half begins the smallest rotation.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// // "aaccaaddbb"
+/// assert_eq!(smallest_rotation_via_lyndon("bbaaccaadd"), 2);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space, n being the number of chars.
+/// 时间和空间复杂度均为 O(n)，n 是字符个数。
+pub fn smallest_rotation_via_lyndon(s: &str) -> usize {
+  let chars: Vec<char> = s.chars().chain(s.chars()).collect();
+  let n = chars.len();
+  let mut i = 0;
+  let mut ans = 0;
+
+  while i < n / 2 {
+    ans = i;
+
+    let (j, k) = scan(&chars, i);
+
+    // 跳过这一组中所有长为 j - k 的相同因子
+    // Skip every factor of length j - k in this group
+    while i <= k {
+      i += j - k;
+    }
+  }
+
+  ans
+}
+
+/// 返回 Lyndon 分解中每个因子的结束位置（不含），按字符计。
+/// Returns the end (exclusive) of every factor of the Lyndon factorization, counted in chars.
+fn factor_ends(chars: &[char]) -> Vec<usize> {
+  let mut ends = vec![];
+  let mut i = 0;
+
+  while i < chars.len() {
+    let (j, k) = scan(chars, i);
+
+    // 已读部分由若干个长为 j - k 的相同 Lyndon 串和一个前缀组成，输出所有完整的 Lyndon 串
+    // What was read is some equal Lyndon words of length j - k followed by a prefix of one; emit
+    // every complete word
+    while i <= k {
+      i += j - k;
+      ends.push(i);
+    }
+  }
+
+  ends
+}
+
+/// Duval 算法的内层循环：从 i 开始尽量向后读，返回停止时的 (j, k)。
+/// The inner loop of Duval's algorithm: reads on from i as far as possible and returns (j, k)
+/// where it stopped.
+fn scan(chars: &[char], i: usize) -> (usize, usize) {
+  let mut j = i + 1;
+  let mut k = i;
+
+  while j < chars.len() && chars[k] <= chars[j] {
+    if chars[k] < chars[j] {
+      k = i;
+    } else {
+      k += 1;
+    }
+
+    j += 1;
+  }
+
+  (j, k)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  /// 通过与所有旋转比较来判断是否为 Lyndon 串。 (Checks the Lyndon property against all rotations.)
+  fn is_lyndon(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+
+    !chars.is_empty()
+      && (1..chars.len()).all(|r| {
+        let rotated: Vec<char> = chars[r..].iter().chain(&chars[..r]).copied().collect();
+        chars < rotated
+      })
+  }
+
+  #[test]
+  fn banana() {
+    assert_eq!(duval("banana"), vec!["b", "an", "an", "a"]);
+  }
+
+  #[test]
+  fn single_lyndon_word() {
+    assert_eq!(duval("aab"), vec!["aab"]);
+    assert_eq!(duval("abcd"), vec!["abcd"]);
+    assert_eq!(duval("aabaabb"), vec!["aabaabb"]);
+    assert_eq!(duval("x"), vec!["x"]);
+  }
+
+  #[test]
+  fn all_equal_chars() {
+    assert_eq!(duval("aaaa"), vec!["a", "a", "a", "a"]);
+    assert_eq!(duval(""), Vec::<String>::new());
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    assert_eq!(duval("éaé"), vec!["é", "aé"]);
+    assert_eq!(smallest_rotation_via_lyndon("éaé"), 1);
+  }
+
+  #[test]
+  fn factors_are_non_increasing_lyndon_words() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+      let s: String = (0..rng.gen_range(0..20))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let factors = duval(&s);
+
+      assert_eq!(factors.concat(), s);
+      assert!(factors.iter().all(|f| is_lyndon(f)), "{s}: {factors:?}");
+      assert!(factors.windows(2).all(|w| w[0] >= w[1]), "{s}: {factors:?}");
+    }
+  }
+
+  #[test]
+  fn smallest_rotation() {
+    assert_eq!(smallest_rotation_via_lyndon("bbaaccaadd"), 2);
+    assert_eq!(smallest_rotation_via_lyndon("abab"), 0);
+    assert_eq!(smallest_rotation_via_lyndon("baba"), 1);
+    assert_eq!(smallest_rotation_via_lyndon("aaaa"), 0);
+    assert_eq!(smallest_rotation_via_lyndon(""), 0);
+  }
+
+  #[test]
+  fn smallest_rotation_matches_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let chars: Vec<char> = (0..rng.gen_range(1..12))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let s: String = chars.iter().collect();
+      let rotation =
+        |r: usize| -> Vec<char> { chars[r..].iter().chain(&chars[..r]).copied().collect() };
+      let expected = (0..chars.len()).min_by_key(|&r| rotation(r)).unwrap();
+
+      assert_eq!(smallest_rotation_via_lyndon(&s), expected, "{s}");
+    }
+  }
+}
+
+fn main() {}
@@ -25,122 +25,125 @@
 /// ```
 /// let input = String::from("babad");
 /// let longest_palindrome = manacher(input);
-/// assert_eq!(longest_palindrome, "bab");
+/// assert_eq!(longest_palindrome, "aba");
 /// ```
 pub fn manacher(s: String) -> String {
-  let l = s.len();
+  match manacher_longest(&s) {
+    Some((start, len)) => s.chars().skip(start).take(len).collect(),
+    None => s,
+  }
+}
 
-  if l <= 1 {
-    return s;
+/// 返回最长回文子串的位置和长度 `(起始字符索引, 字符长度)`，不分配新的字符串。输入为空时返回 `None`。
+/// Returns the position and length `(start_char_index, char_length)` of the longest palindromic
+/// substring without allocating a string, or `None` for an empty input.
+///
+/// 索引和长度都按原字符串的字符计，而不是字节。有多个最长回文时返回最靠后的一个，与 [`manacher`] 一致。
+/// Both the index and the length count chars of the original string, not bytes. If several
+/// palindromes share the maximal length, the last one is returned, as [`manacher`] does.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(manacher_longest("babad"), Some((1, 3)));
+/// assert_eq!(manacher_longest("cbbd"), Some((1, 2)));
+/// assert_eq!(manacher_longest(""), None);
+/// ```
+pub fn manacher_longest(s: &str) -> Option<(usize, usize)> {
+  if s.is_empty() {
+    return None;
   }
 
-  // MEMO: We need to detect odd palindrome as well, therefore, inserting dummy string so that
-  // we can find a pair with dummy center character.
-  // MEMO: 我们需要检测奇数长度的回文，因此插入虚拟字符以便
-  // 我们可以找到具有虚拟中心字符的一对。
-  // Create a vector `chars` with a pre-allocated capacity for efficient memory management.
-  // The capacity is calculated based on the original string length multiplied by 2, plus 1 for additional placeholders.
-  // 使用预分配的容量创建向量 `chars`，以实现有效的内存管理。
-  // 容量的计算基于原始字符串长度乘以 2，再加上 1 用于额外的占位符。
-  let mut chars: Vec<char> = Vec::with_capacity(s.len() * 2 + 1);
+  let radii = palindrome_radii(s);
+  let (center, &radius) = radii
+    .iter()
+    .enumerate()
+    .max_by_key(|&(_, &radius)| radius)
+    .unwrap();
+
+  Some(((center - radius) / 2, radius))
+}
+
+/// 返回在插入分隔符后的字符串上，以每个位置为中心的最长回文半径（不含中心）。
+/// Returns, for each position of the separator-interleaved string, the radius (excluding the
+/// center) of the longest palindrome centered there.
+///
+/// 对长为 n 的字符串，在每两个字符之间以及首尾各插入一个分隔符，得到长为 2n + 1 的序列 "#a#b#...#"，
+/// 这样奇数和偶数长度的回文都有唯一的中心：偶数下标是分隔符（偶数长度回文的中心），奇数下标 2k + 1
+/// 是第 k 个字符。分隔符与任何字符都不相等，所以输入中包含 '#' 也没有问题。
+/// 一个好用的性质：`radii[i]` 恰好等于对应回文在原字符串中的字符长度，其起始字符索引为 `(i - radii[i]) / 2`。
+/// A separator is placed between every two chars and at both ends, giving the sequence
+/// "#a#b#...#" of length 2n + 1, so palindromes of odd and even length both have a single center:
+/// even indices are separators (centers of even-length palindromes) and odd index 2k + 1 is the
+/// k-th char. Separators never equal a char, so inputs containing '#' are fine. Conveniently,
+/// `radii[i]` equals the char length of that palindrome in the original string, which starts at
+/// char index `(i - radii[i]) / 2`.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// // "#a#b#a#"
+/// assert_eq!(palindrome_radii("aba"), vec![0, 1, 0, 3, 0, 1, 0]);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(n): the right edge of the rightmost palindrome found
+/// only moves forward, and radii inside it are copied from their mirror image.
+/// 时间和空间复杂度均为 O(n)：已知回文的最右边界只会向右移动，其内部的半径直接从对称位置复制。
+pub fn palindrome_radii(s: &str) -> Vec<usize> {
+  // MEMO: We need to detect even palindromes as well, therefore, inserting separators so that
+  // each palindrome has a single center.
+  // MEMO: 我们还需要检测偶数长度的回文，因此插入分隔符，使每个回文都有唯一的中心。
+  let mut chars: Vec<Option<char>> = Vec::with_capacity(s.len() * 2 + 1);
 
   for c in s.chars() {
-    chars.push('#');
-    chars.push(c);
+    chars.push(None);
+    chars.push(Some(c));
   }
 
-  chars.push('#');
-
-  // List: storing the length of palindrome at each index of string
-  // 列表：存储字符串每个索引处的回文长度
-  let mut length_of_palindrome = vec![1usize; chars.len()];
-  // Integer: Current checking palindrome's center index
-  // 整数：当前检查回文的中心索引
-  let mut current_center: usize = 0;
-  // Integer: Right edge index existing the radius away from current center
-  // 整数：距离当前中心的右边缘索引
-  let mut right_from_current_center: usize = 0;
-
-  for i in 0..chars.len() {
-    // 1: Check if we are looking at the right side of palindrome.
-    // 1：检查是否在回文的右侧。
-    if right_from_current_center > i && i > current_center {
-      // 1-1: If so, copy from the left side of palindrome.
-      // If the value + index exceeds the right edge index, we should cut and check palindrome later #3.
-      // 1-1：如果是，从回文的左侧复制。
-      // 如果值 + 索引超过右边缘索引，我们应该在以后剪切并检查回文 #3。
-      length_of_palindrome[i] = std::cmp::min(
-        right_from_current_center - i,
-        length_of_palindrome[2 * current_center - i],
-      );
-
-      // 1-2: Move the checking palindrome to a new index if it exceeds the right edge.
-      // 1-2：如果检查的回文超过了右边缘。
-      if length_of_palindrome[i] + i >= right_from_current_center {
-        current_center = i;
-        right_from_current_center = length_of_palindrome[i] + i;
-
-        // 1-3: If the radius exceeds the end of the list, it means checking is over.
-        // You will never get a larger value because the string will only get shorter.
-        // 1-3：如果半径超过列表的末尾，表示检查结束。
-        // 永远不会得到更大的值，因为字符串只会变短。
-        if right_from_current_center >= chars.len() - 1 {
-          break;
-        }
-      } else {
-        // 1-4: If the checking index doesn't exceed the right edge,
-        // it means the length is just the same as the left side.
-        // You don't need to check anymore.
-        // 1-4：如果检查索引未超过右边缘，
-        // 那么长度与左侧相同。不需要再检查。
-        continue;
-      }
-    }
-
-    // Integer: Current radius from the checking index
-    // If it's copied from the left side and more than 1,
-    // it means it's ensured, so you don't need to check inside the radius.
-    // 整数：当前半径从检查索引开始
-    // 如果从左侧复制且大于1，则已确保不需要再检查半径内部。
-    let mut radius: usize = (length_of_palindrome[i] - 1) / 2;
-    radius += 1;
-    // 2: Checking palindrome.
-    // Need to care about overflow usize.
-    // 2：检查回文。
-    // 需要注意 usize 的溢出。
-    while i >= radius && i + radius <= chars.len() - 1 && chars[i - radius] == chars[i + radius] {
-      length_of_palindrome[i] += 2;
+  chars.push(None);
+
+  let len = chars.len();
+  let mut radii = vec![0; len];
+  // 当前右边界最靠右的回文的中心，以及它的右边界
+  // The center of the palindrome reaching furthest right, and that right edge
+  let mut center = 0;
+  let mut right = 0;
+
+  for i in 0..len {
+    // 1: 如果位于已知回文内部，从其左侧的对称位置复制半径，但不超过右边界。
+    // 1: Inside a known palindrome, copy the radius from its mirror on the left side, but not
+    // past the right edge.
+    let mut radius = if i < right {
+      (right - i).min(radii[2 * center - i])
+    } else {
+      0
+    };
+
+    // 2: 向两边扩展检查回文。
+    // 2: Expand to check the palindrome further.
+    while radius < i && i + radius + 1 < len && chars[i - radius - 1] == chars[i + radius + 1] {
       radius += 1;
     }
-  }
 
-  // 3: Find the maximum length and generate the answer.
-  // 3：寻找最大长度并生成答案。
-  let center_of_max = length_of_palindrome
-    .iter()
-    .enumerate()
-    .max_by_key(|(_, &value)| value)
-    .map(|(idx, _)| idx)
-    .unwrap();
+    radii[i] = radius;
 
-  // Calculate the radius of the maximum palindrome centered at `center_of_max`.
-  // 计算以 `center_of_max` 为中心的最大回文串的半径。
-  let radius_of_max = (length_of_palindrome[center_of_max] - 1) / 2;
-
-  // Generate the answer by extracting the substring from `chars` using the calculated indices.
-  // 通过使用计算出的索引从 `chars` 中提取子字符串来生成答案。
-  let answer = &chars[(center_of_max - radius_of_max)..(center_of_max + radius_of_max + 1)]
-    .iter()
-    .collect::<String>();
+    // 3: 超过右边界时，移动当前检查的回文。
+    // 3: Move the checking palindrome if it reaches past the right edge.
+    if i + radius > right {
+      center = i;
+      right = i + radius;
+    }
+  }
 
-  // Remove the '#' characters from the answer to get the final result.
-  // 从答案中移除 '#' 字符，以获得最终结果。
-  answer.replace("#", "")
+  radii
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use rand::Rng;
 
   #[test]
   fn get_longest_palindrome_by_manacher() {
@@ -152,6 +155,75 @@ mod tests {
 
     assert!(ac_ans == "a".to_string() || ac_ans == "c".to_string());
   }
+
+  #[test]
+  fn longest_position() {
+    let babad = manacher_longest("babad");
+
+    assert!(babad == Some((0, 3)) || babad == Some((1, 3)));
+    assert_eq!(manacher_longest("a"), Some((0, 1)));
+    assert_eq!(manacher_longest(""), None);
+    assert_eq!(manacher(String::new()), "");
+  }
+
+  #[test]
+  fn even_length_palindromes() {
+    assert_eq!(manacher_longest("cbbd"), Some((1, 2)));
+    assert_eq!(manacher_longest("xabbay"), Some((1, 4)));
+    assert_eq!(manacher("xabbay".to_string()), "abba");
+  }
+
+  #[test]
+  fn whole_string_palindrome() {
+    assert_eq!(manacher_longest("racecar"), Some((0, 7)));
+    assert_eq!(manacher_longest("noon"), Some((0, 4)));
+    assert_eq!(manacher_longest("aaaaa"), Some((0, 5)));
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    // 索引按字符计："été" 前面有两个字符
+    // Indices count chars: two chars precede "été"
+    assert_eq!(manacher_longest("xyété"), Some((2, 3)));
+    assert_eq!(manacher("xyété".to_string()), "été");
+    assert_eq!(manacher_longest("上海自来水来自海上"), Some((0, 9)));
+    assert_eq!(manacher("ab🦀🦀c".to_string()), "🦀🦀");
+  }
+
+  #[test]
+  fn separator_in_input() {
+    assert_eq!(manacher("a#b#a".to_string()), "a#b#a");
+    assert_eq!(manacher_longest("##x"), Some((0, 2)));
+  }
+
+  #[test]
+  fn radii_match_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+      let s: Vec<char> = (0..rng.gen_range(0..16))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let text: String = s.iter().collect();
+      let radii = palindrome_radii(&text);
+
+      assert_eq!(radii.len(), 2 * s.len() + 1);
+
+      for (i, &radius) in radii.iter().enumerate() {
+        // 从中心向两边逐个比较原字符，求出回文长度
+        // Expand around the center comparing original chars to get the palindrome length
+        let (mut lo, mut hi) = (i / 2, i.div_ceil(2));
+        let mut expected = hi - lo;
+        while lo > 0 && hi < s.len() && s[lo - 1] == s[hi] {
+          lo -= 1;
+          hi += 1;
+          expected += 2;
+        }
+
+        assert_eq!(radius, expected, "{text} at {i}");
+      }
+    }
+  }
 }
 
 fn main() {}
@@ -7,3 +7,45 @@ pub mod knuth_morris_pratt;
 pub mod manacher;
 
 pub mod rabin_karp;
+
+pub mod z_algorithm;
+
+pub mod boyer_moore;
+
+pub mod horspool;
+
+pub mod aho_corasick;
+
+pub mod edit_distance;
+
+pub mod hamming;
+
+pub mod jaro;
+
+pub mod alignment;
+
+pub mod move_to_front;
+
+pub mod huffman;
+
+pub mod permutations;
+
+pub mod lyndon;
+
+pub mod rotation;
+
+pub mod regex_lite;
+
+pub mod hashing;
+
+pub mod duplicates;
+
+pub mod phonetic;
+
+pub mod palindrome;
+
+pub mod periodicity;
+
+pub mod cipher;
+
+pub mod base64;
@@ -0,0 +1,173 @@
+use std::string::FromUtf8Error;
+
+/// 对字节序列应用前移变换（MTF），通常接在 Burrows-Wheeler 变换之后。
+/// Applies the move-to-front transform (MTF) to a byte sequence, usually the stage after the
+/// Burrows-Wheeler transform.
+///
+/// 维护一张 256 个字节的表，初始为 0..=255。每个输入字节输出它在表中的位置，然后把它移到表头。
+/// 因此连续重复的字节除第一个外都编码为 0，最近出现过的字节编码为小数，便于后续的熵编码。
+/// A table of all 256 bytes starts as 0..=255. Each input byte is written as its position in the
+/// table and then moved to the front. Runs of a repeated byte therefore encode as zeros after the
+/// first, and recently seen bytes as small numbers, which suits the entropy coding that follows.
+///
+/// # 参数 (Arguments)
+///
+/// * `input` - 要变换的字节序列。
+///   The bytes to transform.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(mtf_encode(b"aaab"), vec![97, 0, 0, 98]);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(n * 256) in the worst case, but close to O(n) when the input has the
+/// locality MTF is meant for. The space complexity is O(n).
+/// 最坏情况下时间复杂度为 O(n * 256)，但输入具有 MTF 所期望的局部性时接近 O(n)。空间复杂度为 O(n)。
+pub fn mtf_encode(input: &[u8]) -> Vec<u8> {
+  let mut table = initial_table();
+
+  input
+    .iter()
+    .map(|&byte| {
+      let position = table.iter().position(|&b| b == byte).unwrap();
+      move_to_front(&mut table, position);
+      position as u8
+    })
+    .collect()
+}
+
+/// 前移变换的逆变换：每个编码值是当前表中的位置，输出该位置的字节并把它移到表头。
+/// Inverts the move-to-front transform: each encoded value is a position in the current table,
+/// whose byte is written out and moved to the front.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(mtf_decode(&[97, 0, 0, 98]), b"aaab");
+/// ```
+pub fn mtf_decode(encoded: &[u8]) -> Vec<u8> {
+  let mut table = initial_table();
+
+  encoded
+    .iter()
+    .map(|&position| {
+      let byte = table[position as usize];
+      move_to_front(&mut table, position as usize);
+      byte
+    })
+    .collect()
+}
+
+/// 对字符串的 UTF-8 字节应用前移变换。 (Applies the move-to-front transform to a string's UTF-8 bytes.)
+pub fn mtf_encode_str(input: &str) -> Vec<u8> {
+  mtf_encode(input.as_bytes())
+}
+
+/// 对 [`mtf_encode_str`] 的结果做逆变换，解码出的字节不是合法的 UTF-8 时返回错误。
+/// Inverts [`mtf_encode_str`], returning an error if the decoded bytes are not valid UTF-8.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let encoded = mtf_encode_str("héllo");
+/// assert_eq!(mtf_decode_str(&encoded).unwrap(), "héllo");
+/// ```
+pub fn mtf_decode_str(encoded: &[u8]) -> Result<String, FromUtf8Error> {
+  String::from_utf8(mtf_decode(encoded))
+}
+
+fn initial_table() -> [u8; 256] {
+  let mut table = [0; 256];
+
+  for (i, b) in table.iter_mut().enumerate() {
+    *b = i as u8;
+  }
+
+  table
+}
+
+/// 把 `table[position]` 移到表头，前面的字节依次后移一位。
+/// Moves `table[position]` to the front, shifting the bytes before it back by one.
+fn move_to_front(table: &mut [u8; 256], position: usize) {
+  table[..=position].rotate_right(1);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_algorithm::string::burrows_wheeler_transform::{
+    burrows_wheeler_transform, inv_burrows_wheeler_transform,
+  };
+
+  #[test]
+  fn empty() {
+    assert_eq!(mtf_encode(b""), vec![]);
+    assert_eq!(mtf_decode(&[]), vec![]);
+  }
+
+  #[test]
+  fn known_encoding() {
+    assert_eq!(mtf_encode(b"bananaaa"), vec![98, 98, 110, 1, 1, 1, 0, 0]);
+  }
+
+  #[test]
+  fn all_bytes_round_trip() {
+    let input: Vec<u8> = (0..=255).rev().chain(0..=255).collect();
+
+    assert_eq!(mtf_decode(&mtf_encode(&input)), input);
+  }
+
+  #[test]
+  fn runs_encode_as_zeros() {
+    let input = [vec![7u8; 100], vec![200u8; 50], vec![7u8; 100]].concat();
+    let encoded = mtf_encode(&input);
+
+    // 每段只有第一个字节不是 0
+    // Only the first byte of each run is non-zero
+    assert_eq!(encoded.iter().filter(|&&b| b == 0).count(), 247);
+    assert_eq!(mtf_decode(&encoded), input);
+  }
+
+  #[test]
+  fn str_round_trip() {
+    for s in ["", "hello", "héllo wörld", "你好，世界", "🦀🦀🦀"] {
+      assert_eq!(mtf_decode_str(&mtf_encode_str(s)).unwrap(), s);
+    }
+
+    // 单独的 0x80 不是合法的 UTF-8
+    // A lone 0x80 is not valid UTF-8
+    assert!(mtf_decode_str(&[0x80]).is_err());
+  }
+
+  #[test]
+  fn bwt_pipeline_round_trip() {
+    for input in ["banana", "mississippi", "abracadabra", "the rain in spain"] {
+      let (transformed, index) = burrows_wheeler_transform(input.to_string());
+      let encoded = mtf_encode_str(&transformed);
+      let decoded = mtf_decode_str(&encoded).unwrap();
+
+      assert_eq!(
+        inv_burrows_wheeler_transform((decoded, index)),
+        input,
+        "{input}"
+      );
+    }
+  }
+
+  #[test]
+  fn bwt_then_mtf_produces_zeros() {
+    let input = "abcabcabcabcabcabcabcabcabcabc";
+    let (transformed, _) = burrows_wheeler_transform(input.to_string());
+    let encoded = mtf_encode_str(&transformed);
+
+    // BWT 把相同的字符聚在一起，MTF 再把它们变成 0
+    // BWT groups equal chars together and MTF turns them into zeros
+    assert_eq!(transformed, "ccccccccccaaaaaaaaaabbbbbbbbbb");
+    assert_eq!(encoded.iter().filter(|&&b| b == 0).count(), 27);
+  }
+}
+
+fn main() {}
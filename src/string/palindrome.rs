@@ -0,0 +1,114 @@
+use rust_algorithm::string::knuth_morris_pratt::kmp_failure_table;
+
+/// 返回在 `s` 前面添加最少的字符所能得到的最短回文串，按字符处理，所以 Unicode 字符串也适用。
+/// Returns the shortest palindrome obtainable by adding chars in front of `s`. Chars rather than
+/// bytes are mirrored, so Unicode strings work too.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要补成回文串的字符串。
+///   The string to complete into a palindrome.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(shortest_palindrome("aacecaaa"), "aaacecaaa");
+/// assert_eq!(shortest_palindrome("abcd"), "dcbabcd");
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 只需要找出 `s` 最长的回文前缀，再把剩下部分反转后放到前面。`s` 的回文前缀正好是
+/// `s + 分隔符 + reverse(s)` 的边界（既是前缀又是后缀），所以 KMP 部分匹配表的最后一项就是它的长度。
+/// 分隔符使用 UTF-8 中不会出现的字节 0xFF，保证边界不会越过 `s`；UTF-8 的编码方式也保证边界落在字符边界上。
+/// Only the longest palindromic prefix of `s` is needed; the rest is reversed and put in front.
+/// The palindromic prefixes of `s` are exactly the borders (prefixes that are also suffixes) of
+/// `s + separator + reverse(s)`, so the last entry of the KMP partial match table is its length.
+/// The separator is the byte 0xFF, which never occurs in UTF-8, so the border cannot run past
+/// `s`; the way UTF-8 is encoded also keeps the border on a char boundary.
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space, instead of the O(n^2) of checking every prefix.
+/// 时间和空间复杂度均为 O(n)，而不是逐个检查前缀的 O(n^2)。
+pub fn shortest_palindrome(s: &str) -> String {
+  let reversed: String = s.chars().rev().collect();
+
+  let mut combined = Vec::with_capacity(2 * s.len() + 1);
+  combined.extend_from_slice(s.as_bytes());
+  combined.push(0xFF);
+  combined.extend_from_slice(reversed.as_bytes());
+
+  let prefix_len = kmp_failure_table(&combined).last().copied().unwrap_or(0);
+
+  // s 的其余部分反转后就是 reversed 的开头部分
+  // The rest of s, reversed, is the beginning of reversed
+  let rest = &reversed[..s.len() - prefix_len];
+
+  [rest, s].concat()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  fn is_palindrome(s: &str) -> bool {
+    s.chars().eq(s.chars().rev())
+  }
+
+  /// 逐个检查前缀是否为回文串的 O(n^2) 解法。 (The O(n^2) approach checking every prefix.)
+  fn brute_force(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let prefix_len = (0..=chars.len())
+      .rev()
+      .find(|&len| chars[..len].iter().eq(chars[..len].iter().rev()))
+      .unwrap();
+
+    chars[prefix_len..].iter().rev().chain(&chars).collect()
+  }
+
+  #[test]
+  fn known_answers() {
+    assert_eq!(shortest_palindrome("aacecaaa"), "aaacecaaa");
+    assert_eq!(shortest_palindrome("abcd"), "dcbabcd");
+    assert_eq!(shortest_palindrome("aab"), "baab");
+  }
+
+  #[test]
+  fn palindromes_are_unchanged() {
+    assert_eq!(shortest_palindrome("racecar"), "racecar");
+    assert_eq!(shortest_palindrome("abba"), "abba");
+    assert_eq!(shortest_palindrome("aaaa"), "aaaa");
+  }
+
+  #[test]
+  fn single_char_and_empty() {
+    assert_eq!(shortest_palindrome("x"), "x");
+    assert_eq!(shortest_palindrome(""), "");
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    assert_eq!(shortest_palindrome("上海自来水"), "水来自海上海自来水");
+    assert_eq!(shortest_palindrome("éaé"), "éaé");
+    assert_eq!(shortest_palindrome("éa"), "aéa");
+  }
+
+  #[test]
+  fn matches_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let s: String = (0..rng.gen_range(0..16))
+        .map(|_| ['a', 'b', 'é', '好'][rng.gen_range(0..4)])
+        .collect();
+      let palindrome = shortest_palindrome(&s);
+
+      assert_eq!(palindrome, brute_force(&s), "{s}");
+      assert!(is_palindrome(&palindrome) && palindrome.ends_with(&s));
+    }
+  }
+}
+
+fn main() {}
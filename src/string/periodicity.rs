@@ -0,0 +1,140 @@
+use rust_algorithm::string::knuth_morris_pratt::string_period;
+
+/// 如果 `s` 由某个子串 `u` 重复 k >= 2 次构成，返回最短的这样的 `u`，否则返回 `None`。按字符处理。
+/// Returns the shortest unit `u` such that `s` is `u` repeated k >= 2 times, or `None` if there is
+/// none. Chars rather than bytes are compared.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要检查的字符串。
+///   The string to examine.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(repeated_substring_pattern("abcabcabc"), Some("abc".to_string()));
+/// assert_eq!(repeated_substring_pattern("aba"), None);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 由 KMP 部分匹配表得到字符串的最小周期 p = n - f[n - 1]（见 [`string_period`]）。
+/// p 整除 n 且 p < n 时，长为 p 的前缀就是最短的重复单元；否则字符串不是任何子串的重复。
+/// The KMP partial match table gives the smallest period p = n - f[n - 1] of the string (see
+/// [`string_period`]). If p divides n and p < n, the prefix of length p is the shortest repeating
+/// unit; otherwise the string is no repetition of a substring.
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+pub fn repeated_substring_pattern(s: &str) -> Option<String> {
+  match repetition_count(s) {
+    (unit, count) if count >= 2 => Some(unit),
+    _ => None,
+  }
+}
+
+/// 返回 `s` 的本原根和指数：最短的 `u` 和最大的 k，使得 `s` 是 `u` 重复 k 次。
+/// Returns the primitive root and exponent of `s`: the shortest `u` and the largest k such that
+/// `s` is `u` repeated k times.
+///
+/// 不是任何子串的重复的字符串，本原根就是它自身，指数为 1。空字符串返回 `("", 0)`。
+/// A string that repeats no substring is its own primitive root with exponent 1. The empty string
+/// gives `("", 0)`.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(repetition_count("abab"), ("ab".to_string(), 2));
+/// assert_eq!(repetition_count("abc"), ("abc".to_string(), 1));
+/// ```
+pub fn repetition_count(s: &str) -> (String, usize) {
+  let n = s.chars().count();
+  let period = string_period(s);
+
+  if n == 0 {
+    (String::new(), 0)
+  } else if n.is_multiple_of(period) {
+    (s.chars().take(period).collect(), n / period)
+  } else {
+    (s.to_string(), 1)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  /// 逐个检查长度的约数的暴力解法。 (The brute force checking every divisor of the length.)
+  fn brute_force(s: &str) -> (String, usize) {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+
+    (1..=n)
+      .find(|&p| n.is_multiple_of(p) && chars.chunks(p).all(|chunk| chunk == &chars[..p]))
+      .map_or((String::new(), 0), |p| (chars[..p].iter().collect(), n / p))
+  }
+
+  #[test]
+  fn repeated_units() {
+    assert_eq!(repeated_substring_pattern("abab"), Some("ab".to_string()));
+    assert_eq!(
+      repeated_substring_pattern("abcabcabc"),
+      Some("abc".to_string())
+    );
+    assert_eq!(repetition_count("abcabcabc"), ("abc".to_string(), 3));
+  }
+
+  #[test]
+  fn not_repeated() {
+    assert_eq!(repeated_substring_pattern("aba"), None);
+    assert_eq!(repeated_substring_pattern("abcab"), None);
+    assert_eq!(repetition_count("abcab"), ("abcab".to_string(), 1));
+  }
+
+  #[test]
+  fn single_and_empty() {
+    assert_eq!(repeated_substring_pattern("a"), None);
+    assert_eq!(repetition_count("a"), ("a".to_string(), 1));
+    assert_eq!(repeated_substring_pattern(""), None);
+    assert_eq!(repetition_count(""), (String::new(), 0));
+  }
+
+  #[test]
+  fn all_equal_chars() {
+    assert_eq!(repeated_substring_pattern("aaaa"), Some("a".to_string()));
+    assert_eq!(repetition_count("aaaaaaa"), ("a".to_string(), 7));
+    assert_eq!(repetition_count("好好好"), ("好".to_string(), 3));
+  }
+
+  #[test]
+  fn matches_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let unit: String = (0..rng.gen_range(1..4))
+        .map(|_| rng.gen_range('a'..='b'))
+        .collect();
+      let mut s = unit.repeat(rng.gen_range(1..5));
+
+      // 有时再追加一个字符，破坏周期性
+      // Sometimes append one more char to break the periodicity
+      if rng.gen_bool(0.3) {
+        s.push(rng.gen_range('a'..='b'));
+      }
+
+      let expected = brute_force(&s);
+
+      assert_eq!(repetition_count(&s), expected, "{s}");
+      assert_eq!(
+        repeated_substring_pattern(&s),
+        (expected.1 >= 2).then_some(expected.0),
+        "{s}"
+      );
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,290 @@
+/// 用 Heap 算法生成字符串中字符的所有排列。
+/// Generates all permutations of a string's chars with Heap's algorithm.
+///
+/// 重复的字符被视为不同的字符，所以结果中可能有相同的字符串，长为 n 的字符串总是产生 n! 个结果；
+/// 需要去重时使用 [`unique_permutations`]。结果不按字典序排列。空字符串只有一个排列，即空字符串本身。
+/// Repeated chars are treated as distinct, so the result may contain equal strings and a string of
+/// n chars always yields n! of them; use [`unique_permutations`] to skip repeats. The result is
+/// not in lexicographic order. The empty string has exactly one permutation, itself.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(permutations("abc").len(), 6);
+/// assert_eq!(permutations("aab").len(), 6);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// Heap's algorithm gets from one permutation to the next with a single swap, so the time
+/// complexity is O(n! * n), dominated by building the n! strings.
+/// Heap 算法每次只交换一对字符就得到下一个排列，时间复杂度为 O(n! * n)，主要花在构造 n! 个字符串上。
+pub fn permutations(s: &str) -> Vec<String> {
+  Permutations::new(s).collect()
+}
+
+/// 按字典序生成字符串中字符的所有不同排列，跳过因重复字符而相同的排列。
+/// Generates all distinct permutations of a string's chars in lexicographic order, skipping the
+/// ones that repeated chars make equal.
+///
+/// 从排好序的字符开始，反复调用 [`next_permutation`] 直到回到开头。
+/// Starts from the sorted chars and calls [`next_permutation`] until it wraps around.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(unique_permutations("aab"), vec!["aab", "aba", "baa"]);
+/// ```
+pub fn unique_permutations(s: &str) -> Vec<String> {
+  let mut chars: Vec<char> = s.chars().collect();
+  chars.sort_unstable();
+
+  let mut ret = vec![chars.iter().collect()];
+
+  while next_permutation(&mut chars) {
+    ret.push(chars.iter().collect());
+  }
+
+  ret
+}
+
+/// 把字符原地变为字典序中的下一个排列，并返回 true；已经是最后一个（降序）排列时，
+/// 把它变回第一个（升序）排列并返回 false。
+/// Rearranges the chars in place into the lexicographically next permutation and returns true;
+/// if they already are the last (descending) permutation, rearranges them into the first
+/// (ascending) one and returns false.
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 从右向左找到第一个满足 `chars[i] < chars[i + 1]` 的位置 i，它右边是一段降序的后缀。
+/// 把 `chars[i]` 与后缀中比它大的最右边的字符交换，再把后缀反转为升序。
+/// Find the rightmost i with `chars[i] < chars[i + 1]`; everything to its right is a descending
+/// suffix. Swap `chars[i]` with the rightmost char of the suffix that is greater than it, then
+/// reverse the suffix into ascending order.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut chars = vec!['a', 'c', 'b'];
+/// assert!(next_permutation(&mut chars));
+/// assert_eq!(chars, vec!['b', 'a', 'c']);
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time in the worst case and O(1) amortized over a full cycle, with O(1) extra space.
+/// 最坏情况下时间复杂度为 O(n)，遍历所有排列时均摊为 O(1)，额外空间复杂度为 O(1)。
+pub fn next_permutation(chars: &mut [char]) -> bool {
+  let Some(i) = (1..chars.len()).rev().find(|&i| chars[i - 1] < chars[i]) else {
+    chars.reverse();
+    return false;
+  };
+
+  // 后缀 chars[i..] 是降序的，从右向左第一个大于 chars[i - 1] 的就是其中最小的较大者
+  // The suffix chars[i..] is descending, so the first char from the right greater than
+  // chars[i - 1] is the smallest greater one
+  let j = (i..chars.len())
+    .rev()
+    .find(|&j| chars[j] > chars[i - 1])
+    .unwrap();
+
+  chars.swap(i - 1, j);
+  chars[i..].reverse();
+
+  true
+}
+
+/// 用 Heap 算法逐个产生字符串中字符的所有排列的迭代器，不需要一次性构造全部 n! 个字符串。
+/// An iterator over all permutations of a string's chars by Heap's algorithm, producing them one
+/// at a time instead of materializing all n! strings.
+///
+/// 与 [`permutations`] 产生的顺序相同。
+/// Yields the permutations in the same order as [`permutations`].
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let mut perms = Permutations::new("ab");
+/// assert_eq!(perms.next(), Some("ab".to_string()));
+/// assert_eq!(perms.next(), Some("ba".to_string()));
+/// assert_eq!(perms.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Permutations {
+  chars: Vec<char>,
+  /// Heap 算法的栈状态：`counters[i]` 是前 i + 1 个字符的排列中已经做过的交换次数。
+  /// (The stack state of Heap's algorithm: `counters[i]` is how many swaps have been made while
+  /// permuting the first i + 1 chars.)
+  counters: Vec<usize>,
+  /// 下一个要检查的栈层，`None` 表示还没有产生第一个排列。
+  /// (The next stack level to examine, `None` before the first permutation is produced.)
+  level: Option<usize>,
+}
+
+impl Permutations {
+  /// 创建遍历 `s` 中字符的所有排列的迭代器。 (Creates an iterator over all permutations of `s`.)
+  pub fn new(s: &str) -> Self {
+    let chars: Vec<char> = s.chars().collect();
+    let counters = vec![0; chars.len()];
+
+    Permutations {
+      chars,
+      counters,
+      level: None,
+    }
+  }
+}
+
+impl Iterator for Permutations {
+  type Item = String;
+
+  fn next(&mut self) -> Option<String> {
+    let Some(mut i) = self.level else {
+      // 第一个排列就是输入本身
+      // The first permutation is the input itself
+      self.level = Some(1);
+      return Some(self.chars.iter().collect());
+    };
+
+    while i < self.chars.len() {
+      if self.counters[i] < i {
+        // 偶数层与第一个字符交换，奇数层与第 counters[i] 个字符交换
+        // Even levels swap with the first char, odd levels with the counters[i]-th one
+        if i % 2 == 0 {
+          self.chars.swap(0, i);
+        } else {
+          self.chars.swap(self.counters[i], i);
+        }
+
+        self.counters[i] += 1;
+        self.level = Some(1);
+        return Some(self.chars.iter().collect());
+      }
+
+      self.counters[i] = 0;
+      i += 1;
+    }
+
+    self.level = Some(i);
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+
+  fn factorial(n: usize) -> usize {
+    (1..=n).product()
+  }
+
+  #[test]
+  fn counts() {
+    assert_eq!(permutations("abc").len(), 6);
+    assert_eq!(permutations("abcde").len(), factorial(5));
+    assert_eq!(permutations("a").len(), 1);
+    assert_eq!(permutations(""), vec![""]);
+
+    assert_eq!(permutations("aab").len(), 6);
+    assert_eq!(unique_permutations("aab").len(), 3);
+    assert_eq!(unique_permutations("aabb").len(), 6);
+    assert_eq!(unique_permutations("aaa"), vec!["aaa"]);
+    assert_eq!(unique_permutations(""), vec![""]);
+  }
+
+  #[test]
+  fn heap_permutations_are_distinct() {
+    let perms = permutations("abcdef");
+    let distinct: HashSet<&String> = perms.iter().collect();
+
+    assert_eq!(distinct.len(), factorial(6));
+    assert!(perms.iter().all(|p| {
+      let mut chars: Vec<char> = p.chars().collect();
+      chars.sort_unstable();
+      chars == ['a', 'b', 'c', 'd', 'e', 'f']
+    }));
+  }
+
+  #[test]
+  fn heap_order() {
+    assert_eq!(
+      permutations("abc"),
+      vec!["abc", "bac", "cab", "acb", "bca", "cba"]
+    );
+  }
+
+  #[test]
+  fn next_permutation_lexicographic() {
+    let mut chars = vec!['a', 'b', 'c', 'd'];
+    let mut seen = vec![chars.clone()];
+
+    while next_permutation(&mut chars) {
+      assert!(seen.last().unwrap() < &chars);
+      seen.push(chars.clone());
+    }
+
+    assert_eq!(seen.len(), factorial(4));
+    assert_eq!(seen.last().unwrap(), &vec!['d', 'c', 'b', 'a']);
+
+    // 最后一个排列之后回到第一个
+    // After the last permutation it wraps around to the first
+    assert_eq!(chars, vec!['a', 'b', 'c', 'd']);
+  }
+
+  #[test]
+  fn next_permutation_edge_cases() {
+    let mut empty: Vec<char> = vec![];
+    assert!(!next_permutation(&mut empty));
+
+    let mut single = vec!['x'];
+    assert!(!next_permutation(&mut single));
+    assert_eq!(single, vec!['x']);
+
+    let mut chars = vec!['a', 'b', 'b'];
+    assert!(next_permutation(&mut chars));
+    assert_eq!(chars, vec!['b', 'a', 'b']);
+  }
+
+  #[test]
+  fn unique_permutations_sorted_and_distinct() {
+    let perms = unique_permutations("baca");
+    let distinct: HashSet<&String> = perms.iter().collect();
+
+    assert_eq!(perms.len(), 12);
+    assert_eq!(distinct.len(), 12);
+    assert!(perms.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(perms.first().unwrap(), "aabc");
+  }
+
+  #[test]
+  fn iterator_matches_batch() {
+    for s in ["", "a", "ab", "abc", "abcd", "aabb", "héllo"] {
+      let streamed: Vec<String> = Permutations::new(s).collect();
+
+      assert_eq!(streamed, permutations(s), "{s}");
+    }
+  }
+
+  #[test]
+  fn iterator_is_fused() {
+    let mut perms = Permutations::new("ab");
+
+    assert_eq!(perms.by_ref().count(), 2);
+    assert_eq!(perms.next(), None);
+    assert_eq!(perms.next(), None);
+  }
+
+  #[test]
+  fn iterator_is_lazy() {
+    // 20! 个排列无法全部构造，但可以取前几个
+    // 20! permutations could never be materialized, but the first few can be taken
+    let first: Vec<String> = Permutations::new("abcdefghijklmnopqrst").take(3).collect();
+
+    assert_eq!(first[0], "abcdefghijklmnopqrst");
+    assert_eq!(first[1], "bacdefghijklmnopqrst");
+    assert_eq!(first.len(), 3);
+  }
+}
+
+fn main() {}
@@ -0,0 +1,163 @@
+/// 计算名字的美国 Soundex 编码：保留首字母（大写），后面跟三位数字，不足时补 0。
+/// Computes the American Soundex code of a name: the first letter (uppercased) followed by three
+/// digits, padded with zeros.
+///
+/// 只考虑 ASCII 字母，大小写不敏感；其他字符（包括非 ASCII 字母、空格和标点）被当作不存在而直接跳过，
+/// 所以不会 panic。没有任何 ASCII 字母时返回空字符串。
+/// Only ASCII letters are considered, case-insensitively; every other char (non-ASCII letters,
+/// spaces and punctuation included) is skipped as if it were not there, so nothing panics. The
+/// result is the empty string if there are no ASCII letters at all.
+///
+/// # 参数 (Arguments)
+///
+/// * `name` - 要编码的名字。
+///   The name to encode.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Tymczak"), "T522");
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 字母按发音分组编码：BFPV → 1，CGJKQSXZ → 2，DT → 3，L → 4，MN → 5，R → 6，元音和 H、W、Y 没有编码。
+/// 相邻的相同编码只保留一个（首字母也参与比较）；元音会把相同编码隔开，而 H 和 W 不会。
+/// Letters are coded by sound: BFPV → 1, CGJKQSXZ → 2, DT → 3, L → 4, MN → 5, R → 6, while
+/// vowels, H, W and Y get no code. Adjacent equal codes collapse into one (the first letter takes
+/// part too); a vowel separates equal codes, but H and W do not.
+pub fn soundex(name: &str) -> String {
+  let mut letters = name
+    .chars()
+    .filter(char::is_ascii_alphabetic)
+    .map(|c| c.to_ascii_uppercase());
+
+  let Some(first) = letters.next() else {
+    return String::new();
+  };
+
+  let mut ret = String::from(first);
+  let mut last = code(first);
+
+  for c in letters {
+    if ret.len() == 4 {
+      break;
+    }
+
+    match code(c) {
+      // H 和 W 不会隔开相同的编码
+      // H and W do not separate equal codes
+      None if c == 'H' || c == 'W' => {}
+      None => last = None,
+      Some(digit) => {
+        if last != Some(digit) {
+          ret.push(digit);
+        }
+
+        last = Some(digit);
+      }
+    }
+  }
+
+  format!("{ret:0<4}")
+}
+
+/// 判断两个名字的 Soundex 编码是否相同。不含 ASCII 字母的名字与任何名字都不相似。
+/// Tells whether two names have the same Soundex code. A name without ASCII letters sounds like
+/// no name at all.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert!(sounds_like("Robert", "Rupert"));
+/// assert!(!sounds_like("Robert", "Rubin"));
+/// ```
+pub fn sounds_like(a: &str, b: &str) -> bool {
+  let code = soundex(a);
+
+  !code.is_empty() && code == soundex(b)
+}
+
+/// 大写字母的 Soundex 数字，没有编码的字母返回 `None`。
+/// The Soundex digit of an uppercase letter, or `None` for letters without one.
+fn code(c: char) -> Option<char> {
+  match c {
+    'B' | 'F' | 'P' | 'V' => Some('1'),
+    'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+    'D' | 'T' => Some('3'),
+    'L' => Some('4'),
+    'M' | 'N' => Some('5'),
+    'R' => Some('6'),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn canonical_codes() {
+    assert_eq!(soundex("Robert"), "R163");
+    assert_eq!(soundex("Rupert"), "R163");
+    assert_eq!(soundex("Rubin"), "R150");
+    assert_eq!(soundex("Tymczak"), "T522");
+    assert_eq!(soundex("Pfister"), "P236");
+    assert_eq!(soundex("Honeyman"), "H555");
+    assert_eq!(soundex("Ashcraft"), "A261");
+    assert_eq!(soundex("Tschirner"), "T265");
+  }
+
+  #[test]
+  fn case_insensitive() {
+    assert_eq!(soundex("robert"), "R163");
+    assert_eq!(soundex("ROBERT"), "R163");
+    assert!(sounds_like("ashcraft", "ASHCROFT"));
+  }
+
+  #[test]
+  fn short_names_are_padded() {
+    assert_eq!(soundex("Lee"), "L000");
+    assert_eq!(soundex("A"), "A000");
+    assert_eq!(soundex("Gauss"), "G200");
+    assert_eq!(soundex("Hilbert"), "H416");
+  }
+
+  #[test]
+  fn no_letters() {
+    assert_eq!(soundex(""), "");
+    assert_eq!(soundex("123 -!"), "");
+    assert_eq!(soundex("王小明"), "");
+    assert!(!sounds_like("", ""));
+    assert!(!sounds_like("42", "42"));
+  }
+
+  #[test]
+  fn other_chars_are_skipped() {
+    assert_eq!(soundex("O'Hara"), soundex("OHara"));
+    assert_eq!(soundex("  robert "), "R163");
+    assert_eq!(soundex("Müller"), soundex("Mller"));
+    assert_eq!(soundex("Émile"), "M400");
+  }
+
+  #[test]
+  fn sounds_like_pairs() {
+    assert!(sounds_like("Robert", "Rupert"));
+    assert!(sounds_like("Smith", "Smyth"));
+    assert!(!sounds_like("Robert", "Rubin"));
+    assert!(!sounds_like("Smith", "Jones"));
+  }
+
+  #[test]
+  fn stable_across_calls() {
+    for name in ["Robert", "Tymczak", "Pfister", "Honeyman", "Lee", ""] {
+      let code = soundex(name);
+
+      assert!(code.is_empty() || code.len() == 4, "{name}: {code}");
+      assert_eq!(soundex(name), code);
+    }
+  }
+}
+
+fn main() {}
@@ -1,3 +1,14 @@
+/// [`rabin_karp`] 使用的多项式哈希的基数：每个字节是一位 256 进制数字。
+/// (The base of the polynomial hash [`rabin_karp`] uses: each byte is one base-256 digit.)
+pub const BASE: u64 = 256;
+
+/// [`rabin_karp`] 使用的多项式哈希的模数，一个大质数。两个不同的等长窗口发生冲突的概率约为 1 / MODULUS，
+/// 而模数小于 2^30，所以滚动时的 `hash * BASE + byte` 不会溢出 u64。
+/// (The modulus of the polynomial hash [`rabin_karp`] uses, a large prime. Two different windows of
+/// equal length collide with a probability of about 1 / MODULUS, and as the modulus is below
+/// 2^30, `hash * BASE + byte` never overflows a u64 while rolling.)
+pub const MODULUS: u64 = 1_000_000_007;
+
 /// Searches for occurrences of the `pattern` string within the `target` string using the Rabin-Karp algorithm.
 ///
 /// The Rabin-Karp algorithm is a string searching algorithm that uses hashing to quickly locate the occurrences
@@ -24,115 +35,220 @@
 /// # 返回值
 ///
 /// 包含所有模式字符串出现位置的起始索引的向量。
+///
+/// # 复杂度 (Complexity)
+///
+/// The first window's hash is computed once and then rolled in O(1) per position, so the
+/// expected time complexity is O(n + m); only hash hits are verified byte by byte. No memory is
+/// allocated per window.
+/// 第一个窗口的哈希值只计算一次，之后每个位置 O(1) 地滚动更新，期望时间复杂度为 O(n + m)；
+/// 只有哈希相同的窗口才逐字节验证，每个窗口都不分配内存。
 pub fn rabin_karp(target: String, pattern: String) -> Vec<usize> {
-  // Quick exit
-  if target.is_empty() || pattern.is_empty() || pattern.len() > target.len() {
-    return vec![];
-  }
-
-  // Convert pattern to a string to avoid mutability issues
-  // 将模式字符串转换为新的字符串，以避免可变性问题
-  let string: String = (&pattern[0..pattern.len()]).to_string();
-
-  // Calculate hash of the pattern
-  // 计算模式字符串的哈希值
-  let hash_pattern = hash(string.clone());
-  let mut ret = vec![];
-
-  // Iterate through the target string
-  // 遍历目标字符串
-  for i in 0..(target.len() - pattern.len() + 1) {
-    // Extract a substring of the same length as the pattern
-    // 提取与模式字符串长度相同的子字符串
-    let s = (&target[i..(i + pattern.len())]).to_string();
-
-    // Calculate hash of the current substring
-    // 计算当前子字符串的哈希值
-    let string_hash = hash(s.clone());
-
-    // Compare hashes and full strings to find matches
-    // 比较哈希值和完整字符串以找到匹配项
-    if string_hash == hash_pattern && s == string {
-      // Store the starting index of the match
-      // 存储匹配项的起始索引
-      ret.push(i);
-    }
-  }
-
-  ret
+  search(target.as_bytes(), pattern.as_bytes()).0
 }
 
-/// Calculates the hash value of a string using the Rabin-Karp hash function.
-///
-/// The Rabin-Karp hash function is used to generate a hash value for a string based on its ASCII values and a prime number.
-///
-/// # Arguments
-///
-/// * `s` - The input string for which to calculate the hash value.
-///
-/// # Returns
+/// 计算字节串的多项式哈希值：把它看作一个 `base` 进制数，再对 `modulus` 取模。
+/// Calculates the polynomial hash of a byte string, reading it as a base-`base` number modulo
+/// `modulus`.
 ///
-/// The calculated hash value of the input string.
+/// 即 `(s[0] * base^(m-1) + s[1] * base^(m-2) + ... + s[m-1]) mod modulus`。中间结果用 u128 计算，
+/// 所以任意 u64 的基数和模数都不会溢出。[`rabin_karp`] 使用的是 [`BASE`] 和 [`MODULUS`]。
+/// That is `(s[0] * base^(m-1) + s[1] * base^(m-2) + ... + s[m-1]) mod modulus`. Intermediate
+/// results are computed in u128, so no u64 base or modulus can overflow. [`rabin_karp`] uses
+/// [`BASE`] and [`MODULUS`].
 ///
-/// 使用 Rabin-Karp 哈希函数计算字符串的哈希值。
+/// # 参数 (Arguments)
 ///
-/// Rabin-Karp 哈希函数基于字符串的 ASCII 值和一个质数来生成哈希值。
+/// * `s` - 需要计算哈希值的字节串。
+///   The bytes for which to calculate the hash value.
+/// * `base` - 每个字节作为一位数字的基数。
+///   The base in which each byte is one digit.
+/// * `modulus` - 模数，不能为 0。
+///   The modulus, which must not be 0.
 ///
-/// # 参数
+/// # 示例 (Examples)
 ///
-/// * `s` - 需要计算哈希值的输入字符串。
+/// ```
+/// assert_eq!(polynomial_hash(b"hi", 256, 1_000_000_007), 104 * 256 + 105);
+/// assert_eq!(polynomial_hash(b"hi", 256, 101), (104 * 256 + 105) % 101);
+/// ```
 ///
-/// # 返回值
+/// # Panics
 ///
-/// 输入字符串的计算哈希值。
-fn hash(mut s: String) -> u16 {
-  let prime: u16 = 101;
-  let last_char = s
-    .drain(s.len() - 1..)
-    .next()
-    .expect("Failed to get the last char of the string");
-  let mut res: u16 = 0;
-
-  // Calculate hash using ASCII values and a rolling hash approach
-  // 使用 ASCII 值和滚动哈希的方法计算哈希值
-  // 1. 我们首先获取字符串的第一个字符的 ASCII 值，将其乘以 256，然后对一个质数（在这里是 101）取模，得到初始的哈希值 res。
-  // 2. 随后，我们遍历字符串中的每个字符（除了第一个字符），将其 ASCII 值加到 res 中，并对质数取模。这相当于将当前字符的影响叠加到先前的哈希值中。
-  // 3. 由于我们将当前字符的 ASCII 值添加到 res 中，我们需要确保 res 不会溢出，因此我们再次对质数取模。
-  // 这样，我们通过对每个字符的 ASCII 值应用一系列操作，就能够计算出整个字符串的哈希值。在滑动窗口移动时，我们只需添加新字符的影响并去除旧字符的影响，从而快速更新哈希值
-  for (i, &c) in s.as_bytes().iter().enumerate() {
-    if i == 0 {
-      res = (c as u16 * 256) % prime;
-    } else {
-      res = (((res + c as u16) % 101) * 256) % 101;
+/// Panics if `modulus` is 0.
+/// `modulus` 为 0 时 panic。
+pub fn polynomial_hash(s: &[u8], base: u64, modulus: u64) -> u64 {
+  assert!(modulus > 0, "modulus must not be 0");
+
+  let (base, modulus) = (base as u128, modulus as u128);
+  let hash = s
+    .iter()
+    .fold(0, |acc, &b| (acc * base + b as u128) % modulus);
+
+  hash as u64
+}
+
+/// 查找所有匹配位置，同时返回哈希相同后逐字节验证的次数。
+/// Finds all matches and also returns how many hash hits were verified byte by byte.
+fn search(text: &[u8], pattern: &[u8]) -> (Vec<usize>, usize) {
+  // Quick exit
+  if text.is_empty() || pattern.is_empty() || pattern.len() > text.len() {
+    return (vec![], 0);
+  }
+
+  // 按字节比较：合法的 UTF-8 模式不会从一个字符的中间开始匹配，所以返回的字节位置都在字符边界上
+  // Compare bytes: a valid UTF-8 pattern cannot match starting inside a char, so every returned
+  // byte position lies on a char boundary
+  let m = pattern.len();
+
+  // 窗口最高位的权重 BASE^(m-1)，用于移除离开窗口的字节
+  // The weight BASE^(m-1) of the window's leading digit, used to remove the outgoing byte
+  let high = (1..m).fold(1, |acc, _| acc * BASE % MODULUS);
+
+  let hash_pattern = polynomial_hash(pattern, BASE, MODULUS);
+  let mut window = polynomial_hash(&text[..m], BASE, MODULUS);
+  let mut ret = vec![];
+  let mut verifications = 0;
+
+  for i in 0..=(text.len() - m) {
+    // 哈希相同时再逐字节比较，排除哈希冲突
+    // On a hash hit, compare byte by byte to rule out a collision
+    if window == hash_pattern {
+      verifications += 1;
+
+      if &text[i..i + m] == pattern {
+        ret.push(i);
+      }
+    }
+
+    // 滚动哈希：减去离开的字节，整体左移一位，再加上进入的字节
+    // Roll the hash: subtract the outgoing byte, shift by one digit and add the incoming byte
+    if i + m < text.len() {
+      let outgoing = text[i] as u64 * high % MODULUS;
+      window = ((window + MODULUS - outgoing) * BASE + text[i + m] as u64) % MODULUS;
     }
   }
 
-  (res + last_char as u16) % prime
+  (ret, verifications)
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use rand::Rng;
+  use std::collections::{HashMap, HashSet};
+  use std::time::Instant;
+
+  fn hash(s: &[u8]) -> u64 {
+    polynomial_hash(s, BASE, MODULUS)
+  }
+
+  /// 旧实现使用的 u16 哈希，模 101，用于构造冲突。
+  /// The u16 hash modulo 101 the old implementation used, for crafting collisions.
+  fn old_hash(s: &[u8]) -> u16 {
+    let (&last, rest) = s.split_last().unwrap();
+    let mut res: u16 = 0;
+
+    for (i, &c) in rest.iter().enumerate() {
+      if i == 0 {
+        res = (c as u16 * 256) % 101;
+      } else {
+        res = (((res + c as u16) % 101) * 256) % 101;
+      }
+    }
+
+    (res + last as u16) % 101
+  }
 
   #[test]
   fn hi_hash() {
-    let hash_result = hash("hi".to_string());
+    let hash_result = hash(b"hi");
 
-    assert_eq!(hash_result, 65);
+    // 104 * 256 + 105
+    assert_eq!(hash_result, 26729);
   }
 
   #[test]
   fn abr_hash() {
-    let hash_result = hash("abr".to_string());
+    let hash_result = hash(b"abr");
 
-    assert_eq!(hash_result, 4);
+    // (97 * 256 + 98) * 256 + 114
+    assert_eq!(hash_result, 6382194);
   }
 
   #[test]
   fn bra_hash() {
-    let hash_result = hash("bra".to_string());
+    let hash_result = hash(b"bra");
+
+    // (98 * 256 + 114) * 256 + 97
+    assert_eq!(hash_result, 6451809);
+  }
+
+  #[test]
+  fn long_hash_wraps_modulus() {
+    // 256^4 = 4294967296 ≡ 294967268 (mod 1_000_000_007)
+    assert_eq!(hash(&[1, 0, 0, 0, 0]), 294967268);
+  }
+
+  #[test]
+  fn polynomial_hash_large_modulus() {
+    // 中间结果超过 u64 也不会溢出
+    // Intermediate results beyond u64 do not overflow
+    let modulus = u64::MAX - 58;
+    let expected = ((u64::MAX as u128 * 256 + 255) % modulus as u128) as u64;
+
+    assert_eq!(polynomial_hash(&[255; 9], 256, modulus), expected);
+    assert_eq!(polynomial_hash(b"", 256, modulus), 0);
+  }
+
+  #[test]
+  fn collision_rate() {
+    let mut rng = rand::thread_rng();
+    let strings: HashSet<Vec<u8>> = (0..10_000)
+      .map(|_| {
+        (0..rng.gen_range(1..=8))
+          .map(|_| rng.gen_range(b'a'..=b'z'))
+          .collect()
+      })
+      .collect();
+
+    let mut buckets = HashMap::new();
+    for s in &strings {
+      *buckets.entry(hash(s)).or_insert(0) += 1;
+    }
+
+    // 冲突数：哈希值与之前某个不同字符串相同的字符串个数
+    // Collisions: strings hashing to the same value as an earlier, different string
+    let collisions = strings.len() - buckets.len();
+    let old_collisions = strings.len()
+      - strings
+        .iter()
+        .map(|s| old_hash(s))
+        .collect::<HashSet<_>>()
+        .len();
+
+    assert!(collisions * 1000 < strings.len(), "{collisions} collisions");
+    assert!(old_collisions * 2 > strings.len());
+  }
 
-    assert_eq!(hash_result, 30);
+  #[test]
+  fn old_collisions_are_not_verified() {
+    // 这些窗口在旧的模 101 哈希下都与 "hi" 冲突
+    // Under the old hash modulo 101, each of these windows collides with "hi"
+    let text = b"dw fp jb su un wg";
+    for window in text.split(|&b| b == b' ') {
+      assert_eq!(old_hash(window), old_hash(b"hi"));
+    }
+
+    let (matches, verifications) = search(text, b"hi");
+
+    assert!(matches.is_empty());
+    assert_eq!(verifications, 0);
+
+    let (matches, verifications) = search(b"dw hi fp", b"hi");
+
+    assert_eq!(matches, vec![3]);
+    assert_eq!(verifications, 1);
   }
 
   // Attribution to @pgimalac for his tests from Knuth-Morris-Pratt
@@ -192,6 +308,67 @@ mod tests {
     assert_eq!(index, vec![]);
   }
 
+  #[test]
+  fn multi_byte_chars() {
+    let index = rabin_karp("héllo wörld héllo".to_string(), "héllo".to_string());
+
+    assert_eq!(index, vec![0, 14]);
+  }
+
+  #[test]
+  fn matches_naive_search() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+      let text: String = (0..rng.gen_range(1..200))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let pattern: String = (0..rng.gen_range(1..6))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let expected: Vec<usize> = (0..text.len())
+        .filter(|&i| text[i..].starts_with(&pattern))
+        .collect();
+
+      assert_eq!(rabin_karp(text.clone(), pattern.clone()), expected);
+    }
+  }
+
+  /// 在 5 MB 的文本上比较滚动哈希与逐窗口重新计算哈希的耗时。
+  /// Compares rolling the hash against rehashing every window on a 5 MB text.
+  ///
+  /// `cargo test --release --lib rabin_karp -- --ignored --nocapture`
+  #[test]
+  #[ignore]
+  fn timing_5mb() {
+    let mut rng = rand::thread_rng();
+    let text: String = (0..5 * 1024 * 1024)
+      .map(|_| rng.gen_range('a'..='d'))
+      .collect();
+    let pattern: String = text[text.len() - 256..].to_string();
+
+    let start = Instant::now();
+    let rolling = rabin_karp(text.clone(), pattern.clone());
+    let rolling_time = start.elapsed();
+
+    // 旧实现的做法：每个窗口都从头计算哈希，复杂度为 O(n * m)
+    // What the old implementation did: hash every window from scratch, in O(n * m)
+    let start = Instant::now();
+    let target = hash(pattern.as_bytes());
+    let rehashing: Vec<usize> = text
+      .as_bytes()
+      .windows(pattern.len())
+      .enumerate()
+      .filter(|(_, w)| hash(w) == target && *w == pattern.as_bytes())
+      .map(|(i, _)| i)
+      .collect();
+    let rehashing_time = start.elapsed();
+
+    println!("rolling: {rolling_time:?}, rehashing every window: {rehashing_time:?}");
+    assert_eq!(rolling, rehashing);
+    assert!(rolling_time < rehashing_time);
+  }
+
   #[test]
   fn empty_string() {
     let index = rabin_karp("".to_string(), "abcdef".to_string());
@@ -199,3 +376,5 @@ mod tests {
     assert_eq!(index, vec![]);
   }
 }
+
+fn main() {}
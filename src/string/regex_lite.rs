@@ -0,0 +1,658 @@
+use std::fmt;
+
+/// 编译正则表达式失败的原因，位置是模式中的字符索引。
+/// Why a regular expression could not be compiled; positions are char indices into the pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+  /// 位于 `position` 的 '(' 没有对应的 ')'。 (The '(' at `position` is never closed.)
+  UnclosedGroup { position: usize },
+  /// 位于 `position` 的 ')' 没有对应的 '('。 (The ')' at `position` has no matching '('.)
+  UnmatchedParen { position: usize },
+  /// 位于 `position` 的 '[' 没有对应的 ']'。 (The '[' at `position` is never closed.)
+  UnclosedClass { position: usize },
+  /// 位于 `position` 的量词前面没有可以重复的内容。
+  /// (The quantifier at `position` has nothing to repeat.)
+  NothingToRepeat { position: usize },
+  /// 位于 `position` 的字符范围的起点大于终点。
+  /// (The char range at `position` starts after it ends.)
+  InvalidRange {
+    position: usize,
+    start: char,
+    end: char,
+  },
+  /// 模式以单独的 '\' 结尾。 (The pattern ends with a lone '\'.)
+  DanglingEscape { position: usize },
+}
+
+impl fmt::Display for RegexError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RegexError::UnclosedGroup { position } => {
+        write!(f, "unclosed group opened at position {}", position)
+      }
+      RegexError::UnmatchedParen { position } => {
+        write!(f, "unmatched ')' at position {}", position)
+      }
+      RegexError::UnclosedClass { position } => {
+        write!(
+          f,
+          "unclosed character class opened at position {}",
+          position
+        )
+      }
+      RegexError::NothingToRepeat { position } => {
+        write!(
+          f,
+          "quantifier at position {} has nothing to repeat",
+          position
+        )
+      }
+      RegexError::InvalidRange {
+        position,
+        start,
+        end,
+      } => {
+        write!(
+          f,
+          "invalid range {}-{} at position {}",
+          start, end, position
+        )
+      }
+      RegexError::DanglingEscape { position } => {
+        write!(f, "dangling escape at position {}", position)
+      }
+    }
+  }
+}
+
+impl std::error::Error for RegexError {}
+
+/// 编译为 Thompson NFA 的小型正则表达式引擎，匹配时间与文本长度成线性关系。
+/// A minimal regular expression engine compiled to a Thompson NFA, matching in time linear in the
+/// length of the text.
+///
+/// 支持的语法 (Supported syntax):
+///
+/// * 字面字符，`\` 转义下一个字符。 (Literal chars; `\` escapes the next char.)
+/// * `.` 匹配任意一个字符。 (`.` matches any single char.)
+/// * `[a-z0-9_]` 字符类，`[^...]` 取反；`]` 写在开头、`-` 写在开头或结尾时按字面处理。
+///   (Character classes, negated with `[^...]`; a leading `]` and a leading or trailing `-` are
+///   literal.)
+/// * `(...)` 分组，`|` 选择，优先级低于连接。
+///   (Grouping with `(...)` and alternation with `|`, which binds looser than concatenation.)
+/// * `*`、`+`、`?` 贪婪量词。 (The greedy quantifiers `*`, `+` and `?`.)
+///
+/// 没有回溯：所有可能的 NFA 状态在文本上同时推进，所以像 `(a*)*b` 这样会让回溯引擎指数爆炸的模式
+/// 也只需要 O(n * m) 的时间，其中 n 是文本长度，m 是模式长度。
+/// There is no backtracking: all possible NFA states advance through the text simultaneously, so
+/// even patterns like `(a*)*b` that make backtracking engines explode take O(n * m) time, n being
+/// the length of the text and m that of the pattern.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let re = Regex::compile("gr(a|e)y+").unwrap();
+/// assert!(re.is_match("a grey cat"));
+/// assert_eq!(re.find("the grayyy sky"), Some((4, 10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Regex {
+  states: Vec<State>,
+  start: usize,
+}
+
+/// 语法树。 (The syntax tree.)
+#[derive(Debug, Clone)]
+enum Node {
+  Empty,
+  Char(char),
+  Any,
+  Class {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+  },
+  Concat(Vec<Node>),
+  Alt(Vec<Node>),
+  Star(Box<Node>),
+  Plus(Box<Node>),
+  Question(Box<Node>),
+}
+
+/// NFA 的状态，`usize` 是后继状态的下标。 (An NFA state; each `usize` indexes a successor state.)
+#[derive(Debug, Clone)]
+enum State {
+  Char(char, usize),
+  Any(usize),
+  Class {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+    next: usize,
+  },
+  /// 不消耗字符地转到两个状态之一，前者优先。
+  /// (Moves to either state without consuming a char, preferring the first.)
+  Split(usize, usize),
+  Match,
+}
+
+impl Regex {
+  /// 把模式编译为 NFA，语法错误时返回 [`RegexError`]。
+  /// Compiles a pattern to an NFA, returning a [`RegexError`] on a syntax error.
+  pub fn compile(pattern: &str) -> Result<Regex, RegexError> {
+    let mut parser = Parser {
+      chars: pattern.chars().collect(),
+      pos: 0,
+    };
+    let node = parser.parse_alt()?;
+
+    // parse_alt 只会在 ')' 或模式结尾处停下，此时停在 ')' 说明它没有对应的 '('
+    // parse_alt only stops at a ')' or the end; stopping at a ')' means it has no '('
+    if parser.pos < parser.chars.len() {
+      return Err(RegexError::UnmatchedParen {
+        position: parser.pos,
+      });
+    }
+
+    let mut states = vec![State::Match];
+    let start = compile(&node, 0, &mut states);
+
+    Ok(Regex { states, start })
+  }
+
+  /// 判断文本中是否有任意一处匹配。 (Tells whether the pattern matches anywhere in the text.)
+  pub fn is_match(&self, text: &str) -> bool {
+    self.find(text).is_some()
+  }
+
+  /// 返回最左边的匹配的字节范围 `(start, end)`。只保证起点是最左的、范围是一个合法的匹配；
+  /// 同一起点有多种匹配时，大多按贪婪量词和靠左的选择分支优先，但重复的子模式可以匹配空串时，
+  /// 选中的匹配可能与 Perl 不同。
+  /// Returns the byte range `(start, end)` of the leftmost match. It only promises the leftmost
+  /// start and some valid match from there: greedy quantifiers and earlier alternatives usually
+  /// win, but when a repeated subpattern can match the empty string the chosen end may differ
+  /// from Perl's.
+  pub fn find(&self, text: &str) -> Option<(usize, usize)> {
+    let mut current = Threads::new(self.states.len());
+    let mut next = Threads::new(self.states.len());
+    let mut found = None;
+
+    let positions = text
+      .char_indices()
+      .map(|(i, c)| (i, Some(c)))
+      .chain(std::iter::once((text.len(), None)));
+
+    for (pos, c) in positions {
+      // 还没有找到匹配时，从当前位置开始一个新的、优先级最低的线程
+      // Until a match is found, start a new thread here with the lowest priority
+      if found.is_none() {
+        self.add(&mut current, self.start, pos);
+      }
+
+      if current.is_empty() {
+        break;
+      }
+
+      for &(state, start) in &current.threads {
+        let advance = match &self.states[state] {
+          State::Match => {
+            found = Some((start, pos));
+            // 优先级更低的线程不会再被采用
+            // Lower-priority threads can no longer win
+            break;
+          }
+          State::Char(expected, succ) => (c == Some(*expected)).then_some(*succ),
+          State::Any(succ) => c.map(|_| *succ),
+          State::Class {
+            ranges,
+            negated,
+            next: succ,
+          } => c.filter(|&c| in_class(ranges, *negated, c)).map(|_| *succ),
+          State::Split(..) => unreachable!("split states are never on a thread list"),
+        };
+
+        if let Some(succ) = advance {
+          self.add(&mut next, succ, start);
+        }
+      }
+
+      std::mem::swap(&mut current, &mut next);
+      next.clear();
+    }
+
+    found
+  }
+
+  /// 把状态及其不消耗字符就能到达的所有状态加入线程列表，保持优先级顺序。
+  /// Adds a state and everything reachable from it without consuming a char to the thread list,
+  /// in priority order.
+  fn add(&self, threads: &mut Threads, state: usize, start: usize) {
+    let mut stack = vec![state];
+
+    while let Some(state) = stack.pop() {
+      if !threads.visit(state) {
+        continue;
+      }
+
+      match self.states[state] {
+        State::Split(preferred, other) => {
+          stack.push(other);
+          stack.push(preferred);
+        }
+        _ => threads.threads.push((state, start)),
+      }
+    }
+  }
+}
+
+/// 同一步中的线程列表：每个线程是 (状态, 匹配起点)，每个状态最多出现一次。
+/// The thread list of one step: each thread is (state, match start), each state at most once.
+struct Threads {
+  threads: Vec<(usize, usize)>,
+  visited: Vec<bool>,
+  touched: Vec<usize>,
+}
+
+impl Threads {
+  fn new(len: usize) -> Self {
+    Threads {
+      threads: vec![],
+      visited: vec![false; len],
+      touched: vec![],
+    }
+  }
+
+  fn is_empty(&self) -> bool {
+    self.threads.is_empty()
+  }
+
+  /// 标记状态已访问，第一次访问时返回 true。
+  /// Marks a state as visited, returning true on the first visit.
+  fn visit(&mut self, state: usize) -> bool {
+    if self.visited[state] {
+      return false;
+    }
+
+    self.visited[state] = true;
+    self.touched.push(state);
+    true
+  }
+
+  fn clear(&mut self) {
+    for state in self.touched.drain(..) {
+      self.visited[state] = false;
+    }
+
+    self.threads.clear();
+  }
+}
+
+fn in_class(ranges: &[(char, char)], negated: bool, c: char) -> bool {
+  ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != negated
+}
+
+/// 从后向前编译：给定匹配完成后要去的状态 `next`，返回这段语法树的起始状态。
+/// Compiles back to front: given the state `next` to continue with afterwards, returns the start
+/// state of the node.
+fn compile(node: &Node, next: usize, states: &mut Vec<State>) -> usize {
+  let push = |states: &mut Vec<State>, state| {
+    states.push(state);
+    states.len() - 1
+  };
+
+  match node {
+    Node::Empty => next,
+    Node::Char(c) => push(states, State::Char(*c, next)),
+    Node::Any => push(states, State::Any(next)),
+    Node::Class { ranges, negated } => push(
+      states,
+      State::Class {
+        ranges: ranges.clone(),
+        negated: *negated,
+        next,
+      },
+    ),
+    Node::Concat(nodes) => nodes
+      .iter()
+      .rev()
+      .fold(next, |next, node| compile(node, next, states)),
+    Node::Alt(branches) => {
+      let mut starts: Vec<usize> = branches
+        .iter()
+        .map(|branch| compile(branch, next, states))
+        .collect();
+      let last = starts.pop().unwrap();
+
+      // 从右向左串起 Split，使左边的分支优先
+      // Chain the splits from the right so that earlier branches are preferred
+      starts.into_iter().rev().fold(last, |rest, branch| {
+        push(states, State::Split(branch, rest))
+      })
+    }
+    Node::Star(inner) => {
+      // 先占位，循环体编译完后再回填
+      // Reserve the split first and fill it in once the body is compiled
+      let split = push(states, State::Split(next, next));
+      let body = compile(inner, split, states);
+      states[split] = State::Split(body, next);
+      split
+    }
+    Node::Plus(inner) => {
+      let split = push(states, State::Split(next, next));
+      let body = compile(inner, split, states);
+      states[split] = State::Split(body, next);
+      body
+    }
+    Node::Question(inner) => {
+      let body = compile(inner, next, states);
+      push(states, State::Split(body, next))
+    }
+  }
+}
+
+/// 递归下降语法分析器。 (A recursive descent parser.)
+struct Parser {
+  chars: Vec<char>,
+  pos: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.pos).copied()
+  }
+
+  fn bump(&mut self) -> Option<char> {
+    let c = self.peek();
+    self.pos += 1;
+    c
+  }
+
+  /// alt := concat ('|' concat)*
+  fn parse_alt(&mut self) -> Result<Node, RegexError> {
+    let mut branches = vec![self.parse_concat()?];
+
+    while self.peek() == Some('|') {
+      self.pos += 1;
+      branches.push(self.parse_concat()?);
+    }
+
+    Ok(if branches.len() == 1 {
+      branches.pop().unwrap()
+    } else {
+      Node::Alt(branches)
+    })
+  }
+
+  /// concat := repeat*
+  fn parse_concat(&mut self) -> Result<Node, RegexError> {
+    let mut nodes = vec![];
+
+    while !matches!(self.peek(), None | Some('|') | Some(')')) {
+      nodes.push(self.parse_repeat()?);
+    }
+
+    Ok(match nodes.len() {
+      0 => Node::Empty,
+      1 => nodes.pop().unwrap(),
+      _ => Node::Concat(nodes),
+    })
+  }
+
+  /// repeat := atom ('*' | '+' | '?')*
+  fn parse_repeat(&mut self) -> Result<Node, RegexError> {
+    let mut node = self.parse_atom()?;
+
+    loop {
+      node = match self.peek() {
+        Some('*') => Node::Star(Box::new(node)),
+        Some('+') => Node::Plus(Box::new(node)),
+        Some('?') => Node::Question(Box::new(node)),
+        _ => return Ok(node),
+      };
+      self.pos += 1;
+    }
+  }
+
+  fn parse_atom(&mut self) -> Result<Node, RegexError> {
+    let position = self.pos;
+
+    match self.bump().unwrap() {
+      '(' => {
+        let inner = self.parse_alt()?;
+
+        if self.bump() != Some(')') {
+          return Err(RegexError::UnclosedGroup { position });
+        }
+
+        Ok(inner)
+      }
+      '*' | '+' | '?' => Err(RegexError::NothingToRepeat { position }),
+      '.' => Ok(Node::Any),
+      '[' => self.parse_class(position),
+      '\\' => self.escaped().map(Node::Char),
+      c => Ok(Node::Char(c)),
+    }
+  }
+
+  /// 解析 '[' 之后的字符类，`open` 是 '[' 的位置。
+  /// Parses a character class after its '[', `open` being the position of the '['.
+  fn parse_class(&mut self, open: usize) -> Result<Node, RegexError> {
+    let negated = self.peek() == Some('^');
+    if negated {
+      self.pos += 1;
+    }
+
+    let mut ranges = vec![];
+    let mut first = true;
+
+    loop {
+      let position = self.pos;
+      let lo = match self.bump() {
+        None => return Err(RegexError::UnclosedClass { position: open }),
+        Some(']') if !first => break,
+        Some('\\') => self.escaped()?,
+        Some(c) => c,
+      };
+      first = false;
+
+      // '-' 后面还有不是 ']' 的字符时才构成范围
+      // A '-' forms a range only when followed by something other than ']'
+      let is_range =
+        self.peek() == Some('-') && !matches!(self.chars.get(self.pos + 1), None | Some(']'));
+
+      if !is_range {
+        ranges.push((lo, lo));
+        continue;
+      }
+
+      self.pos += 1;
+      let hi = match self.bump() {
+        Some('\\') => self.escaped()?,
+        Some(c) => c,
+        None => unreachable!(),
+      };
+
+      if lo > hi {
+        return Err(RegexError::InvalidRange {
+          position,
+          start: lo,
+          end: hi,
+        });
+      }
+
+      ranges.push((lo, hi));
+    }
+
+    Ok(Node::Class { ranges, negated })
+  }
+
+  /// 读取 '\' 之后被转义的字符。 (Reads the char escaped by a preceding '\'.)
+  fn escaped(&mut self) -> Result<char, RegexError> {
+    self.bump().ok_or(RegexError::DanglingEscape {
+      position: self.pos - 2,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::{Duration, Instant};
+
+  fn re(pattern: &str) -> Regex {
+    Regex::compile(pattern).unwrap()
+  }
+
+  #[test]
+  fn literals_and_dot() {
+    assert!(re("abc").is_match("xxabcxx"));
+    assert!(!re("abc").is_match("abx"));
+    assert_eq!(re("a.c").find("xxabcxx"), Some((2, 5)));
+    assert_eq!(re("b").find("abcb"), Some((1, 2)));
+    assert_eq!(re("").find("abc"), Some((0, 0)));
+    assert_eq!(re("x").find(""), None);
+  }
+
+  #[test]
+  fn escapes() {
+    assert!(re(r"a\.b").is_match("a.b"));
+    assert!(!re(r"a\.b").is_match("axb"));
+    assert!(re(r"\(\*\)").is_match("(*)"));
+    assert!(re(r"\\").is_match(r"a\b"));
+  }
+
+  #[test]
+  fn alternation_binds_looser_than_concatenation() {
+    // "ab|cd" 是 "ab" 或 "cd"，而不是 "a(b|c)d"
+    // "ab|cd" is "ab" or "cd", not "a(b|c)d"
+    let r = re("ab|cd");
+
+    assert!(r.is_match("ab"));
+    assert!(r.is_match("cd"));
+    assert!(!r.is_match("ad"));
+    assert_eq!(r.find("acd"), Some((1, 3)));
+    assert_eq!(r.find("abd"), Some((0, 2)));
+    assert!(re("a(b|c)d").is_match("acd"));
+    assert!(!re("a(b|c)d").is_match("ad"));
+  }
+
+  #[test]
+  fn quantifiers() {
+    assert_eq!(re("ab*").find("abbbc"), Some((0, 4)));
+    assert_eq!(re("ab*").find("ac"), Some((0, 1)));
+    assert_eq!(re("ab+").find("ac ab"), Some((3, 5)));
+    assert_eq!(re("colou?r").find("color"), Some((0, 5)));
+    assert_eq!(re("colou?r").find("colour"), Some((0, 6)));
+    assert_eq!(re("a**").find("aaa"), Some((0, 3)));
+  }
+
+  #[test]
+  fn nested_groups() {
+    let r = re("((ab)+|c)*d");
+
+    assert_eq!(r.find("xababcabd"), Some((1, 9)));
+    assert_eq!(r.find("d"), Some((0, 1)));
+    assert!(!r.is_match("abab"));
+    assert!(re("(a(b(c)))").is_match("abc"));
+    assert!(re("()").is_match(""));
+  }
+
+  #[test]
+  fn leftmost_first_priority() {
+    // 同一起点上，左边的选择分支优先，贪婪量词尽量多匹配
+    // At the same start the earlier alternative wins and greedy quantifiers take all they can
+    assert_eq!(re("a|ab").find("ab"), Some((0, 1)));
+    assert_eq!(re("ab|a").find("ab"), Some((0, 2)));
+    assert_eq!(re("a*").find("baaa"), Some((0, 0)));
+    assert_eq!(re("a+").find("baaa"), Some((1, 4)));
+  }
+
+  #[test]
+  fn empty_iterations_differ_from_perl() {
+    // Perl 不再重复一次只匹配了空串的迭代，得到 (0, 2)；这里的引擎不做这种检查，得到的是另一个合法匹配
+    // Perl refuses to repeat after an iteration that matched empty and gives (0, 2); this engine
+    // has no such rule and returns another valid match
+    let regex = re("b*((b*)?|.*[^a])*");
+    assert_eq!(regex.find("bbaaacc"), Some((0, 7)));
+  }
+
+  #[test]
+  fn classes() {
+    let r = re("[a-z0-9_]+");
+
+    assert_eq!(r.find("  foo_42!"), Some((2, 8)));
+    assert!(!r.is_match("ABC"));
+    assert_eq!(re("[^0-9]+").find("123abc4"), Some((3, 6)));
+    assert!(re("[]]").is_match("]"));
+    assert!(re("[a-]").is_match("-"));
+    assert!(re("[-a]").is_match("-"));
+    assert!(re(r"[\]\\]").is_match("\\"));
+    assert!(re("[^]]").is_match("a"));
+    assert!(!re("[^]]").is_match("]"));
+  }
+
+  #[test]
+  fn byte_offsets_with_multi_byte_chars() {
+    assert_eq!(re("ö+").find("héllo wööl"), Some((8, 12)));
+    assert_eq!(re("[α-ω]+").find("abc λογος!"), Some((4, 14)));
+    assert_eq!(re(".").find("你好"), Some((0, 3)));
+  }
+
+  #[test]
+  fn pathological_pattern_is_linear() {
+    let r = re("(a*)*b");
+    let text = "a".repeat(100_000) + "c";
+
+    let start = Instant::now();
+    assert!(!r.is_match("aaaaaaaaac"));
+    assert!(!r.is_match(&text));
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    assert!(r.is_match("aaaaaaaaab"));
+    assert!(re("(a|aa)*c").find(&"a".repeat(50_000)).is_none());
+  }
+
+  #[test]
+  fn invalid_patterns() {
+    assert_eq!(
+      Regex::compile("(ab").unwrap_err(),
+      RegexError::UnclosedGroup { position: 0 }
+    );
+    assert_eq!(
+      Regex::compile("a(b(c)").unwrap_err(),
+      RegexError::UnclosedGroup { position: 1 }
+    );
+    assert_eq!(
+      Regex::compile("ab)").unwrap_err(),
+      RegexError::UnmatchedParen { position: 2 }
+    );
+    assert_eq!(
+      Regex::compile("[a-z").unwrap_err(),
+      RegexError::UnclosedClass { position: 0 }
+    );
+    assert_eq!(
+      Regex::compile("a|*b").unwrap_err(),
+      RegexError::NothingToRepeat { position: 2 }
+    );
+    assert_eq!(
+      Regex::compile("+").unwrap_err(),
+      RegexError::NothingToRepeat { position: 0 }
+    );
+    assert_eq!(
+      Regex::compile("x[z-a]").unwrap_err(),
+      RegexError::InvalidRange {
+        position: 2,
+        start: 'z',
+        end: 'a'
+      }
+    );
+    assert_eq!(
+      Regex::compile(r"ab\").unwrap_err(),
+      RegexError::DanglingEscape { position: 2 }
+    );
+    assert_eq!(
+      RegexError::UnmatchedParen { position: 2 }.to_string(),
+      "unmatched ')' at position 2"
+    );
+  }
+}
+
+fn main() {}
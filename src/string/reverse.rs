@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 pub fn main() {}
 
 /// Reverses a given string.
@@ -78,6 +80,108 @@ pub fn reverse(text: &str) -> String {
   reversed_text
 }
 
+/// 按字形簇（用户感知的字符）反转字符串，需要启用 `unicode` 特性。
+/// Reverses a string by grapheme clusters (user-perceived characters); requires the `unicode`
+/// feature.
+///
+/// [`reverse`] 按 Unicode 标量值反转，会把组合字符与它修饰的字符分开，例如 "noe\u{308}l"（noël）
+/// 会变成 "l\u{308}eon"，分音符落到了 l 上；ZWJ 连接的 emoji 序列和由两个区域指示符组成的国旗也会被拆散。
+/// 本函数把每个扩展字形簇作为整体保持不变，只反转它们的顺序。对纯 ASCII 文本，两者的结果相同。
+/// [`reverse`] reverses Unicode scalar values, which separates combining marks from the chars
+/// they modify: "noe\u{308}l" (noël) becomes "l\u{308}eon", with the diaeresis now on the l; ZWJ
+/// emoji sequences and flags made of two regional indicators are torn apart too. This function
+/// keeps each extended grapheme cluster intact and only reverses their order. For plain ASCII
+/// text both give the same result.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(reverse_graphemes("noe\u{308}l"), "le\u{308}on");
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+#[cfg(feature = "unicode")]
+pub fn reverse_graphemes(text: &str) -> String {
+  use unicode_segmentation::UnicodeSegmentation;
+
+  text.graphemes(true).rev().collect()
+}
+
+/// 反转句子中以空白分隔的单词的顺序，每段空白原样保留在原来的位置。
+/// Reverses the order of the whitespace-separated words of a sentence, keeping every whitespace
+/// run verbatim in its place.
+///
+/// 第 i 段空白仍然是输出中的第 i 段空白，包括开头和结尾的空白、制表符和换行符；只有单词的顺序被反转。
+/// The i-th whitespace run stays the i-th run of the output, leading and trailing runs, tabs and
+/// newlines included; only the order of the words is reversed.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(reverse_words("  hello   world "), "  world   hello ");
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+pub fn reverse_words(text: &str) -> String {
+  let spans = spans(text);
+  let mut words = spans
+    .iter()
+    .filter(|(is_word, _)| *is_word)
+    .map(|(_, span)| &text[span.clone()])
+    .rev();
+
+  let mut reversed = String::with_capacity(text.len());
+
+  // 按原来的顺序写出每一段，单词段依次换成倒序的单词
+  // Write the spans in their original order, substituting the words in reverse order
+  for (is_word, span) in &spans {
+    if *is_word {
+      reversed.push_str(words.next().unwrap());
+    } else {
+      reversed.push_str(&text[span.clone()]);
+    }
+  }
+
+  reversed
+}
+
+/// 反转句子中单词的顺序，并把空白规范为单词之间的单个空格，去掉开头和结尾的空白。
+/// Reverses the order of the words of a sentence, normalizing whitespace to single spaces between
+/// words and dropping leading and trailing whitespace.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(reverse_words_collapsed("  hello \t world "), "world hello");
+/// ```
+pub fn reverse_words_collapsed(text: &str) -> String {
+  text.split_whitespace().rev().collect::<Vec<_>>().join(" ")
+}
+
+/// 把文本切分为交替的单词段和空白段，返回 (是否为单词, 字节范围)。
+/// Splits the text into alternating word and whitespace spans, as (is_word, byte range).
+fn spans(text: &str) -> Vec<(bool, Range<usize>)> {
+  let mut spans: Vec<(bool, Range<usize>)> = vec![];
+
+  for (i, c) in text.char_indices() {
+    let is_word = !c.is_whitespace();
+    let end = i + c.len_utf8();
+
+    match spans.last_mut() {
+      Some((last, span)) if *last == is_word => span.end = end,
+      _ => spans.push((is_word, i..end)),
+    }
+  }
+
+  spans
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -91,4 +195,100 @@ mod tests {
   fn test_sentence() {
     assert_eq!(reverse("step on no pets"), "step on no pets");
   }
+
+  #[cfg(feature = "unicode")]
+  #[test]
+  fn test_reverse_graphemes_combining_mark() {
+    let noel = "noe\u{308}l";
+    let reversed = reverse_graphemes(noel);
+
+    // ë 保持为 e 后接分音符，而按字符反转会把分音符移到 l 上
+    // ë stays an e followed by its diaeresis, whereas reversing chars moves it onto the l
+    assert_eq!(reversed, "le\u{308}on");
+    assert_eq!(reverse(noel), "l\u{308}eon");
+    assert_eq!(reverse_graphemes(&reversed), noel);
+  }
+
+  #[cfg(feature = "unicode")]
+  #[test]
+  fn test_reverse_graphemes_zwj_family() {
+    // 👨‍👩‍👧 由三个 emoji 和两个零宽连接符组成
+    // 👨‍👩‍👧 is three emoji joined by two zero-width joiners
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let text = format!("a{family}b");
+
+    assert_eq!(reverse_graphemes(&text), format!("b{family}a"));
+    assert_ne!(reverse(&text), format!("b{family}a"));
+  }
+
+  #[cfg(feature = "unicode")]
+  #[test]
+  fn test_reverse_graphemes_flags() {
+    // 🇫🇷🇯🇵：每面国旗是一对区域指示符
+    // 🇫🇷🇯🇵: each flag is a pair of regional indicators
+    let flags = "\u{1F1EB}\u{1F1F7}\u{1F1EF}\u{1F1F5}";
+
+    assert_eq!(
+      reverse_graphemes(flags),
+      "\u{1F1EF}\u{1F1F5}\u{1F1EB}\u{1F1F7}"
+    );
+    // 按字符反转得到的是 🇵🇯🇷🇫，不同的国旗
+    // Reversing chars gives 🇵🇯🇷🇫, different flags
+    assert_eq!(reverse(flags), "\u{1F1F5}\u{1F1EF}\u{1F1F7}\u{1F1EB}");
+  }
+
+  #[cfg(feature = "unicode")]
+  #[test]
+  fn test_reverse_graphemes_ascii_and_empty() {
+    for text in ["racecar", "hello, world", "step on no pets", "a\tb\nc", ""] {
+      assert_eq!(reverse_graphemes(text), reverse(text));
+    }
+  }
+
+  #[test]
+  fn test_reverse_words() {
+    assert_eq!(reverse_words("hello world"), "world hello");
+    assert_eq!(reverse_words("the quick brown fox"), "fox brown quick the");
+  }
+
+  #[test]
+  fn test_reverse_words_keeps_spaces() {
+    assert_eq!(reverse_words("  hello   world "), "  world   hello ");
+    assert_eq!(reverse_words(" a  bb   ccc"), " ccc  bb   a");
+    assert_eq!(reverse_words("   "), "   ");
+  }
+
+  #[test]
+  fn test_reverse_words_keeps_tabs_and_newlines() {
+    assert_eq!(
+      reverse_words("first\tsecond\nthird \r\n fourth"),
+      "fourth\tthird\nsecond \r\n first"
+    );
+  }
+
+  #[test]
+  fn test_reverse_words_single_word_and_empty() {
+    assert_eq!(reverse_words("hello"), "hello");
+    assert_eq!(reverse_words(" hello\n"), " hello\n");
+    assert_eq!(reverse_words(""), "");
+  }
+
+  #[test]
+  fn test_reverse_words_cjk() {
+    assert_eq!(reverse_words("你好 世界"), "世界 你好");
+    // 全角空格 U+3000 也是空白
+    // The ideographic space U+3000 is whitespace too
+    assert_eq!(
+      reverse_words("東京\u{3000}大阪  京都"),
+      "京都\u{3000}大阪  東京"
+    );
+  }
+
+  #[test]
+  fn test_reverse_words_collapsed() {
+    assert_eq!(reverse_words_collapsed("  hello   world "), "world hello");
+    assert_eq!(reverse_words_collapsed("a\tb\nc"), "c b a");
+    assert_eq!(reverse_words_collapsed("   "), "");
+    assert_eq!(reverse_words_collapsed(""), "");
+  }
 }
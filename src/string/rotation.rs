@@ -0,0 +1,185 @@
+use rust_algorithm::string::knuth_morris_pratt::kmp_search;
+
+/// 用 Booth 算法求字典序最小的旋转的起始字符索引；有多个时返回最小的索引，空字符串返回 0。
+/// Finds the starting char index of the lexicographically least rotation with Booth's algorithm,
+/// the smallest such index if there are several, or 0 for the empty string.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要求最小旋转的字符串，按字符比较。
+///   The string whose least rotation is wanted, compared by chars.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(least_rotation("bca"), 2);
+/// assert_eq!(least_rotation("abab"), 0);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// 在 s + s 上从左到右扫描，k 是目前找到的最小旋转的起点，并为从 k 开始的串维护 KMP 的失配函数。
+/// 失配时如果新字符更小，说明从更靠后的位置开始的旋转更小，k 随之移动，失配函数也相对新的 k 继续使用。
+/// The doubled string s + s is scanned left to right while k is the start of the least rotation
+/// found so far, and the KMP failure function of the string starting at k is maintained. When a
+/// mismatch reveals a smaller char, a rotation starting further right is smaller, so k moves
+/// there and the failure function carries on relative to the new k.
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space, n being the number of chars.
+/// 时间和空间复杂度均为 O(n)，n 是字符个数。
+pub fn least_rotation(s: &str) -> usize {
+  let chars: Vec<char> = s.chars().chain(s.chars()).collect();
+
+  // 失配函数，-1 表示没有边界
+  // The failure function, -1 meaning no border
+  let mut failure = vec![-1isize; chars.len()];
+  let mut k = 0;
+
+  for j in 1..chars.len() {
+    let mut i = failure[j - k - 1];
+
+    while i != -1 && chars[j] != chars[k + i as usize + 1] {
+      if chars[j] < chars[k + i as usize + 1] {
+        k = j - i as usize - 1;
+      }
+
+      i = failure[i as usize];
+    }
+
+    if i == -1 && chars[j] != chars[k] {
+      if chars[j] < chars[k] {
+        k = j;
+      }
+
+      failure[j - k] = -1;
+    } else {
+      failure[j - k] = i + 1;
+    }
+  }
+
+  k
+}
+
+/// 返回字典序最小的旋转本身，可以作为"互为旋转"这一等价关系的规范代表。
+/// Returns the lexicographically least rotation itself, a canonical representative for the
+/// "is a rotation of" equivalence.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(canonical_rotation("bca"), "abc");
+/// assert_eq!(canonical_rotation("cab"), canonical_rotation("abc"));
+/// ```
+pub fn canonical_rotation(s: &str) -> String {
+  let start = least_rotation(s);
+
+  s.chars().skip(start).chain(s.chars().take(start)).collect()
+}
+
+/// 判断 `a` 是否是 `b` 的某个旋转：长度相同，并且 `a` 出现在 `b + b` 中。
+/// Tells whether `a` is a rotation of `b`: they have the same length and `a` occurs in `b + b`.
+///
+/// 用 KMP 在 `b + b` 中搜索 `a`，而不是逐个比较所有旋转。两个空字符串互为旋转。
+/// The search for `a` in `b + b` uses KMP rather than comparing every rotation. Two empty
+/// strings are rotations of each other.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert!(is_rotation("bca", "abc"));
+/// assert!(!is_rotation("acb", "abc"));
+/// ```
+///
+/// # 复杂度 (Complexity)
+///
+/// O(n) time and space.
+/// 时间和空间复杂度均为 O(n)。
+pub fn is_rotation(a: &str, b: &str) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  a.is_empty() || !kmp_search(&[b, b].concat(), a).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  fn rotations(chars: &[char]) -> Vec<Vec<char>> {
+    (0..chars.len())
+      .map(|r| chars[r..].iter().chain(&chars[..r]).copied().collect())
+      .collect()
+  }
+
+  #[test]
+  fn basic_rotations() {
+    assert_eq!(least_rotation("bca"), 2);
+    assert_eq!(least_rotation("abc"), 0);
+    assert_eq!(canonical_rotation("bca"), "abc");
+    assert_eq!(canonical_rotation("cab"), "abc");
+    assert!(is_rotation("bca", "abc"));
+    assert!(is_rotation("abc", "cab"));
+    assert!(!is_rotation("acb", "abc"));
+  }
+
+  #[test]
+  fn repeated_periods() {
+    assert_eq!(least_rotation("abab"), 0);
+    assert_eq!(least_rotation("baba"), 1);
+    assert_eq!(least_rotation("aaaa"), 0);
+    assert_eq!(canonical_rotation("baba"), "abab");
+    assert!(is_rotation("abab", "baba"));
+    assert!(!is_rotation("aabb", "abab"));
+  }
+
+  #[test]
+  fn unequal_lengths() {
+    assert!(!is_rotation("ab", "aba"));
+    assert!(!is_rotation("abab", "ab"));
+    assert!(!is_rotation("", "a"));
+  }
+
+  #[test]
+  fn empty_strings() {
+    assert_eq!(least_rotation(""), 0);
+    assert_eq!(canonical_rotation(""), "");
+    assert!(is_rotation("", ""));
+  }
+
+  #[test]
+  fn multi_byte_chars() {
+    assert_eq!(least_rotation("éaé"), 1);
+    assert_eq!(canonical_rotation("éaé"), "aéé");
+    assert!(is_rotation("好你", "你好"));
+    assert!(!is_rotation("你你", "你好"));
+  }
+
+  #[test]
+  fn booth_matches_brute_force() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+      let chars: Vec<char> = (0..rng.gen_range(1..12))
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect();
+      let s: String = chars.iter().collect();
+      let all = rotations(&chars);
+      let expected = (0..chars.len()).min_by_key(|&r| &all[r]).unwrap();
+
+      assert_eq!(least_rotation(&s), expected, "{s}");
+
+      for rotation in &all {
+        let rotated: String = rotation.iter().collect();
+
+        assert!(is_rotation(&rotated, &s));
+        assert_eq!(canonical_rotation(&rotated), canonical_rotation(&s));
+      }
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,192 @@
+/// 计算字节串的 Z 数组：`z[i]` 是 `s` 与其后缀 `s[i..]` 的最长公共前缀的长度。
+/// Computes the Z-array of a byte string: `z[i]` is the length of the longest common prefix of
+/// `s` and its suffix `s[i..]`.
+///
+/// 约定 `z[0]` 等于 `s` 的长度，因为整个字符串与自身匹配。
+/// By convention `z[0]` is the length of `s`, since the whole string matches itself.
+///
+/// # 参数 (Arguments)
+///
+/// * `s` - 要分析的字节串。
+///   The byte string to analyse.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// assert_eq!(z_array(b"abacaba"), vec![7, 0, 1, 0, 3, 0, 1]);
+/// ```
+///
+/// # 算法概述 (Algorithm Overview)
+///
+/// The algorithm maintains the Z-box `[l, r)`, the rightmost segment found so far that matches a
+/// prefix of `s`. For a position `i` inside the box, `z[i - l]` already tells how far the match
+/// reaches, so comparisons only resume past `r`, which only ever moves right.
+/// 算法维护 Z-box `[l, r)`，即目前找到的与 `s` 的前缀相匹配的最靠右的区间。
+/// 对于位于区间内的位置 `i`，`z[i - l]` 已经给出了匹配的长度，只需从 `r` 之后继续比较，而 `r` 只会向右移动。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time complexity is O(n) and the space complexity is O(n) for the Z-array.
+/// 时间复杂度为 O(n)，Z 数组的空间复杂度为 O(n)。
+pub fn z_array(s: &[u8]) -> Vec<usize> {
+  let n = s.len();
+  let mut z = vec![0; n];
+
+  if n == 0 {
+    return z;
+  }
+
+  z[0] = n;
+
+  // Z-box [l, r)：s[l..r] 与 s[..r - l] 相同
+  // The Z-box [l, r): s[l..r] equals s[..r - l]
+  let (mut l, mut r) = (0, 0);
+
+  for i in 1..n {
+    // 位于 Z-box 内时复用已知结果，但不能超出 Z-box
+    // Inside the Z-box, reuse the known value without reaching past the box
+    if i < r {
+      z[i] = z[i - l].min(r - i);
+    }
+
+    // 从已知匹配的末尾继续逐字符比较
+    // Keep comparing character by character past the known match
+    while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+      z[i] += 1;
+    }
+
+    // 匹配超出了 Z-box，更新 Z-box
+    // The match reaches past the Z-box, so move the box
+    if i + z[i] > r {
+      l = i;
+      r = i + z[i];
+    }
+  }
+
+  z
+}
+
+/// 使用 Z 算法在文本中查找模式的所有出现位置，返回起始索引（字节索引），与 `knuth_morris_pratt` 相同。
+/// Finds all occurrences of the pattern in the text with the Z-algorithm and returns their start
+/// (byte) indices, like `knuth_morris_pratt` does.
+///
+/// # 参数 (Arguments)
+///
+/// * `text` - 要在其中搜索模式的文本字符串。
+///   The text string in which to search for the pattern.
+/// * `pattern` - 要在文本中搜索的模式字符串。
+///   The pattern string to search for within the text.
+///
+/// # 示例 (Examples)
+///
+/// ```
+/// let indices = z_search("ABABDABACDABABCABAB", "ABAB");
+/// assert_eq!(indices, vec![0, 10, 15]);
+/// ```
+///
+/// # 实现细节 (Implementation Details)
+///
+/// The function builds the Z-array of `pattern + '\x01' + text`; a position in the text part whose
+/// Z-value reaches the pattern length starts a match. The separator only has to split the two
+/// parts: a Z-value is compared with `>=` rather than `==`, so matches are still found correctly
+/// when the inputs themselves contain `'\x01'`.
+/// 函数构建 `pattern + '\x01' + text` 的 Z 数组，文本部分中 Z 值达到模式长度的位置就是一个匹配的起点。
+/// 分隔符只用于隔开两部分：比较时使用 `>=` 而不是 `==`，所以即使输入本身包含 `'\x01'` 也能正确匹配。
+///
+/// # 复杂度 (Complexity)
+///
+/// The time and space complexity are both O(n + m), where n is the length of the text and m is the
+/// length of the pattern.
+/// 时间复杂度和空间复杂度都是 O(n + m)，其中 n 是文本的长度，m 是模式的长度。
+pub fn z_search(text: &str, pattern: &str) -> Vec<usize> {
+  // 如果文本或模式为空，则返回一个空向量
+  // Return an empty vector if either the text or pattern is empty
+  if text.is_empty() || pattern.is_empty() {
+    return vec![];
+  }
+
+  let m = pattern.len();
+  let combined = [pattern.as_bytes(), b"\x01", text.as_bytes()].concat();
+  let z = z_array(&combined);
+
+  // 文本中位置 i 对应拼接串中的位置 m + 1 + i
+  // Text position i is position m + 1 + i in the combined string
+  (0..text.len()).filter(|&i| z[m + 1 + i] >= m).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn z_array_of_repeated_letter() {
+    assert_eq!(z_array(b"aaaaa"), vec![5, 4, 3, 2, 1]);
+  }
+
+  #[test]
+  fn z_array_of_abacaba() {
+    assert_eq!(z_array(b"abacaba"), vec![7, 0, 1, 0, 3, 0, 1]);
+  }
+
+  #[test]
+  fn z_array_of_empty() {
+    assert_eq!(z_array(b""), Vec::<usize>::new());
+  }
+
+  #[test]
+  fn each_letter_matches() {
+    assert_eq!(z_search("aaa", "a"), vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn a_few_separate_matches() {
+    assert_eq!(z_search("abababa", "ab"), vec![0, 2, 4]);
+  }
+
+  #[test]
+  fn one_match() {
+    assert_eq!(z_search("ABC ABCDAB ABCDABCDABDE", "ABCDABD"), vec![15]);
+  }
+
+  #[test]
+  fn lots_of_matches() {
+    assert_eq!(z_search("aaabaabaaaaa", "aa"), vec![0, 1, 4, 7, 8, 9, 10]);
+  }
+
+  #[test]
+  fn lots_of_intricate_matches() {
+    assert_eq!(z_search("ababababa", "aba"), vec![0, 2, 4, 6]);
+  }
+
+  #[test]
+  fn not_found0() {
+    assert_eq!(z_search("abcde", "f"), vec![]);
+  }
+
+  #[test]
+  fn not_found1() {
+    assert_eq!(z_search("abcde", "ac"), vec![]);
+  }
+
+  #[test]
+  fn not_found2() {
+    assert_eq!(z_search("ababab", "bababa"), vec![]);
+  }
+
+  #[test]
+  fn empty_string() {
+    assert_eq!(z_search("", "abcdef"), vec![]);
+    assert_eq!(z_search("abcdef", ""), vec![]);
+  }
+
+  #[test]
+  fn separator_in_input() {
+    assert_eq!(z_search("a\x01a\x01a", "a\x01a"), vec![0, 2]);
+    assert_eq!(z_search("\x01\x01\x01", "\x01"), vec![0, 1, 2]);
+  }
+}
+
+fn main() {
+  let indices = z_search("ABABDABACDABABCABAB", "ABAB");
+  assert_eq!(indices, vec![0, 10, 15]);
+}
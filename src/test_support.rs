@@ -0,0 +1,48 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+thread_local! {
+  static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 比较时计数的元素，供各个测试统计排序和查找执行的比较次数。
+///
+/// An element that counts the comparisons made on it, letting the tests measure how many
+/// comparisons a sort or a search performs. Only the ordering comparisons are counted, `==`
+/// is not. The counter is per thread, so tests running in parallel do not disturb each other.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Counted<T>(pub T);
+
+impl<T: PartialEq> PartialEq for Counted<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+impl<T: Eq> Eq for Counted<T> {}
+
+impl<T: Ord> PartialOrd for Counted<T> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: Ord> Ord for Counted<T> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    COMPARISONS.with(|count| count.set(count.get() + 1));
+    self.0.cmp(&other.0)
+  }
+}
+
+/// 把 `values` 中的每个元素包装为 [`Counted`]。 (Wraps every element of `values` in [`Counted`].)
+pub fn counted<T: Clone>(values: &[T]) -> Vec<Counted<T>> {
+  values.iter().cloned().map(Counted).collect()
+}
+
+/// 运行 `f`，返回其间 [`Counted`] 元素上发生的比较次数。
+/// (Runs `f` and returns the number of comparisons made on [`Counted`] elements meanwhile.)
+pub fn count_comparisons(f: impl FnOnce()) -> usize {
+  COMPARISONS.with(|count| count.set(0));
+  f();
+  COMPARISONS.with(|count| count.get())
+}
@@ -0,0 +1,100 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::time::Instant;
+
+use rand::Rng;
+use rust_algorithm::sorting::counting_sort::counting_sort_auto;
+use rust_algorithm::sorting::radix_sort::radix_sort;
+
+/// 统计当前线程分配次数的全局分配器。 (A global allocator counting allocations on the current thread.)
+struct CountingAllocator;
+
+thread_local! {
+  static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.with(|count| count.set(count.get() + 1));
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+  ALLOCATIONS.with(|count| count.get())
+}
+
+fn random_u64s(len: usize) -> Vec<u64> {
+  let mut rng = rand::thread_rng();
+  (0..len).map(|_| rng.gen()).collect()
+}
+
+/// `radix_sort` 只分配一个与输入等长的缓冲区，用来代替以前每一轮的 `to_owned()`；
+/// 另外还有一个 O(radix) 的计数表，同样只分配一次并在每一轮中复用。
+/// 所以无论需要多少轮，每次排序都正好分配两次。
+///
+/// `radix_sort` allocates a single scratch buffer as long as the input, replacing the former
+/// `to_owned()` of every digit pass. The other allocation is the O(radix) table of digit
+/// counts, which is also allocated once and reused by every pass. So every sort makes exactly
+/// two allocations, however many passes it takes.
+#[test]
+fn one_buffer_and_one_counter_per_sort() {
+  let mut v = random_u64s(1_000_000);
+
+  let before = allocations();
+  radix_sort(&mut v);
+  let after = allocations();
+
+  assert!(v.windows(2).all(|pair| pair[0] <= pair[1]));
+  assert_eq!(after - before, 2);
+
+  // 只需一轮的小值同样是两次分配 (Small values needing a single pass allocate twice as well)
+  let mut v: Vec<u64> = random_u64s(1_000_000).iter().map(|x| x % 1000).collect();
+
+  let before = allocations();
+  radix_sort(&mut v);
+  let after = allocations();
+
+  assert!(v.windows(2).all(|pair| pair[0] <= pair[1]));
+  assert_eq!(after - before, 2);
+}
+
+#[test]
+fn counting_sort_auto_empty_does_not_allocate() {
+  let mut v: Vec<u32> = vec![];
+
+  let before = allocations();
+  counting_sort_auto(&mut v);
+
+  assert_eq!(allocations(), before);
+}
+
+#[test]
+#[ignore]
+fn throughput_1e6_u64() {
+  let mut v = random_u64s(1_000_000);
+  let mut expected = v.clone();
+
+  let start = Instant::now();
+  radix_sort(&mut v);
+  let radix = start.elapsed();
+
+  let start = Instant::now();
+  expected.sort_unstable();
+  let std = start.elapsed();
+
+  assert_eq!(v, expected);
+  println!(
+    "radix_sort: {:?} ({:.1} Melem/s), sort_unstable: {:?}",
+    radix,
+    1.0 / radix.as_secs_f64(),
+    std
+  );
+}
@@ -0,0 +1,103 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use rust_algorithm::sorting::bubble_sort::{bubble_sort, bubble_sort_without_flag};
+use rust_algorithm::sorting::cocktail_shaker_sort::cocktail_shaker_sort;
+use rust_algorithm::sorting::comb_sort::comb_sort;
+use rust_algorithm::sorting::counting_sort::is_sorted;
+use rust_algorithm::sorting::gnome_sort::gnome_sort;
+use rust_algorithm::sorting::heap_sort::{heap_sort, partial_sort};
+use rust_algorithm::sorting::insertion_sort::{
+  binary_insertion_sort, insertion_sort, insertion_sort_binary_search,
+};
+use rust_algorithm::sorting::merge_sort::merge_sort;
+use rust_algorithm::sorting::odd_even_sort::odd_even_sort;
+use rust_algorithm::sorting::quick_sort::quick_sort;
+use rust_algorithm::sorting::radix_sort::radix_sort_i32;
+use rust_algorithm::sorting::selection_sort::{double_selection_sort, selection_sort};
+use rust_algorithm::sorting::shell_sort::{shell_sort, shell_sort_with_gaps, GapSequence};
+use rust_algorithm::sorting::stooge_sort::stooge_sort;
+use rust_algorithm::sorting::tim_sort::tim_sort;
+
+// 排序的三个性质：结果有序、结果是输入的一个排列、再次排序不改变结果
+// (The three properties of a sort: the output is sorted, it is a permutation of the input,
+// and sorting it again changes nothing)
+fn check_sort<T, F>(input: &[T], sort: F) -> Result<(), TestCaseError>
+where
+  T: Ord + Clone + std::fmt::Debug,
+  F: Fn(&mut [T]),
+{
+  let mut output = input.to_vec();
+  sort(&mut output);
+  prop_assert!(is_sorted(&output), "not sorted: {:?}", output);
+
+  let mut expected = input.to_vec();
+  expected.sort();
+  prop_assert_eq!(&output, &expected);
+
+  let mut again = output.clone();
+  sort(&mut again);
+  prop_assert_eq!(&again, &output);
+
+  Ok(())
+}
+
+// 每行注册一个排序：`模块名 => 排序函数`，生成 `Vec<i32>` 和 `Vec<String>` 两个测试
+// (Register one sort per line as `module_name => sort`, which generates one `Vec<i32>` and one
+// `Vec<String>` test)
+macro_rules! sort_properties {
+  ($($name:ident => $sort:expr),* $(,)?) => {
+    $(
+      mod $name {
+        use super::*;
+
+        proptest! {
+          #[test]
+          fn ints(input in vec(any::<i32>(), 0..100)) {
+            check_sort(&input, $sort)?;
+          }
+
+          #[test]
+          fn small_ints(input in vec(-5..5i32, 0..100)) {
+            check_sort(&input, $sort)?;
+          }
+
+          #[test]
+          fn strings(input in vec("[a-c]{0,3}", 0..50)) {
+            check_sort(&input, $sort)?;
+          }
+        }
+      }
+    )*
+  };
+}
+
+sort_properties! {
+  bubble => bubble_sort,
+  bubble_without_flag => bubble_sort_without_flag,
+  cocktail_shaker => cocktail_shaker_sort,
+  comb => comb_sort,
+  gnome => gnome_sort,
+  heap => heap_sort,
+  partial_full => |arr| partial_sort(arr, usize::MAX),
+  insertion => insertion_sort,
+  insertion_binary_search => insertion_sort_binary_search,
+  binary_insertion => binary_insertion_sort,
+  merge => merge_sort,
+  odd_even => odd_even_sort,
+  quick => quick_sort,
+  selection => selection_sort,
+  double_selection => double_selection_sort,
+  shell => shell_sort,
+  shell_halving => |arr| shell_sort_with_gaps(arr, GapSequence::Shell),
+  shell_knuth => |arr| shell_sort_with_gaps(arr, GapSequence::Knuth),
+  stooge => stooge_sort,
+  tim => tim_sort,
+}
+
+// 只能对整数排序的算法 (Sorts that only handle integers)
+proptest! {
+  #[test]
+  fn radix_i32(input in vec(any::<i32>(), 0..200)) {
+    check_sort(&input, radix_sort_i32)?;
+  }
+}